@@ -8,6 +8,12 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use vct_core::analysis::{HourZone, PathStyle, ToolVersionReq};
+use vct_core::pricing::ThresholdBasis;
+use vct_core::usage::{GroupBy, ModelBudget, SplitPeriod, WebhookFormat};
+use vct_core::utils::{DedupeBy, ModelFixup, TextEncoding};
+use vct_tui::display::common::{CostThresholds, DEFAULT_MODEL_WIDTH, SummaryFormat};
+use vct_tui::display::usage::CalendarMetric;
 
 // `TimeRange` and the period-flag resolvers are core domain logic (no clap
 // types), so they live in `models::filter`; re-exported here for the clap layer
@@ -31,7 +37,12 @@ pub enum QuotaProvider {
 #[derive(Parser, Debug)]
 #[command(name = "vibe_coding_tracker")]
 #[command(author, version = vct_core::VERSION, about, long_about = None)]
+#[command(after_help = crate::exit::EXIT_CODES_HELP)]
 pub struct Cli {
+    /// Print every warning the run collected instead of only their count.
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
     /// The subcommand to run.
     #[command(subcommand)]
     pub command: Commands,
@@ -45,6 +56,7 @@ pub enum Commands {
         /// JSONL or JSON session file to analyze; prints complete JSON by default.
         #[arg(
             value_name = "FILE",
+            value_parser = existing_path,
             conflicts_with_all = ["daily", "weekly", "monthly", "all"]
         )]
         file: Option<PathBuf>,
@@ -61,6 +73,260 @@ pub enum Commands {
         #[arg(long, group = "analysis_format")]
         table: bool,
 
+        /// Check the parsed sessions against their own invariants (detail sums
+        /// match totals, token counts are non-negative, …) and list every
+        /// violation; exits nonzero when any are found. Combine with `--json`
+        /// for a JSON array.
+        #[arg(long, conflicts_with_all = ["text", "table"])]
+        validate: bool,
+
+        /// Classify every discovered session file by content only (no full
+        /// parse) and print how many each provider claims, including
+        /// `Unknown`, as a table (or `--json`).
+        #[arg(long, conflicts_with_all = ["file", "text", "validate"])]
+        provider_detect_stats: bool,
+
+        /// Count the distinct session files started per day (by file date,
+        /// no parsing) and print them as a bar-chart table, or a
+        /// `{"date": count}` object with `--json`.
+        #[arg(
+            long,
+            conflicts_with_all = ["file", "text", "validate", "provider_detect_stats"]
+        )]
+        sessions_per_day: bool,
+
+        /// Count the API error events (rate limits, overloads, other) the
+        /// sessions logged and print them per provider as a table (or
+        /// `--json`). Honors `--tool-version` and `--files-from`.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "file", "text", "validate", "provider_detect_stats", "sessions_per_day"
+            ]
+        )]
+        error_report: bool,
+
+        /// List, for every session whose folder is in a git repository, the
+        /// commits `git log` finds between the session's first operation and
+        /// its last activity, next to the lines it wrote; as a table (or
+        /// `--json`). Honors `--tool-version` and `--files-from`.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "file", "text", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report"
+            ]
+        )]
+        correlate_git: bool,
+
+        /// Count the file operations and shell commands recorded in each
+        /// hour of the day (0–23) across all sessions, by each operation's
+        /// own timestamp, and print them as a bar-chart table (or `--json`).
+        /// Honors `--tool-version` and `--files-from`.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "file", "text", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report", "correlate_git"
+            ]
+        )]
+        hod: bool,
+
+        /// Zone `--hod` reads hours in: `local` (the default), `utc`, or a
+        /// fixed offset such as `+08:00`.
+        #[arg(long, value_name = "ZONE", requires = "hod")]
+        timezone: Option<HourZone>,
+
+        /// Keep only sessions whose assistant CLI version satisfies a semver
+        /// range (e.g. `>=1.0.90, <1.1`). Sessions without a recorded version
+        /// are dropped unless `--include-unversioned` is given. Without a
+        /// format flag the result prints as the static table.
+        #[arg(
+            long,
+            value_name = "RANGE",
+            conflicts_with_all = ["file", "provider_detect_stats", "sessions_per_day"]
+        )]
+        tool_version: Option<ToolVersionReq>,
+
+        /// With `--tool-version`, also keep sessions that record no version
+        /// (Gemini, Grok, and database-backed providers).
+        #[arg(long, requires = "tool_version")]
+        include_unversioned: bool,
+
+        /// Add `uniqueReadLines` to every JSON record: read lines counting
+        /// each distinct file once, at its largest read. `totalReadLines` is
+        /// unchanged. Implies JSON output.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "text", "table", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report", "correlate_git", "hod"
+            ]
+        )]
+        dedupe_reads: bool,
+
+        /// Rewrite every read/write/edit `filePath` in JSON output to one
+        /// form: `absolute` (joined onto `folderPath`), `relative` (to
+        /// `folderPath`; paths outside it stay absolute), or `basename`.
+        /// Implies JSON output.
+        #[arg(
+            long,
+            value_name = "STYLE",
+            conflicts_with_all = [
+                "text", "table", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report", "correlate_git", "hod"
+            ]
+        )]
+        normalize_paths: Option<PathStyle>,
+
+        /// Add `rawSource` to every JSON record: a base64 copy of the session
+        /// file it was parsed from, for auditing. Output grows by the size
+        /// of every session file and carries its full contents (prompts,
+        /// code, any secrets pasted into a session). Implies JSON output.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "text", "table", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report", "correlate_git", "hod"
+            ]
+        )]
+        embed_raw: bool,
+
+        /// Print only the totals (lines and tool calls summed over every
+        /// model, plus the model count) instead of the per-model rows; with
+        /// `--json`, just the totals object.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "file", "validate", "provider_detect_stats", "sessions_per_day",
+                "error_report", "correlate_git", "hod", "dedupe_reads", "normalize_paths",
+                "embed_raw"
+            ]
+        )]
+        summary_only: bool,
+
+        /// Re-aggregate every `--interval` seconds and append one JSON line
+        /// per tick to `--output`: a timestamp plus the per-model counter
+        /// changes since the previous tick (the first line is the baseline).
+        /// Idle ticks write nothing. Runs until interrupted with Ctrl-C.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "file", "json", "text", "table", "validate", "provider_detect_stats",
+                "sessions_per_day", "error_report", "correlate_git", "hod", "tool_version",
+                "dedupe_reads", "normalize_paths", "embed_raw", "summary_only", "files_from",
+                "fail_on_empty"
+            ]
+        )]
+        keep_running: bool,
+
+        /// Seconds between `--keep-running` ticks. Defaults to
+        /// `[analysis] refresh_interval`.
+        #[arg(
+            long,
+            value_name = "SECS",
+            requires = "keep_running",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        interval: Option<u64>,
+
+        /// Log file `--keep-running` appends to; created if missing.
+        #[arg(
+            long,
+            value_name = "PATH",
+            requires = "keep_running",
+            required_if_eq("keep_running", "true")
+        )]
+        output: Option<PathBuf>,
+
+        /// Blank the `machineId` field in JSON output. Also enabled by
+        /// `VCT_DISABLE_MACHINE_ID=1` or `[analysis] hide_machine_id`.
+        #[arg(long)]
+        no_machine_id: bool,
+
+        /// Analyze exactly the session files listed one per line in `PATH`
+        /// (`-` reads the list from stdin, e.g. from `fd -e jsonl | vct
+        /// analysis --files-from -`) instead of discovering them. Each
+        /// file's provider is detected from its content; without a format
+        /// flag the result prints as the static table.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = [
+                "file", "provider_detect_stats", "sessions_per_day",
+                "daily", "weekly", "monthly", "all"
+            ]
+        )]
+        files_from: Option<PathBuf>,
+
+        /// Skip discovered session files matching the gitignore-style globs
+        /// in `PATH`, instead of the default `~/.vct/.vctignore`.
+        #[arg(
+            long,
+            value_name = "PATH",
+            value_parser = existing_path,
+            conflicts_with = "file"
+        )]
+        ignore_file: Option<PathBuf>,
+
+        /// Exit with code 2 when the selected range holds no sessions
+        /// (`--json`, `--text`, `--table`; the output is still printed).
+        #[arg(long, conflicts_with = "file")]
+        fail_on_empty: bool,
+
+        /// How to read session files that are not valid UTF-8: `utf8`
+        /// (default) fails naming the file and byte offset, `lossy` replaces
+        /// invalid sequences and logs a warning.
+        #[arg(long, value_name = "ENCODING")]
+        encoding: Option<TextEncoding>,
+
+        /// Merge models whose names differ only in case (`GPT-4o` and
+        /// `gpt-4o`) into one row, shown under the lowercased name.
+        #[arg(long)]
+        fold_case: bool,
+
+        /// Count a repeated token event once: `message-id` skips the usage
+        /// of a Claude message logged again under the same `message.id`
+        /// (a retried turn, or one record per content block).
+        #[arg(long, value_name = "KEY")]
+        dedupe_by: Option<DedupeBy>,
+
+        /// Rename models in the rows before they are merged: `FROM=TO`, where
+        /// FROM is an exact name or a whole-name regex between slashes
+        /// (`/claude-3-5-sonnet-\d{8}/=claude-3-5-sonnet`). Repeatable; the
+        /// first matching rule wins.
+        #[arg(long = "fixup-model", value_name = "FROM=TO")]
+        fixup_model: Vec<ModelFixup>,
+
+        /// Show well-known models under friendly names (`Claude 3.5 Sonnet`
+        /// for `claude-3-5-sonnet-20241022`) in tables and text; JSON keeps
+        /// the raw ids. `[display] model_names` in the config adds or
+        /// overrides names.
+        #[arg(long)]
+        pretty_models: bool,
+
+        /// Cut model names wider than `COLS` columns to fit, ending in `…`,
+        /// in tables and the TUI. JSON and `--text` keep the full name.
+        #[arg(
+            long,
+            value_name = "COLS",
+            default_value_t = DEFAULT_MODEL_WIDTH as u16,
+            value_parser = clap::value_parser!(u16).range(4..)
+        )]
+        model_width: u16,
+
+        /// Start the interactive TUI in its compact layout: the table and a
+        /// one-line status, without the provider band or summary bar. Press
+        /// `c` to switch layouts.
+        #[arg(long, conflicts_with_all = ["file", "analysis_format"])]
+        compact_tui: bool,
+
+        /// What the interactive TUI's summary bar leads with: `cost`,
+        /// `tokens` (lines, in this view), or `balanced` (default). The lead
+        /// metric comes first and stays bold.
+        #[arg(long, value_name = "FORMAT", conflicts_with_all = ["file", "analysis_format"])]
+        summary_format: Option<SummaryFormat>,
+
         /// Show only today's data.
         #[arg(long, group = "period")]
         daily: bool,
@@ -98,6 +364,310 @@ pub enum Commands {
         #[arg(long)]
         merge_providers: bool,
 
+        /// Add a per-row count of the distinct sessions that contributed
+        /// usage (the TUI, `--table`, `--text`, and `--group-by`; `--json`
+        /// always carries `sessions`).
+        #[arg(long)]
+        show_sessions: bool,
+
+        /// Fold every model costing less than `PCT` percent of the total into
+        /// one `Other (N models)` row (`--table` / `--text`); the grand total
+        /// is unchanged. Ignored for `--json`.
+        #[arg(long, value_name = "PCT", value_parser = percentage)]
+        group_threshold: Option<f64>,
+
+        /// Fast rough numbers: price from whatever pricing cache exists (never
+        /// fetch) at base rates, skipping context-tier classification. Sources
+        /// are parsed and bucketed by date exactly as in a normal scan; only
+        /// pricing is approximated. Output is labeled as an estimate; without
+        /// a format flag it prints the static table.
+        #[arg(long)]
+        estimate_only: bool,
+
+        /// Which tokens of a request are compared against a model's
+        /// long-context price threshold: `total_input` (prompt incl. cache),
+        /// `total` (prompt + output), or `per_type` (each token type on its
+        /// own). Overrides `usage.threshold_basis` in the config.
+        #[arg(long, value_name = "BASIS", conflicts_with = "estimate_only")]
+        threshold_basis: Option<ThresholdBasis>,
+
+        /// Compare two saved `usage --json` exports: per-model and total token
+        /// and cost deltas from the first file to the second. Prints a table by
+        /// default, one line per model with `--text`, or JSON with `--json`.
+        #[arg(
+            long,
+            num_args = 2,
+            value_names = ["BEFORE", "AFTER"],
+            value_parser = existing_path,
+            conflicts_with_all = ["estimate_only", "threshold_basis", "models_without_usage"]
+        )]
+        diff: Option<Vec<PathBuf>>,
+
+        /// Audit a pricing/override JSON file: list its model keys that no
+        /// session of any period or provider used under exactly that name
+        /// (one per line, or a JSON array with `--json`).
+        #[arg(
+            long,
+            value_name = "FILE",
+            value_parser = existing_path,
+            conflicts_with_all = ["text", "table"]
+        )]
+        models_without_usage: Option<PathBuf>,
+
+        /// Run the usage and analysis scans side by side and list every
+        /// date and model whose token totals disagree (or `consistent`); a
+        /// JSON array with `--json`. Catches accounting bugs in either
+        /// pipeline.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "table", "estimate_only", "diff", "models_without_usage", "files_from"
+            ]
+        )]
+        cross_check: bool,
+
+        /// Token difference `--cross-check` still treats as agreement.
+        /// Defaults to 0.
+        #[arg(long, value_name = "TOKENS", requires = "cross_check")]
+        cross_check_tolerance: Option<u64>,
+
+        /// Sum usage per composite key instead of per model: a comma-separated
+        /// list of `date`, `provider`, and `model` (e.g. `date,provider` gives
+        /// one row per provider per day). Renders a table, or `--text` /
+        /// `--json`.
+        #[arg(
+            long,
+            value_name = "KEYS",
+            conflicts_with_all = ["diff", "models_without_usage", "cross_check"]
+        )]
+        group_by: Option<GroupBy>,
+
+        /// Add a `cost_breakdown` to each `--json` row: cost per token type,
+        /// each tagged with the tier it was billed at (`base` or
+        /// `above_200k`).
+        #[arg(
+            long,
+            requires = "json",
+            conflicts_with_all = ["diff", "models_without_usage", "cross_check", "group_by"]
+        )]
+        cost_breakdown: bool,
+
+        /// Print only the footer figures (total cost, today's cost, total
+        /// tokens, model count) instead of the per-model table; with
+        /// `--json`, just that object.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "diff", "models_without_usage", "cross_check", "group_by", "cost_breakdown"
+            ]
+        )]
+        summary_only: bool,
+
+        /// Draw a heatmap of daily activity instead of the per-model table:
+        /// one column per week, one row per weekday, each day shaded by its
+        /// cost relative to the busiest day (`--by tokens` for tokens).
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "json", "text", "table", "diff", "models_without_usage", "cross_check",
+                "group_by", "cost_breakdown", "summary_only"
+            ]
+        )]
+        calendar: bool,
+
+        /// What `--calendar` shades: `cost` (default) or `tokens`.
+        #[arg(long, value_name = "METRIC", requires = "calendar")]
+        by: Option<CalendarMetric>,
+
+        /// Cap a model's daily cost: `MODEL=USD`, where MODEL matches any
+        /// model whose name contains it (`opus=10`). Lists each capped
+        /// model's spend per day, flagging days over the cap (red in the
+        /// table, `over_model_budget` in JSON). Repeatable.
+        #[arg(
+            long = "model-budget",
+            value_name = "MODEL=USD",
+            conflicts_with_all = [
+                "diff", "models_without_usage", "cross_check", "group_by", "cost_breakdown",
+                "summary_only", "calendar"
+            ]
+        )]
+        model_budget: Vec<ModelBudget>,
+
+        /// Print one table per ISO `week` or calendar `month` instead of the
+        /// per-model table, each listing that period's usage per day and
+        /// model with a subtotal, followed by the grand total.
+        #[arg(
+            long,
+            value_name = "PERIOD",
+            conflicts_with_all = [
+                "json", "text", "diff", "models_without_usage", "cross_check", "group_by",
+                "cost_breakdown", "summary_only", "calendar", "model_budget"
+            ]
+        )]
+        split_by: Option<SplitPeriod>,
+
+        /// After printing, POST the results to `URL` (a Slack or Teams
+        /// incoming webhook, say): the `--json` rows, or a compact summary
+        /// with `--webhook-format summary`. The HTTP status is reported on
+        /// stderr; a failed POST is a warning unless `--webhook-required`.
+        /// Without a format flag the result prints as the static table.
+        #[arg(
+            long,
+            value_name = "URL",
+            conflicts_with_all = ["diff", "models_without_usage", "cross_check", "live_total"]
+        )]
+        webhook: Option<String>,
+
+        /// What `--webhook` posts: `json` (default, the `--json` rows) or
+        /// `summary` (the footer totals plus a one-line `text` message).
+        #[arg(long, value_name = "FORMAT", requires = "webhook")]
+        webhook_format: Option<WebhookFormat>,
+
+        /// Seconds to wait for the webhook before giving up. Defaults to 10.
+        #[arg(
+            long,
+            value_name = "SECS",
+            requires = "webhook",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        webhook_timeout: Option<u64>,
+
+        /// Fail the command when the `--webhook` POST fails instead of only
+        /// warning.
+        #[arg(long, requires = "webhook")]
+        webhook_required: bool,
+
+        /// Keep one line, `today: $X.XX (N tokens)`, updated in place every
+        /// `--interval` seconds instead of drawing the TUI; suited to a
+        /// status bar. Always covers today. Runs until interrupted with
+        /// Ctrl-C.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "json", "text", "table", "estimate_only", "diff", "models_without_usage",
+                "cross_check", "group_by", "cost_breakdown", "summary_only", "calendar",
+                "model_budget", "split_by", "files_from",
+                "fail_on_empty", "weekly", "monthly", "all"
+            ]
+        )]
+        live_total: bool,
+
+        /// Seconds between `--live-total` updates. Defaults to
+        /// `[usage] refresh_interval`.
+        #[arg(
+            long,
+            value_name = "SECS",
+            requires = "live_total",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        interval: Option<u64>,
+
+        /// Breakpoints in USD coloring today's cost in the table footer and
+        /// the TUI summary: green below `LOW`, yellow below `HIGH`, red
+        /// otherwise. Defaults to `5,20`.
+        #[arg(long, value_name = "LOW,HIGH")]
+        cost_thresholds: Option<CostThresholds>,
+
+        /// Minimum Jaro-Winkler similarity (0-1) for a fuzzy pricing match to
+        /// be shown as `model (matched)` in the table; weaker matches show the
+        /// bare model in a warning color. Defaults to 0.9.
+        #[arg(long, value_name = "SIMILARITY", value_parser = unit_interval)]
+        fuzzy_hint_threshold: Option<f64>,
+
+        /// Total exactly the session files listed one per line in `PATH`
+        /// (`-` reads the list from stdin) instead of discovering them. Each
+        /// file's provider is detected from its content; without a format
+        /// flag the result prints as the static table.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = [
+                "diff", "models_without_usage", "daily", "weekly", "monthly", "all"
+            ]
+        )]
+        files_from: Option<PathBuf>,
+
+        /// Skip discovered session files matching the gitignore-style globs
+        /// in `PATH`, instead of the default `~/.vct/.vctignore`.
+        #[arg(
+            long,
+            value_name = "PATH",
+            value_parser = existing_path,
+            conflicts_with = "diff"
+        )]
+        ignore_file: Option<PathBuf>,
+
+        /// Exit with code 2 when the selected range holds no usage
+        /// (`--json`, `--text`, `--table`; the output is still printed).
+        #[arg(long, conflicts_with_all = ["diff", "models_without_usage"])]
+        fail_on_empty: bool,
+
+        /// Suppress informational notes on stderr, such as where vct looked
+        /// for providers whose session directories do not exist.
+        #[arg(long)]
+        quiet: bool,
+
+        /// How to read session files that are not valid UTF-8: `utf8`
+        /// (default) fails naming the file and byte offset, `lossy` replaces
+        /// invalid sequences and logs a warning.
+        #[arg(long, value_name = "ENCODING")]
+        encoding: Option<TextEncoding>,
+
+        /// Merge models whose names differ only in case (`GPT-4o` and
+        /// `gpt-4o`) into one row, shown under the lowercased name.
+        #[arg(long)]
+        fold_case: bool,
+
+        /// Count a repeated token event once: `message-id` skips the usage
+        /// of a Claude message logged again under the same `message.id`
+        /// (a retried turn, or one record per content block).
+        #[arg(long, value_name = "KEY")]
+        dedupe_by: Option<DedupeBy>,
+
+        /// Rename models in the rows once each is priced under its reported
+        /// name, merging rows that end up with one name: `FROM=TO`, where
+        /// FROM is an exact name or a whole-name regex between slashes
+        /// (`/claude-3-5-sonnet-\d{8}/=claude-3-5-sonnet`). Repeatable; the
+        /// first matching rule wins.
+        #[arg(long = "fixup-model", value_name = "FROM=TO")]
+        fixup_model: Vec<ModelFixup>,
+
+        /// Price models no pricing tier matches at the average of the priced
+        /// models in their family (Claude Sonnet, Gemini Flash, ...). Such
+        /// rows are flagged `estimated: true` in JSON and drawn in blue in the
+        /// table.
+        #[arg(long, conflicts_with = "estimate_only")]
+        estimate_missing_pricing: bool,
+
+        /// Show well-known models under friendly names (`Claude 3.5 Sonnet`
+        /// for `claude-3-5-sonnet-20241022`) in tables and text; JSON keeps
+        /// the raw ids. `[display] model_names` in the config adds or
+        /// overrides names.
+        #[arg(long)]
+        pretty_models: bool,
+
+        /// Cut model names wider than `COLS` columns to fit, ending in `…`,
+        /// in tables and the TUI. JSON and `--text` keep the full name.
+        #[arg(
+            long,
+            value_name = "COLS",
+            default_value_t = DEFAULT_MODEL_WIDTH as u16,
+            value_parser = clap::value_parser!(u16).range(4..)
+        )]
+        model_width: u16,
+
+        /// Start the interactive TUI in its compact layout: the table and a
+        /// one-line status, without the quota band or summary bar. Press `c`
+        /// to switch layouts.
+        #[arg(long, conflicts_with = "usage_format")]
+        compact_tui: bool,
+
+        /// What the interactive TUI's summary bar leads with: `cost`,
+        /// `tokens`, or `balanced` (default). The lead metric comes first and
+        /// stays bold.
+        #[arg(long, value_name = "FORMAT", conflicts_with = "usage_format")]
+        summary_format: Option<SummaryFormat>,
+
         /// Show only today's data.
         #[arg(long, group = "period")]
         daily: bool,
@@ -115,6 +685,16 @@ pub enum Commands {
         all: bool,
     },
 
+    /// One-screen overview drawn from both `usage` and `analysis`.
+    ///
+    /// Shows today's and this month's cost, all-time tokens per provider,
+    /// lines written today, the top model, and active days.
+    Stats {
+        /// Output the overview as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Display version information.
     Version {
         /// Output as JSON.
@@ -124,6 +704,11 @@ pub enum Commands {
         /// Output as plain text.
         #[arg(long)]
         text: bool,
+
+        /// Write the `--json` version info to this file instead of stdout
+        /// (e.g. to keep build provenance with CI artifacts).
+        #[arg(long, value_name = "PATH", requires = "json", conflicts_with = "text")]
+        output: Option<PathBuf>,
     },
 
     /// Update to the latest version from GitHub releases.
@@ -135,6 +720,17 @@ pub enum Commands {
         /// Force update without confirmation prompt.
         #[arg(long, short)]
         force: bool,
+
+        /// Show the newer release's notes (rendered as markdown on a
+        /// terminal, raw text when piped) before the prompt or with `--check`.
+        #[arg(long, conflicts_with = "force")]
+        preview: bool,
+
+        /// With `--check`, print `{"current", "latest", "update_available",
+        /// "tag"}` as JSON instead of text; a failed check prints
+        /// `{"error"}` and exits non-zero.
+        #[arg(long, requires = "check", conflicts_with = "preview")]
+        json: bool,
     },
 
     /// Fetch a provider's raw quota/usage API response.
@@ -164,6 +760,32 @@ pub enum Commands {
         #[command(subcommand)]
         action: Option<ConfigAction>,
     },
+
+    /// Print the supported providers as JSON: display name, default session
+    /// locations, recognized session files, and usage/analysis support.
+    Providers,
+
+    /// Contributor tooling: verify (or regenerate) the parser golden files.
+    ///
+    /// Re-analyzes the session next to every `<name>.expected.json` and
+    /// prints pass/fail per fixture; exits non-zero if any fixture fails.
+    /// With `--bench-matcher`, times the pricing matcher instead.
+    #[command(hide = true)]
+    Dev {
+        /// Rewrite each golden from the current parser output instead of
+        /// verifying it.
+        #[arg(long = "regen")]
+        regen_goldens: bool,
+
+        /// Time uncached pricing lookups of every model the local sessions
+        /// used, reporting min/avg/max and which tier matched each model.
+        #[arg(long, conflicts_with_all = ["regen_goldens", "fixtures"])]
+        bench_matcher: bool,
+
+        /// Directory holding the goldens and their session files.
+        #[arg(long, value_name = "DIR", default_value = "tests/fixtures/sessions")]
+        fixtures: PathBuf,
+    },
 }
 
 /// Actions for the `config` subcommand.
@@ -171,6 +793,9 @@ pub enum Commands {
 pub enum ConfigAction {
     /// Print the config file path.
     Path,
+    /// Check that the config file parses and sets no unknown key, printing
+    /// the error with the offending line or the unknown keys when it does not.
+    Validate,
     /// Print the current settings (default).
     Show,
     /// Open the config file in `$VISUAL` / `$EDITOR`.
@@ -186,3 +811,31 @@ pub enum ConfigAction {
     /// the same pass so a schema-aware editor sees the upgraded file right away.
     Migrate,
 }
+
+/// clap value parser for a fraction in `0.0..=1.0`.
+fn unit_interval(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(fraction) if (0.0..=1.0).contains(&fraction) => Ok(fraction),
+        _ => Err(format!("`{value}` is not a number between 0 and 1")),
+    }
+}
+
+/// clap value parser for `--group-threshold`: a percentage from 0 to 100,
+/// with or without a trailing `%`.
+fn percentage(value: &str) -> Result<f64, String> {
+    match value.trim_end_matches('%').parse::<f64>() {
+        Ok(pct) if (0.0..=100.0).contains(&pct) => Ok(pct),
+        _ => Err(format!("`{value}` is not a percentage between 0 and 100")),
+    }
+}
+
+/// clap value parser for path arguments that must name an existing file, so a
+/// typo is rejected as an invalid argument instead of failing mid-run.
+fn existing_path(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(format!("no such file: {value}"))
+    }
+}
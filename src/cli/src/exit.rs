@@ -0,0 +1,75 @@
+//! The process exit-code contract and the mapping from a failed run onto it.
+//!
+//! Scripts rely on these codes staying stable; the same table is rendered as
+//! the `--help` epilog ([`EXIT_CODES_HELP`]) and documented in the README.
+
+use std::fmt;
+
+/// Marker for a required network request refused because `VCT_OFFLINE` is
+/// set; core returns it from the paths that cannot fall back to cached data.
+pub use vct_core::utils::Offline;
+
+/// The command completed.
+pub const SUCCESS: u8 = 0;
+/// Any runtime failure without a more specific code below.
+pub const RUNTIME_ERROR: u8 = 1;
+/// `--fail-on-empty` was given and the selected range produced no data.
+pub const NO_DATA: u8 = 2;
+/// The command line was rejected: unknown flag, bad value, missing file.
+pub const INVALID_ARGUMENTS: u8 = 3;
+/// A required network request failed (update check/download, quota, pricing,
+/// webhook) or was refused because `VCT_OFFLINE` is set.
+pub const NETWORK_ERROR: u8 = 4;
+
+/// `--help` epilog listing every exit code.
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  Success
+  1  Runtime error
+  2  No data in the selected range (with --fail-on-empty)
+  3  Invalid arguments (unknown flag, bad value, nonexistent path)
+  4  Network error (update check, quota, required pricing fetch or webhook),
+     including a required request refused under VCT_OFFLINE";
+
+/// Marker error for an empty result under `--fail-on-empty`.
+#[derive(Debug)]
+pub struct NoData;
+
+impl fmt::Display for NoData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no data found in the selected time range")
+    }
+}
+
+impl std::error::Error for NoData {}
+
+/// Marker error for bad user input found after clap parsing, such as a
+/// nonexistent path inside a `--files-from` list.
+#[derive(Debug)]
+pub struct InvalidArgument(pub String);
+
+impl fmt::Display for InvalidArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidArgument {}
+
+/// Maps a failed run onto the exit-code contract.
+///
+/// The whole `anyhow` chain is inspected, so the markers still match after
+/// callers wrap them in extra context.
+pub fn exit_code_for(error: &anyhow::Error) -> u8 {
+    if error.chain().any(|cause| cause.is::<NoData>()) {
+        NO_DATA
+    } else if error.chain().any(|cause| cause.is::<InvalidArgument>()) {
+        INVALID_ARGUMENTS
+    } else if error.chain().any(|cause| cause.is::<Offline>())
+        || vct_core::utils::is_network_error(error)
+    {
+        NETWORK_ERROR
+    } else {
+        RUNTIME_ERROR
+    }
+}
@@ -10,6 +10,7 @@
 //! load-bearing — see [`main`].
 
 mod cli;
+mod exit;
 
 use crate::cli::{Cli, Commands, ConfigAction, QuotaProvider, resolve_time_range_with_default};
 use anyhow::{Context, Result, bail};
@@ -18,6 +19,8 @@ use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Table, presets
 use owo_colors::OwoColorize;
 use serde::Serialize;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::sync::Arc;
 
 // mimalloc is opt-in behind the `mimalloc` cargo feature. The default build
@@ -29,12 +32,24 @@ use std::sync::Arc;
 #[cfg(feature = "mimalloc")]
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+use vct_core::analysis::{AnalysisDataset, ToolVersionFilter};
 use vct_core::get_version_info;
 use vct_core::scan::build_scan_pool;
 use vct_core::session::{ParseMode, parse_session_file_with_diagnostics};
-use vct_core::usage::scan_usage_priced;
+use vct_core::usage::{
+    WebhookFormat, WebhookSummary, post_webhook, scan_usage_estimate, scan_usage_priced,
+};
+use vct_core::utils::{ModelNames, SessionIgnore};
+use vct_core::warnings::WarningSink;
+use vct_tui::display::common::keymap::Keymap;
+use vct_tui::display::common::tui::TuiOptions;
+use vct_tui::display::update::display_release_notes;
 use vct_tui::display::usage::{
-    display_usage_interactive_with_pool, display_usage_table, display_usage_text,
+    UsageRenderOptions, display_grouped_usage_table, display_grouped_usage_text,
+    display_model_budget_table, display_model_budget_text, display_split_usage_table,
+    display_usage_calendar, display_usage_diff_table, display_usage_diff_text,
+    display_usage_footer, display_usage_interactive_with_pool, display_usage_table,
+    display_usage_text,
 };
 
 /// Parses the CLI and runs the selected subcommand.
@@ -45,14 +60,14 @@ use vct_tui::display::usage::{
 /// the bare flag prints just [`vct_core::VERSION`] without going
 /// through clap (the `version` *subcommand* still renders the full table).
 ///
-/// # Errors
-///
-/// Propagates any failure from the dispatched subcommand: session parsing,
-/// JSON (de)serialization, terminal/TUI errors, or the network and
-/// binary-replacement errors raised by `update`. Pricing
-/// fetch failure in `usage --json` is downgraded to a warning rather than an
-/// error, so costs are reported as unavailable instead of aborting.
-fn main() -> Result<()> {
+/// The process exits with the code contract in [`exit`]: a rejected command
+/// line is [`exit::INVALID_ARGUMENTS`], and a failed subcommand (session
+/// parsing, JSON (de)serialization, terminal/TUI errors, or the network and
+/// binary-replacement errors raised by `update`) is printed to stderr and
+/// mapped by [`exit::exit_code_for`]. Pricing fetch failure in `usage --json`
+/// is downgraded to a warning rather than an error, so costs are reported as
+/// unavailable instead of aborting.
+fn main() -> ExitCode {
     // Cap per-thread glibc arenas and pin the trim threshold before any
     // allocation happens under a Rayon worker. See `tune_system_allocator`
     // for why this matters on long TUI sessions.
@@ -71,34 +86,138 @@ fn main() -> Result<()> {
         Some(arg) if arg == "--version" || arg == "-V"
     ) {
         println!("{}", vct_core::VERSION);
-        return Ok(());
+        return ExitCode::SUCCESS;
     }
 
-    let result = run();
-    if let Err(error) = &result {
-        // Record the final error before anyhow prints it to stderr and the
-        // process exits — the log file is the durable record of the failure.
-        log::error!("command failed: {error:#}");
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(error) => {
+            // `--help` and the subcommand `--version` arrive here too, with a
+            // zero exit code and their text bound for stdout.
+            let _ = error.print();
+            return if error.use_stderr() {
+                ExitCode::from(exit::INVALID_ARGUMENTS)
+            } else {
+                ExitCode::SUCCESS
+            };
+        }
+    };
+
+    let verbose = cli.verbose;
+    let warnings = WarningSink::new();
+    let result = run(cli, &warnings);
+    report_warnings(&warnings, verbose);
+    match result {
+        Ok(()) => ExitCode::from(exit::SUCCESS),
+        Err(error) => {
+            // Record the final error before it is printed and the process
+            // exits — the log file is the durable record of the failure.
+            log::error!("command failed: {error:#}");
+            eprintln!("Error: {error:?}");
+            ExitCode::from(exit::exit_code_for(&error))
+        }
     }
-    result
 }
 
-/// Parses the CLI and dispatches the selected subcommand.
-fn run() -> Result<()> {
-    let cli = Cli::parse();
+/// Prints the warnings the run collected to stderr: every message under
+/// `--verbose`, then a one-line count.
+fn report_warnings(sink: &WarningSink, verbose: bool) {
+    let warnings = sink.take();
+    if warnings.is_empty() {
+        return;
+    }
+    if verbose {
+        for warning in &warnings {
+            if warning.count > 1 {
+                eprintln!("Warning: {} (x{})", warning.message, warning.count);
+            } else {
+                eprintln!("Warning: {}", warning.message);
+            }
+        }
+    }
+    let total: usize = warnings.iter().map(|warning| warning.count).sum();
+    let noun = if total == 1 { "warning" } else { "warnings" };
+    if verbose {
+        eprintln!("⚠️ {total} {noun}");
+    } else {
+        eprintln!("⚠️ {total} {noun} (run with -v for details)");
+    }
+}
 
+/// Dispatches the parsed subcommand, recording run warnings in `warnings`.
+fn run(cli: Cli, warnings: &WarningSink) -> Result<()> {
     match cli.command {
         Commands::Analysis {
             file,
             json,
             text,
             table,
+            validate,
+            provider_detect_stats,
+            sessions_per_day,
+            error_report,
+            correlate_git,
+            hod,
+            timezone,
+            tool_version,
+            include_unversioned,
+            dedupe_reads,
+            normalize_paths,
+            embed_raw,
+            summary_only,
+            keep_running,
+            interval,
+            output,
+            no_machine_id,
+            files_from,
+            ignore_file,
+            fail_on_empty,
+            encoding,
+            fold_case,
+            dedupe_by,
+            fixup_model,
+            pretty_models,
+            model_width,
+            compact_tui,
+            summary_format,
             daily,
             weekly,
             monthly,
             all,
         } => {
+            let encoding = encoding.unwrap_or_default();
+            let scan_options = vct_core::analysis::AnalysisScanOptions {
+                encoding,
+                fold_case,
+                fixups: fixup_model.into(),
+                dedupe_by,
+                warnings: warnings.clone(),
+                ..vct_core::analysis::AnalysisScanOptions::default()
+            };
+            // `analysis FILE` reads the config only for `hide_machine_id`, so
+            // it gets the built-in names only; the batch path below adds
+            // `[display] model_names`.
+            let model_names = ModelNames::new(pretty_models, Default::default());
+            // clap drops a `requires` whose target conflicts with another
+            // given flag, so `FILE --output x` would otherwise slip through.
+            if !keep_running && (interval.is_some() || output.is_some()) {
+                return Err(exit::InvalidArgument(
+                    "--interval and --output require --keep-running".to_string(),
+                )
+                .into());
+            }
             match file {
+                Some(file_path) if validate => {
+                    let (analysis, _) = parse_session_file_with_diagnostics(
+                        &file_path,
+                        ParseMode::Full,
+                        encoding,
+                        dedupe_by,
+                        warnings,
+                    )?;
+                    let violations = vct_core::analysis::validate_analysis(&analysis);
+                    report_invariant_violations(&violations, 1, json)?;
+                }
                 Some(file_path) => {
                     let complete_json = json || (!text && !table);
                     let mode = if complete_json {
@@ -106,23 +225,52 @@ fn run() -> Result<()> {
                     } else {
                         ParseMode::UsageOnly
                     };
-                    let (analysis, diagnostics) =
-                        parse_session_file_with_diagnostics(&file_path, mode)?;
+                    let (mut analysis, diagnostics) = parse_session_file_with_diagnostics(
+                        &file_path, mode, encoding, dedupe_by, warnings,
+                    )?;
                     if diagnostics.skipped_records() > 0 {
-                        eprintln!(
-                            "Warning: Skipped {} malformed or unsupported analyzer records while parsing {}. Successful results are still shown.",
+                        warnings.warn(format!(
+                            "Skipped {} malformed or unsupported analyzer records while parsing {}. Successful results are still shown.",
                             diagnostics.skipped_records(),
                             file_path.display()
-                        );
+                        ));
                     }
                     if complete_json {
+                        if dedupe_reads {
+                            vct_core::analysis::dedupe_reads(&mut analysis);
+                        }
+                        if let Some(style) = normalize_paths {
+                            vct_core::analysis::normalize_paths(&mut analysis, style);
+                        }
+                        if embed_raw {
+                            warn_embed_raw();
+                            vct_core::analysis::embed_raw_source(&mut analysis, &file_path)?;
+                        }
+                        // Only the JSON path carries `machineId`, so only it
+                        // reads the config.
+                        if no_machine_id
+                            || vct_core::utils::machine_id_disabled()
+                            || vct_core::config::load_existing().analysis.hide_machine_id
+                        {
+                            vct_core::analysis::clear_machine_id(&mut analysis);
+                        }
                         write_pretty_json(&analysis)?;
                     } else if text {
-                        let projected = vct_core::analysis::project_code_analysis(&analysis);
-                        vct_tui::display::analysis::display_analysis_text(&projected);
+                        let projected = vct_core::analysis::project_code_analysis_opts(
+                            &analysis,
+                            &scan_options,
+                        );
+                        vct_tui::display::analysis::display_analysis_text(&projected, &model_names);
                     } else {
-                        let projected = vct_core::analysis::project_code_analysis(&analysis);
-                        vct_tui::display::analysis::display_analysis_table(&projected);
+                        let projected = vct_core::analysis::project_code_analysis_opts(
+                            &analysis,
+                            &scan_options,
+                        );
+                        vct_tui::display::analysis::display_analysis_table(
+                            &projected,
+                            &model_names,
+                            model_width.into(),
+                        );
                     }
                 }
                 None => {
@@ -131,6 +279,12 @@ fn run() -> Result<()> {
                     // create `~/.vct/config.toml`.
                     let config = vct_core::config::load();
                     vct_core::logging::apply(&config.logging);
+                    let model_names =
+                        ModelNames::new(pretty_models, config.display.model_names.clone());
+                    let scan_options = vct_core::analysis::AnalysisScanOptions {
+                        ignore: SessionIgnore::load(ignore_file.as_deref())?,
+                        ..scan_options
+                    };
                     let time_range = resolve_time_range_with_default(
                         daily,
                         weekly,
@@ -140,34 +294,227 @@ fn run() -> Result<()> {
                     );
                     let scan_pool =
                         Arc::new(build_scan_pool(config.performance.resolved_scan_threads())?);
-                    if json {
-                        let dataset = scan_pool.install(|| {
-                            vct_core::analysis::collect_analysis_sessions_with(
+                    let tool_filter = tool_version.map(|req| ToolVersionFilter {
+                        req,
+                        include_unversioned,
+                    });
+                    let file_list = files_from.as_deref().map(read_file_list).transpose()?;
+                    // Every dataset-based mode below honors `--tool-version` and
+                    // `--files-from`.
+                    let collect_sessions = |mode: ParseMode| -> Result<AnalysisDataset> {
+                        let mut dataset = scan_pool.install(|| match &file_list {
+                            Some(files) => {
+                                vct_core::analysis::collect_analysis_sessions_from_files_opts(
+                                    files,
+                                    config.providers,
+                                    mode,
+                                    &scan_options,
+                                )
+                            }
+                            None => vct_core::analysis::collect_analysis_sessions_with_opts(
                                 time_range,
                                 config.providers,
-                                ParseMode::Full,
+                                mode,
+                                &scan_options,
+                            ),
+                        })?;
+                        if let Some(filter) = &tool_filter {
+                            scan_pool.install(|| {
+                                vct_core::analysis::retain_tool_version(&mut dataset, filter)
+                            });
+                        }
+                        Ok(dataset)
+                    };
+                    if keep_running {
+                        // clap's `requires` guarantees `--output` is present.
+                        let output = output.context("--keep-running requires --output")?;
+                        let mut cache = vct_core::summary_cache::SummaryScanCache::new();
+                        watch_analysis(
+                            interval.unwrap_or_else(|| config.analysis.refresh_secs()),
+                            &output,
+                            warnings,
+                            || {
+                                scan_pool.install(|| {
+                                    vct_core::analysis::aggregate_sessions_by_model_with_cache_opts(
+                                        time_range,
+                                        config.providers,
+                                        &mut cache,
+                                        &scan_options,
+                                    )
+                                })
+                            },
+                        )?;
+                    } else if provider_detect_stats {
+                        let stats = scan_pool.install(|| {
+                            vct_core::scan::provider_detect_stats(
+                                config.providers,
+                                time_range,
+                                &scan_options.ignore,
                             )
                         })?;
-                        report_analysis_collection(&dataset.diagnostics)?;
+                        if json {
+                            write_pretty_json(&stats)?;
+                        } else {
+                            vct_tui::display::analysis::display_provider_detect_table(&stats);
+                        }
+                    } else if sessions_per_day {
+                        let counts = scan_pool.install(|| {
+                            vct_core::scan::sessions_per_day(
+                                config.providers,
+                                time_range,
+                                &scan_options.ignore,
+                            )
+                        })?;
+                        if json {
+                            write_pretty_json(&counts)?;
+                        } else {
+                            vct_tui::display::analysis::display_sessions_per_day_table(&counts);
+                        }
+                        ensure_not_empty(fail_on_empty, counts.is_empty())?;
+                    } else if error_report {
+                        let dataset = collect_sessions(ParseMode::UsageOnly)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        let report = vct_core::analysis::error_report(&dataset);
+                        if json {
+                            write_pretty_json(&report)?;
+                        } else {
+                            vct_tui::display::analysis::display_error_report_table(&report);
+                        }
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
+                    } else if correlate_git {
+                        // The window starts at the first recorded operation,
+                        // which only a full parse keeps.
+                        let dataset = collect_sessions(ParseMode::Full)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        let sessions = vct_core::analysis::correlate_git(&dataset, warnings);
+                        if json {
+                            write_pretty_json(&sessions)?;
+                        } else {
+                            vct_tui::display::analysis::display_session_commits_table(&sessions);
+                        }
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
+                    } else if hod {
+                        // Per-operation timestamps only survive a full parse.
+                        let dataset = collect_sessions(ParseMode::Full)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        let hours = vct_core::analysis::activity_by_hour(
+                            &dataset,
+                            timezone.unwrap_or_default(),
+                        );
+                        if json {
+                            write_pretty_json(&hours)?;
+                        } else {
+                            vct_tui::display::analysis::display_activity_by_hour_table(&hours);
+                        }
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
+                    } else if validate {
+                        let dataset = collect_sessions(ParseMode::Full)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        let violations = vct_core::analysis::validate_dataset(&dataset);
+                        report_invariant_violations(&violations, dataset.len(), json)?;
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
+                    } else if summary_only {
+                        let data = if tool_filter.is_some() || file_list.is_some() {
+                            let dataset = collect_sessions(ParseMode::UsageOnly)?;
+                            report_analysis_collection(&dataset.diagnostics, warnings)?;
+                            vct_core::analysis::project_analysis_dataset_opts(
+                                &dataset,
+                                &scan_options,
+                            )
+                        } else {
+                            let aggregation = scan_pool.install(|| {
+                                vct_core::analysis::aggregate_sessions_by_model_with_diagnostics_opts(
+                                    time_range,
+                                    config.providers,
+                                    &scan_options,
+                                )
+                            })?;
+                            report_analysis_collection(&aggregation.diagnostics, warnings)?;
+                            aggregation.data
+                        };
+                        let totals = vct_core::analysis::analysis_totals(&data.rows);
+                        if json {
+                            write_pretty_json(&totals)?;
+                        } else {
+                            vct_tui::display::analysis::display_analysis_totals(&totals);
+                        }
+                        ensure_not_empty(fail_on_empty, data.rows.is_empty())?;
+                    } else if json || dedupe_reads || normalize_paths.is_some() || embed_raw {
+                        let mut dataset = collect_sessions(ParseMode::Full)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        if dedupe_reads {
+                            vct_core::analysis::dedupe_dataset_reads(&mut dataset);
+                        }
+                        if let Some(style) = normalize_paths {
+                            vct_core::analysis::normalize_dataset_paths(&mut dataset, style);
+                        }
+                        if embed_raw {
+                            warn_embed_raw();
+                            vct_core::analysis::embed_dataset_raw_sources(&mut dataset)?;
+                        }
+                        if no_machine_id
+                            || config.analysis.hide_machine_id
+                            || vct_core::utils::machine_id_disabled()
+                        {
+                            vct_core::analysis::clear_dataset_machine_id(&mut dataset);
+                        }
                         write_pretty_json(&dataset)?;
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
+                    } else if tool_filter.is_some() || file_list.is_some() {
+                        // The cached aggregation keeps no per-session versions and
+                        // only walks provider directories, so a filtered or listed
+                        // summary is projected from the parsed dataset.
+                        let dataset = collect_sessions(ParseMode::UsageOnly)?;
+                        report_analysis_collection(&dataset.diagnostics, warnings)?;
+                        let data = vct_core::analysis::project_analysis_dataset_opts(
+                            &dataset,
+                            &scan_options,
+                        );
+                        if text {
+                            vct_tui::display::analysis::display_analysis_text(&data, &model_names);
+                        } else {
+                            vct_tui::display::analysis::display_analysis_table(
+                                &data,
+                                &model_names,
+                                model_width.into(),
+                            );
+                        }
+                        ensure_not_empty(fail_on_empty, dataset.is_empty())?;
                     } else if text || table {
                         let aggregation = scan_pool.install(|| {
-                            vct_core::analysis::aggregate_sessions_by_model_with_diagnostics(
+                            vct_core::analysis::aggregate_sessions_by_model_with_diagnostics_opts(
                                 time_range,
                                 config.providers,
+                                &scan_options,
                             )
                         })?;
-                        report_analysis_collection(&aggregation.diagnostics)?;
+                        report_analysis_collection(&aggregation.diagnostics, warnings)?;
 
                         if text {
-                            vct_tui::display::analysis::display_analysis_text(&aggregation.data);
+                            vct_tui::display::analysis::display_analysis_text(
+                                &aggregation.data,
+                                &model_names,
+                            );
                         } else {
-                            vct_tui::display::analysis::display_analysis_table(&aggregation.data);
+                            vct_tui::display::analysis::display_analysis_table(
+                                &aggregation.data,
+                                &model_names,
+                                model_width.into(),
+                            );
                         }
+                        ensure_not_empty(fail_on_empty, aggregation.data.rows.is_empty())?;
                     } else {
                         vct_tui::display::analysis::display_analysis_interactive_loading_with_pool(
                             time_range,
                             config.providers,
+                            scan_options,
+                            TuiOptions {
+                                keymap: Keymap::from_config(&config.tui, warnings),
+                                model_names,
+                                compact: compact_tui,
+                                summary_format: summary_format.unwrap_or_default(),
+                                model_width: model_width.into(),
+                            },
                             config.analysis.refresh_secs(),
                             scan_pool,
                         )?;
@@ -181,6 +528,42 @@ fn run() -> Result<()> {
             text,
             table,
             merge_providers,
+            show_sessions,
+            group_threshold,
+            estimate_only,
+            threshold_basis,
+            diff,
+            models_without_usage,
+            cross_check,
+            cross_check_tolerance,
+            group_by,
+            cost_breakdown,
+            summary_only,
+            calendar,
+            by,
+            model_budget,
+            split_by,
+            webhook,
+            webhook_format,
+            webhook_timeout,
+            webhook_required,
+            live_total,
+            interval,
+            cost_thresholds,
+            fuzzy_hint_threshold,
+            files_from,
+            ignore_file,
+            fail_on_empty,
+            quiet,
+            encoding,
+            fold_case,
+            dedupe_by,
+            fixup_model,
+            estimate_missing_pricing,
+            pretty_models,
+            model_width,
+            compact_tui,
+            summary_format,
             daily,
             weekly,
             monthly,
@@ -188,6 +571,7 @@ fn run() -> Result<()> {
         } => {
             let config = vct_core::config::load();
             vct_core::logging::apply(&config.logging);
+            let model_names = ModelNames::new(pretty_models, config.display.model_names.clone());
             let time_range = resolve_time_range_with_default(
                 daily,
                 weekly,
@@ -198,27 +582,282 @@ fn run() -> Result<()> {
             // A `--merge-providers` flag forces merging on; otherwise the saved
             // preference decides. The TUI's `m` toggle persists back to config.
             let merge = merge_providers || config.usage.merge_models;
+            let cost_thresholds = cost_thresholds.unwrap_or_default();
+            let render = UsageRenderOptions {
+                merge,
+                show_sessions,
+                group_threshold,
+                estimate: estimate_only,
+                cost_thresholds,
+                fuzzy_hint_threshold: fuzzy_hint_threshold
+                    .unwrap_or(vct_core::pricing::DEFAULT_FUZZY_HINT_THRESHOLD),
+            };
+            let threshold_basis = threshold_basis.unwrap_or(config.usage.threshold_basis);
             let scan_pool = Arc::new(build_scan_pool(config.performance.resolved_scan_threads())?);
+            let file_list = files_from.as_deref().map(read_file_list).transpose()?;
+            let scan_options = vct_core::usage::UsageScanOptions {
+                encoding: encoding.unwrap_or_default(),
+                fold_case,
+                fixups: fixup_model.into(),
+                estimate_missing_pricing,
+                ignore: SessionIgnore::load(ignore_file.as_deref())?,
+                dedupe_by,
+                warnings: warnings.clone(),
+                ..vct_core::usage::UsageScanOptions::default()
+            };
 
-            if json {
-                let scan = scan_usage_priced(time_range, config.providers, &scan_pool)?;
+            // As for `analysis --interval`: clap drops the `requires` when
+            // `--live-total` conflicts with another given flag.
+            if interval.is_some() && !live_total {
+                return Err(
+                    exit::InvalidArgument("--interval requires --live-total".to_string()).into(),
+                );
+            }
+            if cross_check_tolerance.is_some() && !cross_check {
+                return Err(exit::InvalidArgument(
+                    "--cross-check-tolerance requires --cross-check".to_string(),
+                )
+                .into());
+            }
+            if by.is_some() && !calendar {
+                return Err(exit::InvalidArgument("--by requires --calendar".to_string()).into());
+            }
+
+            if live_total {
+                let mut scan = vct_core::usage::LiveTotalScan::new(
+                    config.providers,
+                    threshold_basis,
+                    scan_options,
+                )?;
                 if let Some(error) = &scan.pricing_error {
+                    warnings.warn(format!(
+                        "Failed to load pricing data: {error}. Costs will be unavailable."
+                    ));
+                }
+                show_live_total(
+                    interval.unwrap_or_else(|| config.usage.refresh_secs()),
+                    || scan.tick(&scan_pool),
+                )?;
+            } else if let Some(exports) = diff {
+                // Two saved exports carry everything the diff needs; no scan and
+                // no pricing fetch.
+                let before = vct_core::usage::load_usage_export(&exports[0])?;
+                let after = vct_core::usage::load_usage_export(&exports[1])?;
+                let changes = vct_core::usage::diff_usage_exports(&before, &after);
+                if json {
+                    write_pretty_json(&changes)?;
+                } else if text {
+                    display_usage_diff_text(&changes);
+                } else {
+                    display_usage_diff_table(&changes, model_width.into());
+                }
+            } else if let Some(override_file) = models_without_usage {
+                // The audit needs only the distinct model set, so it skips the
+                // pricing fetch the other non-interactive modes perform. A key
+                // is unused only if no session ever needed it, so the set spans
+                // all time and every provider regardless of the period flags.
+                let keys = vct_core::usage::load_override_keys(&override_file)?;
+                let collection = scan_pool.install(|| {
+                    vct_core::usage::aggregate_usage_from_home_with_diagnostics_opts(
+                        vct_core::TimeRange::All,
+                        config.providers,
+                        &scan_options,
+                    )
+                })?;
+                report_usage_collection(&collection.diagnostics, warnings)?;
+                let unused =
+                    vct_core::usage::unused_override_keys(&keys, collection.data.distinct_models());
+                if json {
+                    write_pretty_json(&unused)?;
+                } else {
+                    for key in &unused {
+                        println!("{key}");
+                    }
+                }
+            } else if cross_check {
+                let check = scan_pool.install(|| {
+                    vct_core::cross_check::cross_check(
+                        time_range,
+                        config.providers,
+                        cross_check_tolerance.unwrap_or(0),
+                        &scan_options,
+                    )
+                })?;
+                report_usage_collection(&check.usage_diagnostics, warnings)?;
+                report_analysis_collection(&check.analysis_diagnostics, warnings)?;
+                if json {
+                    write_pretty_json(&check.discrepancies)?;
+                } else if check.discrepancies.is_empty() {
+                    println!("consistent");
+                } else {
+                    for row in &check.discrepancies {
+                        println!(
+                            "{} {}: usage {} vs analysis {} ({:+})",
+                            row.date,
+                            row.model,
+                            row.usage_tokens,
+                            row.analysis_tokens,
+                            row.difference
+                        );
+                    }
+                }
+            } else if json
+                || text
+                || table
+                || estimate_only
+                || group_by.is_some()
+                || summary_only
+                || calendar
+                || !model_budget.is_empty()
+                || split_by.is_some()
+                || webhook.is_some()
+                || file_list.is_some()
+            {
+                let from_file_list = file_list.is_some();
+                let scan_options = vct_core::usage::UsageScanOptions {
+                    files: file_list,
+                    ..scan_options
+                };
+                let scan = if estimate_only {
+                    scan_usage_estimate(time_range, config.providers, scan_options, &scan_pool)?
+                } else {
+                    scan_usage_priced(
+                        time_range,
+                        config.providers,
+                        threshold_basis,
+                        scan_options,
+                        &scan_pool,
+                    )?
+                };
+                if let Some(error) = &scan.pricing_error {
+                    warnings.warn(format!(
+                        "Failed to load pricing data: {error}. Costs will be unavailable."
+                    ));
+                }
+                report_usage_collection(&scan.collection.diagnostics, warnings)?;
+                if estimate_only && !quiet {
                     eprintln!(
-                        "Warning: Failed to fetch pricing data: {error}. Costs will be unavailable."
+                        "Note: estimate only: cached pricing (possibly stale), base rates without context tiers."
                     );
                 }
-                report_usage_collection(&scan.collection.diagnostics)?;
-                let priced =
-                    vct_core::usage::price_usage_data(&scan.collection.data, &scan.pricing);
-                write_pretty_json(&priced)?;
-            } else if text {
-                let scan = scan_usage_priced(time_range, config.providers, &scan_pool)?;
-                report_usage_collection(&scan.collection.diagnostics)?;
-                display_usage_text(&scan.collection.data, merge);
-            } else if table {
-                let scan = scan_usage_priced(time_range, config.providers, &scan_pool)?;
-                report_usage_collection(&scan.collection.diagnostics)?;
-                display_usage_table(&scan.collection.data, merge);
+                // An explicit `--files-from` list never looks in provider
+                // directories, so their absence explains nothing.
+                if scan.collection.data.models.is_empty() && !from_file_list && !quiet {
+                    note_missing_providers(config.providers)?;
+                }
+                if calendar {
+                    display_usage_calendar(
+                        &scan.collection.data,
+                        &scan.pricing,
+                        by.unwrap_or_default(),
+                    );
+                } else if let Some(period) = split_by {
+                    let sections =
+                        vct_core::usage::split_usage(&scan.collection.data, period, &scan.pricing);
+                    display_split_usage_table(&sections, period, &model_names, model_width.into());
+                } else if !model_budget.is_empty() {
+                    let days = vct_core::usage::check_model_budgets(
+                        &scan.collection.data,
+                        &model_budget,
+                        &scan.pricing,
+                    );
+                    if json {
+                        write_pretty_json(&days)?;
+                    } else if text {
+                        display_model_budget_text(&days);
+                    } else {
+                        display_model_budget_table(&days);
+                    }
+                } else if let Some(group_by) = &group_by {
+                    let rows = vct_core::usage::group_usage(
+                        &scan.collection.data,
+                        group_by,
+                        &scan.pricing,
+                    );
+                    if json {
+                        write_pretty_json(&rows)?;
+                    } else if text {
+                        display_grouped_usage_text(&rows, group_by, show_sessions, &model_names);
+                    } else {
+                        display_grouped_usage_table(
+                            &rows,
+                            group_by,
+                            show_sessions,
+                            &model_names,
+                            model_width.into(),
+                        );
+                    }
+                } else if summary_only {
+                    let footer = vct_core::usage::summary::usage_footer(
+                        &scan.collection.data,
+                        &scan.pricing,
+                        merge,
+                    );
+                    if json {
+                        write_pretty_json(&footer)?;
+                    } else {
+                        display_usage_footer(&footer);
+                    }
+                } else if json {
+                    let mut priced =
+                        vct_core::usage::price_usage_data(&scan.collection.data, &scan.pricing);
+                    if cost_breakdown {
+                        vct_core::usage::add_cost_breakdowns(
+                            &mut priced,
+                            &scan.collection.data,
+                            &scan.pricing,
+                        );
+                    }
+                    write_pretty_json(&priced)?;
+                } else if text {
+                    display_usage_text(&scan.collection.data, &scan.pricing, render, &model_names);
+                } else {
+                    // `--estimate-only` or `--files-from` alone renders the static
+                    // table: the auto-refreshing TUI rescans provider directories.
+                    display_usage_table(
+                        &scan.collection.data,
+                        &scan.pricing,
+                        render,
+                        &model_names,
+                        model_width.into(),
+                    );
+                }
+                if let Some(url) = &webhook {
+                    let timeout = std::time::Duration::from_secs(
+                        webhook_timeout.unwrap_or(vct_core::usage::DEFAULT_WEBHOOK_TIMEOUT_SECS),
+                    );
+                    let sent = match webhook_format.unwrap_or_default() {
+                        WebhookFormat::Json => {
+                            let mut priced = vct_core::usage::price_usage_data(
+                                &scan.collection.data,
+                                &scan.pricing,
+                            );
+                            if cost_breakdown {
+                                vct_core::usage::add_cost_breakdowns(
+                                    &mut priced,
+                                    &scan.collection.data,
+                                    &scan.pricing,
+                                );
+                            }
+                            post_webhook(url, &priced, timeout)
+                        }
+                        WebhookFormat::Summary => {
+                            let footer = vct_core::usage::summary::usage_footer(
+                                &scan.collection.data,
+                                &scan.pricing,
+                                merge,
+                            );
+                            post_webhook(url, &WebhookSummary::from(footer), timeout)
+                        }
+                    };
+                    match sent {
+                        Ok(status) if !quiet => eprintln!("Webhook: HTTP {status}"),
+                        Ok(_) => {}
+                        Err(err) if webhook_required => return Err(err),
+                        Err(err) => warnings.warn(format!("{err:#}")),
+                    }
+                }
+                ensure_not_empty(fail_on_empty, scan.collection.data.models.is_empty())?;
             } else {
                 // `config` is not used after this, so hand the panel list off by
                 // move; read both cadences first so the borrows end before the
@@ -227,9 +866,18 @@ fn run() -> Result<()> {
                 let quota_refresh = config.usage.quota_refresh_secs();
                 display_usage_interactive_with_pool(
                     time_range,
-                    merge,
+                    render,
                     config.usage.quota.panels,
                     config.providers,
+                    threshold_basis,
+                    scan_options,
+                    TuiOptions {
+                        keymap: Keymap::from_config(&config.tui, warnings),
+                        model_names,
+                        compact: compact_tui,
+                        summary_format: summary_format.unwrap_or_default(),
+                        model_width: model_width.into(),
+                    },
                     refresh,
                     quota_refresh,
                     scan_pool,
@@ -237,7 +885,32 @@ fn run() -> Result<()> {
             }
         }
 
-        Commands::Version { json, text } => {
+        Commands::Stats { json } => {
+            let config = vct_core::config::load();
+            vct_core::logging::apply(&config.logging);
+            let scan_pool = build_scan_pool(config.performance.resolved_scan_threads())?;
+            let stats = vct_core::stats::collect_stats(
+                config.providers,
+                config.usage.threshold_basis,
+                &SessionIgnore::load(None)?,
+                warnings,
+                &scan_pool,
+            )?;
+            if let Some(error) = &stats.pricing_error {
+                warnings.warn(format!(
+                    "Failed to load pricing data: {error}. Costs will be unavailable."
+                ));
+            }
+            report_usage_collection(&stats.usage_diagnostics, warnings)?;
+            report_analysis_collection(&stats.analysis_diagnostics, warnings)?;
+            if json {
+                write_pretty_json(&stats.overview)?;
+            } else {
+                vct_tui::display::stats::display_stats_table(&stats.overview);
+            }
+        }
+
+        Commands::Version { json, text, output } => {
             let version_info = get_version_info();
 
             if json {
@@ -246,7 +919,12 @@ fn run() -> Result<()> {
                     "Rust Version": version_info.rust_version,
                     "Cargo Version": version_info.cargo_version
                 });
-                println!("{}", serde_json::to_string_pretty(&json_output)?);
+                if let Some(path) = output {
+                    vct_core::utils::save_json_pretty(&path, &json_output)?;
+                    println!("Version info saved to {}", path.display());
+                } else {
+                    println!("{}", serde_json::to_string_pretty(&json_output)?);
+                }
             } else if text {
                 println!("Version: {}", version_info.version);
                 println!("Rust Version: {}", version_info.rust_version);
@@ -288,11 +966,30 @@ fn run() -> Result<()> {
             }
         }
 
-        Commands::Update { check, force } => {
-            if check {
-                vct_core::update::check_update()?;
+        Commands::Update {
+            check,
+            force,
+            preview,
+            json,
+        } => {
+            if json {
+                match vct_core::update::check_update_status() {
+                    Ok(status) => write_pretty_json(&status)?,
+                    Err(error) => {
+                        write_pretty_json(&serde_json::json!({ "error": format!("{error:#}") }))?;
+                        return Err(error);
+                    }
+                }
+            } else if check {
+                let release = vct_core::update::check_update()?;
+                if let Some(body) = release.and_then(|release| release.body)
+                    && preview
+                {
+                    display_release_notes(&body);
+                }
             } else {
-                vct_core::update::update_interactive(force)?;
+                let show_notes: &dyn Fn(&str) = &display_release_notes;
+                vct_core::update::update_interactive(force, preview.then_some(show_notes))?;
             }
         }
 
@@ -308,8 +1005,119 @@ fn run() -> Result<()> {
         Commands::Config { action } => {
             run_config(action.unwrap_or(ConfigAction::Show))?;
         }
+
+        Commands::Providers => {
+            let paths = vct_core::utils::resolve_paths()?;
+            write_pretty_json(&vct_core::scan::provider_capabilities(&paths))?;
+        }
+
+        Commands::Dev {
+            regen_goldens,
+            bench_matcher,
+            fixtures,
+        } => {
+            if bench_matcher {
+                run_bench_matcher(warnings)?;
+            } else {
+                run_dev_goldens(&fixtures, regen_goldens)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Explains an empty usage scan: one stderr note per enabled provider whose
+/// session locations are all missing, naming where vct looked.
+fn note_missing_providers(providers: vct_core::config::ProvidersConfig) -> Result<()> {
+    let paths = vct_core::utils::resolve_paths()?;
+    for (provider, looked_in) in paths.missing_providers(providers) {
+        let looked_in: Vec<String> = looked_in
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        eprintln!(
+            "Note: No {provider} sessions found; expected in {}",
+            looked_in.join(", ")
+        );
     }
+    Ok(())
+}
+
+/// Handles the hidden `dev` subcommand: verifies every parser golden under
+/// `dir` against a fresh analysis, or rewrites them all with `regen`.
+fn run_dev_goldens(dir: &Path, regen: bool) -> Result<()> {
+    use vct_core::session::golden::{check_golden, discover_goldens, regen_golden};
+
+    let fixtures = discover_goldens(dir)?;
+    if fixtures.is_empty() {
+        anyhow::bail!("no `*.expected.json` goldens found in {}", dir.display());
+    }
+    let mut failed = 0;
+    for fixture in &fixtures {
+        let outcome = if regen {
+            regen_golden(fixture).map(|()| None)
+        } else {
+            check_golden(fixture)
+        };
+        match outcome {
+            Ok(None) if regen => println!("{} {}", "regen".bright_cyan(), fixture.name),
+            Ok(None) => println!("{} {}", "pass".green(), fixture.name),
+            Ok(Some(mismatch)) => {
+                failed += 1;
+                println!("{} {}: {mismatch}", "FAIL".red().bold(), fixture.name);
+            }
+            Err(error) => {
+                failed += 1;
+                println!("{} {}: {error:#}", "FAIL".red().bold(), fixture.name);
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} golden fixtures failed", fixtures.len());
+    }
+    Ok(())
+}
+
+/// Timed lookups per model for `dev --bench-matcher`.
+const BENCH_MATCHER_ROUNDS: usize = 100;
 
+/// Handles `dev --bench-matcher`: times the real pricing map's lookups of
+/// every model the enabled providers' sessions used and prints the per-tier
+/// breakdown.
+fn run_bench_matcher(warnings: &WarningSink) -> Result<()> {
+    let pricing = vct_core::pricing::fetch_model_pricing(warnings)?;
+    let providers = vct_core::config::load().providers;
+    let options = vct_core::usage::UsageScanOptions {
+        ignore: SessionIgnore::load(None)?,
+        warnings: warnings.clone(),
+        ..vct_core::usage::UsageScanOptions::default()
+    };
+    let collection = vct_core::usage::aggregate_usage_from_home_with_diagnostics_opts(
+        vct_core::TimeRange::All,
+        providers,
+        &options,
+    )?;
+    report_usage_collection(&collection.diagnostics, warnings)?;
+    let models: Vec<String> = collection.data.models.into_keys().collect();
+    let bench = vct_core::pricing::bench_matcher(&pricing, &models, BENCH_MATCHER_ROUNDS);
+    println!(
+        "Pricing matcher: {} models, {} lookups each",
+        bench.models, bench.rounds
+    );
+    println!(
+        "  min {:?}  avg {:?}  max {:?}",
+        bench.min, bench.avg, bench.max
+    );
+    println!(
+        "  slow path {:.1}% ({} of {} models)",
+        bench.slow_path_rate() * 100.0,
+        bench.slow_path_hits(),
+        bench.models
+    );
+    for (kind, count) in &bench.kind_counts {
+        println!("  {kind:<10} {count}");
+    }
     Ok(())
 }
 
@@ -323,6 +1131,11 @@ fn run_config(action: ConfigAction) -> Result<()> {
         ConfigAction::Path => {
             println!("{}", vct_core::utils::get_config_path()?.display());
         }
+        ConfigAction::Validate => {
+            let path = vct_core::utils::get_config_path()?;
+            vct_core::config::validate_config_file(&path)?;
+            println!("Config is valid: {}", path.display());
+        }
         ConfigAction::Show => {
             let path = vct_core::utils::get_config_path()?;
             // Ensure the file exists (first-run creation) before reading it back.
@@ -438,8 +1251,154 @@ fn write_pretty_json(value: &impl Serialize) -> Result<()> {
     Ok(())
 }
 
-/// Rejects a completely failed noninteractive scan and reports partial data.
-fn report_analysis_collection(diagnostics: &vct_core::analysis::ScanDiagnostics) -> Result<()> {
+/// Runs `analysis --keep-running`: calls `aggregate` every `interval_secs`
+/// and appends one [`vct_core::analysis::WatchSnapshot`] line to `output` for
+/// each tick whose per-model counters moved.
+///
+/// The file is reopened in append mode on every write and each line is
+/// flushed before sleeping, so an interrupt (Ctrl-C) between ticks never
+/// leaves a partial line behind, and the log can be rotated while running.
+/// A failed scan is reported on stderr and retried next tick; only a failure
+/// to write `output` ends the watch.
+fn watch_analysis(
+    interval_secs: u64,
+    output: &Path,
+    warnings: &WarningSink,
+    mut aggregate: impl FnMut() -> Result<vct_core::analysis::AnalysisCollection>,
+) -> Result<()> {
+    eprintln!(
+        "Appending analysis changes to {} every {interval_secs}s (Ctrl-C to stop)",
+        output.display()
+    );
+    let mut previous = Vec::new();
+    loop {
+        // A session file caught half-written or mid-rotation fails one scan,
+        // not the watch: warn and try again on the next interval.
+        let aggregation = match aggregate().and_then(|aggregation| {
+            report_analysis_collection(&aggregation.diagnostics, warnings)?;
+            Ok(aggregation)
+        }) {
+            Ok(aggregation) => aggregation,
+            Err(error) => {
+                log::warn!("analysis watch scan failed: {error:#}");
+                eprintln!("Warning: analysis scan failed: {error:#}; retrying in {interval_secs}s");
+                std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+                continue;
+            }
+        };
+        let changes = vct_core::analysis::analysis_delta(&previous, &aggregation.data.rows);
+        if !changes.is_empty() {
+            let mut line = serde_json::to_string(&vct_core::analysis::WatchSnapshot::now(changes))?;
+            line.push('\n');
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(output)
+                .with_context(|| format!("Failed to open watch log {}", output.display()))?;
+            file.write_all(line.as_bytes())
+                .and_then(|()| file.flush())
+                .with_context(|| format!("Failed to append to watch log {}", output.display()))?;
+        }
+        previous = aggregation.data.rows;
+        std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+    }
+}
+
+/// Runs `usage --live-total`: calls `tick` every `interval_secs` and rewrites
+/// one `today: $X.XX (N tokens)` line in place with `\r`, padding over any
+/// longer previous text. A failed tick is reported on stderr below the last
+/// total and retried next interval. Never returns except when stdout fails;
+/// Ctrl-C stops it.
+fn show_live_total(interval_secs: u64, mut tick: impl FnMut() -> Result<(f64, i64)>) -> Result<()> {
+    let mut stdout = io::stdout();
+    let mut width = 0;
+    loop {
+        let (cost, tokens) = match tick() {
+            Ok(total) => total,
+            Err(error) => {
+                log::warn!("live total scan failed: {error:#}");
+                // End the rewritten line so the warning does not overwrite it.
+                if width > 0 {
+                    writeln!(stdout)?;
+                    width = 0;
+                }
+                eprintln!("Warning: usage scan failed: {error:#}; retrying in {interval_secs}s");
+                std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+                continue;
+            }
+        };
+        let line = format!(
+            "today: ${cost:.2} ({} tokens)",
+            vct_core::utils::format_number(tokens)
+        );
+        write!(stdout, "\r{line:<width$}")?;
+        stdout.flush()?;
+        width = line.len();
+        std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+    }
+}
+
+/// Tells the user on stderr that `--embed-raw` output carries whole sessions.
+///
+/// Printed immediately rather than collected with the run's warnings: it
+/// cautions about the output itself, before it is written.
+fn warn_embed_raw() {
+    eprintln!(
+        "Warning: --embed-raw copies every session file into the output; it may contain prompts, source code, and secrets."
+    );
+}
+
+/// Reads a `--files-from` list: one session file path per line of `source`
+/// (`-` is stdin), blank lines skipped.
+///
+/// # Errors
+///
+/// Returns [`exit::InvalidArgument`] when the list cannot be read or names a
+/// path that is not an existing file.
+fn read_file_list(source: &Path) -> Result<Arc<[PathBuf]>> {
+    let contents = if source == Path::new("-") {
+        io::read_to_string(io::stdin().lock())
+    } else {
+        std::fs::read_to_string(source)
+    }
+    .map_err(|error| {
+        exit::InvalidArgument(format!(
+            "cannot read file list {}: {error}",
+            source.display()
+        ))
+    })?;
+    let mut files = Vec::new();
+    for line in contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let path = PathBuf::from(line);
+        if !path.is_file() {
+            return Err(exit::InvalidArgument(format!(
+                "listed session file does not exist: {line}"
+            ))
+            .into());
+        }
+        files.push(path);
+    }
+    Ok(files.into())
+}
+
+/// Fails with [`exit::NoData`] when `--fail-on-empty` is set and the
+/// noninteractive result just printed was empty.
+fn ensure_not_empty(fail_on_empty: bool, empty: bool) -> Result<()> {
+    if fail_on_empty && empty {
+        return Err(exit::NoData.into());
+    }
+    Ok(())
+}
+
+/// Rejects a completely failed noninteractive analysis scan and reports partial data.
+fn report_analysis_collection(
+    diagnostics: &vct_core::analysis::ScanDiagnostics,
+    warnings: &WarningSink,
+) -> Result<()> {
     let Some(first) = diagnostics.failures.first() else {
         return Ok(());
     };
@@ -453,20 +1412,23 @@ fn report_analysis_collection(diagnostics: &vct_core::analysis::ScanDiagnostics)
         );
     }
     if diagnostics.partially_failed() {
-        eprintln!(
-            "Warning: Encountered {} analysis source failures while scanning {} candidates. Successful results are still shown. First failure: {} {}: {}",
+        warnings.warn(format!(
+            "Encountered {} analysis source failures while scanning {} candidates. Successful results are still shown. First failure: {} {}: {}",
             diagnostics.failures.len(),
             diagnostics.candidates,
             first.provider,
             first.source.display(),
             first.error
-        );
+        ));
     }
     Ok(())
 }
 
 /// Rejects a completely failed noninteractive usage scan and reports partial data.
-fn report_usage_collection(diagnostics: &vct_core::usage::ScanDiagnostics) -> Result<()> {
+fn report_usage_collection(
+    diagnostics: &vct_core::usage::ScanDiagnostics,
+    warnings: &WarningSink,
+) -> Result<()> {
     let Some(first) = diagnostics.failures.first() else {
         return Ok(());
     };
@@ -480,14 +1442,41 @@ fn report_usage_collection(diagnostics: &vct_core::usage::ScanDiagnostics) -> Re
         );
     }
     if diagnostics.partially_failed() {
-        eprintln!(
-            "Warning: Encountered {} usage source failures while scanning {} candidates. Successful results are still shown. First failure: {} {}: {}",
+        warnings.warn(format!(
+            "Encountered {} usage source failures while scanning {} candidates. Successful results are still shown. First failure: {} {}: {}",
             diagnostics.failures.len(),
             diagnostics.candidates,
             first.provider,
             first.source.display(),
             first.error
-        );
+        ));
+    }
+    Ok(())
+}
+
+/// Prints `analysis --validate` results and fails when any invariant broke.
+///
+/// Violations go to stdout (one per line, or a JSON array with `json`); the
+/// error return is what turns a non-empty list into a nonzero exit code.
+fn report_invariant_violations(
+    violations: &[vct_core::analysis::InvariantViolation],
+    sessions: usize,
+    json: bool,
+) -> Result<()> {
+    if json {
+        write_pretty_json(&violations)?;
+    } else if violations.is_empty() {
+        println!("No invariant violations found in {sessions} session(s)");
+    } else {
+        for violation in violations {
+            println!(
+                "{} (record {}): {}",
+                violation.session, violation.record, violation.message
+            );
+        }
+    }
+    if !violations.is_empty() {
+        bail!("{} analysis invariant violation(s) found", violations.len());
     }
     Ok(())
 }
@@ -18,7 +18,7 @@ use predicates::prelude::*;
 use serde_json::json;
 use vct_core::session::{ParseMode, parse_session_file_typed_with_mode};
 use vct_core::{VERSION, parse_session_file_typed};
-use vct_test_support::{TempHome, fixture};
+use vct_test_support::{TempHome, fixture, fixture_str};
 
 /// A minimal cost-fields pricing map used to seed the offline cache so `usage`
 /// prices its models without a network fetch.
@@ -41,6 +41,7 @@ fn child_cmd(home: &TempHome) -> Command {
         .env("USERPROFILE", home.home())
         .env("HERMES_HOME", home.home().join(".hermes"))
         .env("VCT_OFFLINE", "1")
+        .env_remove("VCT_DISABLE_MACHINE_ID")
         .env_remove("XDG_CONFIG_HOME")
         .env_remove("XDG_DATA_HOME");
     cmd
@@ -93,6 +94,34 @@ fn test_version_command_json() {
     assert!(json["Version"].is_string(), "Should have Version field");
 }
 
+#[test]
+fn version_json_output_writes_the_version_info_to_a_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("version.json");
+    Command::cargo_bin("vibe_coding_tracker")
+        .unwrap()
+        .args(["version", "--json", "--output"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Version info saved to"))
+        .stdout(predicate::str::contains("version.json"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(json["Version"], VERSION);
+    assert!(json["Rust Version"].is_string());
+    assert!(json["Cargo Version"].is_string());
+
+    // The file is JSON, so `--output` needs `--json`.
+    Command::cargo_bin("vibe_coding_tracker")
+        .unwrap()
+        .args(["version", "--output"])
+        .arg(&path)
+        .assert()
+        .code(3);
+}
+
 #[test]
 fn test_version_command_text() {
     Command::cargo_bin("vibe_coding_tracker")
@@ -147,6 +176,664 @@ fn analysis_file_json_matches_typed_parser_for_every_file_provider() {
     }
 }
 
+#[test]
+fn analysis_validate_passes_for_fixture_sessions() {
+    let home = TempHome::new();
+    child_cmd(&home)
+        .arg("analysis")
+        .arg("--validate")
+        .arg(fixture("sessions/claude_code.jsonl"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No invariant violations"));
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg("--validate")
+        .arg("--json")
+        .arg(fixture("sessions/codex.jsonl"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap(),
+        json!([])
+    );
+}
+
+#[test]
+fn analysis_dedupe_reads_counts_a_reread_file_once() {
+    // Re-issue the fixture's `go.mod` Read (call + result) under a fresh tool
+    // id, so the session reads that file twice.
+    let fixture = fixture_str("sessions/claude_code.jsonl");
+    let reread: Vec<String> = fixture
+        .lines()
+        .filter(|line| line.contains("toolu_014WvJQVXQ9y641v1V4N2v4P"))
+        .map(|line| line.replace("toolu_014WvJQVXQ9y641v1V4N2v4P", "toolu_reread"))
+        .collect();
+    assert_eq!(reread.len(), 2, "fixture Read call and result");
+    let home = TempHome::new();
+    let session = home.put_claude_session(
+        "project",
+        "reread.jsonl",
+        &format!("{fixture}\n{}\n", reread.join("\n")),
+    );
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg("--dedupe-reads")
+        .arg(&session)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let analysis: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let record = &analysis["records"][0];
+    assert_eq!(record["readFileDetails"].as_array().unwrap().len(), 3);
+    assert_eq!(record["totalReadLines"], 79);
+    assert_eq!(record["uniqueReadLines"], 40);
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg(&session)
+        .output()
+        .unwrap();
+    let analysis: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(analysis["records"][0].get("uniqueReadLines").is_none());
+}
+
+#[test]
+fn analysis_normalize_paths_rewrites_file_paths_in_json() {
+    let home = TempHome::new();
+    let session = fixture("sessions/claude_code.jsonl");
+    let paths_for = |style: &str| -> Vec<String> {
+        let output = child_cmd(&home)
+            .args(["analysis", "--normalize-paths", style])
+            .arg(&session)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let analysis: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        analysis["records"][0]["readFileDetails"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|detail| detail["filePath"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let absolute = paths_for("absolute");
+    assert!(!absolute.is_empty());
+    assert!(absolute.iter().all(|path| path.starts_with('/')));
+    let relative = paths_for("relative");
+    assert!(
+        relative
+            .iter()
+            .all(|path| !path.starts_with("/home/wei/repo/claude-code"))
+    );
+    assert!(paths_for("basename").iter().all(|path| !path.contains('/')));
+
+    child_cmd(&home)
+        .args(["analysis", "--normalize-paths", "canonical"])
+        .arg(&session)
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn analysis_embed_raw_round_trips_the_session_file() {
+    let home = TempHome::new();
+    let contents = fixture_str("sessions/claude_code.jsonl");
+    let session = home.put_claude_session("project", "session.jsonl", &contents);
+    let json_for = |args: &[&str]| {
+        let output = child_cmd(&home).args(args).output().unwrap();
+        assert!(output.status.success(), "{args:?}");
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        (value, String::from_utf8(output.stderr).unwrap())
+    };
+    let raw = |record: &serde_json::Value| {
+        vct_core::analysis::decode_raw_source(record["rawSource"].as_str().unwrap()).unwrap()
+    };
+
+    let session_arg = session.to_str().unwrap();
+    let (single, stderr) = json_for(&["analysis", "--embed-raw", session_arg]);
+    assert!(stderr.contains("--embed-raw"), "{stderr}");
+    assert_eq!(raw(&single["records"][0]), contents.as_bytes());
+
+    let (batch, _) = json_for(&["analysis", "--embed-raw"]);
+    assert_eq!(raw(&batch[0]["records"][0]), contents.as_bytes());
+
+    let (plain, stderr) = json_for(&["analysis", "--json"]);
+    assert!(plain[0]["records"][0].get("rawSource").is_none());
+    assert!(!stderr.contains("--embed-raw"), "{stderr}");
+    let (plain, _) = json_for(&["analysis", session_arg]);
+    assert!(plain["records"][0].get("rawSource").is_none());
+}
+
+#[test]
+fn empty_usage_notes_missing_provider_directories_unless_quiet() {
+    let home = TempHome::new();
+    std::fs::create_dir_all(&home.paths.codex_session_dir).unwrap();
+    let claude_dir = home.paths.claude_session_dir.display().to_string();
+
+    child_cmd(&home)
+        .args(["usage", "--json"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "Note: No Claude-Code sessions found; expected in {claude_dir}"
+        )))
+        // Codex's directory exists; it just holds no sessions.
+        .stderr(predicate::str::contains("No Codex sessions").not());
+
+    child_cmd(&home)
+        .args(["usage", "--json", "--quiet"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Note:").not());
+}
+
+#[test]
+fn cross_check_reports_consistent_when_usage_and_analysis_agree() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "a.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_codex_session("2025/01/01/b.jsonl", &fixture_str("sessions/codex.jsonl"));
+    home.put_gemini_session("project", "c.jsonl", &fixture_str("sessions/gemini.jsonl"));
+    home.put_grok_fixture_session("workspace", "session");
+
+    child_cmd(&home)
+        .args(["usage", "--cross-check"])
+        .assert()
+        .success()
+        .stdout("consistent\n");
+    let output = child_cmd(&home)
+        .args(["usage", "--cross-check", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap(),
+        json!([])
+    );
+
+    child_cmd(&home)
+        .args(["usage", "--table", "--cross-check-tolerance", "5"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn providers_lists_every_provider_with_its_home_directories() {
+    let home = TempHome::new();
+    let output = child_cmd(&home).arg("providers").output().unwrap();
+    assert!(output.status.success());
+    let providers: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let names: Vec<_> = providers
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|provider| provider["extensionName"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "Claude-Code",
+            "Codex",
+            "Copilot-CLI",
+            "Gemini",
+            "Grok",
+            "OpenCode",
+            "Cursor",
+            "Hermes"
+        ]
+    );
+    for (provider, (_, locations)) in providers
+        .as_array()
+        .unwrap()
+        .iter()
+        .zip(home.paths.provider_locations())
+    {
+        let directories: Vec<_> = provider["directories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|directory| directory.as_str().unwrap())
+            .collect();
+        let expected: Vec<_> = locations
+            .iter()
+            .map(|location| location.to_str().unwrap())
+            .collect();
+        assert_eq!(directories, expected, "{provider}");
+        assert!(provider["usage"].as_bool().unwrap());
+        assert_eq!(
+            provider["analysis"].as_bool().unwrap(),
+            provider["extensionName"] != "Hermes"
+        );
+    }
+}
+
+#[test]
+fn fixup_model_regex_merges_dated_variants_under_one_name() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "june.jsonl", &claude);
+    home.put_claude_session(
+        "project",
+        "january.jsonl",
+        &claude.replace("claude-sonnet-4-20250514", "claude-sonnet-4-20250101"),
+    );
+    let rule = r"/(claude-sonnet-4)-\d{8}/=$1";
+
+    let output = child_cmd(&home)
+        .args(["usage", "--json", "--fixup-model", rule])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let models: Vec<&str> = rows
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["model"].as_str().unwrap())
+        .collect();
+    assert_eq!(models, ["claude-sonnet-4"]);
+    assert_eq!(rows[0]["sessions"], 2);
+
+    let output = child_cmd(&home)
+        .args([
+            "analysis",
+            "--summary-only",
+            "--json",
+            "--fixup-model",
+            rule,
+        ])
+        .output()
+        .unwrap();
+    let totals: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(totals["models"], 1);
+}
+
+#[test]
+fn pretty_models_renames_known_ids_in_tables_but_not_in_json() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let stdout = |args: &[&str]| {
+        let output = child_cmd(&home).args(args).output().unwrap();
+        assert!(output.status.success(), "{args:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let table = stdout(&["usage", "--table", "--pretty-models"]);
+    assert!(table.contains("Claude Sonnet 4"), "{table}");
+    assert!(!table.contains("claude-sonnet-4-20250514"), "{table}");
+    let plain = stdout(&["usage", "--table"]);
+    assert!(plain.contains("claude-sonnet-4-20250514"), "{plain}");
+
+    let json = stdout(&["usage", "--json", "--pretty-models"]);
+    assert!(json.contains("\"claude-sonnet-4-20250514\""), "{json}");
+    assert!(!json.contains("Claude Sonnet 4"), "{json}");
+
+    // A configured name wins over the built-in one.
+    home.put(
+        ".vct/config.toml",
+        "[display.model_names]\n\"claude-sonnet-4-20250514\" = \"Team Sonnet\"\n",
+    );
+    let analysis = stdout(&["analysis", "--text", "--pretty-models"]);
+    assert!(analysis.starts_with("Team Sonnet: "), "{analysis}");
+}
+
+#[test]
+fn summary_only_prints_the_totals_without_model_rows() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_codex_session(
+        "2025/01/01/rollout.jsonl",
+        &fixture_str("sessions/codex.jsonl"),
+    );
+    let stdout = |args: &[&str]| {
+        let output = child_cmd(&home).args(args).output().unwrap();
+        assert!(output.status.success(), "{args:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let analysis = stdout(&["analysis", "--summary-only"]);
+    assert_eq!(analysis.lines().count(), 1, "{analysis}");
+    assert!(analysis.starts_with("TOTAL: "), "{analysis}");
+    assert!(analysis.contains("models=2"), "{analysis}");
+    assert!(!analysis.contains("claude-sonnet"), "{analysis}");
+
+    let totals: serde_json::Value =
+        serde_json::from_str(&stdout(&["analysis", "--summary-only", "--json"])).unwrap();
+    assert_eq!(totals["models"], 2);
+    assert!(totals["writeLines"].as_u64().unwrap() > 0);
+
+    let usage = stdout(&["usage", "--summary-only"]);
+    assert_eq!(usage.lines().count(), 1, "{usage}");
+    assert!(usage.starts_with("Total Cost: "), "{usage}");
+    assert!(usage.contains("Models: 2"), "{usage}");
+    assert!(!usage.contains("claude-sonnet"), "{usage}");
+
+    let footer: serde_json::Value =
+        serde_json::from_str(&stdout(&["usage", "--summary-only", "--json"])).unwrap();
+    assert_eq!(footer["models"], 2);
+    assert!(footer["totalTokens"].as_i64().unwrap() > 0);
+    assert!(footer.get("totalCost").is_some());
+}
+
+#[test]
+fn calendar_draws_a_weekday_grid_of_the_recorded_days() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let output = child_cmd(&home)
+        .args(["usage", "--calendar", "--by", "tokens"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Usage Calendar"), "{stdout}");
+    for weekday in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+        assert!(
+            stdout.lines().any(|line| line.starts_with(weekday)),
+            "{stdout}"
+        );
+    }
+    assert!(stdout.contains("over 1 active day"), "{stdout}");
+    assert!(stdout.contains("Busiest "), "{stdout}");
+
+    child_cmd(&home)
+        .args(["usage", "--table", "--by", "tokens"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn analysis_reports_invalid_utf8_and_decodes_it_when_lossy() {
+    let home = TempHome::new();
+    let fixture = fixture_str("sessions/claude_code.jsonl");
+    let session = home.put_claude_session("project", "session.jsonl", &fixture);
+    // Swap one `o` of the first line's `cwd` for a lone latin-1 byte.
+    let mut bytes = fixture.into_bytes();
+    let at = bytes.windows(11).position(|w| w == b"claude-code").unwrap() + 8;
+    bytes[at] = 0xE9;
+    std::fs::write(&session, &bytes).unwrap();
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg(&session)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not valid UTF-8"), "{stderr}");
+    assert!(stderr.contains(&format!("offset {at}")), "{stderr}");
+
+    let output = child_cmd(&home)
+        .args(["analysis", "--encoding", "lossy"])
+        .arg(&session)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(!value["records"].as_array().unwrap().is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(stderr.trim_end(), "⚠️ 1 warning (run with -v for details)");
+}
+
+#[test]
+fn analysis_no_machine_id_blanks_the_field() {
+    let home = TempHome::new();
+    let session = home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let machine_id = |cmd: &mut Command| {
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        value["machineId"].as_str().unwrap().to_string()
+    };
+
+    assert!(!machine_id(child_cmd(&home).arg("analysis").arg(&session)).is_empty());
+    assert_eq!(
+        machine_id(
+            child_cmd(&home)
+                .arg("analysis")
+                .arg("--no-machine-id")
+                .arg(&session)
+        ),
+        ""
+    );
+    assert_eq!(
+        machine_id(
+            child_cmd(&home)
+                .env("VCT_DISABLE_MACHINE_ID", "1")
+                .arg("analysis")
+                .arg(&session)
+        ),
+        ""
+    );
+    // Only `1` or `true` opt out; `0` keeps the id.
+    assert!(
+        !machine_id(
+            child_cmd(&home)
+                .env("VCT_DISABLE_MACHINE_ID", "0")
+                .arg("analysis")
+                .arg(&session)
+        )
+        .is_empty()
+    );
+
+    // Single-file and batch JSON honor the config switch too.
+    std::fs::create_dir_all(home.home().join(".vct")).unwrap();
+    std::fs::write(
+        home.home().join(".vct/config.toml"),
+        "[analysis]\nhide_machine_id = true\n",
+    )
+    .unwrap();
+    assert_eq!(
+        machine_id(child_cmd(&home).arg("analysis").arg(&session)),
+        ""
+    );
+    let output = child_cmd(&home)
+        .args(["analysis", "--json", "--all"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let sessions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let sessions = sessions.as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["machineId"], "");
+}
+
+#[test]
+fn analysis_provider_detect_stats_reports_unknown_files() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "a.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_codex_session("2025/01/01/blank.jsonl", "{\"note\":\"no marker\"}\n");
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg("--provider-detect-stats")
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stats["total_files"], 2);
+    assert_eq!(
+        stats["providers"],
+        json!([
+            {"provider": "Claude-Code", "files": 1, "percent": 50.0},
+            {"provider": "Unknown", "files": 1, "percent": 50.0},
+        ])
+    );
+
+    child_cmd(&home)
+        .arg("analysis")
+        .arg("--provider-detect-stats")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Provider Detection"))
+        .stdout(predicate::str::contains("Unknown"));
+}
+
+#[test]
+fn analysis_sessions_per_day_emits_date_counts() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "a.jsonl", &claude);
+    home.put_claude_session("project", "b.jsonl", &claude);
+
+    let output = child_cmd(&home)
+        .arg("analysis")
+        .arg("--sessions-per-day")
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let counts: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let counts = counts.as_object().unwrap();
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts.values().next(), Some(&json!(2)));
+
+    child_cmd(&home)
+        .arg("analysis")
+        .arg("--sessions-per-day")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sessions per Day"));
+}
+
+#[test]
+fn usage_fold_case_merges_case_variant_models() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "lower.jsonl", &claude);
+    home.put_claude_session(
+        "project",
+        "upper.jsonl",
+        &claude.replace("claude-sonnet-4-20250514", "Claude-Sonnet-4-20250514"),
+    );
+
+    let models = |fold: bool| {
+        let mut cmd = child_cmd(&home);
+        cmd.arg("usage").arg("--json");
+        if fold {
+            cmd.arg("--fold-case");
+        }
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let mut models: Vec<String> = rows
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row["model"].as_str().unwrap().to_string())
+            .collect();
+        models.sort();
+        models
+    };
+
+    assert_eq!(models(false).len(), 2);
+    assert_eq!(models(true), ["claude-sonnet-4-20250514"]);
+}
+
+#[test]
+fn usage_model_width_cuts_long_names_in_the_table_but_not_in_json() {
+    let home = TempHome::new();
+    let long_model = format!("proxy/{}/claude-sonnet-4", "x".repeat(200));
+    home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl").replace("claude-sonnet-4-20250514", &long_model),
+    );
+
+    let output = child_cmd(&home)
+        .args(["usage", "--table", "--model-width", "24"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let table = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        table.contains(&format!("{}…", &long_model[..23])),
+        "{table}"
+    );
+    assert!(!table.contains(&long_model));
+
+    let output = child_cmd(&home).args(["usage", "--json"]).output().unwrap();
+    assert!(output.status.success());
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rows[0]["model"], long_model.as_str());
+}
+
+#[test]
+fn files_from_stdin_processes_exactly_the_listed_files() {
+    // The home holds an unrelated session that discovery would pick up.
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "ignored.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let list = format!(
+        "{}\n\n{}\n",
+        fixture("sessions/claude_code.jsonl").display(),
+        fixture("sessions/codex.jsonl").display()
+    );
+
+    let output = child_cmd(&home)
+        .args(["analysis", "--files-from", "-", "--json"])
+        .write_stdin(list.clone())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let sessions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let providers: Vec<&str> = sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|session| session["extensionName"].as_str().unwrap())
+        .collect();
+    assert_eq!(providers, ["Claude-Code", "Codex"]);
+
+    let output = child_cmd(&home)
+        .args(["usage", "--files-from", "-", "--json"])
+        .write_stdin(list)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rows.as_array().unwrap().len(), 2);
+
+    child_cmd(&home)
+        .args(["usage", "--files-from", "-"])
+        .write_stdin("/no/such/session.jsonl\n")
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("does not exist"));
+}
+
 #[test]
 fn analysis_legacy_path_and_output_flags_are_rejected() {
     let path = fixture("sessions/claude_code.jsonl");
@@ -180,7 +867,78 @@ fn test_analysis_command_with_nonexistent_file() {
         .arg("analysis")
         .arg("nonexistent_file.jsonl")
         .assert()
-        .failure();
+        .code(3)
+        .stderr(predicate::str::contains("no such file"));
+}
+
+#[test]
+fn exit_code_contract_covers_success_no_data_and_network_failure() {
+    let home = TempHome::new();
+    child_cmd(&home)
+        .args(["version", "--json"])
+        .assert()
+        .code(0);
+
+    // An empty HOME has no sessions, which `--fail-on-empty` turns into 2
+    // after the (empty) output is still printed.
+    child_cmd(&home)
+        .args(["usage", "--json", "--fail-on-empty"])
+        .assert()
+        .code(2);
+    child_cmd(&home).args(["usage", "--json"]).assert().code(0);
+
+    // Route the update check through a proxy on a closed local port so the
+    // request fails deterministically without touching GitHub.
+    child_cmd(&home)
+        .args(["update", "--check"])
+        .env_remove("VCT_OFFLINE")
+        .env_remove("NO_PROXY")
+        .env_remove("no_proxy")
+        .env("HTTPS_PROXY", "http://127.0.0.1:9")
+        .env("https_proxy", "http://127.0.0.1:9")
+        .env("ALL_PROXY", "http://127.0.0.1:9")
+        .assert()
+        .code(4);
+}
+
+#[test]
+fn update_check_json_reports_a_failed_check_as_an_error_object() {
+    let home = TempHome::new();
+    // Same closed-port proxy as above: the release fetch fails without
+    // touching GitHub.
+    let output = child_cmd(&home)
+        .args(["update", "--check", "--json"])
+        .env_remove("VCT_OFFLINE")
+        .env_remove("NO_PROXY")
+        .env_remove("no_proxy")
+        .env("HTTPS_PROXY", "http://127.0.0.1:9")
+        .env("https_proxy", "http://127.0.0.1:9")
+        .env("ALL_PROXY", "http://127.0.0.1:9")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    let body: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        body["error"].as_str().unwrap().contains("release"),
+        "{body}"
+    );
+
+    // Offline mode is an error too, not a silent "no update".
+    let offline = child_cmd(&home)
+        .args(["update", "--check", "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(offline.status.code(), Some(4));
+    let body: serde_json::Value = serde_json::from_slice(&offline.stdout).unwrap();
+    assert!(body["error"].as_str().unwrap().contains("VCT_OFFLINE"));
+    // ...and the plain check agrees with the JSON one.
+    child_cmd(&home)
+        .args(["update", "--check"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("VCT_OFFLINE"));
+
+    child_cmd(&home).args(["update", "--json"]).assert().code(3);
 }
 
 #[test]
@@ -222,7 +980,7 @@ fn analysis_file_warns_when_only_some_analyzer_payloads_fail() {
     .unwrap();
 
     let output = child_cmd(&home)
-        .arg("analysis")
+        .args(["analysis", "--verbose"])
         .arg(&path)
         .output()
         .unwrap();
@@ -337,7 +1095,64 @@ fn test_analysis_help() {
         .stdout(predicate::str::contains("analysis"))
         .stdout(predicate::str::contains("[FILE]"))
         .stdout(predicate::str::contains("--path").not())
-        .stdout(predicate::str::contains("--output").not());
+        // `--output` only names the `--keep-running` log, not an export file.
+        .stdout(predicate::str::contains(
+            "Log file `--keep-running` appends to",
+        ));
+}
+
+#[test]
+fn dev_verifies_every_committed_golden_and_stays_out_of_help() {
+    let home = TempHome::new();
+    child_cmd(&home)
+        .args(["dev", "--fixtures"])
+        .arg(fixture("sessions"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("claude_code"))
+        .stdout(predicate::str::contains("grok"))
+        .stdout(predicate::str::contains("FAIL").not());
+
+    Command::cargo_bin("vibe_coding_tracker")
+        .unwrap()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dev").not());
+}
+
+#[test]
+fn dev_bench_matcher_reports_lookup_times_and_per_kind_counts() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_codex_session("2025/01/01/a.jsonl", &fixture_str("sessions/codex.jsonl"));
+    home.seed_pricing_cache(&pricing_seed());
+
+    let output = child_cmd(&home)
+        .args(["dev", "--bench-matcher"])
+        .output()
+        .expect("spawn vct");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stdout={stdout}");
+    assert!(stdout.contains("Pricing matcher: 2 models"), "{stdout}");
+    assert!(stdout.contains("avg "), "{stdout}");
+    let count = |kind: &str| {
+        stdout
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(kind))
+            .map(str::trim)
+            .unwrap_or_else(|| panic!("no `{kind}` line in {stdout}"))
+            .to_string()
+    };
+    // The seeded Claude model is a pricing key verbatim; the Codex one is not.
+    assert_eq!(count("exact"), "1");
+    for kind in ["normalized", "builtin", "substring", "fuzzy", "unmatched"] {
+        count(kind);
+    }
 }
 
 #[test]
@@ -636,6 +1451,77 @@ fn usage_json_empty_home_is_empty_array() {
     assert_eq!(rows.as_array().map(|a| a.len()), Some(0));
 }
 
+#[test]
+fn stats_json_combines_usage_and_analysis() {
+    let home = TempHome::new();
+    // Sessions are dated by mtime, so a freshly written fixture counts as today.
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &vct_test_support::fixture_str("sessions/claude_code.jsonl"),
+    );
+    // An older session counts toward all-time totals but neither today's nor
+    // this month's cost.
+    let old = home.put_claude_session(
+        "proj",
+        "old.jsonl",
+        &vct_test_support::fixture_str("sessions/claude_code.jsonl"),
+    );
+    vct_test_support::backdate(&old, 40);
+    home.seed_pricing_cache(&pricing_seed());
+
+    let output = child_cmd(&home)
+        .args(["stats", "--json"])
+        .output()
+        .expect("spawn vct");
+    assert!(
+        output.status.success(),
+        "vct stats --json failed: stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let today = stats["today_cost_usd"].as_f64().unwrap();
+    assert!(today > 0.0, "the seeded model is priced");
+    assert_eq!(stats["month_cost_usd"].as_f64(), Some(today));
+    assert_eq!(stats["top_model"]["model"], "claude-sonnet-4-20250514");
+    assert_eq!(stats["active_days"], 2);
+    assert!(stats["lines_written_today"].as_u64().unwrap() > 0);
+    let providers = stats["tokens_by_provider"].as_array().unwrap();
+    assert_eq!(providers.len(), 1);
+    assert_eq!(providers[0]["provider"], "Claude");
+    assert_eq!(providers[0]["tokens"], stats["total_tokens"]);
+
+    child_cmd(&home)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Overview"))
+        .stdout(predicate::str::contains("claude-sonnet-4-20250514"));
+}
+
+#[test]
+fn stats_skips_sessions_in_vctignore() {
+    let home = TempHome::new();
+    let session = vct_test_support::fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("proj", "session.jsonl", &session);
+    home.put_claude_session("scratch", "trial.jsonl", &session);
+    home.seed_pricing_cache(&pricing_seed());
+    let total_tokens = || {
+        let output = child_cmd(&home)
+            .args(["stats", "--json"])
+            .output()
+            .expect("spawn vct");
+        assert!(output.status.success());
+        let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        stats["total_tokens"].as_i64().unwrap()
+    };
+
+    let both = total_tokens();
+    home.put(".vct/.vctignore", "scratch/\n");
+    assert_eq!(total_tokens() * 2, both);
+}
+
 #[test]
 fn usage_text_and_table_smoke() {
     let home = TempHome::new();
@@ -651,6 +1537,163 @@ fn usage_text_and_table_smoke() {
     }
 }
 
+#[test]
+fn usage_webhook_failure_warns_unless_required() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.seed_pricing_cache(&pricing_seed());
+    // `child_cmd` runs offline, so the POST is refused before any request.
+    let webhook = [
+        "usage",
+        "--table",
+        "-v",
+        "--webhook",
+        "http://127.0.0.1:9/hook/secret-token",
+    ];
+
+    let output = child_cmd(&home).args(webhook).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!output.stdout.is_empty());
+    assert!(
+        stderr.contains("Not posting to webhook http://127.0.0.1"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("secret-token"), "{stderr}");
+
+    child_cmd(&home)
+        .args(webhook)
+        .arg("--webhook-required")
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Not posting to webhook"));
+}
+
+#[test]
+fn offline_refusals_exit_with_the_network_error_code() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.seed_pricing_cache(&pricing_seed());
+
+    child_cmd(&home)
+        .env("VCT_OFFLINE", "1")
+        .args(["update", "--check"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("VCT_OFFLINE"));
+    child_cmd(&home)
+        .env("VCT_OFFLINE", "1")
+        .args([
+            "usage",
+            "--json",
+            "--webhook",
+            "http://127.0.0.1:9/hook",
+            "--webhook-required",
+        ])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("VCT_OFFLINE"));
+}
+
+#[test]
+fn usage_diff_reports_per_model_deltas_between_two_exports() {
+    let home = TempHome::new();
+    let before = home.home().join("before.json");
+    let after = home.home().join("after.json");
+    std::fs::write(
+        &before,
+        r#"[{"cost_usd": 1.0, "model": "claude-3-5-sonnet", "sessions": 1,
+             "usage": {"input_tokens": 1000, "output_tokens": 0}}]"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &after,
+        r#"[{"cost_usd": 3.1, "model": "claude-3-5-sonnet", "sessions": 2,
+             "usage": {"input_tokens": 46000, "output_tokens": 0}}]"#,
+    )
+    .unwrap();
+
+    child_cmd(&home)
+        .args(["usage", "--text", "--diff"])
+        .arg(&before)
+        .arg(&after)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "claude-3-5-sonnet: +$2.10, +45.0K tokens",
+        ));
+
+    let output = child_cmd(&home)
+        .args(["usage", "--json", "--diff"])
+        .arg(&before)
+        .arg(&after)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(diff["models"][0]["tokens_delta"], 45_000);
+    assert_eq!(diff["total"]["model"], "TOTAL");
+    assert_eq!(diff["total"]["tokens_after"], 46_000);
+}
+
+#[test]
+fn usage_estimate_only_prices_from_a_stale_cache_without_fetching() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &vct_test_support::fixture_str("sessions/claude_code.jsonl"),
+    );
+    // Only an old cache exists, so a normal run would have to fetch today's.
+    let today = home.seed_pricing_cache(&pricing_seed());
+    std::fs::rename(
+        &today,
+        home.paths.cache_dir.join("model_pricing_2020-01-01.json"),
+    )
+    .unwrap();
+
+    // Network is left enabled but routed to a dead proxy: any fetch attempt
+    // would fail and surface as a pricing warning with zero costs.
+    let output = child_cmd(&home)
+        .env_remove("VCT_OFFLINE")
+        .env("HTTPS_PROXY", "http://127.0.0.1:9")
+        .env("HTTP_PROXY", "http://127.0.0.1:9")
+        .env("ALL_PROXY", "http://127.0.0.1:9")
+        .arg("usage")
+        .arg("--estimate-only")
+        .arg("--json")
+        .output()
+        .expect("spawn vct");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr={stderr}");
+    assert!(stderr.contains("estimate only"), "stderr={stderr}");
+    assert!(!stderr.contains("pricing data"), "stderr={stderr}");
+
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let sonnet = rows
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["model"] == "claude-sonnet-4-20250514")
+        .expect("seeded Claude model");
+    assert!(sonnet["cost_usd"].as_f64().unwrap() > 0.0);
+
+    child_cmd(&home)
+        .arg("usage")
+        .arg("--estimate-only")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(estimate)"));
+}
+
 #[test]
 fn usage_merge_providers_flag_smoke() {
     let home = TempHome::new();
@@ -780,7 +1823,7 @@ fn analysis_noninteractive_formats_warn_and_keep_partial_results() {
         home.put_claude_session("broken", "broken.jsonl", "{not json\n");
 
         child_cmd(&home)
-            .arg("analysis")
+            .args(["analysis", "-v"])
             .arg(format)
             .assert()
             .success()
@@ -791,6 +1834,38 @@ fn analysis_noninteractive_formats_warn_and_keep_partial_results() {
     }
 }
 
+#[test]
+fn skipped_session_warnings_are_summarized_after_the_output() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "valid",
+        "valid.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_claude_session("broken", "broken.jsonl", "{not json\n");
+    home.seed_pricing_cache(&pricing_seed());
+
+    let output = child_cmd(&home).args(["usage", "--json"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap();
+    assert_eq!(stderr.trim_end(), "⚠️ 1 warning (run with -v for details)");
+
+    let output = child_cmd(&home)
+        .args(["usage", "--json", "-v"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert_eq!(lines.len(), 2, "{stderr}");
+    assert!(
+        lines[0].starts_with("Warning: Encountered 1 usage source failures"),
+        "{stderr}"
+    );
+    assert!(lines[0].contains("broken.jsonl"), "{stderr}");
+    assert_eq!(lines[1], "⚠️ 1 warning");
+}
+
 #[test]
 fn all_short_flag_parses_for_both_subcommands() {
     let home = TempHome::new();
@@ -844,6 +1919,32 @@ fn config_path_prints_config_toml_location() {
         .stdout(predicate::str::contains("config.toml"));
 }
 
+#[test]
+fn config_validate_reports_the_offending_line_or_confirms_validity() {
+    let home = TempHome::new();
+    home.put(
+        ".vct/config.toml",
+        "[usage]\nmerge_models = true\nrefresh_interval = \"soon\"\n",
+    );
+    child_cmd(&home)
+        .args(["config", "validate"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid config file"))
+        .stderr(predicate::str::contains("line 3"))
+        .stderr(predicate::str::contains("refresh_interval = \"soon\""));
+
+    home.put(
+        ".vct/config.toml",
+        "[usage]\nmerge_models = true\nrefresh_interval = 30\n",
+    );
+    child_cmd(&home)
+        .args(["config", "validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Config is valid"));
+}
+
 #[test]
 fn config_show_creates_and_prints_settings() {
     let home = TempHome::new();
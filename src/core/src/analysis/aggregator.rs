@@ -8,23 +8,26 @@ use crate::session::cursor::{
 };
 use crate::session::diagnostics::DatabaseAnalysisRow;
 use crate::session::opencode::read_opencode_analysis_with_diagnostics;
-use crate::session::parser::parse_session_file_typed_as_with_diagnostics;
+use crate::session::parser::{ParseOptions, parse_session_file_typed_as_with_diagnostics};
 use crate::session::sqlite::is_cacheable_sqlite_failure;
 use crate::session::state::ParseMode;
 use crate::summary_cache::{
     CompactSourceSummary, SourceFingerprint, SummaryCacheKey, SummaryKind, SummaryScanCache,
 };
 use crate::utils::directory::{FileInfo, collect_files_with_max_depth_diagnostics};
+use crate::utils::fold_case::fold_keys;
+use crate::utils::model_fixup::{ModelFixups, model_names_rewritten, rewrite_model_name};
 use crate::utils::{
-    COPILOT_SESSION_MAX_DEPTH, GROK_SESSION_MAX_DEPTH, HelperPaths, get_current_user,
-    get_machine_id, is_claude_session_file, is_codex_session_file, is_copilot_session_file,
-    is_gemini_session_file, is_grok_session_file,
+    COPILOT_SESSION_MAX_DEPTH, DedupeBy, GROK_SESSION_MAX_DEPTH, HelperPaths, SessionIgnore,
+    TextEncoding, extract_token_counts, get_current_user, get_machine_id, is_claude_session_file,
+    is_codex_session_file, is_copilot_session_file, is_gemini_session_file, is_grok_session_file,
 };
+use crate::warnings::WarningSink;
 use anyhow::Result;
 use rayon::prelude::*;
 use serde::{Serialize, Serializer, ser::SerializeSeq};
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 
 // `AggregatedAnalysisRow` is a neutral DTO shared with the scan cache, so it
 // lives in `models`; re-exported here to keep the `analysis::AggregatedAnalysisRow`
@@ -88,15 +91,18 @@ pub struct PerProviderAnalysisRows {
 
 /// One parsed session in the canonical batch-analysis dataset.
 ///
-/// `provider` and `date` retain the source provenance needed by the summary
-/// projection. They are intentionally not part of the public JSON shape; the
-/// nested [`CodeAnalysis`] is the same object emitted by single-file analysis.
+/// `provider`, `date`, and `source` retain the source provenance needed by
+/// the summary projection and the session filters. They are intentionally not
+/// part of the public JSON shape; the nested [`CodeAnalysis`] is the same
+/// object emitted by single-file analysis.
 #[derive(Debug, Clone)]
 pub struct AnalysisSession {
     /// Provider selected from the source directory or database.
     pub provider: ExtensionType,
     /// Local `YYYY-MM-DD` date used by the active-day summary.
     pub date: String,
+    /// Session file the result was parsed from; `None` for database rows.
+    pub source: Option<PathBuf>,
     /// Complete normalized parser result for this session.
     pub analysis: CodeAnalysis,
 }
@@ -138,6 +144,38 @@ impl AnalysisDataset {
         project_analysis_dataset(self)
     }
 
+    /// Total tokens per local `(date, model)`, read from each record's own
+    /// token maps. The analysis side of `usage --cross-check`.
+    ///
+    /// Advisor tokens are counted too: `usage` bills them, so leaving them out
+    /// here would flag every session that consulted an advisor. Models are
+    /// renamed as `options` renames the summary rows.
+    pub fn tokens_by_date_and_model(
+        &self,
+        options: &AnalysisScanOptions,
+    ) -> BTreeMap<(String, String), i64> {
+        let rename = model_names_rewritten(&options.fixups, options.fold_case);
+        let mut totals = BTreeMap::new();
+        for session in &self.sessions {
+            for record in &session.analysis.records {
+                for (model, usage) in record
+                    .conversation_usage
+                    .iter()
+                    .chain(&record.advisor_usage)
+                {
+                    let model = if rename {
+                        rewrite_model_name(model, &options.fixups, options.fold_case)
+                    } else {
+                        model.clone()
+                    };
+                    *totals.entry((session.date.clone(), model)).or_insert(0) +=
+                        extract_token_counts(usage).total;
+                }
+            }
+        }
+        totals
+    }
+
     /// Projects the dataset while retaining its collection diagnostics.
     pub fn summarize_with_diagnostics(&self) -> AnalysisCollection {
         AnalysisCollection {
@@ -160,6 +198,43 @@ impl Serialize for AnalysisDataset {
     }
 }
 
+/// Optional knobs for an analysis scan, the counterpart of
+/// [`UsageScanOptions`](crate::usage::UsageScanOptions).
+///
+/// `encoding` is how invalid UTF-8 in a session file is read (`--encoding`).
+/// `fold_case` merges case variants of a model name in the summary rows
+/// (`--fold-case`), `fixups` renames models in them (`--fixup-model`),
+/// `ignore` skips sessions (`.vctignore`, `--ignore-file`), `dedupe_by`
+/// counts a repeated Claude token event once (`--dedupe-by`), and `warnings`
+/// collects the run warnings the scan records.
+#[derive(Debug, Default, Clone)]
+pub struct AnalysisScanOptions {
+    /// How bytes that are not valid UTF-8 are treated.
+    pub encoding: TextEncoding,
+    /// Merge models whose names differ only in case under the lowercased name.
+    pub fold_case: bool,
+    /// Model renaming rules applied to the summary rows.
+    pub fixups: ModelFixups,
+    /// Sessions excluded from discovery (`.vctignore`, `--ignore-file`).
+    pub ignore: SessionIgnore,
+    /// What repeated Claude token events are recognized by.
+    pub dedupe_by: Option<DedupeBy>,
+    /// Where problems the scan works around are recorded.
+    pub warnings: WarningSink,
+}
+
+impl AnalysisScanOptions {
+    /// The parser settings of this scan; analysis never classifies tiers.
+    fn parse_options(&self) -> ParseOptions<'_> {
+        ParseOptions {
+            tiers: None,
+            encoding: self.encoding,
+            dedupe_by: self.dedupe_by,
+            warnings: Some(&self.warnings),
+        }
+    }
+}
+
 /// Aggregate file-operation metrics across every provider's session files,
 /// keyed by model.
 ///
@@ -211,10 +286,24 @@ pub fn aggregate_sessions_by_model_with_diagnostics(
     time_range: TimeRange,
     providers: ProvidersConfig,
 ) -> Result<AnalysisCollection> {
-    aggregate_sessions_by_model_from_paths_with_diagnostics(
+    aggregate_sessions_by_model_with_diagnostics_opts(
+        time_range,
+        providers,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`aggregate_sessions_by_model_with_diagnostics`] with scan options.
+pub fn aggregate_sessions_by_model_with_diagnostics_opts(
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    options: &AnalysisScanOptions,
+) -> Result<AnalysisCollection> {
+    aggregate_sessions_by_model_from_paths_with_diagnostics_opts(
         &crate::utils::resolve_paths()?,
         time_range,
         providers,
+        options,
     )
 }
 
@@ -250,6 +339,22 @@ pub fn aggregate_sessions_by_model_from_paths_with_diagnostics(
     paths: &HelperPaths,
     time_range: TimeRange,
     providers: ProvidersConfig,
+) -> Result<AnalysisCollection> {
+    aggregate_sessions_by_model_from_paths_with_diagnostics_opts(
+        paths,
+        time_range,
+        providers,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`aggregate_sessions_by_model_from_paths_with_diagnostics`] with scan
+/// options.
+pub fn aggregate_sessions_by_model_from_paths_with_diagnostics_opts(
+    paths: &HelperPaths,
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    options: &AnalysisScanOptions,
 ) -> Result<AnalysisCollection> {
     let mut projection = AnalysisProjection::new();
     let diagnostics = visit_analysis_sessions_from_paths_with(
@@ -257,10 +362,11 @@ pub fn aggregate_sessions_by_model_from_paths_with_diagnostics(
         time_range,
         providers,
         ParseMode::UsageOnly,
+        options,
         &mut |session| projection.add_session(&session),
     )?;
     Ok(AnalysisCollection {
-        data: projection.finish(),
+        data: projection.finish(options),
         diagnostics,
     })
 }
@@ -275,11 +381,27 @@ pub fn collect_analysis_sessions_with(
     providers: ProvidersConfig,
     mode: ParseMode,
 ) -> Result<AnalysisDataset> {
-    collect_analysis_sessions_from_paths_with(
+    collect_analysis_sessions_with_opts(
+        time_range,
+        providers,
+        mode,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`collect_analysis_sessions_with`] with scan options.
+pub fn collect_analysis_sessions_with_opts(
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    mode: ParseMode,
+    options: &AnalysisScanOptions,
+) -> Result<AnalysisDataset> {
+    collect_analysis_sessions_from_paths_with_opts(
         &crate::utils::resolve_paths()?,
         time_range,
         providers,
         mode,
+        options,
     )
 }
 
@@ -293,6 +415,23 @@ pub fn collect_analysis_sessions_from_paths_with(
     time_range: TimeRange,
     providers: ProvidersConfig,
     mode: ParseMode,
+) -> Result<AnalysisDataset> {
+    collect_analysis_sessions_from_paths_with_opts(
+        paths,
+        time_range,
+        providers,
+        mode,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`collect_analysis_sessions_from_paths_with`] with scan options.
+pub fn collect_analysis_sessions_from_paths_with_opts(
+    paths: &HelperPaths,
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    mode: ParseMode,
+    options: &AnalysisScanOptions,
 ) -> Result<AnalysisDataset> {
     let mut sessions = Vec::new();
     let diagnostics = visit_analysis_sessions_from_paths_with(
@@ -300,6 +439,7 @@ pub fn collect_analysis_sessions_from_paths_with(
         time_range,
         providers,
         mode,
+        options,
         &mut |session| sessions.push(session),
     )?;
     Ok(AnalysisDataset {
@@ -308,6 +448,59 @@ pub fn collect_analysis_sessions_from_paths_with(
     })
 }
 
+/// Collects the batch-analysis dataset from an explicit list of session files
+/// (`--files-from`) instead of the provider directories and databases.
+///
+/// Each file's provider is detected from its content (a file without any
+/// marker is read as Codex, as for `analysis FILE`); files of a disabled
+/// provider are left out. Sessions are ordered by provider, then path.
+///
+/// # Errors
+///
+/// Returns an error if a listed file cannot be read for classification.
+pub fn collect_analysis_sessions_from_files(
+    files: &[PathBuf],
+    providers: ProvidersConfig,
+    mode: ParseMode,
+) -> Result<AnalysisDataset> {
+    collect_analysis_sessions_from_files_opts(
+        files,
+        providers,
+        mode,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`collect_analysis_sessions_from_files`] with scan options.
+///
+/// # Errors
+///
+/// Returns an error if a listed file cannot be read for classification.
+pub fn collect_analysis_sessions_from_files_opts(
+    files: &[PathBuf],
+    providers: ProvidersConfig,
+    mode: ParseMode,
+    options: &AnalysisScanOptions,
+) -> Result<AnalysisDataset> {
+    let mut sessions = Vec::new();
+    let mut diagnostics = ScanDiagnostics::default();
+    for (provider, group) in crate::scan::classify_listed_files(files, providers)? {
+        visit_file_infos(
+            group,
+            provider,
+            mode,
+            options.parse_options(),
+            &mut diagnostics,
+            &mut |session| sessions.push(session),
+        );
+    }
+    diagnostics.finalize();
+    Ok(AnalysisDataset {
+        sessions,
+        diagnostics,
+    })
+}
+
 /// Visits parsed sessions in deterministic provider and source order.
 ///
 /// The canonical collector passes a `Vec::push` visitor and retains every
@@ -319,6 +512,7 @@ fn visit_analysis_sessions_from_paths_with<F>(
     time_range: TimeRange,
     providers: ProvidersConfig,
     mode: ParseMode,
+    options: &AnalysisScanOptions,
     visitor: &mut F,
 ) -> Result<ScanDiagnostics>
 where
@@ -334,6 +528,7 @@ where
             time_range,
             None,
             mode,
+            options,
             &mut diagnostics,
             visitor,
         )?;
@@ -347,6 +542,7 @@ where
             time_range,
             None,
             mode,
+            options,
             &mut diagnostics,
             visitor,
         )?;
@@ -360,6 +556,7 @@ where
             time_range,
             Some(COPILOT_SESSION_MAX_DEPTH),
             mode,
+            options,
             &mut diagnostics,
             visitor,
         )?;
@@ -373,6 +570,7 @@ where
             time_range,
             None,
             mode,
+            options,
             &mut diagnostics,
             visitor,
         )?;
@@ -386,6 +584,7 @@ where
             time_range,
             Some(GROK_SESSION_MAX_DEPTH),
             mode,
+            options,
             &mut diagnostics,
             visitor,
         )?;
@@ -462,11 +661,27 @@ pub fn aggregate_sessions_by_model_with_cache(
     providers: ProvidersConfig,
     cache: &mut SummaryScanCache,
 ) -> Result<AnalysisCollection> {
-    aggregate_sessions_by_model_from_paths_with_cache(
+    aggregate_sessions_by_model_with_cache_opts(
+        time_range,
+        providers,
+        cache,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`aggregate_sessions_by_model_with_cache`] with scan options.
+pub fn aggregate_sessions_by_model_with_cache_opts(
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    cache: &mut SummaryScanCache,
+    options: &AnalysisScanOptions,
+) -> Result<AnalysisCollection> {
+    aggregate_sessions_by_model_from_paths_with_cache_opts(
         &crate::utils::resolve_paths()?,
         time_range,
         providers,
         cache,
+        options,
     )
 }
 
@@ -480,6 +695,25 @@ pub fn aggregate_sessions_by_model_from_paths_with_cache(
     providers: ProvidersConfig,
     cache: &mut SummaryScanCache,
 ) -> Result<AnalysisCollection> {
+    aggregate_sessions_by_model_from_paths_with_cache_opts(
+        paths,
+        time_range,
+        providers,
+        cache,
+        &AnalysisScanOptions::default(),
+    )
+}
+
+/// [`aggregate_sessions_by_model_from_paths_with_cache`] with scan options.
+pub fn aggregate_sessions_by_model_from_paths_with_cache_opts(
+    paths: &HelperPaths,
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    cache: &mut SummaryScanCache,
+    options: &AnalysisScanOptions,
+) -> Result<AnalysisCollection> {
+    // A file that failed as strict UTF-8 may parse under another encoding.
+    cache.ensure_parse_settings(0, options.encoding, options.dedupe_by);
     cache.begin_scan();
     let mut projection = AnalysisProjection::new();
     let mut diagnostics = ScanDiagnostics::default();
@@ -489,11 +723,12 @@ pub fn aggregate_sessions_by_model_from_paths_with_cache(
         paths,
         providers,
         time_range,
+        &options.ignore,
         cache,
         &mut seen,
         &mut projection,
         &mut diagnostics,
-        None,
+        options.parse_options(),
     )?;
 
     if providers.opencode && paths.opencode_db.exists() {
@@ -520,7 +755,7 @@ pub fn aggregate_sessions_by_model_from_paths_with_cache(
     cache.retain_kinds(&seen, &[SummaryKind::File, SummaryKind::AnalysisDatabase]);
     diagnostics.finalize();
     Ok(AnalysisCollection {
-        data: projection.finish(),
+        data: projection.finish(options),
         diagnostics,
     })
 }
@@ -725,11 +960,19 @@ fn scan_cursor_analysis(
 /// Projects a canonical dataset into the compact model/provider summaries used
 /// by the TUI, text, and table renderers.
 pub fn project_analysis_dataset(dataset: &AnalysisDataset) -> AnalysisData {
+    project_analysis_dataset_opts(dataset, &AnalysisScanOptions::default())
+}
+
+/// [`project_analysis_dataset`] renaming models as `options` does for a scan.
+pub fn project_analysis_dataset_opts(
+    dataset: &AnalysisDataset,
+    options: &AnalysisScanOptions,
+) -> AnalysisData {
     let mut projection = AnalysisProjection::new();
     for session in &dataset.sessions {
         projection.add_session(session);
     }
-    projection.finish()
+    projection.finish(options)
 }
 
 /// Projects one complete parser result into the same summary shape as a batch.
@@ -738,6 +981,14 @@ pub fn project_analysis_dataset(dataset: &AnalysisDataset) -> AnalysisData {
 /// deliberately shares the batch projection instead of duplicating counters in
 /// CLI wiring.
 pub fn project_code_analysis(analysis: &CodeAnalysis) -> AnalysisData {
+    project_code_analysis_opts(analysis, &AnalysisScanOptions::default())
+}
+
+/// [`project_code_analysis`] renaming models as `options` does for a scan.
+pub fn project_code_analysis_opts(
+    analysis: &CodeAnalysis,
+    options: &AnalysisScanOptions,
+) -> AnalysisData {
     let provider = extension_type_from_name(&analysis.extension_name);
     let mut projection = AnalysisProjection::new();
     projection.add_analysis(provider, analysis);
@@ -755,7 +1006,7 @@ pub fn project_code_analysis(analysis: &CodeAnalysis) -> AnalysisData {
         projection.add_date(provider, date);
     }
 
-    projection.finish()
+    projection.finish(options)
 }
 
 /// Drains a model-keyed map into a `Vec` sorted by model name.
@@ -768,7 +1019,8 @@ fn into_sorted_rows(map: FastHashMap<String, AggregatedAnalysisRow>) -> Vec<Aggr
 type FileSessionOutcome =
     std::result::Result<(Option<AnalysisSession>, Option<ScanFailure>), ScanFailure>;
 
-/// Visits one file-backed provider in deterministic path order.
+/// Visits one file-backed provider in deterministic path order, skipping the
+/// sessions `options.ignore` excludes.
 #[allow(clippy::too_many_arguments)]
 fn visit_file_sessions<F, V>(
     dir: &Path,
@@ -777,6 +1029,7 @@ fn visit_file_sessions<F, V>(
     time_range: TimeRange,
     max_depth: Option<usize>,
     mode: ParseMode,
+    options: &AnalysisScanOptions,
     diagnostics: &mut ScanDiagnostics,
     visitor: &mut V,
 ) -> Result<()>
@@ -784,7 +1037,13 @@ where
     F: Copy + Fn(&Path) -> bool + Sync + Send,
     V: FnMut(AnalysisSession),
 {
-    let discovery = collect_files_with_max_depth_diagnostics(dir, filter_fn, time_range, max_depth);
+    let discovery = collect_files_with_max_depth_diagnostics(
+        dir,
+        filter_fn,
+        time_range,
+        max_depth,
+        &options.ignore,
+    );
     diagnostics.candidates += discovery.failures.len();
     for failure in discovery.failures {
         record_failure(diagnostics, provider, &failure.path, failure.error);
@@ -792,6 +1051,29 @@ where
 
     let mut files = discovery.files;
     files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+    visit_file_infos(
+        files,
+        provider,
+        mode,
+        options.parse_options(),
+        diagnostics,
+        visitor,
+    );
+    Ok(())
+}
+
+/// Parses already-listed `files` of one `provider` in parallel and visits the
+/// sessions in list order, recording each file as a candidate.
+fn visit_file_infos<V>(
+    files: Vec<FileInfo>,
+    provider: ExtensionType,
+    mode: ParseMode,
+    parse: ParseOptions<'_>,
+    diagnostics: &mut ScanDiagnostics,
+    visitor: &mut V,
+) where
+    V: FnMut(AnalysisSession),
+{
     diagnostics.candidates += files.len();
 
     // `Vec::into_par_iter` is indexed, so collecting retains the sorted source
@@ -803,7 +1085,7 @@ where
                 path,
                 modified_date,
             } = file_info;
-            match parse_session_file_typed_as_with_diagnostics(&path, provider, mode, None) {
+            match parse_session_file_typed_as_with_diagnostics(&path, provider, mode, parse) {
                 Ok(parsed) if parsed.diagnostics.is_complete_failure() => {
                     let error = if parsed.diagnostics.recognized_records == 0 {
                         "source contained no recognized provider records".to_string()
@@ -831,6 +1113,16 @@ where
                 }
                 Ok(parsed) => {
                     let partial_failure_count = parsed.diagnostics.partial_failure_count();
+                    let session =
+                        parsed
+                            .diagnostics
+                            .should_emit_session()
+                            .then(|| AnalysisSession {
+                                provider,
+                                date: modified_date,
+                                source: Some(path.clone()),
+                                analysis: parsed.analysis,
+                            });
                     let partial_failure = (partial_failure_count > 0).then_some(ScanFailure {
                         provider,
                         source: path,
@@ -838,13 +1130,6 @@ where
                             partial_failure_count,
                         ),
                     });
-                    let session = parsed.diagnostics.should_emit_session().then_some({
-                        AnalysisSession {
-                            provider,
-                            date: modified_date,
-                            analysis: parsed.analysis,
-                        }
-                    });
                     Ok((session, partial_failure))
                 }
                 Err(err) => Err(ScanFailure {
@@ -870,7 +1155,6 @@ where
             Err(failure) => push_failure(diagnostics, failure),
         }
     }
-    Ok(())
 }
 
 fn record_failure(
@@ -926,6 +1210,7 @@ fn visit_database_sessions<F>(
         visitor(AnalysisSession {
             provider,
             date: row.date,
+            source: None,
             analysis: row.analysis,
         });
     }
@@ -953,7 +1238,7 @@ struct AnalysisProjection {
 }
 
 impl crate::scan::CompactSink for AnalysisProjection {
-    fn fold(&mut self, provider: ExtensionType, summary: &CompactSourceSummary) {
+    fn fold(&mut self, provider: ExtensionType, _source: &Path, summary: &CompactSourceSummary) {
         self.add_compact(provider, summary);
     }
 }
@@ -1067,7 +1352,9 @@ impl AnalysisProjection {
         }
     }
 
-    fn finish(self) -> AnalysisData {
+    /// Drains the projection into sorted rows, renaming models per
+    /// `options.fixups` and `options.fold_case`.
+    fn finish(self, options: &AnalysisScanOptions) -> AnalysisData {
         let provider_days = ProviderActiveDays {
             claude: self.claude_dates.len(),
             codex: self.codex_dates.len(),
@@ -1079,16 +1366,24 @@ impl AnalysisProjection {
             hermes: self.hermes_dates.len(),
             total: self.all_dates.len(),
         };
+        let rewrite = model_names_rewritten(&options.fixups, options.fold_case);
+        let rows = |map| {
+            into_sorted_rows(if rewrite {
+                fold_analysis_rows(map, options)
+            } else {
+                map
+            })
+        };
         AnalysisData {
-            rows: into_sorted_rows(self.all),
+            rows: rows(self.all),
             per_provider: PerProviderAnalysisRows {
-                claude: into_sorted_rows(self.claude),
-                codex: into_sorted_rows(self.codex),
-                copilot: into_sorted_rows(self.copilot),
-                gemini: into_sorted_rows(self.gemini),
-                grok: into_sorted_rows(self.grok),
-                opencode: into_sorted_rows(self.opencode),
-                cursor: into_sorted_rows(self.cursor),
+                claude: rows(self.claude),
+                codex: rows(self.codex),
+                copilot: rows(self.copilot),
+                gemini: rows(self.gemini),
+                grok: rows(self.grok),
+                opencode: rows(self.opencode),
+                cursor: rows(self.cursor),
             },
             provider_days,
         }
@@ -1113,15 +1408,36 @@ fn merge_compact_rows(
                 todo_write_count: 0,
                 write_count: 0,
             });
-        entry.edit_lines += row.edit_lines;
-        entry.read_lines += row.read_lines;
-        entry.write_lines += row.write_lines;
-        entry.bash_count += row.bash_count;
-        entry.edit_count += row.edit_count;
-        entry.read_count += row.read_count;
-        entry.todo_write_count += row.todo_write_count;
-        entry.write_count += row.write_count;
+        add_row_counts(entry, row);
+    }
+}
+
+fn add_row_counts(entry: &mut AggregatedAnalysisRow, row: &AggregatedAnalysisRow) {
+    entry.edit_lines += row.edit_lines;
+    entry.read_lines += row.read_lines;
+    entry.write_lines += row.write_lines;
+    entry.bash_count += row.bash_count;
+    entry.edit_count += row.edit_count;
+    entry.read_count += row.read_count;
+    entry.todo_write_count += row.todo_write_count;
+    entry.write_count += row.write_count;
+}
+
+/// Renames rows by the `--fixup-model` rules and `--fold-case`, merging rows
+/// that end up under the same model name.
+fn fold_analysis_rows(
+    map: FastHashMap<String, AggregatedAnalysisRow>,
+    options: &AnalysisScanOptions,
+) -> FastHashMap<String, AggregatedAnalysisRow> {
+    let mut folded = fold_keys(
+        map,
+        |model| rewrite_model_name(model, &options.fixups, options.fold_case),
+        |existing, incoming| add_row_counts(existing, &incoming),
+    );
+    for (model, row) in folded.iter_mut() {
+        row.model.clone_from(model);
     }
+    folded
 }
 
 fn extension_type_from_name(name: &str) -> Option<ExtensionType> {
@@ -1149,8 +1465,10 @@ fn local_date_from_millis(timestamp: i64) -> Option<String> {
 
 /// Folds one parsed session's per-model counters into `aggregated`.
 ///
-/// Each model in the session's `conversation_usage` gets (or creates) a row,
-/// and that record's line and tool-call counts are added in. Synthetic models
+/// Each of the record's [`analysis_models`](CodeAnalysisRecord::analysis_models)
+/// gets (or creates) a row, and that record's line and tool-call counts are
+/// added in; a tool-only record with no token events lands on
+/// [`UNATTRIBUTED_MODEL`](crate::models::UNATTRIBUTED_MODEL). Synthetic models
 /// (model name containing `<synthetic>`) are skipped so placeholder usage does
 /// not pollute the per-model breakdown.
 fn aggregate_analysis_result(
@@ -1158,24 +1476,25 @@ fn aggregate_analysis_result(
     analysis: &CodeAnalysis,
 ) {
     for record in &analysis.records {
-        for model in record.conversation_usage.keys() {
+        for model in record.analysis_models() {
             if model.contains("<synthetic>") {
                 continue;
             }
 
-            let entry = aggregated
-                .entry(model.clone())
-                .or_insert_with(|| AggregatedAnalysisRow {
-                    model: model.clone(),
-                    edit_lines: 0,
-                    read_lines: 0,
-                    write_lines: 0,
-                    bash_count: 0,
-                    edit_count: 0,
-                    read_count: 0,
-                    todo_write_count: 0,
-                    write_count: 0,
-                });
+            let entry =
+                aggregated
+                    .entry(model.to_string())
+                    .or_insert_with(|| AggregatedAnalysisRow {
+                        model: model.to_string(),
+                        edit_lines: 0,
+                        read_lines: 0,
+                        write_lines: 0,
+                        bash_count: 0,
+                        edit_count: 0,
+                        read_count: 0,
+                        todo_write_count: 0,
+                        write_count: 0,
+                    });
 
             entry.edit_lines += record.total_edit_lines;
             entry.read_lines += record.total_read_lines;
@@ -1193,7 +1512,7 @@ fn aggregate_analysis_result(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{CodeAnalysisRecord, CodeAnalysisToolCalls};
+    use crate::models::{CodeAnalysisRecord, CodeAnalysisToolCalls, ErrorCounts};
     use serde_json::json;
 
     fn analysis_with_advisor() -> CodeAnalysis {
@@ -1209,6 +1528,7 @@ mod tests {
             total_unique_files: 1,
             total_write_lines: 10,
             total_read_lines: 20,
+            unique_read_lines: None,
             total_edit_lines: 5,
             total_write_characters: 0,
             total_read_characters: 0,
@@ -1225,11 +1545,14 @@ mod tests {
                 bash: 3,
             },
             conversation_usage,
+            error_counts: ErrorCounts::default(),
             advisor_usage,
             task_id: String::new(),
             timestamp: 0,
             folder_path: String::new(),
             git_remote_url: String::new(),
+            repositories: Vec::new(),
+            raw_source: None,
         };
 
         CodeAnalysis {
@@ -0,0 +1,91 @@
+//! Commits made during each session (`analysis --correlate-git`).
+//!
+//! A session's window runs from its earliest recorded file operation or
+//! command to its last activity. For every session whose `folderPath` lies in
+//! a git repository, the commits `git log` lists in that window are reported
+//! next to the lines the session wrote, so assistant activity can be read
+//! against what actually landed.
+
+use crate::analysis::AnalysisDataset;
+use crate::models::CodeAnalysisRecord;
+use crate::utils::{GitCommit, find_git_root, git_log_between};
+use crate::warnings::WarningSink;
+use serde::Serialize;
+
+/// One session and the commits made in its repository while it ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCommits {
+    /// Provider label, as in `extensionName`.
+    pub provider: String,
+    /// Session / task identifier from the source log.
+    pub task_id: String,
+    /// Working directory the session ran in.
+    pub folder_path: String,
+    /// Root of the repository holding `folder_path`; `None` when it is not in
+    /// one (or the session recorded no folder).
+    pub repository: Option<String>,
+    /// Unix epoch timestamp (milliseconds) the window starts at.
+    pub start: i64,
+    /// Unix epoch timestamp (milliseconds) the window ends at.
+    pub end: i64,
+    /// Lines the session wrote and edited.
+    pub lines_written: usize,
+    /// Commits in the window, newest first.
+    pub commits: Vec<GitCommit>,
+}
+
+/// The `(start, end)` window of `record` in Unix epoch milliseconds: the
+/// earliest timestamp among its file operations and commands, through its
+/// last activity. A record without operations is a single instant.
+pub fn session_window(record: &CodeAnalysisRecord) -> (i64, i64) {
+    let end = record.timestamp;
+    let start = record
+        .write_file_details
+        .iter()
+        .map(|detail| &detail.base)
+        .chain(record.read_file_details.iter().map(|detail| &detail.base))
+        .chain(record.edit_file_details.iter().map(|detail| &detail.base))
+        .chain(record.run_command_details.iter().map(|detail| &detail.base))
+        .map(|base| base.timestamp)
+        .filter(|&timestamp| timestamp > 0)
+        .min()
+        .map_or(end, |start| start.min(end));
+    (start, end)
+}
+
+/// Lists, for every session record in `dataset` (in dataset order), the
+/// commits made in its repository during its window.
+///
+/// A folder outside any repository gets `repository: None` and no commits. A
+/// repository `git log` cannot read (no commits yet, `git` missing) is
+/// reported to `warnings` and also gets no commits.
+pub fn correlate_git(dataset: &AnalysisDataset, warnings: &WarningSink) -> Vec<SessionCommits> {
+    let mut sessions = Vec::new();
+    for session in &dataset.sessions {
+        for record in &session.analysis.records {
+            let (start, end) = session_window(record);
+            let root = (!record.folder_path.is_empty())
+                .then(|| find_git_root(&record.folder_path))
+                .flatten();
+            let commits = match &root {
+                Some(root) => git_log_between(root, start, end).unwrap_or_else(|err| {
+                    warnings.warn(format!("{err:#}"));
+                    Vec::new()
+                }),
+                None => Vec::new(),
+            };
+            sessions.push(SessionCommits {
+                provider: session.provider.to_string(),
+                task_id: record.task_id.clone(),
+                folder_path: record.folder_path.clone(),
+                repository: root.map(|root| root.display().to_string()),
+                start,
+                end,
+                lines_written: record.total_write_lines + record.total_edit_lines,
+                commits,
+            });
+        }
+    }
+    sessions
+}
@@ -0,0 +1,75 @@
+//! Per-provider API error report (`analysis --error-report`).
+//!
+//! Sums the [`ErrorCounts`] every parser records on its session (rate limits,
+//! overloads, other API errors) so reliability can be compared across
+//! assistants, next to how many sessions hit any error at all.
+
+use crate::analysis::AnalysisDataset;
+use crate::models::{ErrorCounts, ExtensionType};
+use serde::Serialize;
+
+/// Error totals of one provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderErrorCounts {
+    /// Provider label, as in `extensionName`.
+    pub provider: String,
+    /// Sessions of this provider in the dataset.
+    pub sessions: usize,
+    /// Sessions that logged at least one API error.
+    pub sessions_with_errors: usize,
+    /// Error events by kind, summed over the provider's sessions.
+    pub error_counts: ErrorCounts,
+}
+
+/// Error totals per provider, in scan order, plus the grand total.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorReport {
+    /// One row per provider with at least one session.
+    pub providers: Vec<ProviderErrorCounts>,
+    /// Error events by kind across every provider.
+    pub total: ErrorCounts,
+}
+
+/// Aggregates the recorded error counts of `dataset` per provider.
+pub fn error_report(dataset: &AnalysisDataset) -> ErrorReport {
+    let mut rows: Vec<(ExtensionType, ProviderErrorCounts)> = Vec::new();
+    let mut total = ErrorCounts::default();
+    for session in &dataset.sessions {
+        let index = match rows
+            .iter()
+            .position(|(owner, _)| *owner == session.provider)
+        {
+            Some(index) => index,
+            None => {
+                rows.push((
+                    session.provider,
+                    ProviderErrorCounts {
+                        provider: session.provider.to_string(),
+                        sessions: 0,
+                        sessions_with_errors: 0,
+                        error_counts: ErrorCounts::default(),
+                    },
+                ));
+                rows.len() - 1
+            }
+        };
+        let row = &mut rows[index].1;
+        row.sessions += 1;
+        let mut counts = ErrorCounts::default();
+        for record in &session.analysis.records {
+            counts.merge(&record.error_counts);
+        }
+        if !counts.is_empty() {
+            row.sessions_with_errors += 1;
+        }
+        row.error_counts.merge(&counts);
+        total.merge(&counts);
+    }
+    rows.sort_by_key(|(provider, _)| provider.scan_rank());
+    ErrorReport {
+        providers: rows.into_iter().map(|(_, row)| row).collect(),
+        total,
+    }
+}
@@ -0,0 +1,151 @@
+//! Tool activity by hour of day (`analysis --hod`).
+//!
+//! Every recorded file operation and shell command carries its own
+//! timestamp; this bins them into the 24 hours of the day in a chosen zone
+//! (`--timezone`, the system's local zone by default), so the hours someone
+//! codes most stand out regardless of which day they fell on.
+
+use crate::analysis::AnalysisDataset;
+use anyhow::{Result, bail};
+use chrono::{DateTime, FixedOffset, Local, Timelike, Utc};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// The zone hours are read in (`--timezone`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HourZone {
+    /// The system's local zone (the default).
+    #[default]
+    Local,
+    /// A fixed offset from UTC; `utc` is `+00:00`.
+    Fixed(FixedOffset),
+}
+
+impl FromStr for HourZone {
+    type Err = anyhow::Error;
+
+    /// Accepts `local`, `utc` (or `Z`), and offsets such as `+08:00`,
+    /// `-0530`, or `+9`.
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("local") {
+            return Ok(Self::Local);
+        }
+        if value.eq_ignore_ascii_case("utc") || value == "Z" {
+            return Ok(Self::Fixed(FixedOffset::east_opt(0).expect("zero offset")));
+        }
+        let (sign, rest) = match value.as_bytes().first() {
+            Some(b'+') => (1, &value[1..]),
+            Some(b'-') => (-1, &value[1..]),
+            _ => {
+                bail!("unknown timezone `{value}` (expected local, utc, or an offset like +08:00)")
+            }
+        };
+        let (hours, minutes) = match rest.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes),
+            None if rest.len() == 4 => rest.split_at(2),
+            None => (rest, "0"),
+        };
+        let (Ok(hours), Ok(minutes)) = (hours.parse::<i32>(), minutes.parse::<i32>()) else {
+            bail!("`{value}` is not a UTC offset like +08:00");
+        };
+        if !(0..60).contains(&minutes) {
+            bail!("`{value}` is not a UTC offset like +08:00");
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self::Fixed)
+            .ok_or_else(|| anyhow::anyhow!("`{value}` is out of range for a UTC offset"))
+    }
+}
+
+impl HourZone {
+    /// The hour (0–23) `timestamp` (Unix epoch milliseconds) falls in.
+    pub fn hour_of(self, timestamp: i64) -> Option<u32> {
+        let utc = DateTime::<Utc>::from_timestamp_millis(timestamp)?;
+        Some(match self {
+            Self::Local => utc.with_timezone(&Local).hour(),
+            Self::Fixed(offset) => utc.with_timezone(&offset).hour(),
+        })
+    }
+}
+
+/// Operations recorded during one hour of the day, summed over every session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HourActivity {
+    /// Hour of the day, 0–23.
+    pub hour: u32,
+    /// File edits.
+    pub edits: usize,
+    /// File reads.
+    pub reads: usize,
+    /// File writes.
+    pub writes: usize,
+    /// Shell commands.
+    pub commands: usize,
+    /// Sum of the four counts.
+    pub total: usize,
+}
+
+/// One row per hour, 0 through 23 (hours without activity included), of the
+/// operations `dataset` recorded. Operations without a timestamp are left
+/// out.
+///
+/// Only a [`crate::session::ParseMode::Full`] dataset keeps per-operation
+/// records; a usage-only one bins nothing.
+pub fn activity_by_hour(dataset: &AnalysisDataset, zone: HourZone) -> Vec<HourActivity> {
+    let mut hours: Vec<HourActivity> = (0..24)
+        .map(|hour| HourActivity {
+            hour,
+            ..HourActivity::default()
+        })
+        .collect();
+    let mut bin = |timestamp: i64, count: fn(&mut HourActivity) -> &mut usize| {
+        if timestamp <= 0 {
+            return;
+        }
+        if let Some(hour) = zone.hour_of(timestamp) {
+            let row = &mut hours[hour as usize];
+            *count(row) += 1;
+            row.total += 1;
+        }
+    };
+    for session in &dataset.sessions {
+        for record in &session.analysis.records {
+            for detail in &record.edit_file_details {
+                bin(detail.base.timestamp, |row| &mut row.edits);
+            }
+            for detail in &record.read_file_details {
+                bin(detail.base.timestamp, |row| &mut row.reads);
+            }
+            for detail in &record.write_file_details {
+                bin(detail.base.timestamp, |row| &mut row.writes);
+            }
+            for detail in &record.run_command_details {
+                bin(detail.base.timestamp, |row| &mut row.commands);
+            }
+        }
+    }
+    hours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timezone_accepts_local_utc_and_offsets() {
+        let offset = |seconds| HourZone::Fixed(FixedOffset::east_opt(seconds).unwrap());
+        assert_eq!("local".parse::<HourZone>().unwrap(), HourZone::Local);
+        assert_eq!("UTC".parse::<HourZone>().unwrap(), offset(0));
+        assert_eq!("+08:00".parse::<HourZone>().unwrap(), offset(8 * 3600));
+        assert_eq!(
+            "-0530".parse::<HourZone>().unwrap(),
+            offset(-(5 * 3600 + 30 * 60))
+        );
+        assert_eq!("+9".parse::<HourZone>().unwrap(), offset(9 * 3600));
+        assert!("Asia/Taipei".parse::<HourZone>().is_err());
+        assert!("+08:75".parse::<HourZone>().is_err());
+        assert!("+30".parse::<HourZone>().is_err());
+    }
+}
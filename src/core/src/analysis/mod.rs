@@ -3,9 +3,38 @@
 //! Provider-specific parsing stays in [`crate::session`]. This module collects
 //! those [`crate::models::CodeAnalysis`] values into the canonical batch JSON
 //! dataset, then projects the same values into the compact summaries rendered
-//! by the TUI, text, and table views.
+//! by the TUI, text, and table views; [`validate`] cross-checks them and
+//! [`reads`] derives the re-read-insensitive `uniqueReadLines` metric,
+//! [`paths`] rewrites recorded file paths to one form, [`raw`] embeds the
+//! source file each result came from,
+//! [`privacy`] blanks the `machineId` for users who opt out of it,
+//! [`tool_version`] narrows a batch to sessions written by a CLI version range,
+//! [`errors`] totals the logged API errors per provider, [`commits`] lists the
+//! git commits made while each session ran, [`hours`] bins operations by hour
+//! of day, and [`watch`] diffs consecutive aggregations for the
+//! `--keep-running` log.
 pub mod aggregator;
+pub mod commits;
+pub mod errors;
+pub mod hours;
+pub mod paths;
+pub mod privacy;
+pub mod raw;
+pub mod reads;
 pub mod summary;
+pub mod tool_version;
+pub mod validate;
+pub mod watch;
 
 pub use aggregator::*;
+pub use commits::{SessionCommits, correlate_git, session_window};
+pub use errors::{ErrorReport, ProviderErrorCounts, error_report};
+pub use hours::{HourActivity, HourZone, activity_by_hour};
+pub use paths::{PathStyle, normalize_dataset_paths, normalize_paths};
+pub use privacy::{clear_dataset_machine_id, clear_machine_id};
+pub use raw::{decode_raw_source, embed_dataset_raw_sources, embed_raw_source};
+pub use reads::{dedupe_dataset_reads, dedupe_reads, unique_read_lines};
 pub use summary::*;
+pub use tool_version::{ToolVersionFilter, ToolVersionReq, retain_tool_version};
+pub use validate::{InvariantViolation, validate_analysis, validate_dataset};
+pub use watch::{AnalysisDeltaRow, WatchSnapshot, analysis_delta};
@@ -0,0 +1,188 @@
+//! File-path rewriting for analysis output (`analysis --normalize-paths`).
+//!
+//! Parsers resolve a relative path against the session's `folderPath` when
+//! they know it, but a path logged before the working directory (or by a tool
+//! that reports paths its own way) keeps its original form, so one record can
+//! mix absolute and relative paths. [`normalize_paths`] rewrites every
+//! read/write/edit `filePath` to one [`PathStyle`] on the way out. Run-command
+//! details are left alone: their `filePath` is the working directory, not a
+//! file.
+
+use crate::analysis::AnalysisDataset;
+use crate::models::{CodeAnalysis, CodeAnalysisDetailBase};
+use anyhow::{Result, bail};
+use std::path::Path;
+use std::str::FromStr;
+
+/// The form [`normalize_paths`] rewrites file paths to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStyle {
+    /// Relative paths are joined onto the record's `folderPath`.
+    Absolute,
+    /// Paths inside `folderPath` are made relative to it; paths outside it
+    /// stay absolute.
+    Relative,
+    /// Only the final file name is kept.
+    Basename,
+}
+
+impl FromStr for PathStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "absolute" => Ok(Self::Absolute),
+            "relative" => Ok(Self::Relative),
+            "basename" => Ok(Self::Basename),
+            other => {
+                bail!("unknown path style `{other}` (expected absolute, relative, or basename)")
+            }
+        }
+    }
+}
+
+impl PathStyle {
+    /// Rewrites one `path` logged in a session that ran in `folder`.
+    ///
+    /// A path that cannot be rewritten (a relative path with no `folder` to
+    /// join, a path without a file name) is returned unchanged.
+    pub fn apply(self, path: &str, folder: &str) -> String {
+        let file = Path::new(path);
+        match self {
+            Self::Absolute if file.is_relative() && !folder.is_empty() => {
+                Path::new(folder).join(file).to_string_lossy().into_owned()
+            }
+            Self::Relative if !folder.is_empty() => match file.strip_prefix(folder) {
+                Ok(relative) if !relative.as_os_str().is_empty() => {
+                    relative.to_string_lossy().into_owned()
+                }
+                _ => path.to_string(),
+            },
+            Self::Basename => match file.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => path.to_string(),
+            },
+            _ => path.to_string(),
+        }
+    }
+}
+
+/// Rewrites every file path of one parsed session to `style`.
+pub fn normalize_paths(analysis: &mut CodeAnalysis, style: PathStyle) {
+    for record in &mut analysis.records {
+        let folder = record.folder_path.as_str();
+        let rewrite = |base: &mut CodeAnalysisDetailBase| {
+            base.file_path = style.apply(&base.file_path, folder);
+        };
+        record
+            .read_file_details
+            .iter_mut()
+            .for_each(|detail| rewrite(&mut detail.base));
+        record
+            .write_file_details
+            .iter_mut()
+            .for_each(|detail| rewrite(&mut detail.base));
+        record
+            .edit_file_details
+            .iter_mut()
+            .for_each(|detail| rewrite(&mut detail.base));
+    }
+}
+
+/// Rewrites every file path of a batch dataset to `style`.
+pub fn normalize_dataset_paths(dataset: &mut AnalysisDataset, style: PathStyle) {
+    for session in &mut dataset.sessions {
+        normalize_paths(&mut session.analysis, style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mixed_path_analysis() -> CodeAnalysis {
+        let detail = |path: &str| json!({ "filePath": path, "lineCount": 1, "characterCount": 1, "timestamp": 0 });
+        let mut write = detail("notes/todo.md");
+        write["content"] = json!("x");
+        let mut edit = detail("/repo/src/lib.rs");
+        edit["oldString"] = json!("a");
+        edit["newString"] = json!("b");
+        let mut run = detail("/repo");
+        run["command"] = json!("ls");
+        run["description"] = json!("");
+        serde_json::from_value(json!({
+            "user": "", "extensionName": "Codex", "insightsVersion": "", "machineId": "",
+            "records": [{
+                "totalUniqueFiles": 4, "totalWriteLines": 1, "totalReadLines": 2,
+                "totalEditLines": 1, "totalWriteCharacters": 1, "totalReadCharacters": 2,
+                "totalEditCharacters": 1,
+                "readFileDetails": [detail("/repo/src/main.rs"), detail("/etc/hosts")],
+                "writeFileDetails": [write],
+                "editFileDetails": [edit],
+                "runCommandDetails": [run],
+                "toolCallCounts": { "Read": 2, "Write": 1, "Edit": 1, "TodoWrite": 0, "Bash": 1 },
+                "conversationUsage": {},
+                "taskId": "", "timestamp": 0, "folderPath": "/repo", "gitRemoteUrl": ""
+            }]
+        }))
+        .unwrap()
+    }
+
+    fn file_paths(analysis: &CodeAnalysis) -> Vec<&str> {
+        let record = &analysis.records[0];
+        record
+            .read_file_details
+            .iter()
+            .map(|detail| &detail.base)
+            .chain(record.write_file_details.iter().map(|detail| &detail.base))
+            .chain(record.edit_file_details.iter().map(|detail| &detail.base))
+            .chain(record.run_command_details.iter().map(|detail| &detail.base))
+            .map(|base| base.file_path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn each_style_rewrites_mixed_paths_and_leaves_run_commands_alone() {
+        let cases = [
+            (
+                PathStyle::Absolute,
+                [
+                    "/repo/src/main.rs",
+                    "/etc/hosts",
+                    "/repo/notes/todo.md",
+                    "/repo/src/lib.rs",
+                    "/repo",
+                ],
+            ),
+            (
+                PathStyle::Relative,
+                [
+                    "src/main.rs",
+                    "/etc/hosts",
+                    "notes/todo.md",
+                    "src/lib.rs",
+                    "/repo",
+                ],
+            ),
+            (
+                PathStyle::Basename,
+                ["main.rs", "hosts", "todo.md", "lib.rs", "/repo"],
+            ),
+        ];
+        for (style, expected) in cases {
+            let mut analysis = mixed_path_analysis();
+            normalize_paths(&mut analysis, style);
+            assert_eq!(file_paths(&analysis), expected, "{style:?}");
+        }
+    }
+
+    #[test]
+    fn styles_parse_by_name() {
+        assert_eq!(
+            "relative".parse::<PathStyle>().unwrap(),
+            PathStyle::Relative
+        );
+        assert!("canonical".parse::<PathStyle>().is_err());
+    }
+}
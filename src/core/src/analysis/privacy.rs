@@ -0,0 +1,20 @@
+//! Machine-identifier opt-out (`analysis --no-machine-id`).
+//!
+//! Every parser stamps [`get_machine_id`](crate::utils::get_machine_id) into
+//! `machineId`, and the analysis caches keep it. Blanking happens here, on the
+//! way out, so a cached session and a fresh parse are redacted identically.
+
+use crate::analysis::AnalysisDataset;
+use crate::models::CodeAnalysis;
+
+/// Blanks `machineId` on one parsed session.
+pub fn clear_machine_id(analysis: &mut CodeAnalysis) {
+    analysis.machine_id.clear();
+}
+
+/// Blanks `machineId` on every session of a batch dataset.
+pub fn clear_dataset_machine_id(dataset: &mut AnalysisDataset) {
+    for session in &mut dataset.sessions {
+        clear_machine_id(&mut session.analysis);
+    }
+}
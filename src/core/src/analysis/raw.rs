@@ -0,0 +1,54 @@
+//! Source provenance for analysis output (`analysis --embed-raw`).
+//!
+//! Attaches a base64 copy of the session file each result was parsed from as
+//! `rawSource` on its records, so an audit can re-derive the numbers from the
+//! exact bytes. Base64 keeps the copy byte-exact even for files that are not
+//! valid UTF-8. Sessions read from a database have no single source file and
+//! are left without one.
+
+use crate::analysis::AnalysisDataset;
+use crate::models::CodeAnalysis;
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::path::Path;
+
+/// Sets `rawSource` on every record of `analysis` to the base64 of `source`.
+///
+/// # Errors
+///
+/// Returns an error if `source` cannot be read.
+pub fn embed_raw_source(analysis: &mut CodeAnalysis, source: &Path) -> Result<()> {
+    let bytes = std::fs::read(source)
+        .with_context(|| format!("Failed to read session file {}", source.display()))?;
+    let encoded = STANDARD.encode(bytes);
+    for record in &mut analysis.records {
+        record.raw_source = Some(encoded.clone());
+    }
+    Ok(())
+}
+
+/// [`embed_raw_source`] for every file-backed session of a batch dataset.
+///
+/// # Errors
+///
+/// Returns an error if any session file cannot be read.
+pub fn embed_dataset_raw_sources(dataset: &mut AnalysisDataset) -> Result<()> {
+    for session in &mut dataset.sessions {
+        if let Some(source) = &session.source {
+            embed_raw_source(&mut session.analysis, source)?;
+        }
+    }
+    Ok(())
+}
+
+/// Decodes a `rawSource` value back into the original file bytes.
+///
+/// # Errors
+///
+/// Returns an error if `encoded` is not valid base64.
+pub fn decode_raw_source(encoded: &str) -> Result<Vec<u8>> {
+    STANDARD
+        .decode(encoded)
+        .context("rawSource is not valid base64")
+}
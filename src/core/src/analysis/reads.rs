@@ -0,0 +1,59 @@
+//! Re-read-insensitive read metric (`analysis --dedupe-reads`).
+//!
+//! `total_read_lines` counts every `Read`, so a file opened five times counts
+//! five times. [`unique_read_lines`] instead counts each distinct file once at
+//! the largest line count any single read of it returned, which approximates
+//! "how much distinct code was reviewed". Partial reads of one file (different
+//! offsets) are not unioned: the max is a lower bound, not a line-range merge.
+
+use crate::analysis::AnalysisDataset;
+use crate::constants::FastHashMap;
+use crate::models::{CodeAnalysis, CodeAnalysisRecord};
+
+/// Sums each distinct read path's largest single-read line count.
+///
+/// Reads the per-operation details, so it is only meaningful for a
+/// [`ParseMode::Full`](crate::session::ParseMode) record; a `UsageOnly` record
+/// has none and yields `0`.
+pub fn unique_read_lines(record: &CodeAnalysisRecord) -> usize {
+    let mut largest: FastHashMap<&str, usize> = FastHashMap::default();
+    for detail in &record.read_file_details {
+        let lines = largest.entry(detail.base.file_path.as_str()).or_default();
+        *lines = (*lines).max(detail.base.line_count);
+    }
+    largest.values().sum()
+}
+
+/// Fills `unique_read_lines` on every record of one parsed session.
+pub fn dedupe_reads(analysis: &mut CodeAnalysis) {
+    for record in &mut analysis.records {
+        record.unique_read_lines = Some(unique_read_lines(record));
+    }
+}
+
+/// Fills `unique_read_lines` on every record of a batch dataset.
+pub fn dedupe_dataset_reads(dataset: &mut AnalysisDataset) {
+    for session in &mut dataset.sessions {
+        dedupe_reads(&mut session.analysis);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::state::SessionParseState;
+
+    #[test]
+    fn repeated_reads_of_one_file_count_once_at_their_largest() {
+        let mut state = SessionParseState::new();
+        state.folder_path = "/repo".to_string();
+        state.add_read_detail("src/lib.rs", "a\nb\nc\n", 0);
+        state.add_read_detail("src/lib.rs", "a\nb\nc\nd\n", 1);
+        state.add_read_detail("/repo/src/lib.rs", "a\n", 2);
+        state.add_read_detail("src/main.rs", "fn main() {}\n", 3);
+        let record = state.into_record(Default::default());
+
+        assert_eq!(record.total_read_lines, 9);
+        assert_eq!(unique_read_lines(&record), 5);
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::analysis::{AggregatedAnalysisRow, PerProviderAnalysisRows};
 use crate::models::ProviderActiveDays;
+use serde::Serialize;
 
 /// Display-side copy of one model's analysis metrics.
 ///
@@ -34,6 +35,52 @@ pub struct AnalysisRow {
     pub write_count: usize,
 }
 
+/// Column totals of the per-model analysis rows, printed alone by
+/// `analysis --summary-only`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisTotals {
+    /// Total lines changed by `Edit`/`MultiEdit` operations.
+    pub edit_lines: usize,
+    /// Total lines returned by `Read` operations.
+    pub read_lines: usize,
+    /// Total lines emitted by `Write` operations.
+    pub write_lines: usize,
+    /// Number of `Bash` tool calls.
+    pub bash_count: usize,
+    /// Number of `Edit` tool calls.
+    pub edit_count: usize,
+    /// Number of `Read` tool calls.
+    pub read_count: usize,
+    /// Number of `TodoWrite` tool calls.
+    pub todo_write_count: usize,
+    /// Number of `Write` tool calls.
+    pub write_count: usize,
+    /// Model rows summed.
+    pub models: usize,
+}
+
+/// Sums every model row into one [`AnalysisTotals`], the table's `TOTAL` row.
+pub fn analysis_totals(rows: &[AggregatedAnalysisRow]) -> AnalysisTotals {
+    rows.iter().fold(
+        AnalysisTotals {
+            models: rows.len(),
+            ..AnalysisTotals::default()
+        },
+        |mut totals, row| {
+            totals.edit_lines += row.edit_lines;
+            totals.read_lines += row.read_lines;
+            totals.write_lines += row.write_lines;
+            totals.bash_count += row.bash_count;
+            totals.edit_count += row.edit_count;
+            totals.read_count += row.read_count;
+            totals.todo_write_count += row.todo_write_count;
+            totals.write_count += row.write_count;
+            totals
+        },
+    )
+}
+
 /// Per-provider totals for analysis. `days_count` records how many distinct
 /// days contributed to the totals so the display layer can show the spread
 /// without computing a rate.
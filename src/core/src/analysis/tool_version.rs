@@ -0,0 +1,90 @@
+//! Tool-version session filter (`analysis --tool-version`).
+//!
+//! Keeps the sessions whose assistant CLI version (see
+//! [`read_tool_version`]) satisfies a semver range, so behavior before and
+//! after an upgrade can be compared. Database-backed sessions, providers that
+//! record no version, and versions that are not valid semver count as
+//! unversioned.
+
+use crate::analysis::AnalysisDataset;
+use crate::session::tool_version::read_tool_version;
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+use semver::{Version, VersionReq};
+use std::str::FromStr;
+
+/// A semver range such as `>=1.0.90, <1.1`, matched against tool versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolVersionReq(VersionReq);
+
+impl FromStr for ToolVersionReq {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        VersionReq::parse(value)
+            .map(Self)
+            .with_context(|| format!("invalid tool version range `{value}`"))
+    }
+}
+
+/// Which sessions [`retain_tool_version`] keeps.
+#[derive(Debug, Clone)]
+pub struct ToolVersionFilter {
+    /// Range a session's tool version must satisfy.
+    pub req: ToolVersionReq,
+    /// Also keep sessions without a usable version (`--include-unversioned`).
+    pub include_unversioned: bool,
+}
+
+impl ToolVersionFilter {
+    /// Whether a session recording `version` (or none) passes the filter.
+    pub fn matches(&self, version: Option<&str>) -> bool {
+        match version.and_then(|v| Version::parse(v.trim_start_matches('v')).ok()) {
+            Some(version) => self.req.0.matches(&version),
+            None => self.include_unversioned,
+        }
+    }
+}
+
+/// Drops every session of `dataset` that `filter` rejects, keeping order.
+pub fn retain_tool_version(dataset: &mut AnalysisDataset, filter: &ToolVersionFilter) {
+    let keep: Vec<bool> = dataset
+        .sessions
+        .par_iter()
+        .map(|session| {
+            let version = session
+                .source
+                .as_deref()
+                .and_then(|path| read_tool_version(path, session.provider));
+            filter.matches(version.as_deref())
+        })
+        .collect();
+    let mut keep = keep.into_iter();
+    dataset.sessions.retain(|_| keep.next().unwrap_or(false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_matches_ranges_and_handles_unversioned_sessions() {
+        let filter = ToolVersionFilter {
+            req: ">=1.0.90, <1.1".parse().unwrap(),
+            include_unversioned: false,
+        };
+        assert!(filter.matches(Some("1.0.95")));
+        assert!(filter.matches(Some("v1.0.90")));
+        assert!(!filter.matches(Some("1.1.0")));
+        assert!(!filter.matches(None));
+        assert!(!filter.matches(Some("nightly")));
+
+        let lenient = ToolVersionFilter {
+            include_unversioned: true,
+            ..filter
+        };
+        assert!(lenient.matches(None));
+        assert!(!lenient.matches(Some("0.39.0")));
+        assert!("not a range".parse::<ToolVersionReq>().is_err());
+    }
+}
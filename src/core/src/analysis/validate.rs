@@ -0,0 +1,229 @@
+//! Self-check pass over parsed analysis results (`analysis --validate`).
+//!
+//! Every counter on a [`CodeAnalysisRecord`] is tallied by
+//! [`SessionParseState`](crate::session::state::SessionParseState) alongside the
+//! detail record it describes, so a [`ParseMode::Full`](crate::session::ParseMode)
+//! result carries enough redundancy to cross-check itself. Tool-call counts are
+//! compared where the parser derives them from the details: `bash` matches its
+//! details exactly, and every read detail was counted as a read, so the read
+//! count can never fall below the read details (it may exceed them: a search
+//! tool or an unrecoverable read is counted without a detail). Write and edit
+//! counts tally tool invocations, which legitimately differ from their detail
+//! lists (an edit that creates a file).
+
+use crate::analysis::AnalysisDataset;
+use crate::constants::FastHashSet;
+use crate::models::{CodeAnalysis, CodeAnalysisDetailBase, CodeAnalysisRecord};
+use serde::Serialize;
+use serde_json::Value;
+
+/// One broken invariant, attributed to the session and record it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvariantViolation {
+    /// Session label: the record's task id, prefixed by provider for batch scans.
+    pub session: String,
+    /// Index of the offending record within the session's `records`.
+    pub record: usize,
+    /// Human-readable description of the broken invariant.
+    pub message: String,
+}
+
+/// Checks every record of a single parsed session.
+///
+/// `analysis` must come from a [`ParseMode::Full`](crate::session::ParseMode)
+/// parse; a `UsageOnly` result has empty detail lists by design and would fail
+/// the detail-sum checks.
+pub fn validate_analysis(analysis: &CodeAnalysis) -> Vec<InvariantViolation> {
+    let mut violations = Vec::new();
+    for (index, record) in analysis.records.iter().enumerate() {
+        let session = if record.task_id.is_empty() {
+            "<unknown>".to_string()
+        } else {
+            record.task_id.clone()
+        };
+        check_record(&session, index, record, &mut violations);
+    }
+    violations
+}
+
+/// Checks every session of a batch dataset, labeling each with its provider.
+pub fn validate_dataset(dataset: &AnalysisDataset) -> Vec<InvariantViolation> {
+    dataset
+        .sessions
+        .iter()
+        .flat_map(|session| {
+            let provider = session.provider;
+            validate_analysis(&session.analysis)
+                .into_iter()
+                .map(move |mut violation| {
+                    violation.session = format!("{provider} {}", violation.session);
+                    violation
+                })
+        })
+        .collect()
+}
+
+fn check_record(
+    session: &str,
+    index: usize,
+    record: &CodeAnalysisRecord,
+    violations: &mut Vec<InvariantViolation>,
+) {
+    let mut report = |message: String| {
+        violations.push(InvariantViolation {
+            session: session.to_string(),
+            record: index,
+            message,
+        });
+    };
+
+    let sections: [(&str, Vec<&CodeAnalysisDetailBase>, usize, usize); 3] = [
+        (
+            "read",
+            record.read_file_details.iter().map(|d| &d.base).collect(),
+            record.total_read_lines,
+            record.total_read_characters,
+        ),
+        (
+            "write",
+            record.write_file_details.iter().map(|d| &d.base).collect(),
+            record.total_write_lines,
+            record.total_write_characters,
+        ),
+        (
+            "edit",
+            record.edit_file_details.iter().map(|d| &d.base).collect(),
+            record.total_edit_lines,
+            record.total_edit_characters,
+        ),
+    ];
+    let mut detail_files: FastHashSet<&str> = FastHashSet::default();
+    for (kind, details, total_lines, total_characters) in &sections {
+        let lines: usize = details.iter().map(|d| d.line_count).sum();
+        if lines != *total_lines {
+            report(format!(
+                "total_{kind}_lines is {total_lines} but {kind} details sum to {lines}"
+            ));
+        }
+        let characters: usize = details.iter().map(|d| d.character_count).sum();
+        if characters != *total_characters {
+            report(format!(
+                "total_{kind}_characters is {total_characters} but {kind} details sum to {characters}"
+            ));
+        }
+        detail_files.extend(details.iter().map(|d| d.file_path.as_str()));
+    }
+
+    if record.total_unique_files < detail_files.len() {
+        report(format!(
+            "total_unique_files is {} but details touch {} distinct files",
+            record.total_unique_files,
+            detail_files.len()
+        ));
+    }
+
+    let read_details = record.read_file_details.len();
+    if record.tool_call_counts.read < read_details {
+        report(format!(
+            "read count is {} but read_file_details has {read_details} entries",
+            record.tool_call_counts.read
+        ));
+    }
+
+    let bash_details = record.run_command_details.len();
+    if record.tool_call_counts.bash != bash_details {
+        report(format!(
+            "bash count is {} but run_command_details has {bash_details} entries",
+            record.tool_call_counts.bash
+        ));
+    }
+
+    for (label, usage) in [
+        ("conversation_usage", &record.conversation_usage),
+        ("advisor_usage", &record.advisor_usage),
+    ] {
+        for (model, value) in usage {
+            if let Some(field) = first_negative_field(value) {
+                report(format!(
+                    "{label}[{model}] has negative token count `{field}`"
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the path of the first negative number in a usage value.
+fn first_negative_field(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => n.as_f64().filter(|v| *v < 0.0).map(|_| String::new()),
+        Value::Object(map) => map.iter().find_map(|(key, child)| {
+            first_negative_field(child).map(|rest| {
+                if rest.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{key}.{rest}")
+                }
+            })
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::state::SessionParseState;
+    use serde_json::json;
+
+    /// A parser-built (hence consistent) session with one read and one command.
+    fn parsed_analysis() -> CodeAnalysis {
+        let mut state = SessionParseState::new();
+        state.folder_path = "/repo".to_string();
+        state.task_id = "session-1".to_string();
+        state.add_read_detail("src/lib.rs", "fn a() {}\nfn b() {}\n", 0);
+        state.add_run_command("cargo test", "", 0);
+        CodeAnalysis {
+            user: String::new(),
+            extension_name: String::new(),
+            insights_version: String::new(),
+            machine_id: String::new(),
+            records: vec![state.into_record(Default::default())],
+        }
+    }
+
+    #[test]
+    fn parser_built_record_has_no_violations() {
+        assert!(validate_analysis(&parsed_analysis()).is_empty());
+    }
+
+    #[test]
+    fn mismatched_line_total_is_reported_with_its_session() {
+        let mut analysis = parsed_analysis();
+        analysis.records[0].total_read_lines += 3;
+        let violations = validate_analysis(&analysis);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].session, "session-1");
+        assert_eq!(violations[0].record, 0);
+        assert!(violations[0].message.contains("total_read_lines"));
+    }
+
+    #[test]
+    fn read_count_below_read_details_is_reported() {
+        let mut analysis = parsed_analysis();
+        analysis.records[0].tool_call_counts.read = 0;
+        let violations = validate_analysis(&analysis);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("read count is 0"));
+    }
+
+    #[test]
+    fn negative_token_count_is_reported() {
+        let mut analysis = parsed_analysis();
+        analysis.records[0]
+            .conversation_usage
+            .insert("model".to_string(), json!({"output_tokens": -4}));
+        let violations = validate_analysis(&analysis);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("`output_tokens`"));
+    }
+}
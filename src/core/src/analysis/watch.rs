@@ -0,0 +1,155 @@
+//! Tick-to-tick activity deltas for the append-only watch log
+//! (`analysis --keep-running`).
+//!
+//! Each tick re-aggregates the compact per-model rows and diffs them against
+//! the previous tick's rows; only models whose counters moved end up in the
+//! [`WatchSnapshot`] appended to the log. The first tick diffs against
+//! nothing, so it records the whole baseline.
+
+use crate::models::AggregatedAnalysisRow;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// One model's counter changes since the previous tick.
+///
+/// Counters are signed: a session file deleted (or aged out of the selected
+/// period) between ticks shows up as a negative change.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisDeltaRow {
+    /// Model name the counters are grouped under.
+    pub model: String,
+    /// Change in lines changed by `Edit`/`MultiEdit` operations.
+    pub edit_lines: i64,
+    /// Change in lines returned by `Read` operations.
+    pub read_lines: i64,
+    /// Change in lines emitted by `Write` operations.
+    pub write_lines: i64,
+    /// Change in `Bash` tool calls.
+    pub bash_count: i64,
+    /// Change in `Edit` tool calls.
+    pub edit_count: i64,
+    /// Change in `Read` tool calls.
+    pub read_count: i64,
+    /// Change in `TodoWrite` tool calls.
+    pub todo_write_count: i64,
+    /// Change in `Write` tool calls.
+    pub write_count: i64,
+}
+
+impl AnalysisDeltaRow {
+    fn is_zero(&self) -> bool {
+        [
+            self.edit_lines,
+            self.read_lines,
+            self.write_lines,
+            self.bash_count,
+            self.edit_count,
+            self.read_count,
+            self.todo_write_count,
+            self.write_count,
+        ]
+        .iter()
+        .all(|value| *value == 0)
+    }
+}
+
+/// One line of the watch log: when the tick ran and what changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchSnapshot {
+    /// Local RFC 3339 time of the tick.
+    pub timestamp: String,
+    /// Models whose counters changed, by name.
+    pub changes: Vec<AnalysisDeltaRow>,
+}
+
+impl WatchSnapshot {
+    /// Stamps `changes` with the current local time.
+    pub fn now(changes: Vec<AnalysisDeltaRow>) -> Self {
+        Self {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            changes,
+        }
+    }
+}
+
+/// Diffs two consecutive aggregations model by model.
+///
+/// Rows sharing a model on one side are summed first. A model present on only
+/// one side diffs against zero on the other; models with no change are
+/// dropped, so an idle tick yields an empty list.
+pub fn analysis_delta(
+    before: &[AggregatedAnalysisRow],
+    after: &[AggregatedAnalysisRow],
+) -> Vec<AnalysisDeltaRow> {
+    let mut deltas: BTreeMap<&str, AnalysisDeltaRow> = BTreeMap::new();
+    for (sign, rows) in [(-1, before), (1, after)] {
+        for row in rows {
+            let delta = deltas.entry(row.model.as_str()).or_default();
+            let add = |field: &mut i64, value: usize| *field += sign * value as i64;
+            add(&mut delta.edit_lines, row.edit_lines);
+            add(&mut delta.read_lines, row.read_lines);
+            add(&mut delta.write_lines, row.write_lines);
+            add(&mut delta.bash_count, row.bash_count);
+            add(&mut delta.edit_count, row.edit_count);
+            add(&mut delta.read_count, row.read_count);
+            add(&mut delta.todo_write_count, row.todo_write_count);
+            add(&mut delta.write_count, row.write_count);
+        }
+    }
+    deltas
+        .into_iter()
+        .filter(|(_, delta)| !delta.is_zero())
+        .map(|(model, delta)| AnalysisDeltaRow {
+            model: model.to_string(),
+            ..delta
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(model: &str, edit_lines: usize, read_count: usize) -> AggregatedAnalysisRow {
+        AggregatedAnalysisRow {
+            model: model.to_string(),
+            edit_lines,
+            read_lines: 0,
+            write_lines: 0,
+            bash_count: 0,
+            edit_count: 0,
+            read_count,
+            todo_write_count: 0,
+            write_count: 0,
+        }
+    }
+
+    #[test]
+    fn consecutive_snapshots_keep_only_models_that_moved() {
+        let first = [row("claude-sonnet-4", 10, 2), row("gpt-5", 4, 1)];
+        let second = [
+            row("claude-sonnet-4", 25, 3),
+            row("gpt-5", 4, 1),
+            row("gemini-2.5-pro", 0, 5),
+        ];
+
+        let changes = analysis_delta(&first, &second);
+        let moved: Vec<(&str, i64, i64)> = changes
+            .iter()
+            .map(|delta| (delta.model.as_str(), delta.edit_lines, delta.read_count))
+            .collect();
+        assert_eq!(
+            moved,
+            [("claude-sonnet-4", 15, 1), ("gemini-2.5-pro", 0, 5)]
+        );
+
+        // An idle tick records nothing; a model that disappears goes negative.
+        assert!(analysis_delta(&second, &second).is_empty());
+        let dropped = analysis_delta(&second, &second[..2]);
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].model, "gemini-2.5-pro");
+        assert_eq!(dropped[0].read_count, -5);
+    }
+}
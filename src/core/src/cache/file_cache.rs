@@ -3,7 +3,7 @@ use crate::models::{
     CodeAnalysis, CodeAnalysisApplyDiffDetail, CodeAnalysisReadDetail, CodeAnalysisRecord,
     CodeAnalysisRunCommandDetail, CodeAnalysisWriteDetail, ExtensionType,
 };
-use crate::session::ParseMode;
+use crate::utils::TextEncoding;
 use anyhow::Result;
 use lru::LruCache;
 use std::fs;
@@ -51,8 +51,12 @@ struct FileFingerprint {
 /// long Claude sessions that roughly doubles the working set. Callers that
 /// need a `Value` (CLI single-file dump) serialise on demand from the typed
 /// form, which only happens once per request rather than once per cache entry.
+///
+/// Entries are keyed by path **and** [`TextEncoding`]: the same file can fail
+/// as strict UTF-8 yet parse under lossy decoding, so one encoding's result is
+/// never served for the other.
 pub struct FileParseCache {
-    cache: RwLock<LruCache<PathBuf, CachedFile>>,
+    cache: RwLock<LruCache<(PathBuf, TextEncoding), CachedFile>>,
 }
 
 impl FileParseCache {
@@ -85,7 +89,7 @@ impl FileParseCache {
     /// session file fails (malformed data, unreadable contents, etc.). A
     /// poisoned cache lock does not error — it simply forces a reparse.
     pub fn get_or_parse<P: AsRef<Path>>(&self, path: P) -> Result<Arc<CodeAnalysis>> {
-        self.get_or_parse_inner(path.as_ref(), None)
+        self.get_or_parse_inner(path.as_ref(), None, TextEncoding::Utf8)
     }
 
     /// Same as [`Self::get_or_parse`] but the caller specifies which provider
@@ -103,12 +107,28 @@ impl FileParseCache {
         path: P,
         provider: ExtensionType,
     ) -> Result<Arc<CodeAnalysis>> {
-        self.get_or_parse_inner(path.as_ref(), Some(provider))
+        self.get_or_parse_inner(path.as_ref(), Some(provider), TextEncoding::Utf8)
     }
 
-    /// Shared cache lookup + parse path behind [`Self::get_or_parse`] and
-    /// [`Self::get_or_parse_as`]; `provider` of `None` triggers content-based
-    /// auto-detection.
+    /// [`Self::get_or_parse`] (`provider` of `None`) or
+    /// [`Self::get_or_parse_as`] reading invalid UTF-8 per `encoding`, which
+    /// both of those leave at [`TextEncoding::Utf8`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::get_or_parse_as`].
+    pub fn get_or_parse_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        provider: Option<ExtensionType>,
+        encoding: TextEncoding,
+    ) -> Result<Arc<CodeAnalysis>> {
+        self.get_or_parse_inner(path.as_ref(), provider, encoding)
+    }
+
+    /// Shared cache lookup + parse path behind [`Self::get_or_parse`],
+    /// [`Self::get_or_parse_as`], and [`Self::get_or_parse_with`]; `provider`
+    /// of `None` triggers content-based auto-detection.
     ///
     /// # Errors
     ///
@@ -118,8 +138,9 @@ impl FileParseCache {
         &self,
         path: &Path,
         provider: Option<ExtensionType>,
+        encoding: TextEncoding,
     ) -> Result<Arc<CodeAnalysis>> {
-        let path_buf = path.to_path_buf();
+        let key = (path.to_path_buf(), encoding);
 
         let primary = file_stamp(path)?;
 
@@ -127,7 +148,7 @@ impl FileParseCache {
         {
             if let Ok(cache_read) = self.cache.read() {
                 // Use peek() instead of get() to avoid requiring write lock
-                if let Some(cached) = cache_read.peek(&path_buf) {
+                if let Some(cached) = cache_read.peek(&key) {
                     let is_grok = provider == Some(ExtensionType::Grok)
                         || cached.analysis.extension_name == "Grok";
                     let fingerprint = FileFingerprint {
@@ -142,7 +163,7 @@ impl FileParseCache {
 
                         // Promote entry to front (requires write lock but quick operation)
                         if let Ok(mut cache_write) = self.cache.write() {
-                            cache_write.get(&path_buf); // Updates LRU position
+                            cache_write.get(&key); // Updates LRU position
                         }
 
                         return Ok(result);
@@ -156,10 +177,8 @@ impl FileParseCache {
         let possible_grok_dependencies = (provider.is_none()
             || provider == Some(ExtensionType::Grok))
         .then(|| grok_dependency_stamps(path));
-        let analysis = match provider {
-            Some(p) => crate::session::parse_session_file_typed_as(path, p, ParseMode::Full)?,
-            None => crate::session::parse_session_file_typed(path)?,
-        };
+        let analysis =
+            crate::session::parser::parse_session_file_with_encoding(path, provider, encoding)?;
         let arc_analysis = Arc::new(analysis);
         let size_bytes = estimate_analysis_bytes(arc_analysis.as_ref());
 
@@ -168,7 +187,7 @@ impl FileParseCache {
             let is_grok =
                 provider == Some(ExtensionType::Grok) || arc_analysis.extension_name == "Grok";
             cache_write.put(
-                path_buf,
+                key,
                 CachedFile {
                     fingerprint: FileFingerprint {
                         primary,
@@ -197,10 +216,10 @@ impl FileParseCache {
     pub fn cleanup_stale(&self) {
         if let Ok(mut cache) = self.cache.write() {
             // LRU cache doesn't have retain(), so we collect keys first
-            let stale_keys: Vec<PathBuf> = cache
+            let stale_keys: Vec<(PathBuf, TextEncoding)> = cache
                 .iter()
-                .filter(|(path, _)| !path.exists())
-                .map(|(path, _)| path.clone())
+                .filter(|((path, _), _)| !path.exists())
+                .map(|(key, _)| key.clone())
                 .collect();
 
             for key in stale_keys {
@@ -225,17 +244,23 @@ impl FileParseCache {
         }
     }
 
-    /// Removes a specific file from the cache.
+    /// Removes a specific file from the cache, under every encoding.
     pub fn invalidate<P: AsRef<Path>>(&self, path: P) {
         if let Ok(mut cache) = self.cache.write() {
-            cache.pop(&path.as_ref().to_path_buf());
+            let path = path.as_ref().to_path_buf();
+            for encoding in [TextEncoding::Utf8, TextEncoding::Lossy] {
+                cache.pop(&(path.clone(), encoding));
+            }
         }
     }
 
     /// Returns all currently cached file paths.
     pub fn get_cached_paths(&self) -> Vec<PathBuf> {
         if let Ok(cache) = self.cache.write() {
-            cache.iter().map(|(path, _)| path.clone()).collect()
+            let mut paths: Vec<PathBuf> = cache.iter().map(|((path, _), _)| path.clone()).collect();
+            paths.sort();
+            paths.dedup();
+            paths
         } else {
             Vec::new()
         }
@@ -305,6 +330,12 @@ fn estimate_analysis_bytes(analysis: &CodeAnalysis) -> usize {
         bytes += record.task_id.capacity();
         bytes += record.folder_path.capacity();
         bytes += record.git_remote_url.capacity();
+        bytes += record.repositories.capacity() * size_of::<String>();
+        bytes += record
+            .repositories
+            .iter()
+            .map(String::capacity)
+            .sum::<usize>();
 
         bytes += record.write_file_details.capacity() * size_of::<CodeAnalysisWriteDetail>();
         for detail in &record.write_file_details {
@@ -23,12 +23,14 @@
 //! (e.g. a hand-edited inline `usage = { ... }` table), so the returned [`Config`]
 //! is always correct even when the file was not rewritten.
 
-use crate::models::TimeRange;
+use crate::models::{ExtensionType, TimeRange};
+use crate::pricing::ThresholdBasis;
 use crate::utils::{get_cache_dir, write_string_atomic};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::path::Path;
 use toml_edit::{Array, DocumentMut, Item, Table, TableLike, value};
 
@@ -48,6 +50,10 @@ pub struct Config {
     #[serde(default)]
     pub analysis: AnalysisConfig,
     #[serde(default)]
+    pub tui: TuiConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
     pub performance: PerformanceConfig,
     #[serde(default)]
     pub providers: ProvidersConfig,
@@ -55,6 +61,40 @@ pub struct Config {
     pub logging: LoggingConfig,
 }
 
+/// `[tui]` — key bindings shared by the usage and analysis dashboards.
+///
+/// A key is a single character (`"q"`, case-sensitive), a named key
+/// (`"esc"`, `"enter"`, `"tab"`, `"backspace"`), or `"ctrl+<char>"`. The first
+/// key of each list is the one shown in the controls footer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TuiConfig {
+    /// Keys that quit the dashboard.
+    #[serde(default = "default_quit_keys")]
+    pub quit_keys: Vec<String>,
+    /// Keys that rescan the sessions immediately.
+    #[serde(default = "default_refresh_keys")]
+    pub refresh_keys: Vec<String>,
+}
+
+impl Default for TuiConfig {
+    fn default() -> Self {
+        Self {
+            quit_keys: default_quit_keys(),
+            refresh_keys: default_refresh_keys(),
+        }
+    }
+}
+
+/// `[display]` — how names are shown in tables and text output.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayConfig {
+    /// Friendly names used by `--pretty-models`, keyed by exact model id
+    /// (e.g. `"claude-3-5-sonnet-20241022" = "Claude 3.5 Sonnet"`). Entries
+    /// win over the built-in names; JSON output always keeps the id.
+    #[serde(default)]
+    pub model_names: BTreeMap<String, String>,
+}
+
 /// `[performance]` - controls for CPU-bound local scans.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct PerformanceConfig {
@@ -113,6 +153,11 @@ pub struct UsageConfig {
     /// Seconds between automatic redraws of the usage TUI (minimum 1).
     #[serde(default = "default_refresh_secs")]
     pub refresh_interval: u64,
+    /// Which tokens of a request are compared against a model's long-context
+    /// price threshold: "total_input" (prompt incl. cache), "total" (prompt +
+    /// output), or "per_type" (each token type on its own).
+    #[serde(default)]
+    pub threshold_basis: ThresholdBasis,
     /// Live quota-panel preferences.
     #[serde(default)]
     pub quota: QuotaConfig,
@@ -123,6 +168,7 @@ impl Default for UsageConfig {
         Self {
             merge_models: false,
             refresh_interval: default_refresh_secs(),
+            threshold_basis: ThresholdBasis::default(),
             quota: QuotaConfig::default(),
         }
     }
@@ -182,12 +228,17 @@ pub struct AnalysisConfig {
     /// Seconds between automatic redraws of the analysis TUI (minimum 1).
     #[serde(default = "default_refresh_secs")]
     pub refresh_interval: u64,
+    /// Blank the `machineId` field in `vct analysis` JSON output
+    /// (same effect as `--no-machine-id` or `VCT_DISABLE_MACHINE_ID=1`).
+    #[serde(default)]
+    pub hide_machine_id: bool,
 }
 
 impl Default for AnalysisConfig {
     fn default() -> Self {
         Self {
             refresh_interval: default_refresh_secs(),
+            hide_machine_id: false,
         }
     }
 }
@@ -238,6 +289,22 @@ impl Default for ProvidersConfig {
     }
 }
 
+impl ProvidersConfig {
+    /// Whether `provider`'s toggle is on.
+    pub fn is_enabled(self, provider: ExtensionType) -> bool {
+        match provider {
+            ExtensionType::ClaudeCode => self.claude,
+            ExtensionType::Codex => self.codex,
+            ExtensionType::Copilot => self.copilot,
+            ExtensionType::Gemini => self.gemini,
+            ExtensionType::OpenCode => self.opencode,
+            ExtensionType::Cursor => self.cursor,
+            ExtensionType::Hermes => self.hermes,
+            ExtensionType::Grok => self.grok,
+        }
+    }
+}
+
 /// `[logging]` — file logging preferences.
 ///
 /// Diagnostics are written to `~/.vct/logs/vct-YYYY-MM-DD.log` (plain text, one
@@ -300,6 +367,17 @@ fn default_quota_refresh_secs() -> u64 {
     60
 }
 
+fn default_quit_keys() -> Vec<String> {
+    ["q", "Q", "esc", "ctrl+c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_refresh_keys() -> Vec<String> {
+    vec!["r".to_string()]
+}
+
 fn default_quota_panels() -> Vec<String> {
     ["claude", "codex", "copilot", "cursor"]
         .iter()
@@ -319,6 +397,17 @@ pub fn load() -> Config {
     }
 }
 
+/// [`load`] without the first-run side effect: when no `config.toml` exists
+/// yet, [`Config::default`] is returned and nothing is written.
+///
+/// For commands that only peek at a setting, like `analysis FILE`.
+pub fn load_existing() -> Config {
+    match get_cache_dir() {
+        Ok(dir) if dir.join("config.toml").exists() => load_in(&dir),
+        _ => Config::default(),
+    }
+}
+
 /// [`load`] rooted at an explicit directory (test seam).
 pub fn load_in(dir: &Path) -> Config {
     let path = dir.join("config.toml");
@@ -380,6 +469,82 @@ pub fn migrate_config_file(path: &Path) -> Result<MigrationStatus> {
     }
 }
 
+/// Checks that the `config.toml` at `path` parses into [`Config`] and sets
+/// no key [`Config`] does not read, for `vct config validate`.
+///
+/// A legacy-format file that only parses after the migration [`load_in`]
+/// applies still counts as valid; nothing is written. Parse errors always
+/// point into the file as written, not the migrated text.
+///
+/// # Errors
+///
+/// Returns an error if the file is missing or unreadable, the TOML parse
+/// error (with the offending line and a caret under the column) when it does
+/// not describe valid settings, or the dotted path of every unknown key (a
+/// misspelled key the infallible [`load`] would silently ignore).
+pub fn validate_config_file(path: &Path) -> Result<()> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("cannot read config file {}", path.display()))?;
+    let effective = migrate_text(&text)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| text.clone());
+    let config = match toml_edit::de::from_str::<Config>(&effective) {
+        Ok(config) => config,
+        Err(migrated_error) => {
+            let error = toml_edit::de::from_str::<Config>(&text)
+                .err()
+                .unwrap_or(migrated_error);
+            return Err(error).with_context(|| format!("invalid config file {}", path.display()));
+        }
+    };
+    let unknown = unknown_keys(&effective, &config);
+    anyhow::ensure!(
+        unknown.is_empty(),
+        "invalid config file {}: unknown key(s) {}",
+        path.display(),
+        unknown.join(", ")
+    );
+    Ok(())
+}
+
+/// Keys the read-time [`migrate_legacy`] shim still honors, so they are not
+/// unknown even where the structural migration leaves them in place.
+const LEGACY_KEYS: [&str; 2] = ["quota_panels", "refresh_interval_secs"];
+
+/// Dotted paths of the keys in `text` that no field of `config` (the same
+/// text, already parsed) reads. serde ignores such keys, so comparing the
+/// document with `config` serialized back is the only way to see them.
+fn unknown_keys(text: &str, config: &Config) -> Vec<String> {
+    let (Ok(given), Ok(known)) = (
+        toml_edit::de::from_str::<Value>(text),
+        serde_json::to_value(config),
+    ) else {
+        return Vec::new();
+    };
+    let mut unknown = Vec::new();
+    collect_unknown_keys(&given, &known, "", &mut unknown);
+    unknown
+}
+
+fn collect_unknown_keys(given: &Value, known: &Value, prefix: &str, unknown: &mut Vec<String>) {
+    let (Value::Object(given), Value::Object(known)) = (given, known) else {
+        return;
+    };
+    for (key, value) in given {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match known.get(key) {
+            Some(field) => collect_unknown_keys(value, field, &path, unknown),
+            None if LEGACY_KEYS.contains(&key.as_str()) => {}
+            None => unknown.push(path),
+        }
+    }
+}
+
 /// Applies the structural migration to raw config text: `Ok(Some(new))` when it
 /// changed, `Ok(None)` when already current, `Err` when the text is not valid
 /// TOML (so a caller never overwrites an unparseable file with defaults).
@@ -832,8 +997,10 @@ mod tests {
         assert!(cfg.usage.shows_quota_panel("cursor"));
         assert!(!cfg.usage.merge_models);
         assert_eq!(cfg.usage.refresh_interval, 10);
+        assert_eq!(cfg.usage.threshold_basis, ThresholdBasis::TotalInput);
         assert_eq!(cfg.usage.quota.refresh_interval, 60);
         assert_eq!(cfg.analysis.refresh_interval, 10);
+        assert!(!cfg.analysis.hide_machine_id);
         assert_eq!(cfg.performance.scan_threads, 0);
         assert_eq!(cfg.providers, ProvidersConfig::default());
         assert!(cfg.providers.cursor);
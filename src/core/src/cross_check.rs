@@ -0,0 +1,167 @@
+//! The `usage --cross-check` accounting audit.
+//!
+//! `usage` and `analysis` read the same sessions but total tokens through
+//! separate pipelines (the compact usage summaries versus the full parsed
+//! records), so a bug in either shows up as the two disagreeing. This module
+//! runs both scans over the same range, totals tokens per local
+//! `(date, model)` on each side, and reports every key whose totals differ by
+//! more than a tolerance.
+
+use crate::analysis::{
+    AnalysisScanOptions, ScanDiagnostics, collect_analysis_sessions_from_paths_with_opts,
+};
+use crate::config::ProvidersConfig;
+use crate::models::TimeRange;
+use crate::session::ParseMode;
+use crate::summary_cache::SummaryScanCache;
+use crate::usage::{
+    UsageScanOptions, aggregate_usage_from_paths_with_cache_opts, tokens_by_date_and_model,
+};
+use crate::utils::{HelperPaths, resolve_paths};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// One `(date, model)` whose usage and analysis token totals disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenDiscrepancy {
+    /// Local `YYYY-MM-DD` date of the sessions.
+    pub date: String,
+    /// Model name.
+    pub model: String,
+    /// Total tokens the `usage` pipeline counted.
+    pub usage_tokens: i64,
+    /// Total tokens the `analysis` pipeline counted.
+    pub analysis_tokens: i64,
+    /// `usage_tokens - analysis_tokens`.
+    pub difference: i64,
+}
+
+/// The discrepancies plus the diagnostics of the two scans behind them.
+pub struct CrossCheck {
+    /// Disagreeing keys in date, then model order; empty when consistent.
+    pub discrepancies: Vec<TokenDiscrepancy>,
+    /// Diagnostics of the usage scan.
+    pub usage_diagnostics: ScanDiagnostics,
+    /// Diagnostics of the analysis scan.
+    pub analysis_diagnostics: ScanDiagnostics,
+}
+
+/// Cross-checks the current user's sessions; see [`cross_check_from_paths`].
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be resolved or either scan
+/// fails.
+pub fn cross_check(
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    tolerance: u64,
+    options: &UsageScanOptions,
+) -> Result<CrossCheck> {
+    cross_check_from_paths(&resolve_paths()?, time_range, providers, tolerance, options)
+}
+
+/// Runs the usage and analysis scans over `paths` and compares their token
+/// totals per `(date, model)`, reading and renaming models in both as
+/// `options` does (its `files` and `tiers` are not used).
+///
+/// Hermes is left out of both scans: it records usage only, so every Hermes
+/// token would otherwise show up as a discrepancy.
+///
+/// # Errors
+///
+/// Returns an error if either scan fails.
+pub fn cross_check_from_paths(
+    paths: &HelperPaths,
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    tolerance: u64,
+    options: &UsageScanOptions,
+) -> Result<CrossCheck> {
+    let providers = ProvidersConfig {
+        hermes: false,
+        ..providers
+    };
+    let usage = aggregate_usage_from_paths_with_cache_opts(
+        paths,
+        time_range,
+        providers,
+        &mut SummaryScanCache::new(),
+        &UsageScanOptions {
+            encoding: options.encoding,
+            fold_case: options.fold_case,
+            fixups: options.fixups.clone(),
+            ignore: options.ignore.clone(),
+            warnings: options.warnings.clone(),
+            ..UsageScanOptions::default()
+        },
+    )?;
+    let analysis_options = AnalysisScanOptions {
+        encoding: options.encoding,
+        fold_case: options.fold_case,
+        fixups: options.fixups.clone(),
+        ignore: options.ignore.clone(),
+        dedupe_by: options.dedupe_by,
+        warnings: options.warnings.clone(),
+    };
+    let analysis = collect_analysis_sessions_from_paths_with_opts(
+        paths,
+        time_range,
+        providers,
+        ParseMode::UsageOnly,
+        &analysis_options,
+    )?;
+    Ok(CrossCheck {
+        discrepancies: compare_token_totals(
+            &tokens_by_date_and_model(&usage.data),
+            &analysis.tokens_by_date_and_model(&analysis_options),
+            tolerance,
+        ),
+        usage_diagnostics: usage.diagnostics,
+        analysis_diagnostics: analysis.diagnostics,
+    })
+}
+
+/// Keys whose totals differ by more than `tolerance` tokens; a key missing
+/// on one side counts as zero there.
+pub fn compare_token_totals(
+    usage: &BTreeMap<(String, String), i64>,
+    analysis: &BTreeMap<(String, String), i64>,
+    tolerance: u64,
+) -> Vec<TokenDiscrepancy> {
+    let keys: std::collections::BTreeSet<_> = usage.keys().chain(analysis.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let usage_tokens = usage.get(key).copied().unwrap_or(0);
+            let analysis_tokens = analysis.get(key).copied().unwrap_or(0);
+            let difference = usage_tokens - analysis_tokens;
+            (difference.unsigned_abs() > tolerance).then(|| TokenDiscrepancy {
+                date: key.0.clone(),
+                model: key.1.clone(),
+                usage_tokens,
+                analysis_tokens,
+                difference,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn differences_within_the_tolerance_are_not_reported() {
+        let key = |model: &str| ("2025-10-01".to_string(), model.to_string());
+        let usage = BTreeMap::from([(key("a"), 100), (key("b"), 100), (key("c"), 5)]);
+        let analysis = BTreeMap::from([(key("a"), 100), (key("b"), 97)]);
+
+        let reported: Vec<_> = compare_token_totals(&usage, &analysis, 3)
+            .into_iter()
+            .map(|discrepancy| (discrepancy.model, discrepancy.difference))
+            .collect();
+        assert_eq!(reported, [("c".to_string(), 5)]);
+        assert_eq!(compare_token_totals(&usage, &analysis, 0).len(), 2);
+    }
+}
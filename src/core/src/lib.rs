@@ -24,23 +24,29 @@
 //! Supporting modules: [`pricing`] (LiteLLM price lookup with a daily
 //! on-disk cache), [`cache`] (LRU file cache keyed by mtime), [`update`]
 //! (self-replace from the matching GitHub release asset), [`utils`]
-//! (path resolution and the glibc allocator tuning), and [`constants`]
-//! (capacity and buffer sizing).
+//! (path resolution and the glibc allocator tuning), [`stats`] (the
+//! `vct stats` digest drawn from both views), [`cross_check`] (the
+//! `usage --cross-check` audit of the two views' token totals), [`warnings`]
+//! (the end-of-run warning summary), and [`constants`] (capacity and buffer
+//! sizing).
 
 pub mod analysis;
 pub mod cache;
 pub mod config;
 pub mod constants;
+pub mod cross_check;
 pub mod logging;
 pub mod models;
 pub mod pricing;
 pub mod quota;
 pub mod scan;
 pub mod session;
+pub mod stats;
 pub mod summary_cache;
 pub mod update;
 pub mod usage;
 pub mod utils;
+pub mod warnings;
 
 pub use models::*;
 pub use session::parser::{parse_session_file_to_value, parse_session_file_typed};
@@ -50,7 +56,7 @@ pub use usage::aggregator::{UsageData, aggregate_usage_from_home, aggregate_usag
 // shared scan diagnostics.
 pub use analysis::{AnalysisData, project_code_analysis};
 pub use scan::{ScanDiagnostics, ScanFailure};
-pub use usage::{PricedUsageRow, price_usage_data};
+pub use usage::{PricedUsageRow, distinct_models, price_usage_data};
 
 /// Full build version: latest git tag plus commits-since and short SHA
 /// (with a `-dirty` suffix when the worktree is modified), generated by
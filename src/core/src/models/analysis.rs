@@ -127,6 +127,83 @@ pub struct CodeAnalysisToolCalls {
     pub bash: usize,
 }
 
+/// The kind of an API error event a session logged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// The provider throttled the request (HTTP 429, `rate_limit_error`).
+    RateLimit,
+    /// The provider was out of capacity (HTTP 529, `overloaded_error`).
+    Overloaded,
+    /// Any other logged API error.
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Classifies an error from its HTTP `status` and its error type name
+    /// (`rate_limit_error`, `overloaded_error`, or the short `rate_limit` /
+    /// `overloaded`); the status wins when both are known. Free text such as
+    /// messages or request ids is never consulted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vct_core::models::ApiErrorKind;
+    ///
+    /// assert_eq!(ApiErrorKind::classify(Some(429), None), ApiErrorKind::RateLimit);
+    /// assert_eq!(ApiErrorKind::classify(None, Some("overloaded_error")), ApiErrorKind::Overloaded);
+    /// assert_eq!(ApiErrorKind::classify(Some(400), Some("rate_limit")), ApiErrorKind::Other);
+    /// ```
+    pub fn classify(status: Option<u64>, error_type: Option<&str>) -> Self {
+        match (status, error_type) {
+            (Some(429), _) | (None, Some("rate_limit" | "rate_limit_error")) => Self::RateLimit,
+            (Some(529), _) | (None, Some("overloaded" | "overloaded_error")) => Self::Overloaded,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Per-session counts of logged API error events, by [`ApiErrorKind`].
+///
+/// Serialized with camelCase keys (`rateLimit`, `overloaded`, `other`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCounts {
+    /// Rate-limit errors.
+    pub rate_limit: usize,
+    /// Overloaded / capacity errors.
+    pub overloaded: usize,
+    /// Every other API error.
+    pub other: usize,
+}
+
+impl ErrorCounts {
+    /// Counts one error event of `kind`.
+    pub fn record(&mut self, kind: ApiErrorKind) {
+        match kind {
+            ApiErrorKind::RateLimit => self.rate_limit += 1,
+            ApiErrorKind::Overloaded => self.overloaded += 1,
+            ApiErrorKind::Other => self.other += 1,
+        }
+    }
+
+    /// Adds every count of `other`.
+    pub fn merge(&mut self, other: &Self) {
+        self.rate_limit += other.rate_limit;
+        self.overloaded += other.overloaded;
+        self.other += other.other;
+    }
+
+    /// Total error events of every kind.
+    pub fn total(&self) -> usize {
+        self.rate_limit + self.overloaded + self.other
+    }
+
+    /// Whether no error event was counted.
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
 /// Aggregated metrics and per-operation details for a single coding session.
 ///
 /// One record corresponds to one session file. When parsed in
@@ -142,6 +219,10 @@ pub struct CodeAnalysisRecord {
     pub total_write_lines: usize,
     /// Sum of lines read across all read operations.
     pub total_read_lines: usize,
+    /// Lines read counting each distinct file once at its largest read; only
+    /// filled (and serialized) under `analysis --dedupe-reads`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique_read_lines: Option<usize>,
     /// Sum of replacement lines across all edit operations.
     pub total_edit_lines: usize,
     /// Sum of characters written across all write operations.
@@ -160,6 +241,10 @@ pub struct CodeAnalysisRecord {
     pub run_command_details: Vec<CodeAnalysisRunCommandDetail>,
     /// Tool-call counters for the session.
     pub tool_call_counts: CodeAnalysisToolCalls,
+    /// API error events (rate limits, overloads, …) the session logged;
+    /// omitted from JSON when there were none.
+    #[serde(default, skip_serializing_if = "ErrorCounts::is_empty")]
+    pub error_counts: ErrorCounts,
     /// Token-usage payloads keyed by model name; shape varies by provider
     /// (see [`crate::models::UsageResult`]).
     #[serde(serialize_with = "serialize_conversation_usage")]
@@ -182,6 +267,41 @@ pub struct CodeAnalysisRecord {
     pub folder_path: String,
     /// Git remote URL of the project, when one was detected.
     pub git_remote_url: String,
+    /// Root of every git repository the session worked in, in first-seen
+    /// order; only filled (and serialized) when it crossed into more than one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<String>,
+    /// Base64 copy of the session file the record was parsed from; only
+    /// filled (and serialized) under `analysis --embed-raw`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_source: Option<String>,
+}
+
+/// Model label the `analysis` roll-up files a record under when it has tool
+/// activity but no token events (an empty `conversation_usage`).
+///
+/// Such a session still did work, so `analysis` keeps its operations under
+/// this row; `usage` has no tokens to report and shows no row for it.
+pub const UNATTRIBUTED_MODEL: &str = "unknown";
+
+impl CodeAnalysisRecord {
+    /// Whether the record holds any file operation or tool call.
+    pub fn has_tool_activity(&self) -> bool {
+        let calls = &self.tool_call_counts;
+        self.total_edit_lines + self.total_read_lines + self.total_write_lines > 0
+            || calls.read + calls.write + calls.edit + calls.todo_write + calls.bash > 0
+    }
+
+    /// Model names this record's operations count toward in the `analysis`
+    /// roll-up: every `conversation_usage` key, or [`UNATTRIBUTED_MODEL`] for
+    /// a tool-only record with no token events.
+    pub fn analysis_models(&self) -> Vec<&str> {
+        if self.conversation_usage.is_empty() && self.has_tool_activity() {
+            vec![UNATTRIBUTED_MODEL]
+        } else {
+            self.conversation_usage.keys().map(String::as_str).collect()
+        }
+    }
 }
 
 /// Top-level analysis result: environment metadata plus one record per session.
@@ -560,6 +680,7 @@ mod tests {
             total_unique_files: 5,
             total_write_lines: 100,
             total_read_lines: 200,
+            unique_read_lines: None,
             total_edit_lines: 50,
             total_write_characters: 2500,
             total_read_characters: 5000,
@@ -570,11 +691,14 @@ mod tests {
             run_command_details: vec![],
             tool_call_counts: CodeAnalysisToolCalls::default(),
             conversation_usage: FastHashMap::default(),
+            error_counts: ErrorCounts::default(),
             advisor_usage: FastHashMap::default(),
             task_id: "task-123".to_string(),
             timestamp: 1234567890,
             folder_path: "/workspace".to_string(),
             git_remote_url: "https://github.com/test/repo".to_string(),
+            repositories: Vec::new(),
+            raw_source: None,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -593,6 +717,7 @@ mod tests {
             total_unique_files: 0,
             total_write_lines: 0,
             total_read_lines: 0,
+            unique_read_lines: None,
             total_edit_lines: 0,
             total_write_characters: 0,
             total_read_characters: 0,
@@ -603,11 +728,14 @@ mod tests {
             run_command_details: vec![],
             tool_call_counts: CodeAnalysisToolCalls::default(),
             conversation_usage: FastHashMap::default(),
+            error_counts: ErrorCounts::default(),
             advisor_usage: FastHashMap::default(),
             task_id: String::new(),
             timestamp: 0,
             folder_path: String::new(),
             git_remote_url: String::new(),
+            repositories: Vec::new(),
+            raw_source: None,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -44,6 +44,18 @@ pub struct ClaudeCodeLog {
     /// `toolUseResult`.
     #[serde(default)]
     pub is_sidechain: bool,
+    /// `true` on the synthetic assistant record Claude Code writes when an
+    /// API request finally failed.
+    #[serde(default)]
+    pub is_api_error_message: bool,
+    /// Record subtype; `api_error` marks a failed request attempt on a
+    /// `system` record.
+    #[serde(default)]
+    pub subtype: Option<String>,
+    /// The logged API error: a short kind string on error messages
+    /// (`rate_limit`), the response object on `api_error` records.
+    #[serde(default)]
+    pub error: Option<Value>,
 }
 
 /// Assistant/user message with only the fields `session::claude::parse_claude_logs` inspects.
@@ -54,6 +66,10 @@ pub struct ClaudeCodeLog {
 /// swallowed without allocating.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct ClaudeMessage {
+    /// API message id (`msg_…`); Claude Code repeats it on every record it
+    /// logs for the same response.
+    #[serde(default)]
+    pub id: Option<String>,
     /// Model name that produced an assistant message.
     #[serde(default)]
     pub model: Option<String>,
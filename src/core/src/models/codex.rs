@@ -180,6 +180,9 @@ pub struct CodexGitInfo {
 pub struct CodexShellArguments {
     /// Command argv vector.
     pub command: Vec<String>,
+    /// Working directory for the command (empty when unset).
+    #[serde(default)]
+    pub workdir: String,
 }
 
 /// Arguments for the current `name == "exec_command"` function call.
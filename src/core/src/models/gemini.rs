@@ -123,23 +123,98 @@ pub struct GeminiThought {
 
 /// Token-usage breakdown for a single Gemini message.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawGeminiTokens")]
 pub struct GeminiTokens {
     /// Input (prompt) tokens.
-    #[serde(default)]
     pub input: i64,
     /// Output (response) tokens.
-    #[serde(default)]
     pub output: i64,
-    /// Tokens served from cache.
-    #[serde(default)]
+    /// Tokens served from cache. Gemini CLI versions that copy the API's
+    /// usage metadata spell it `cachedContentTokenCount` (or the snake_case
+    /// `cached_content_token_count`); all three are accepted.
     pub cached: i64,
     /// Tokens spent on reasoning / thoughts.
-    #[serde(default)]
     pub thoughts: i64,
     /// Tokens attributed to tool use.
-    #[serde(default)]
     pub tool: i64,
     /// Total token count for the message.
-    #[serde(default)]
     pub total: i64,
 }
+
+/// [`GeminiTokens`] as logged, with every spelling of the cached count kept
+/// apart: a record carrying more than one spelling deserializes instead of
+/// failing on a duplicate field, and `cached` wins, then
+/// `cachedContentTokenCount`, then `cached_content_token_count`.
+#[derive(Deserialize)]
+struct RawGeminiTokens {
+    #[serde(default)]
+    input: i64,
+    #[serde(default)]
+    output: i64,
+    cached: Option<i64>,
+    #[serde(rename = "cachedContentTokenCount")]
+    cached_content_token_count_camel: Option<i64>,
+    cached_content_token_count: Option<i64>,
+    #[serde(default)]
+    thoughts: i64,
+    #[serde(default)]
+    tool: i64,
+    #[serde(default)]
+    total: i64,
+}
+
+impl From<RawGeminiTokens> for GeminiTokens {
+    fn from(raw: RawGeminiTokens) -> Self {
+        Self {
+            input: raw.input,
+            output: raw.output,
+            cached: raw
+                .cached
+                .or(raw.cached_content_token_count_camel)
+                .or(raw.cached_content_token_count)
+                .unwrap_or_default(),
+            thoughts: raw.thoughts,
+            tool: raw.tool,
+            total: raw.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cached_tokens_accept_every_spelling() {
+        for field in [
+            "cached",
+            "cachedContentTokenCount",
+            "cached_content_token_count",
+        ] {
+            let tokens: GeminiTokens =
+                serde_json::from_value(json!({ "input": 120, "output": 30, field: 80 })).unwrap();
+            assert_eq!(tokens.cached, 80, "{field}");
+            assert_eq!(tokens.input, 120, "{field}");
+        }
+    }
+
+    #[test]
+    fn cached_tokens_prefer_the_native_spelling_when_several_are_present() {
+        let tokens: GeminiTokens = serde_json::from_value(json!({
+            "input": 120,
+            "cached_content_token_count": 10,
+            "cachedContentTokenCount": 20,
+            "cached": 30,
+        }))
+        .unwrap();
+        assert_eq!(tokens.cached, 30);
+
+        let tokens: GeminiTokens = serde_json::from_value(json!({
+            "cached_content_token_count": 10,
+            "cachedContentTokenCount": 20,
+        }))
+        .unwrap();
+        assert_eq!(tokens.cached, 20);
+    }
+}
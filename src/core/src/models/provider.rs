@@ -1,3 +1,4 @@
+use crate::models::ExtensionType;
 use std::fmt;
 
 /// Supported AI coding assistant providers.
@@ -135,6 +136,22 @@ impl Provider {
     }
 }
 
+impl From<ExtensionType> for Provider {
+    /// The provider a session source directory or database belongs to.
+    fn from(extension: ExtensionType) -> Self {
+        match extension {
+            ExtensionType::ClaudeCode => Self::ClaudeCode,
+            ExtensionType::Codex => Self::Codex,
+            ExtensionType::Copilot => Self::Copilot,
+            ExtensionType::Gemini => Self::Gemini,
+            ExtensionType::OpenCode => Self::OpenCode,
+            ExtensionType::Cursor => Self::Cursor,
+            ExtensionType::Hermes => Self::Hermes,
+            ExtensionType::Grok => Self::Grok,
+        }
+    }
+}
+
 impl fmt::Display for Provider {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.display_name())
@@ -0,0 +1,106 @@
+//! Self-timing of the pricing matcher (`vct dev --bench-matcher`).
+//!
+//! Times uncached [`ModelPricingMap::get`] lookups of a model set — normally
+//! every model the local sessions used — and counts which tier resolved each
+//! one, showing whether the substring/fuzzy scans dominate for that data.
+
+use super::matching::{ModelPricingMap, clear_pricing_cache};
+use std::time::{Duration, Instant};
+
+/// Tier labels in the order [`ModelPricingMap::get`] tries them.
+const KIND_LABELS: [&str; 7] = [
+    "exact",
+    "normalized",
+    "builtin",
+    "substring",
+    "fuzzy",
+    "estimated",
+    "unmatched",
+];
+
+/// Timing and tier breakdown of one matcher benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatcherBenchmark {
+    /// Distinct models looked up.
+    pub models: usize,
+    /// Timed lookups per model.
+    pub rounds: usize,
+    /// Fastest single lookup.
+    pub min: Duration,
+    /// Mean lookup time.
+    pub avg: Duration,
+    /// Slowest single lookup.
+    pub max: Duration,
+    /// Models resolved by each tier, in tier order, zero counts included.
+    pub kind_counts: Vec<(&'static str, usize)>,
+}
+
+impl MatcherBenchmark {
+    /// Models that reached the slow path: the substring and fuzzy scans over
+    /// every pricing key, or a miss after them.
+    pub fn slow_path_hits(&self) -> usize {
+        self.kind_counts
+            .iter()
+            .filter(|(label, _)| {
+                matches!(*label, "substring" | "fuzzy" | "estimated" | "unmatched")
+            })
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// [`slow_path_hits`](Self::slow_path_hits) as a fraction of the models;
+    /// `0.0` for an empty set.
+    pub fn slow_path_rate(&self) -> f64 {
+        if self.models == 0 {
+            0.0
+        } else {
+            self.slow_path_hits() as f64 / self.models as f64
+        }
+    }
+}
+
+/// Looks every model in `models` up `rounds` times, clearing the match cache
+/// before each lookup so every one runs the full tier chain.
+///
+/// Clearing the cache invalidates every pricing map in the process, so this
+/// is meant for the one-shot `dev` command, not alongside real pricing work.
+pub fn bench_matcher<S: AsRef<str>>(
+    pricing: &ModelPricingMap,
+    models: &[S],
+    rounds: usize,
+) -> MatcherBenchmark {
+    let mut kind_counts: Vec<(&'static str, usize)> =
+        KIND_LABELS.iter().map(|label| (*label, 0)).collect();
+    let mut min = Duration::MAX;
+    let mut max = Duration::ZERO;
+    let mut total = Duration::ZERO;
+    let mut lookups = 0u32;
+    for model in models {
+        let model = model.as_ref();
+        for round in 0..rounds.max(1) {
+            clear_pricing_cache();
+            let start = Instant::now();
+            let result = pricing.get(model);
+            let elapsed = start.elapsed();
+            min = min.min(elapsed);
+            max = max.max(elapsed);
+            total += elapsed;
+            lookups += 1;
+            if round == 0
+                && let Some(entry) = kind_counts
+                    .iter_mut()
+                    .find(|(label, _)| *label == result.match_kind.label())
+            {
+                entry.1 += 1;
+            }
+        }
+    }
+    MatcherBenchmark {
+        models: models.len(),
+        rounds: rounds.max(1),
+        min: if lookups == 0 { Duration::ZERO } else { min },
+        avg: total.checked_div(lookups).unwrap_or_default(),
+        max,
+        kind_counts,
+    }
+}
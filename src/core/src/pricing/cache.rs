@@ -375,6 +375,8 @@ pub fn build_filtered_cost_json(raw: &Value) -> Value {
     Value::Object(filtered_map)
 }
 
+/// The UTC date today's pricing cache is keyed by; the cache expires when it
+/// rolls over.
 pub(crate) fn pricing_cache_date() -> String {
     Utc::now().date_naive().format("%Y-%m-%d").to_string()
 }
@@ -421,8 +423,35 @@ pub fn load_from_cache_in(dir: &Path) -> Result<HashMap<String, ModelPricing>> {
     let today = pricing_cache_date();
     let cache_path = find_pricing_cache_for_date_in(dir, &today)
         .ok_or_else(|| anyhow::anyhow!("No cache file found for today"))?;
+    load_cache_file(&cache_path)
+}
+
+/// Loads the newest pricing cache file under `dir`, whatever its date.
+///
+/// Used by estimate-only scans, which accept stale prices in exchange for never
+/// touching the network. Returns the cache date alongside the map so callers
+/// can say how old the prices are.
+///
+/// # Errors
+///
+/// Returns an error if `dir` holds no pricing cache file, or the newest one
+/// fails the same checks as [`load_from_cache_in`].
+pub fn load_latest_cache_in(dir: &Path) -> Result<(String, HashMap<String, ModelPricing>)> {
+    // `model_pricing_YYYY-MM-DD.json` sorts chronologically by name.
+    let (filename, cache_path) = list_pricing_cache_files_in(dir)
+        .into_iter()
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .ok_or_else(|| anyhow::anyhow!("No pricing cache file found"))?;
+    let date = filename
+        .trim_start_matches("model_pricing_")
+        .trim_end_matches(".json")
+        .to_string();
+    Ok((date, load_cache_file(&cache_path)?))
+}
 
-    let content = fs::read_to_string(&cache_path).context("Failed to read cached pricing file")?;
+/// Reads, validates, and parses one pricing cache file.
+fn load_cache_file(cache_path: &Path) -> Result<HashMap<String, ModelPricing>> {
+    let content = fs::read_to_string(cache_path).context("Failed to read cached pricing file")?;
     let raw: Value =
         serde_json::from_str(&content).context("Failed to parse cached pricing JSON")?;
 
@@ -1,5 +1,61 @@
 use super::cache::{ModelPricing, TierRange};
 use crate::utils::TokenCounts;
+use serde::{Deserialize, Serialize};
+
+/// A token bucket [`calculate_cost`] prices on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenType {
+    #[serde(rename = "input")]
+    Input,
+    #[serde(rename = "output")]
+    Output,
+    #[serde(rename = "reasoning")]
+    Reasoning,
+    #[serde(rename = "cache_read")]
+    CacheRead,
+    #[serde(rename = "cache_creation_5m")]
+    CacheCreation5m,
+    #[serde(rename = "cache_creation_1h")]
+    CacheCreation1h,
+}
+
+impl TokenType {
+    /// Every bucket, in billing order.
+    const ALL: [TokenType; 6] = [
+        TokenType::Input,
+        TokenType::Output,
+        TokenType::Reasoning,
+        TokenType::CacheRead,
+        TokenType::CacheCreation5m,
+        TokenType::CacheCreation1h,
+    ];
+}
+
+/// Which price a slice of tokens was billed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceTier {
+    /// The model's base (or, for range-priced models, its volume range) price.
+    #[serde(rename = "base")]
+    Base,
+    /// The model's context-tier price (LiteLLM's lowest `above_*_tokens`
+    /// tier, 200K for Claude and Gemini), charged to requests whose prompt
+    /// crossed the threshold.
+    #[serde(rename = "above_200k")]
+    Above200k,
+}
+
+/// One token type's slice of a cost, billed at a single [`PriceTier`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostComponent {
+    /// Token bucket the slice belongs to.
+    pub token_type: TokenType,
+    /// Price the slice was billed at.
+    pub tier: PriceTier,
+    /// Tokens in the slice.
+    pub tokens: i64,
+    /// Cost in USD of the slice.
+    pub cost_usd: f64,
+}
 
 /// One resolved set of per-token prices (base level or one tier).
 struct PriceLevel {
@@ -27,15 +83,9 @@ impl PriceLevel {
         }
     }
 
-    fn bill(
-        &self,
-        input: i64,
-        output: i64,
-        reasoning: i64,
-        cache_read: i64,
-        cc_5m: i64,
-        cc_1h: i64,
-    ) -> f64 {
+    /// Per-token prices in [`TokenType::ALL`] order, with the reasoning and
+    /// 1h cache-write fallbacks applied.
+    fn prices(&self) -> [f64; 6] {
         let reasoning_price = if self.reasoning_raw > 0.0 {
             self.reasoning_raw
         } else {
@@ -46,12 +96,22 @@ impl PriceLevel {
         } else {
             self.cc_5m
         };
-        input as f64 * self.input
-            + output as f64 * self.output
-            + reasoning as f64 * reasoning_price
-            + cache_read as f64 * self.cache_read
-            + cc_5m as f64 * self.cc_5m
-            + cc_1h as f64 * cc_1h_price
+        [
+            self.input,
+            self.output,
+            reasoning_price,
+            self.cache_read,
+            self.cc_5m,
+            cc_1h_price,
+        ]
+    }
+
+    /// Bills `tokens` (in [`TokenType::ALL`] order) at this level.
+    fn bill(&self, tokens: [i64; 6]) -> f64 {
+        self.prices()
+            .iter()
+            .zip(tokens)
+            .fold(0.0, |cost, (price, tokens)| cost + tokens as f64 * price)
     }
 }
 
@@ -106,6 +166,84 @@ impl PriceLevel {
 /// assert_eq!(cost, 1000.0 * 3e-6 + 500.0 * 1.5e-5);
 /// ```
 pub fn calculate_cost(counts: &TokenCounts, pricing: &ModelPricing) -> f64 {
+    let mut cost = 0.0;
+    for_each_level(counts, pricing, |_, level, tokens| {
+        cost += level.bill(tokens)
+    });
+    cost
+}
+
+/// Splits the cost [`calculate_cost`] computes into one [`CostComponent`]
+/// per token type and the tier it was billed at.
+///
+/// A token type whose tokens straddle the context threshold yields two
+/// components (its base slice and its `above_200k` slice); types with no
+/// tokens are omitted. Above-threshold slices of a model that publishes no
+/// tier bill at base prices and are reported as `base`. The components sum
+/// to `calculate_cost` for the same inputs.
+///
+/// # Examples
+///
+/// ```
+/// use vct_core::pricing::{ModelPricing, PriceTier, TokenType, calculate_cost_breakdown};
+/// use vct_core::utils::TokenCounts;
+///
+/// let pricing = ModelPricing {
+///     input_cost_per_token: 3e-6,
+///     ..Default::default()
+/// };
+/// let counts = TokenCounts {
+///     input_tokens: 1000,
+///     ..Default::default()
+/// };
+/// let breakdown = calculate_cost_breakdown(&counts, &pricing);
+/// assert_eq!(breakdown.len(), 1);
+/// assert_eq!(breakdown[0].token_type, TokenType::Input);
+/// assert_eq!(breakdown[0].tier, PriceTier::Base);
+/// ```
+pub fn calculate_cost_breakdown(
+    counts: &TokenCounts,
+    pricing: &ModelPricing,
+) -> Vec<CostComponent> {
+    let mut components: Vec<CostComponent> = Vec::new();
+    for_each_level(counts, pricing, |tier, level, tokens| {
+        for ((token_type, price), tokens) in
+            TokenType::ALL.into_iter().zip(level.prices()).zip(tokens)
+        {
+            if tokens == 0 {
+                continue;
+            }
+            let cost_usd = tokens as f64 * price;
+            match components
+                .iter_mut()
+                .find(|c| c.token_type == token_type && c.tier == tier)
+            {
+                Some(component) => {
+                    component.tokens += tokens;
+                    component.cost_usd += cost_usd;
+                }
+                None => components.push(CostComponent {
+                    token_type,
+                    tier,
+                    tokens,
+                    cost_usd,
+                }),
+            }
+        }
+    });
+    components
+}
+
+/// Walks the price levels `counts` is billed at, handing each its tier and
+/// the tokens (in [`TokenType::ALL`] order) it bills.
+///
+/// This is the single source of the per-type tier decision that both
+/// [`calculate_cost`] and [`calculate_cost_breakdown`] report.
+fn for_each_level(
+    counts: &TokenCounts,
+    pricing: &ModelPricing,
+    mut bill: impl FnMut(PriceTier, &PriceLevel, [i64; 6]),
+) {
     if let Some(ranges) = &pricing.ranges {
         // Range-based pricing dispatches on input volume and has no
         // cache_creation fields on the range rows (LiteLLM doesn't publish
@@ -119,14 +257,19 @@ pub fn calculate_cost(counts: &TokenCounts, pricing: &ModelPricing) -> f64 {
             cc_5m: pricing.cache_creation_input_token_cost,
             cc_1h_raw: pricing.cache_creation_input_token_cost_above_1hr,
         };
-        return level.bill(
-            counts.input_tokens,
-            counts.output_tokens,
-            counts.reasoning_tokens,
-            counts.cache_read,
-            counts.cache_creation_5m,
-            counts.cache_creation_1h,
+        bill(
+            PriceTier::Base,
+            &level,
+            [
+                counts.input_tokens,
+                counts.output_tokens,
+                counts.reasoning_tokens,
+                counts.cache_read,
+                counts.cache_creation_5m,
+                counts.cache_creation_1h,
+            ],
         );
+        return;
     }
 
     let base = PriceLevel::base(pricing);
@@ -135,13 +278,17 @@ pub fn calculate_cost(counts: &TokenCounts, pricing: &ModelPricing) -> f64 {
     // the remainder. Clamp defensively so a malformed merge can never bill
     // negative tokens.
     let base_slice = |total: i64, above: i64| (total - above).max(0);
-    let mut cost = base.bill(
-        base_slice(counts.input_tokens, counts.above_input),
-        base_slice(counts.output_tokens, counts.above_output),
-        base_slice(counts.reasoning_tokens, counts.above_reasoning),
-        base_slice(counts.cache_read, counts.above_cache_read),
-        base_slice(counts.cache_creation_5m, counts.above_cache_creation_5m),
-        base_slice(counts.cache_creation_1h, counts.above_cache_creation_1h),
+    bill(
+        PriceTier::Base,
+        &base,
+        [
+            base_slice(counts.input_tokens, counts.above_input),
+            base_slice(counts.output_tokens, counts.above_output),
+            base_slice(counts.reasoning_tokens, counts.above_reasoning),
+            base_slice(counts.cache_read, counts.above_cache_read),
+            base_slice(counts.cache_creation_5m, counts.above_cache_creation_5m),
+            base_slice(counts.cache_creation_1h, counts.above_cache_creation_1h),
+        ],
     );
 
     let has_above = counts.above_input != 0
@@ -154,34 +301,39 @@ pub fn calculate_cost(counts: &TokenCounts, pricing: &ModelPricing) -> f64 {
         // Classification uses the lowest threshold, so the lowest tier's
         // prices apply. A tier field the model doesn't publish (0.0) falls
         // back to the base price for that bucket rather than billing $0.
-        let tier = match pricing.tiers.first() {
-            Some(tier) => PriceLevel {
-                input: positive_or(tier.input_cost_per_token, base.input),
-                output: positive_or(tier.output_cost_per_token, base.output),
-                // LiteLLM publishes no tier-specific reasoning rate; billing
-                // tier reasoning at the tier output rate matches "once you're
-                // in the tier, everything is more expensive".
-                reasoning_raw: 0.0,
-                cache_read: positive_or(tier.cache_read_input_token_cost, base.cache_read),
-                cc_5m: positive_or(tier.cache_creation_input_token_cost, base.cc_5m),
-                cc_1h_raw: tier.cache_creation_input_token_cost_above_1hr,
-            },
+        let (tier, level) = match pricing.tiers.first() {
+            Some(tier) => (
+                PriceTier::Above200k,
+                PriceLevel {
+                    input: positive_or(tier.input_cost_per_token, base.input),
+                    output: positive_or(tier.output_cost_per_token, base.output),
+                    // LiteLLM publishes no tier-specific reasoning rate; billing
+                    // tier reasoning at the tier output rate matches "once you're
+                    // in the tier, everything is more expensive".
+                    reasoning_raw: 0.0,
+                    cache_read: positive_or(tier.cache_read_input_token_cost, base.cache_read),
+                    cc_5m: positive_or(tier.cache_creation_input_token_cost, base.cc_5m),
+                    cc_1h_raw: tier.cache_creation_input_token_cost_above_1hr,
+                },
+            ),
             // Above-slices without a published tier (e.g. thresholds derived
             // from a newer pricing snapshot than this entry): bill at base
             // rates verbatim, keeping the model's dedicated reasoning rate.
-            None => base,
+            None => (PriceTier::Base, base),
         };
-        cost += tier.bill(
-            counts.above_input,
-            counts.above_output,
-            counts.above_reasoning,
-            counts.above_cache_read,
-            counts.above_cache_creation_5m,
-            counts.above_cache_creation_1h,
+        bill(
+            tier,
+            &level,
+            [
+                counts.above_input,
+                counts.above_output,
+                counts.above_reasoning,
+                counts.above_cache_read,
+                counts.above_cache_creation_5m,
+                counts.above_cache_creation_1h,
+            ],
         );
     }
-
-    cost
 }
 
 fn positive_or(value: f64, fallback: f64) -> f64 {
@@ -304,6 +456,45 @@ mod tests {
         assert_eq!(cost, base_part + tier_part);
     }
 
+    #[test]
+    fn test_breakdown_reports_per_type_tiers_across_the_threshold() {
+        let p = sonnet_like_pricing();
+        // Input straddles the threshold, output stays below it, and every
+        // cache read came from requests above it.
+        let mut c = counts(300_000, 1_000, 0, 80_000, 0, 0);
+        c.above_input = 250_000;
+        c.above_cache_read = 80_000;
+
+        let breakdown = calculate_cost_breakdown(&c, &p);
+        let tiers: Vec<(TokenType, PriceTier, i64)> = breakdown
+            .iter()
+            .map(|c| (c.token_type, c.tier, c.tokens))
+            .collect();
+        assert_eq!(
+            tiers,
+            [
+                (TokenType::Input, PriceTier::Base, 50_000),
+                (TokenType::Output, PriceTier::Base, 1_000),
+                (TokenType::Input, PriceTier::Above200k, 250_000),
+                (TokenType::CacheRead, PriceTier::Above200k, 80_000),
+            ]
+        );
+        assert_eq!(breakdown[2].cost_usd, 250_000.0 * 0.000006);
+        let sum: f64 = breakdown.iter().map(|c| c.cost_usd).sum();
+        assert!((sum - calculate_cost(&c, &p)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_breakdown_without_published_tier_reports_base() {
+        let p = flat_pricing();
+        let mut c = counts(300_000, 0, 0, 0, 0, 0);
+        c.above_input = 200_000;
+        let breakdown = calculate_cost_breakdown(&c, &p);
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].tier, PriceTier::Base);
+        assert_eq!(breakdown[0].tokens, 300_000);
+    }
+
     #[test]
     fn test_fully_above_request_bills_everything_at_tier() {
         let p = sonnet_like_pricing();
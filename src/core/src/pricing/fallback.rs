@@ -0,0 +1,48 @@
+//! Built-in list prices for recent models LiteLLM may not carry yet.
+//!
+//! LiteLLM usually lists a model within days of its release, but until it
+//! does every session using it would price at `$0.00`. [`ModelPricingMap::get`]
+//! consults this table only after every LiteLLM tier (exact, normalized,
+//! substring, fuzzy) came up empty, so a stale built-in rate never overrides a
+//! LiteLLM price.
+//! Rates are the providers' published base prices (no long-context tiers) as
+//! of [`BUILTIN_PRICING_VERSION`]; bump the version whenever a rate changes.
+//!
+//! [`ModelPricingMap::get`]: super::ModelPricingMap::get
+
+use super::cache::ModelPricing;
+
+/// Release of the built-in table, shown next to every price taken from it.
+pub const BUILTIN_PRICING_VERSION: &str = "2025-10";
+
+/// USD per million tokens: input, output, cache read, cache write.
+const BUILTIN_RATES: &[(&str, [f64; 4])] = &[
+    ("claude-opus-4-1", [15.0, 75.0, 1.5, 18.75]),
+    ("claude-sonnet-4-5", [3.0, 15.0, 0.3, 3.75]),
+    ("claude-haiku-4-5", [1.0, 5.0, 0.1, 1.25]),
+    ("gpt-5", [1.25, 10.0, 0.125, 0.0]),
+    ("gpt-5-mini", [0.25, 2.0, 0.025, 0.0]),
+    ("gpt-5-nano", [0.05, 0.4, 0.005, 0.0]),
+    ("gpt-5-codex", [1.25, 10.0, 0.125, 0.0]),
+    ("grok-4", [3.0, 15.0, 0.75, 0.0]),
+    ("grok-code-fast-1", [0.2, 1.5, 0.02, 0.0]),
+];
+
+/// Looks up `normalized_name` (a [`super::normalize_model_name`] result) in
+/// the built-in table, returning the table key and its pricing.
+pub(super) fn builtin_pricing(normalized_name: &str) -> Option<(&'static str, ModelPricing)> {
+    let (key, [input, output, cache_read, cache_write]) = BUILTIN_RATES
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(normalized_name))?;
+    let per_token = 1e-6;
+    Some((
+        key,
+        ModelPricing {
+            input_cost_per_token: input * per_token,
+            output_cost_per_token: output * per_token,
+            cache_read_input_token_cost: cache_read * per_token,
+            cache_creation_input_token_cost: cache_write * per_token,
+            ..ModelPricing::default()
+        },
+    ))
+}
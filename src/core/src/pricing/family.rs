@@ -0,0 +1,101 @@
+//! Same-family price estimates for models nothing else could price
+//! (`usage --estimate-missing-pricing`).
+//!
+//! A model family is a vendor's tier — Claude Sonnet, Gemini Flash, GPT mini —
+//! whose members are priced alike across versions. When every tier of
+//! [`ModelPricingMap::get`](super::ModelPricingMap::get) misses, the estimate
+//! averages the priced members of the model's family instead of leaving it at
+//! $0. The estimate is off unless the map is built with
+//! [`ModelPricingMap::with_family_estimates`](super::ModelPricingMap::with_family_estimates),
+//! and an estimated price is always flagged as such.
+
+use super::cache::ModelPricing;
+
+/// Claude tiers; each is its own family whatever the version.
+const CLAUDE_TIERS: [&str; 3] = ["opus", "sonnet", "haiku"];
+
+/// Gemini and GPT tiers, checked in order so `flash-lite` wins over `flash`.
+const VENDOR_TIERS: [(&str, &[&str]); 2] = [
+    ("gemini", &["flash-lite", "flash", "pro"]),
+    ("gpt", &["mini", "nano"]),
+];
+
+/// The family `model` belongs to, e.g. `claude-sonnet` for both
+/// `claude-sonnet-4-20250514` and `bedrock/claude-3-7-sonnet`; `None` when the
+/// name names no known tier.
+///
+/// # Examples
+///
+/// ```
+/// use vct_core::pricing::family_of;
+///
+/// assert_eq!(family_of("claude-3-5-sonnet-20241022").as_deref(), Some("claude-sonnet"));
+/// assert_eq!(family_of("gemini-2.5-flash-lite").as_deref(), Some("gemini-flash-lite"));
+/// assert_eq!(family_of("gpt-5"), None);
+/// ```
+pub fn family_of(model: &str) -> Option<String> {
+    let lower = model.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    let segments: Vec<&str> = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    if let Some(tier) = CLAUDE_TIERS.iter().find(|tier| segments.contains(tier)) {
+        return Some(format!("claude-{tier}"));
+    }
+    let vendor_segment = |vendor: &str| segments.iter().any(|s| s.starts_with(vendor));
+    VENDOR_TIERS
+        .iter()
+        .filter(|(vendor, _)| vendor_segment(vendor))
+        .find_map(|(vendor, tiers)| {
+            tiers
+                .iter()
+                .find(|tier| name.contains(&format!("-{tier}")))
+                .map(|tier| format!("{vendor}-{tier}"))
+        })
+}
+
+/// Averages the base per-token prices of the priced entries of `entries`.
+///
+/// Context tiers are left out: members disagree on where they start, so an
+/// average of them would describe no real model. Returns `None` and the
+/// member count when nothing is priced.
+pub(crate) fn average_pricing<'a>(
+    entries: impl IntoIterator<Item = &'a ModelPricing>,
+) -> Option<(ModelPricing, usize)> {
+    let mut sum = ModelPricing::default();
+    let mut count = 0usize;
+    for pricing in entries {
+        if pricing.input_cost_per_token <= 0.0 && pricing.output_cost_per_token <= 0.0 {
+            continue;
+        }
+        sum.input_cost_per_token += pricing.input_cost_per_token;
+        sum.output_cost_per_token += pricing.output_cost_per_token;
+        sum.cache_read_input_token_cost += pricing.cache_read_input_token_cost;
+        sum.cache_creation_input_token_cost += pricing.cache_creation_input_token_cost;
+        sum.cache_creation_input_token_cost_above_1hr +=
+            pricing.cache_creation_input_token_cost_above_1hr;
+        sum.output_cost_per_reasoning_token += pricing.output_cost_per_reasoning_token;
+        sum.web_search_cost_per_query += pricing.web_search_cost_per_query;
+        count += 1;
+    }
+    if count == 0 {
+        return None;
+    }
+    let n = count as f64;
+    Some((
+        ModelPricing {
+            input_cost_per_token: sum.input_cost_per_token / n,
+            output_cost_per_token: sum.output_cost_per_token / n,
+            cache_read_input_token_cost: sum.cache_read_input_token_cost / n,
+            cache_creation_input_token_cost: sum.cache_creation_input_token_cost / n,
+            cache_creation_input_token_cost_above_1hr: sum
+                .cache_creation_input_token_cost_above_1hr
+                / n,
+            output_cost_per_reasoning_token: sum.output_cost_per_reasoning_token / n,
+            web_search_cost_per_query: sum.web_search_cost_per_query / n,
+            ..ModelPricing::default()
+        },
+        count,
+    ))
+}
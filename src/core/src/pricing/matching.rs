@@ -1,4 +1,6 @@
 use super::cache::ModelPricing;
+use super::fallback::{BUILTIN_PRICING_VERSION, builtin_pricing};
+use super::family::{average_pricing, family_of};
 use lru::LruCache;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -18,6 +20,57 @@ const PRICING_MATCH_CACHE_SIZE: usize = 64;
 // cache that can leak matches between unrelated pricing maps.
 static MATCH_CACHE_GENERATION: AtomicU64 = AtomicU64::new(0);
 
+/// Default Jaro-Winkler similarity at or above which a fuzzy match is shown
+/// with its `(matched)` hint; weaker matches are flagged instead.
+pub const DEFAULT_FUZZY_HINT_THRESHOLD: f64 = 0.9;
+
+/// Which tier of [`ModelPricingMap::get`] produced a lookup result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchKind {
+    /// The model name is a pricing key verbatim.
+    Exact,
+    /// The names agree once version/date suffixes are stripped.
+    Normalized,
+    /// No LiteLLM tier matched; the price comes from the built-in fallback
+    /// table.
+    Builtin,
+    /// One name contains the other.
+    Substring,
+    /// Jaro-Winkler similarity of the two names, at least 0.7.
+    Fuzzy {
+        /// Similarity in `0.0..=1.0`.
+        similarity: f64,
+    },
+    /// Nothing matched, and `--estimate-missing-pricing` averaged the priced
+    /// models of the same family instead.
+    Estimated,
+    /// Nothing matched; the pricing is all zero.
+    Unmatched,
+}
+
+impl MatchKind {
+    /// The similarity of a fuzzy match, `None` for every other kind.
+    pub fn fuzzy_similarity(self) -> Option<f64> {
+        match self {
+            Self::Fuzzy { similarity } => Some(similarity),
+            _ => None,
+        }
+    }
+
+    /// Short lowercase name of the tier, e.g. `fuzzy`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Normalized => "normalized",
+            Self::Builtin => "builtin",
+            Self::Substring => "substring",
+            Self::Fuzzy { .. } => "fuzzy",
+            Self::Estimated => "estimated",
+            Self::Unmatched => "unmatched",
+        }
+    }
+}
+
 /// Result of a model pricing lookup, including the matched model name for transparency.
 #[derive(Debug, Clone)]
 pub struct ModelPricingResult {
@@ -25,6 +78,8 @@ pub struct ModelPricingResult {
     pub pricing: ModelPricing,
     /// The actual model key that matched, or `None` for an exact match or no match.
     pub matched_model: Option<String>,
+    /// How the lookup matched.
+    pub match_kind: MatchKind,
 }
 
 /// Optimized pricing map with precomputed indices for O(1) exact matches and fast fuzzy matching.
@@ -36,6 +91,8 @@ pub struct ModelPricingMap {
     normalized_index: HashMap<String, Vec<Rc<str>>>,
     // Precomputed lowercase keys for substring/fuzzy matching
     lowercase_keys: Vec<(String, Rc<str>)>, // (lowercase_key, original_key as Rc)
+    // Whether models no tier matched get their family's average price
+    estimate_missing: bool,
     // Lookup results belong to this map. A process-global result cache is
     // incorrect because model names can map to different prices in each map.
     match_cache: RefCell<MatchCache>,
@@ -110,10 +167,36 @@ impl ModelPricingMap {
             raw: rc_raw,
             normalized_index,
             lowercase_keys,
+            estimate_missing: false,
             match_cache: RefCell::new(MatchCache::new()),
         }
     }
 
+    /// Prices models no tier matched at the average of their family's priced
+    /// entries (`usage --estimate-missing-pricing`) instead of leaving them
+    /// unmatched; off by default. Lookups already memoized are dropped so the
+    /// setting applies to every later [`get`](Self::get).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use vct_core::pricing::{MatchKind, ModelPricing, ModelPricingMap};
+    ///
+    /// let mut raw = HashMap::new();
+    /// raw.insert(
+    ///     "claude-sonnet-4".to_string(),
+    ///     ModelPricing { input_cost_per_token: 3e-6, ..Default::default() },
+    /// );
+    /// let map = ModelPricingMap::new(raw).with_family_estimates(true);
+    /// assert_eq!(map.get("vertex_ai/sonnet@preview-x").match_kind, MatchKind::Estimated);
+    /// ```
+    pub fn with_family_estimates(mut self, enabled: bool) -> Self {
+        self.estimate_missing = enabled;
+        self.match_cache = RefCell::new(MatchCache::new());
+        self
+    }
+
     /// Retrieves pricing for a model using a multi-tier matching strategy.
     ///
     /// Matching strategy (in order of priority):
@@ -121,7 +204,11 @@ impl ModelPricingMap {
     /// 2. Normalized match (removes version suffixes).
     /// 3. Substring match (bidirectional contains check).
     /// 4. Fuzzy match (Jaro-Winkler ≥ 0.7 threshold).
-    /// 5. Default (zero cost) if no match found.
+    /// 5. Built-in fallback table, for recent models no LiteLLM tier
+    ///    matched; `matched_model` names the table entry and its version.
+    /// 6. With `--estimate-missing-pricing`, the average price of the
+    ///    model's family (see [`family_of`](super::family_of)).
+    /// 7. Default (zero cost) if no match found.
     ///
     /// Results are cached per map. [`clear_pricing_cache`] invalidates every
     /// existing map lazily, so even the "no match" outcome can be memoized
@@ -159,6 +246,7 @@ impl ModelPricingMap {
             let result = ModelPricingResult {
                 pricing: pricing.clone(),
                 matched_model: None,
+                match_kind: MatchKind::Exact,
             };
             self.cache_result(model_name, &result);
             return result;
@@ -172,11 +260,22 @@ impl ModelPricingMap {
             let result = ModelPricingResult {
                 pricing: pricing.clone(),
                 matched_model: Some(original_key.to_string()),
+                match_kind: MatchKind::Normalized,
             };
             self.cache_result(model_name, &result);
             return result;
         }
 
+        let result = self
+            .loose_match(model_name)
+            .or_else(|| self.builtin(&normalized_name))
+            .unwrap_or_else(|| self.unmatched(model_name));
+        self.cache_result(model_name, &result);
+        result
+    }
+
+    /// The substring and fuzzy LiteLLM tiers of [`Self::get`].
+    fn loose_match(&self, model_name: &str) -> Option<ModelPricingResult> {
         // Loose (substring / fuzzy) matching needs a distinctive query: a
         // placeholder like `default` (what cursor-agent stores for auto-mode
         // conversations) or a very short fragment would otherwise inherit a
@@ -184,12 +283,7 @@ impl ModelPricingMap {
         // answer for those.
         let model_lower = model_name.to_lowercase();
         if !eligible_for_loose_match(model_without_provider(&model_lower)) {
-            let result = ModelPricingResult {
-                pricing: ModelPricing::default(),
-                matched_model: None,
-            };
-            self.cache_result(model_name, &result);
-            return result;
+            return None;
         }
 
         // Slow path 1: inspect every substring candidate and choose the most
@@ -198,33 +292,67 @@ impl ModelPricingMap {
         if let Some(matched_key) = self.substring_match(&model_lower)
             && let Some(pricing) = self.raw.get(matched_key.as_ref())
         {
-            let result = ModelPricingResult {
+            return Some(ModelPricingResult {
                 pricing: pricing.clone(),
                 matched_model: Some(matched_key.to_string()),
-            };
-            self.cache_result(model_name, &result);
-            return result;
+                match_kind: MatchKind::Substring,
+            });
         }
 
         // Slow path 2: fuzzy matching runs only when normalization and
         // substring matching found nothing.
-        if let Some(matched_key) = self.fuzzy_match(&model_lower)
+        if let Some((matched_key, similarity)) = self.fuzzy_match(&model_lower)
             && let Some(pricing) = self.raw.get(matched_key.as_ref())
         {
-            let result = ModelPricingResult {
+            return Some(ModelPricingResult {
                 pricing: pricing.clone(),
                 matched_model: Some(matched_key.to_string()),
-            };
-            self.cache_result(model_name, &result);
-            return result;
+                match_kind: MatchKind::Fuzzy { similarity },
+            });
+        }
+        None
+    }
+
+    /// The built-in list price for a model no LiteLLM tier matched; a
+    /// LiteLLM guess from a similar name is fresher than the table, so this
+    /// runs after every LiteLLM tier.
+    fn builtin(&self, normalized_name: &str) -> Option<ModelPricingResult> {
+        let (key, pricing) = builtin_pricing(normalized_name)?;
+        Some(ModelPricingResult {
+            pricing,
+            matched_model: Some(format!("{key} [built-in {BUILTIN_PRICING_VERSION}]")),
+            match_kind: MatchKind::Builtin,
+        })
+    }
+
+    /// The result for a model no tier matched: the same-family average when
+    /// [`with_family_estimates`](Self::with_family_estimates) is on and the
+    /// family has priced members, else zero pricing.
+    fn unmatched(&self, model_name: &str) -> ModelPricingResult {
+        let estimate = self
+            .estimate_missing
+            .then(|| family_of(model_name))
+            .flatten()
+            .and_then(|family| {
+                let members = self
+                    .raw
+                    .iter()
+                    .filter(|(key, _)| family_of(key).as_deref() == Some(family.as_str()))
+                    .map(|(_, pricing)| pricing);
+                average_pricing(members).map(|(pricing, count)| (family, pricing, count))
+            });
+        match estimate {
+            Some((family, pricing, count)) => ModelPricingResult {
+                pricing,
+                matched_model: Some(format!("{family} family average of {count}")),
+                match_kind: MatchKind::Estimated,
+            },
+            None => ModelPricingResult {
+                pricing: ModelPricing::default(),
+                matched_model: None,
+                match_kind: MatchKind::Unmatched,
+            },
         }
-
-        let result = ModelPricingResult {
-            pricing: ModelPricing::default(),
-            matched_model: None,
-        };
-        self.cache_result(model_name, &result);
-        result
     }
 
     fn cached_result(&self, model_name: &str) -> Option<ModelPricingResult> {
@@ -283,7 +411,7 @@ impl ModelPricingMap {
             .map(|(_, _, _, key)| key.clone())
     }
 
-    fn fuzzy_match(&self, model_lower: &str) -> Option<Rc<str>> {
+    fn fuzzy_match(&self, model_lower: &str) -> Option<(Rc<str>, f64)> {
         if model_lower.is_empty() {
             return None;
         }
@@ -303,7 +431,7 @@ impl ModelPricingMap {
                     .then_with(|| a.1.cmp(&b.1))
                     .then_with(|| a.2.as_ref().cmp(b.2.as_ref()))
             })
-            .map(|(_, _, key)| key.clone())
+            .map(|(similarity, _, key)| (key.clone(), similarity))
     }
 
     /// Returns the pricing for an **exact** model-name match only.
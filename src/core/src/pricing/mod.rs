@@ -6,17 +6,24 @@
 //! re-exports below; the `cache` / `calculation` / `matching` submodules are
 //! internal wiring.
 //!
-//! Lookup proceeds exact -> normalized -> substring -> Jaro-Winkler fuzzy
-//! (see [`ModelPricingMap::get`]), and cost is computed by [`calculate_cost`]
-//! across flat, threshold-tiered, and range-tiered pricing shapes.
+//! Lookup proceeds exact -> normalized -> substring -> Jaro-Winkler fuzzy ->
+//! built-in fallback -> (opt-in) same-family average (see
+//! [`ModelPricingMap::get`]), and cost is computed by
+//! [`calculate_cost`] across flat, threshold-tiered, and range-tiered pricing
+//! shapes. [`PriceUnit`] rescales per-token prices for display only.
 
+mod bench;
 mod cache;
 mod calculation;
 mod cost;
+mod fallback;
+mod family;
 mod matching;
 mod tiers;
+mod unit;
 
 use crate::utils::{find_pricing_cache_for_date_in, get_cache_dir};
+use crate::warnings::WarningSink;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -37,13 +44,21 @@ static FAILED_FETCHES: LazyLock<Mutex<HashMap<PricingFetchKey, Instant>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
 // Re-export public types and functions
+pub use bench::{MatcherBenchmark, bench_matcher};
+pub(crate) use cache::pricing_cache_date;
 pub use cache::{ModelPricing, ThresholdTier, TierRange};
-pub use calculation::calculate_cost;
+pub use calculation::{
+    CostComponent, PriceTier, TokenType, calculate_cost, calculate_cost_breakdown,
+};
 pub use cost::{CostSource, resolve_model_cost};
+pub use fallback::BUILTIN_PRICING_VERSION;
+pub use family::family_of;
 pub use matching::{
-    ModelPricingMap, ModelPricingResult, clear_pricing_cache, normalize_model_name,
+    DEFAULT_FUZZY_HINT_THRESHOLD, MatchKind, ModelPricingMap, ModelPricingResult,
+    clear_pricing_cache, normalize_model_name,
 };
-pub use tiers::{TierClassifier, TierThresholds};
+pub use tiers::{RequestTokens, ThresholdBasis, TierClassifier, TierThresholds};
+pub use unit::PriceUnit;
 
 /// Fetches AI model pricing data from the LiteLLM repository with automatic caching.
 ///
@@ -51,8 +66,8 @@ pub use tiers::{TierClassifier, TierThresholds};
 /// Pricing is cached locally by UTC calendar date to minimize network calls.
 /// If today's cache exists and is in the current schema it is loaded directly;
 /// otherwise the upstream JSON is fetched, filtered to its cost fields,
-/// persisted, and parsed. A failure to write the cache is logged but does not
-/// abort the fetch.
+/// persisted, and parsed. A failure to write the cache is recorded in
+/// `warnings` but does not abort the fetch.
 ///
 /// # Errors
 ///
@@ -66,12 +81,14 @@ pub use tiers::{TierClassifier, TierThresholds};
 /// ```no_run
 /// use vct_core::pricing::fetch_model_pricing;
 ///
-/// let pricing = fetch_model_pricing()?;
+/// use vct_core::warnings::WarningSink;
+///
+/// let pricing = fetch_model_pricing(&WarningSink::new())?;
 /// let opus = pricing.get("claude-opus-4");
 /// assert!(opus.pricing.input_cost_per_token >= 0.0);
 /// # Ok::<(), anyhow::Error>(())
 /// ```
-pub fn fetch_model_pricing() -> Result<ModelPricingMap> {
+pub fn fetch_model_pricing(warnings: &WarningSink) -> Result<ModelPricingMap> {
     let cache_dir = get_cache_dir()?;
 
     // Offline mode: never hit the network, but still honour a cache hit.
@@ -88,7 +105,23 @@ pub fn fetch_model_pricing() -> Result<ModelPricingMap> {
         return Ok(ModelPricingMap::new(std::collections::HashMap::new()));
     }
 
-    fetch_model_pricing_with(LITELLM_PRICING_URL, &cache_dir)
+    fetch_model_pricing_with(LITELLM_PRICING_URL, &cache_dir, warnings)
+}
+
+/// Loads the newest on-disk pricing cache without ever fetching.
+///
+/// The fast path behind `usage --estimate-only`: any cached day is accepted, so
+/// prices may be stale. A missing or unreadable cache is an error the caller is
+/// expected to degrade to an empty map (costs unavailable).
+///
+/// # Errors
+///
+/// Returns an error if the cache directory cannot be resolved or holds no
+/// loadable pricing cache file.
+pub fn load_cached_model_pricing() -> Result<ModelPricingMap> {
+    let (date, pricing) = cache::load_latest_cache_in(&get_cache_dir()?)?;
+    log::debug!("Loaded model pricing from the {date} cache (estimate only)");
+    Ok(ModelPricingMap::new(pricing))
 }
 
 /// Fetches model pricing from an explicit URL, caching under an explicit dir.
@@ -108,7 +141,11 @@ pub fn fetch_model_pricing() -> Result<ModelPricingMap> {
 /// one priced model. A corrupt or legacy on-disk cache is logged and falls
 /// through to a refetch. Failed requests are backed off for five minutes per
 /// URL and cache directory.
-pub fn fetch_model_pricing_with(url: &str, cache_dir: &Path) -> Result<ModelPricingMap> {
+pub fn fetch_model_pricing_with(
+    url: &str,
+    cache_dir: &Path,
+    warnings: &WarningSink,
+) -> Result<ModelPricingMap> {
     let today = cache::pricing_cache_date();
     let fetch_key = PricingFetchKey {
         url: url.to_string(),
@@ -137,7 +174,7 @@ pub fn fetch_model_pricing_with(url: &str, cache_dir: &Path) -> Result<ModelPric
         );
     }
 
-    let result = fetch_model_pricing_remote(url, cache_dir);
+    let result = fetch_model_pricing_remote(url, cache_dir, warnings);
     match result {
         Ok(pricing) => {
             clear_fetch_failure(&fetch_key);
@@ -153,6 +190,7 @@ pub fn fetch_model_pricing_with(url: &str, cache_dir: &Path) -> Result<ModelPric
 fn fetch_model_pricing_remote(
     url: &str,
     cache_dir: &Path,
+    warnings: &WarningSink,
 ) -> Result<HashMap<String, ModelPricing>> {
     // Fetch from remote
     log::info!("Fetching model pricing from remote...");
@@ -208,7 +246,7 @@ fn fetch_model_pricing_remote(
     // doesn't consume yet are still available to future versions without
     // a re-fetch.
     if let Err(e) = cache::save_to_cache_in(cache_dir, &filtered_raw) {
-        log::warn!("Failed to save pricing to cache: {}", e);
+        warnings.warn(format!("failed to save pricing to cache: {e}"));
     } else {
         log::debug!("Saved model pricing to cache with today's date");
     }
@@ -15,10 +15,78 @@
 //! `above_tier` bucket that `calculate_cost` bills at the tier rate. Parsers
 //! without the snapshot (the `analysis` paths, offline runs) classify nothing,
 //! which degrades to billing everything at base rates.
+//!
+//! What "a request's context" means is the snapshot's [`ThresholdBasis`]:
+//! providers differ on whether the threshold applies to the prompt, to the
+//! whole request, or to each token type on its own.
 
 use crate::constants::FastHashMap;
 use crate::pricing::normalize_model_name;
+use anyhow::{Result, bail};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::str::FromStr;
+
+/// Which of a request's tokens are compared against a context-tier threshold.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdBasis {
+    /// Each token type (uncached input, cache reads, cache writes, output) is
+    /// compared on its own; any one crossing promotes the request.
+    PerType,
+    /// The full prompt: uncached input + cache reads + cache writes. This is
+    /// how Anthropic, OpenAI, and Google bill their long-context tiers.
+    #[default]
+    TotalInput,
+    /// The full prompt plus output (reasoning included).
+    Total,
+}
+
+impl FromStr for ThresholdBasis {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "per_type" => Ok(Self::PerType),
+            "total_input" => Ok(Self::TotalInput),
+            "total" => Ok(Self::Total),
+            other => bail!(
+                "unknown threshold basis `{other}` (expected per_type, total_input, or total)"
+            ),
+        }
+    }
+}
+
+/// One request's tokens in the disjoint buckets a [`ThresholdBasis`] sums.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestTokens {
+    /// Prompt tokens neither read from nor written to the cache.
+    pub input: i64,
+    /// Prompt tokens served from the cache.
+    pub cache_read: i64,
+    /// Prompt tokens written to the cache (all TTLs).
+    pub cache_creation: i64,
+    /// Generated tokens, reasoning included.
+    pub output: i64,
+}
+
+impl RequestTokens {
+    /// The figure compared against the threshold under `basis`. For
+    /// [`ThresholdBasis::PerType`] that is the largest single bucket.
+    pub fn context(&self, basis: ThresholdBasis) -> i64 {
+        let prompt = self.input + self.cache_read + self.cache_creation;
+        match basis {
+            ThresholdBasis::PerType => self
+                .input
+                .max(self.cache_read)
+                .max(self.cache_creation)
+                .max(self.output),
+            ThresholdBasis::TotalInput => prompt,
+            ThresholdBasis::Total => prompt + self.output,
+        }
+    }
+}
 
 /// Immutable "model → lowest context-tier threshold (tokens)" snapshot.
 ///
@@ -29,6 +97,7 @@ use std::hash::{DefaultHasher, Hash, Hasher};
 #[derive(Debug, Default)]
 pub struct TierThresholds {
     thresholds: FastHashMap<Box<str>, i64>,
+    basis: ThresholdBasis,
     fingerprint: u64,
 }
 
@@ -62,10 +131,39 @@ impl TierThresholds {
 
         Self {
             thresholds,
+            basis: ThresholdBasis::default(),
             fingerprint,
         }
     }
 
+    /// Sets the basis requests are classified on.
+    ///
+    /// A non-default basis is folded into the fingerprint so cached above-tier
+    /// slices classified under another basis are rebuilt; the default keeps the
+    /// fingerprint (and existing caches) unchanged.
+    pub fn with_basis(mut self, basis: ThresholdBasis) -> Self {
+        if basis != self.basis {
+            let mut hasher = DefaultHasher::new();
+            self.basis.hash(&mut hasher);
+            let previous = hasher.finish();
+            let mut hasher = DefaultHasher::new();
+            basis.hash(&mut hasher);
+            if self.basis != ThresholdBasis::default() {
+                self.fingerprint ^= previous;
+            }
+            if basis != ThresholdBasis::default() {
+                self.fingerprint ^= hasher.finish();
+            }
+            self.basis = basis;
+        }
+        self
+    }
+
+    /// The basis requests are classified on.
+    pub fn basis(&self) -> ThresholdBasis {
+        self.basis
+    }
+
     /// Lowest tier threshold for `model`, or `None` when the model has no
     /// context tier (or cannot be resolved).
     pub fn threshold_for(&self, model: &str) -> Option<i64> {
@@ -110,9 +208,9 @@ impl<'a> TierClassifier<'a> {
         }
     }
 
-    /// Whether a request for `model` with `request_context` prompt tokens
-    /// (input + cache read + cache creation) is billed at the tier rate.
-    pub fn is_above(&mut self, model: &str, request_context: i64) -> bool {
+    /// Whether a request for `model` with these tokens is billed at the tier
+    /// rate, measured on the snapshot's [`ThresholdBasis`].
+    pub fn is_above(&mut self, model: &str, request: RequestTokens) -> bool {
         let threshold = match self.memo.get(model) {
             Some(threshold) => *threshold,
             None => {
@@ -121,7 +219,7 @@ impl<'a> TierClassifier<'a> {
                 resolved
             }
         };
-        threshold.is_some_and(|threshold| request_context > threshold)
+        threshold.is_some_and(|threshold| request.context(self.thresholds.basis) > threshold)
     }
 }
 
@@ -151,15 +249,101 @@ mod tests {
         assert_eq!(tiers.threshold_for("gpt-x"), Some(200_000));
     }
 
+    fn prompt(input: i64) -> RequestTokens {
+        RequestTokens {
+            input,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn classifier_compares_strictly_above() {
         let tiers = snapshot();
         let mut classifier = TierClassifier::new(&tiers);
-        assert!(!classifier.is_above("gpt-5.4", 272_000));
-        assert!(classifier.is_above("gpt-5.4", 272_001));
-        assert!(!classifier.is_above("no-tier-model", i64::MAX));
+        assert!(!classifier.is_above("gpt-5.4", prompt(272_000)));
+        assert!(classifier.is_above("gpt-5.4", prompt(272_001)));
+        assert!(!classifier.is_above("no-tier-model", prompt(i64::MAX)));
         // Memoized second lookup takes the fast path.
-        assert!(classifier.is_above("gpt-5.4", 300_000));
+        assert!(classifier.is_above("gpt-5.4", prompt(300_000)));
+    }
+
+    #[test]
+    fn each_basis_classifies_the_same_request_differently() {
+        // 150K uncached + 60K cache read + 30K output against a 200K threshold:
+        // no single type crosses it, the prompt does, and so does the total.
+        let request = RequestTokens {
+            input: 150_000,
+            cache_read: 60_000,
+            cache_creation: 0,
+            output: 30_000,
+        };
+        assert_eq!(request.context(ThresholdBasis::PerType), 150_000);
+        assert_eq!(request.context(ThresholdBasis::TotalInput), 210_000);
+        assert_eq!(request.context(ThresholdBasis::Total), 240_000);
+
+        let verdict = |basis: ThresholdBasis, request: RequestTokens| {
+            let tiers = snapshot().with_basis(basis);
+            TierClassifier::new(&tiers).is_above("gemini-3.1-pro-preview", request)
+        };
+        assert!(!verdict(ThresholdBasis::PerType, request));
+        assert!(verdict(ThresholdBasis::TotalInput, request));
+        assert!(verdict(ThresholdBasis::Total, request));
+
+        // 190K prompt + 20K output: only the whole-request basis promotes it.
+        let near = RequestTokens {
+            input: 190_000,
+            output: 20_000,
+            ..Default::default()
+        };
+        assert!(!verdict(ThresholdBasis::PerType, near));
+        assert!(!verdict(ThresholdBasis::TotalInput, near));
+        assert!(verdict(ThresholdBasis::Total, near));
+
+        // A single oversized bucket crosses under every basis.
+        let big_read = RequestTokens {
+            cache_read: 250_000,
+            ..Default::default()
+        };
+        for basis in [
+            ThresholdBasis::PerType,
+            ThresholdBasis::TotalInput,
+            ThresholdBasis::Total,
+        ] {
+            assert!(verdict(basis, big_read), "{basis:?}");
+        }
+    }
+
+    #[test]
+    fn basis_parses_and_only_a_non_default_basis_moves_the_fingerprint() {
+        assert_eq!(
+            "per_type".parse::<ThresholdBasis>().unwrap(),
+            ThresholdBasis::PerType
+        );
+        assert_eq!(
+            "total".parse::<ThresholdBasis>().unwrap(),
+            ThresholdBasis::Total
+        );
+        assert!("input".parse::<ThresholdBasis>().is_err());
+
+        let base = snapshot().fingerprint();
+        assert_eq!(
+            snapshot()
+                .with_basis(ThresholdBasis::TotalInput)
+                .fingerprint(),
+            base
+        );
+        let per_type = snapshot().with_basis(ThresholdBasis::PerType).fingerprint();
+        let total = snapshot().with_basis(ThresholdBasis::Total).fingerprint();
+        assert_ne!(per_type, base);
+        assert_ne!(total, base);
+        assert_ne!(per_type, total);
+        assert_eq!(
+            snapshot()
+                .with_basis(ThresholdBasis::Total)
+                .with_basis(ThresholdBasis::TotalInput)
+                .fingerprint(),
+            base
+        );
     }
 
     #[test]
@@ -0,0 +1,80 @@
+//! Display units for per-token prices.
+//!
+//! LiteLLM (and [`super::ModelPricing`]) store prices per token, which prints
+//! as `0.000003`; people quote them per million tokens. [`PriceUnit`] only
+//! rescales a price for display — cost math always stays per token.
+
+use anyhow::{Result, bail};
+use std::str::FromStr;
+
+/// How many tokens a displayed price covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PriceUnit {
+    /// Per single token, as stored.
+    Token,
+    /// Per thousand tokens.
+    Thousand,
+    /// Per million tokens, the unit providers publish.
+    #[default]
+    Million,
+}
+
+impl FromStr for PriceUnit {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "token" => Ok(Self::Token),
+            "1k" => Ok(Self::Thousand),
+            "1m" => Ok(Self::Million),
+            other => bail!("unknown price unit `{other}` (expected token, 1k, or 1m)"),
+        }
+    }
+}
+
+impl PriceUnit {
+    /// Tokens covered by one displayed price.
+    pub fn tokens(self) -> f64 {
+        match self {
+            Self::Token => 1.0,
+            Self::Thousand => 1_000.0,
+            Self::Million => 1_000_000.0,
+        }
+    }
+
+    /// Converts a per-token price to this unit.
+    pub fn scale(self, per_token: f64) -> f64 {
+        per_token * self.tokens()
+    }
+
+    /// Column-header suffix, e.g. `/1M tok`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Token => "/tok",
+            Self::Thousand => "/1K tok",
+            Self::Million => "/1M tok",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_per_token_price_converts_to_every_unit() {
+        // claude-sonnet-4 input: $3 per million tokens.
+        let per_token = 0.000_003;
+        let cases = [("token", per_token), ("1k", 0.003), ("1M", 3.0)];
+        for (name, expected) in cases {
+            let unit: PriceUnit = name.parse().unwrap();
+            assert!(
+                (unit.scale(per_token) - expected).abs() < 1e-12,
+                "{name}: {}",
+                unit.scale(per_token)
+            );
+        }
+        assert_eq!(PriceUnit::default(), PriceUnit::Million);
+        assert!("1b".parse::<PriceUnit>().is_err());
+    }
+}
@@ -0,0 +1,62 @@
+//! Machine-readable description of the supported providers (`vct providers`).
+//!
+//! Everything here is derived from the same sources the scanners use — the
+//! provider order, [`HelperPaths::provider_locations`], and the display names
+//! on [`Provider`] — so the description cannot drift from what a scan reads.
+
+use crate::models::{ExtensionType, Provider};
+use crate::utils::HelperPaths;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// What `vct` reads for one provider and which views it feeds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderCapability {
+    /// The `extensionName` the provider's sessions carry in analysis output.
+    pub extension_name: String,
+    /// Short name shown in tables (`Claude`, `Copilot`, ...).
+    pub display_name: &'static str,
+    /// Default session locations under the current home directory.
+    pub directories: Vec<PathBuf>,
+    /// Session files recognized inside those locations.
+    pub session_files: &'static [&'static str],
+    /// Whether the provider contributes to `vct usage`.
+    pub usage: bool,
+    /// Whether the provider contributes to `vct analysis`.
+    pub analysis: bool,
+}
+
+/// Session files each provider's scanner accepts, mirroring the
+/// `is_*_session_file` filters and the SQLite readers.
+fn session_files(provider: ExtensionType) -> &'static [&'static str] {
+    match provider {
+        ExtensionType::ClaudeCode => &["*.jsonl"],
+        ExtensionType::Codex => &["*.jsonl", "*.json"],
+        ExtensionType::Copilot => &["events.jsonl"],
+        ExtensionType::Gemini => &["chats/*.jsonl"],
+        ExtensionType::Grok => &["signals.json"],
+        ExtensionType::OpenCode => &["opencode.db"],
+        ExtensionType::Cursor => &["store.db", "ai-code-tracking.db"],
+        ExtensionType::Hermes => &["state.db"],
+    }
+}
+
+/// Describes every supported provider, in provider scan order.
+///
+/// Hermes records token usage only, so it is the one provider without
+/// analysis support.
+pub fn provider_capabilities(paths: &HelperPaths) -> Vec<ProviderCapability> {
+    paths
+        .provider_locations()
+        .into_iter()
+        .map(|(provider, locations)| ProviderCapability {
+            extension_name: provider.to_string(),
+            display_name: Provider::from(provider).display_name(),
+            directories: locations.into_iter().map(PathBuf::from).collect(),
+            session_files: session_files(provider),
+            usage: true,
+            analysis: provider != ExtensionType::Hermes,
+        })
+        .collect()
+}
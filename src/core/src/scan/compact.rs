@@ -3,21 +3,21 @@
 //! Both features discover the same session files, look each one up in the same
 //! incremental [`SummaryScanCache`], parse misses into the same
 //! [`CompactSourceSummary`], and record the same failures. The only per-feature
-//! part is where a parsed summary is folded, expressed by [`CompactSink`]. Usage
-//! also threads a per-request tier snapshot; analysis passes `None`.
+//! part is where a parsed summary is folded, expressed by [`CompactSink`]. Both
+//! thread the scan's [`ParseOptions`]; only usage sets a tier snapshot.
 
 use super::ScanDiagnostics;
 use crate::constants::FastHashSet;
 use crate::models::ExtensionType;
 use crate::models::TimeRange;
-use crate::pricing::TierThresholds;
 use crate::session::ParseMode;
 use crate::session::diagnostics::partial_failure_reason;
-use crate::session::parser::parse_session_file_typed_as_with_diagnostics;
+use crate::session::parser::{ParseOptions, parse_session_file_typed_as_with_diagnostics};
 use crate::summary_cache::{
     CachedSourceSummary, CompactSourceSummary, SourceFingerprint, SummaryCacheKey, SummaryKind,
     SummaryScanCache,
 };
+use crate::utils::SessionIgnore;
 use crate::utils::directory::{FileInfo, collect_files_with_max_depth_diagnostics};
 use anyhow::Result;
 use rayon::prelude::*;
@@ -32,24 +32,25 @@ pub(crate) struct LoadedCompactSummary {
 
 /// The per-feature fold target: usage accumulates token maps, analysis
 /// accumulates file-operation rows. The shared scanners only need this one hook.
+/// `source` is the file or database the summary was read from.
 pub(crate) trait CompactSink {
-    fn fold(&mut self, provider: ExtensionType, summary: &CompactSourceSummary);
+    fn fold(&mut self, provider: ExtensionType, source: &Path, summary: &CompactSourceSummary);
 }
 
 /// Parses one session file into a compact summary in `UsageOnly` mode.
 ///
-/// The only feature-specific input is the optional per-request tier snapshot
-/// (usage passes it, analysis passes `None`).
+/// The only feature-specific input is `parse`: usage sets its per-request tier
+/// snapshot, analysis leaves it `None`; both carry the scan's encoding.
 pub(crate) fn load_compact_file_summary(
     file: &FileInfo,
     provider: ExtensionType,
-    tiers: Option<&TierThresholds>,
+    parse: ParseOptions<'_>,
 ) -> Result<LoadedCompactSummary> {
     let parsed = parse_session_file_typed_as_with_diagnostics(
         &file.path,
         provider,
         ParseMode::UsageOnly,
-        tiers,
+        parse,
     )?;
     if parsed.diagnostics.is_complete_failure() {
         let failure = if parsed.diagnostics.recognized_records == 0 {
@@ -93,7 +94,7 @@ pub(crate) fn fold_cached(
 ) {
     if cached.parsed {
         diagnostics.parsed += 1;
-        sink.fold(provider, &cached.summary);
+        sink.fold(provider, source, &cached.summary);
     }
     if let Some(error) = &cached.failure {
         diagnostics.record_failure(provider, source, error.clone());
@@ -110,7 +111,7 @@ pub(crate) fn fold_loaded(
 ) {
     if loaded.parsed {
         diagnostics.parsed += 1;
-        sink.fold(provider, &loaded.summary);
+        sink.fold(provider, source, &loaded.summary);
     }
     if let Some(error) = &loaded.failure {
         diagnostics.record_failure(provider, source, error.clone());
@@ -128,16 +129,18 @@ pub(crate) fn scan_cached_files<F>(
     filter: F,
     time_range: TimeRange,
     max_depth: Option<usize>,
+    ignore: &SessionIgnore,
     cache: &mut SummaryScanCache,
     seen: &mut FastHashSet<SummaryCacheKey>,
     sink: &mut impl CompactSink,
     diagnostics: &mut ScanDiagnostics,
-    tiers: Option<&TierThresholds>,
+    parse: ParseOptions<'_>,
 ) -> Result<()>
 where
     F: Copy + Fn(&Path) -> bool + Sync + Send,
 {
-    let discovery = collect_files_with_max_depth_diagnostics(dir, filter, time_range, max_depth);
+    let discovery =
+        collect_files_with_max_depth_diagnostics(dir, filter, time_range, max_depth, ignore);
     if !discovery.failures.is_empty() {
         cache.preserve_provider_keys(seen, SummaryKind::File, provider);
     }
@@ -169,7 +172,7 @@ where
     let loaded: Vec<_> = misses
         .into_par_iter()
         .map(|(file, key, fingerprint)| {
-            let result = load_compact_file_summary(&file, provider, tiers);
+            let result = load_compact_file_summary(&file, provider, parse);
             (file.path, key, fingerprint, result)
         })
         .collect();
@@ -11,14 +11,16 @@ use crate::config::ProvidersConfig;
 use crate::constants::FastHashSet;
 use crate::models::ExtensionType;
 use crate::models::TimeRange;
-use crate::pricing::TierThresholds;
+use crate::session::parser::ParseOptions;
 use crate::summary_cache::{SummaryCacheKey, SummaryScanCache};
+use crate::utils::SessionIgnore;
+use crate::utils::directory::{FileInfo, collect_files_with_max_depth_diagnostics};
 use crate::utils::{
     COPILOT_SESSION_MAX_DEPTH, GROK_SESSION_MAX_DEPTH, HelperPaths, is_claude_session_file,
     is_codex_session_file, is_copilot_session_file, is_gemini_session_file, is_grok_session_file,
 };
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// One file-backed provider's scan parameters.
 struct FileProviderSpec {
@@ -71,19 +73,31 @@ const FILE_PROVIDERS: [FileProviderSpec; 5] = [
     },
 ];
 
+/// The enabled file-backed providers, in canonical scan order.
+pub(crate) fn enabled_file_providers(
+    providers: ProvidersConfig,
+) -> impl Iterator<Item = ExtensionType> {
+    FILE_PROVIDERS
+        .iter()
+        .filter(move |spec| (spec.enabled)(&providers))
+        .map(|spec| spec.provider)
+}
+
 /// Scans every enabled file-backed provider through the incremental cache,
 /// folding each into `sink`. Replaces the per-provider `if` ladder in both the
-/// usage and analysis cached collectors.
+/// usage and analysis cached collectors. Sessions `ignore` excludes are not
+/// discovered.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn scan_all_cached_files(
     paths: &HelperPaths,
     providers: ProvidersConfig,
     time_range: TimeRange,
+    ignore: &SessionIgnore,
     cache: &mut SummaryScanCache,
     seen: &mut FastHashSet<SummaryCacheKey>,
     sink: &mut impl CompactSink,
     diagnostics: &mut ScanDiagnostics,
-    tiers: Option<&TierThresholds>,
+    parse: ParseOptions<'_>,
 ) -> Result<()> {
     for spec in &FILE_PROVIDERS {
         if (spec.enabled)(&providers) {
@@ -93,13 +107,54 @@ pub(crate) fn scan_all_cached_files(
                 spec.filter,
                 time_range,
                 spec.max_depth,
+                ignore,
                 cache,
                 seen,
                 sink,
                 diagnostics,
-                tiers,
+                parse,
             )?;
         }
     }
     Ok(())
 }
+
+/// Lists every file the enabled file-backed providers would scan, in provider
+/// then path order, without parsing or caching anything. Sessions matched by
+/// `ignore` are left out, as the scans themselves skip them.
+pub(crate) fn discover_all_files(
+    paths: &HelperPaths,
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> Vec<PathBuf> {
+    discover_all_dated_files(paths, providers, time_range, ignore)
+        .into_iter()
+        .map(|file| file.path)
+        .collect()
+}
+
+/// [`discover_all_files`] keeping each file's local modification date.
+pub(crate) fn discover_all_dated_files(
+    paths: &HelperPaths,
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> Vec<FileInfo> {
+    let mut files = Vec::new();
+    for spec in &FILE_PROVIDERS {
+        if (spec.enabled)(&providers) {
+            let mut discovered = collect_files_with_max_depth_diagnostics(
+                (spec.dir)(paths),
+                spec.filter,
+                time_range,
+                spec.max_depth,
+                ignore,
+            )
+            .files;
+            discovered.sort_by(|a, b| a.path.cmp(&b.path));
+            files.extend(discovered);
+        }
+    }
+    files
+}
@@ -0,0 +1,112 @@
+//! Content-only provider census over every discovered session file
+//! (`analysis --provider-detect-stats`).
+//!
+//! Runs [`classify_file`] on each file the enabled file-backed providers would
+//! scan and tallies the verdicts, without parsing sessions or touching the
+//! summary cache. A file sitting in one provider's directory but classified as
+//! another (or as nothing) is the usual answer to "why is X missing".
+
+use super::descriptor::discover_all_files;
+use crate::config::ProvidersConfig;
+use crate::models::{ExtensionType, TimeRange};
+use crate::session::classify_file;
+use crate::utils::{HelperPaths, SessionIgnore};
+use anyhow::Result;
+use rayon::prelude::*;
+use serde::Serialize;
+
+/// Label for files the detector could not attribute to any provider.
+pub const UNKNOWN_PROVIDER_LABEL: &str = "Unknown";
+
+/// How many discovered files were classified as one provider.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderDetectCount {
+    /// Provider display name, or [`UNKNOWN_PROVIDER_LABEL`].
+    pub provider: String,
+    /// Files classified as this provider.
+    pub files: usize,
+    /// Share of all discovered files, `0.0..=100.0`.
+    pub percent: f64,
+}
+
+/// Per-provider classification counts across every discovered file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ProviderDetectStats {
+    /// Number of files classified.
+    pub total_files: usize,
+    /// Providers with at least one file in scan order, then `Unknown` (always
+    /// present, possibly zero).
+    pub providers: Vec<ProviderDetectCount>,
+}
+
+/// Classifies every file under the default provider directories.
+pub fn provider_detect_stats(
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> Result<ProviderDetectStats> {
+    Ok(provider_detect_stats_from_paths(
+        &crate::utils::resolve_paths()?,
+        providers,
+        time_range,
+        ignore,
+    ))
+}
+
+/// Classifies every file under explicit provider paths, skipping sessions
+/// matched by `ignore`.
+///
+/// A file that cannot be opened or read counts as `Unknown`, the same as one
+/// without any provider marker.
+pub fn provider_detect_stats_from_paths(
+    paths: &HelperPaths,
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> ProviderDetectStats {
+    let files = discover_all_files(paths, providers, time_range, ignore);
+    let verdicts: Vec<Option<ExtensionType>> = files
+        .par_iter()
+        .map(|path| classify_file(path).ok().flatten())
+        .collect();
+
+    let mut known: Vec<(ExtensionType, usize)> = Vec::new();
+    let mut unknown = 0;
+    for verdict in verdicts {
+        match verdict {
+            Some(provider) => match known.iter_mut().find(|(seen, _)| *seen == provider) {
+                Some((_, count)) => *count += 1,
+                None => known.push((provider, 1)),
+            },
+            None => unknown += 1,
+        }
+    }
+    known.sort_by_key(|(provider, _)| provider.scan_rank());
+
+    let total_files = files.len();
+    let percent = |count: usize| {
+        if total_files == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / total_files as f64
+        }
+    };
+    let mut counts: Vec<ProviderDetectCount> = known
+        .into_iter()
+        .map(|(provider, files)| ProviderDetectCount {
+            provider: provider.to_string(),
+            files,
+            percent: percent(files),
+        })
+        .collect();
+    counts.push(ProviderDetectCount {
+        provider: UNKNOWN_PROVIDER_LABEL.to_string(),
+        files: unknown,
+        percent: percent(unknown),
+    });
+
+    ProviderDetectStats {
+        total_files,
+        providers: counts,
+    }
+}
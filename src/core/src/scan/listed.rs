@@ -0,0 +1,97 @@
+//! Explicit session-file lists (`--files-from`).
+//!
+//! The input set is given rather than discovered, so no provider directory is
+//! walked and each file's provider is read from its content instead of its
+//! location. Database-backed providers have no file to list and are skipped.
+
+use super::ScanDiagnostics;
+use super::compact::{CompactSink, fold_loaded, load_compact_file_summary};
+use super::descriptor::enabled_file_providers;
+use crate::config::ProvidersConfig;
+use crate::models::ExtensionType;
+use crate::session::detector::classify_file;
+use crate::session::parser::ParseOptions;
+use crate::utils::directory::FileInfo;
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+use std::path::PathBuf;
+
+/// Listed files grouped by provider, in canonical scan order.
+pub(crate) type ListedFiles = Vec<(ExtensionType, Vec<FileInfo>)>;
+
+/// Classifies each listed file by content and groups them per enabled
+/// provider, each group sorted by path with duplicates dropped.
+///
+/// Like `analysis FILE`, a file without any provider marker is read as Codex.
+/// Files of a disabled provider are left out.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or its modification time is
+/// unavailable; the list is explicit, so an unreadable entry is not skipped.
+pub(crate) fn classify_listed_files(
+    files: &[PathBuf],
+    providers: ProvidersConfig,
+) -> Result<ListedFiles> {
+    let mut paths: Vec<&PathBuf> = files.iter().collect();
+    paths.sort_unstable();
+    paths.dedup();
+
+    let classified = paths
+        .into_par_iter()
+        .map(|path| -> Result<(ExtensionType, FileInfo)> {
+            let provider = classify_file(path)?.unwrap_or(ExtensionType::Codex);
+            let modified = std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .with_context(|| format!("Failed to read metadata: {}", path.display()))?;
+            let modified: chrono::DateTime<chrono::Local> = modified.into();
+            Ok((
+                provider,
+                FileInfo {
+                    path: path.clone(),
+                    modified_date: modified.format("%Y-%m-%d").to_string(),
+                },
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut groups: ListedFiles = enabled_file_providers(providers)
+        .map(|provider| (provider, Vec::new()))
+        .collect();
+    for (provider, file) in classified {
+        if let Some((_, group)) = groups.iter_mut().find(|(owner, _)| *owner == provider) {
+            group.push(file);
+        }
+    }
+    groups.retain(|(_, group)| !group.is_empty());
+    Ok(groups)
+}
+
+/// Parses every listed file into a compact summary and folds it into `sink`.
+///
+/// The uncached counterpart of
+/// [`scan_cached_files`](super::compact::scan_cached_files): an explicit list
+/// is a one-shot input, so nothing is looked up in or stored to the cache.
+pub(crate) fn scan_listed_files(
+    groups: ListedFiles,
+    sink: &mut impl CompactSink,
+    diagnostics: &mut ScanDiagnostics,
+    parse: ParseOptions<'_>,
+) {
+    for (provider, files) in groups {
+        diagnostics.candidates += files.len();
+        let loaded: Vec<_> = files
+            .into_par_iter()
+            .map(|file| {
+                let result = load_compact_file_summary(&file, provider, parse);
+                (file.path, result)
+            })
+            .collect();
+        for (source, result) in loaded {
+            match result {
+                Ok(loaded) => fold_loaded(provider, &source, &loaded, sink, diagnostics),
+                Err(error) => diagnostics.record_hard_failure(provider, &source, error.to_string()),
+            }
+        }
+    }
+}
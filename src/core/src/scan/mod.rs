@@ -5,13 +5,24 @@
 //! parts that do not depend on which feature is folding: the unified
 //! [`ScanDiagnostics`] result type and the dedicated scan thread pool.
 
+pub mod capabilities;
 pub(crate) mod compact;
 pub(crate) mod descriptor;
+pub(crate) mod detect;
+pub(crate) mod listed;
+pub(crate) mod per_day;
 
+pub use capabilities::{ProviderCapability, provider_capabilities};
 pub(crate) use compact::{
     CompactSink, LoadedCompactSummary, fold_cached, fold_loaded, scan_cached_files,
 };
 pub(crate) use descriptor::scan_all_cached_files;
+pub use detect::{
+    ProviderDetectCount, ProviderDetectStats, UNKNOWN_PROVIDER_LABEL, provider_detect_stats,
+    provider_detect_stats_from_paths,
+};
+pub(crate) use listed::{classify_listed_files, scan_listed_files};
+pub use per_day::{sessions_per_day, sessions_per_day_from_paths};
 
 use crate::models::ExtensionType;
 use anyhow::Result;
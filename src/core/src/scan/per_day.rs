@@ -0,0 +1,48 @@
+//! Session-start rhythm from file metadata alone (`analysis --sessions-per-day`).
+//!
+//! Counts the distinct session files the enabled file-backed providers would
+//! scan, bucketed by each file's local modification date. Nothing is opened
+//! or parsed, so this stays fast on large histories. Database-backed
+//! providers (OpenCode, Cursor, Hermes) have no per-session file and are not
+//! counted.
+
+use super::descriptor::discover_all_dated_files;
+use crate::config::ProvidersConfig;
+use crate::constants::FastHashSet;
+use crate::models::TimeRange;
+use crate::utils::{HelperPaths, SessionIgnore};
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+/// Counts session files per date under the default provider directories.
+pub fn sessions_per_day(
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> Result<BTreeMap<String, usize>> {
+    Ok(sessions_per_day_from_paths(
+        &crate::utils::resolve_paths()?,
+        providers,
+        time_range,
+        ignore,
+    ))
+}
+
+/// Counts session files per `YYYY-MM-DD` date under explicit provider paths,
+/// in ascending date order. Dates without a session are absent, and sessions
+/// matched by `ignore` are not counted.
+pub fn sessions_per_day_from_paths(
+    paths: &HelperPaths,
+    providers: ProvidersConfig,
+    time_range: TimeRange,
+    ignore: &SessionIgnore,
+) -> BTreeMap<String, usize> {
+    let mut seen = FastHashSet::default();
+    let mut counts = BTreeMap::new();
+    for file in discover_all_dated_files(paths, providers, time_range, ignore) {
+        if seen.insert(file.path) {
+            *counts.entry(file.modified_date).or_insert(0) += 1;
+        }
+    }
+    counts
+}
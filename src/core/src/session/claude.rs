@@ -12,7 +12,8 @@ use crate::pricing::{TierClassifier, TierThresholds};
 use crate::session::diagnostics::{ParseDiagnostics, ParsedAnalysis};
 use crate::session::state::{ParseMode, SessionParseState};
 use crate::utils::{
-    claude_request_context, get_git_remote_url, parse_iso_timestamp, process_claude_usage,
+    DedupeBy, SeenClaudeMessages, claude_request_tokens, get_git_remote_url, parse_iso_timestamp,
+    process_claude_usage,
 };
 use anyhow::Result;
 use serde_json::Value;
@@ -61,17 +62,19 @@ pub fn parse_claude_logs<I>(logs: I, mode: ParseMode) -> Result<CodeAnalysis>
 where
     I: IntoIterator<Item = ClaudeCodeLog>,
 {
-    Ok(parse_claude_logs_with_diagnostics(logs, mode, None)?.analysis)
+    Ok(parse_claude_logs_with_diagnostics(logs, mode, None, None)?.analysis)
 }
 
 /// Streaming Claude parser with parser-only schema diagnostics.
 ///
 /// `tiers` enables per-request context-tier classification (usage scans
-/// only); `None` skips classification entirely.
+/// only); `None` skips classification entirely. `dedupe_by` is the
+/// `--dedupe-by` key a re-logged message's usage is skipped by.
 pub(crate) fn parse_claude_logs_with_diagnostics<I>(
     logs: I,
     mode: ParseMode,
     tiers: Option<&TierThresholds>,
+    dedupe_by: Option<DedupeBy>,
 ) -> Result<ParsedAnalysis>
 where
     I: IntoIterator<Item = ClaudeCodeLog>,
@@ -89,6 +92,8 @@ where
     // `filePath` (which ExitPlanMode also carries).
     let mut pending_tool_uses: FastHashMap<String, PendingClaudeTool> =
         FastHashMap::with_capacity(64);
+    // Under `--dedupe-by message-id`, a re-logged message's usage counts once.
+    let mut seen_messages = SeenClaudeMessages::new(dedupe_by);
     let mut diagnostics = ParseDiagnostics::default();
 
     for log in logs {
@@ -120,9 +125,7 @@ where
         } else {
             diagnostics.record_unrecognized();
         }
-        if state.folder_path.is_empty() && !log.cwd.is_empty() {
-            state.folder_path.clone_from(&log.cwd);
-        }
+        state.note_working_dir(&log.cwd);
         if !log.session_id.is_empty() {
             state.task_id.clone_from(&log.session_id);
         }
@@ -132,6 +135,16 @@ where
             state.last_ts = ts;
         }
 
+        // A failed request is logged once per retry (`system` / `api_error`)
+        // and once more when Claude Code gives up (`isApiErrorMessage`).
+        if log.is_api_error_message
+            || (log.log_type == "system" && log.subtype.as_deref() == Some("api_error"))
+        {
+            state
+                .error_counts
+                .record(classify_claude_error(log.error.as_ref()));
+        }
+
         if log.log_type == "assistant" && log.message.is_none() {
             diagnostics.record_relevant(false);
         }
@@ -143,12 +156,15 @@ where
                 let model = message.model.as_deref().filter(|model| !model.is_empty());
                 let normalized = is_supported_claude_usage(usage) && model.is_some();
                 diagnostics.record_relevant(normalized);
-                if normalized && let Some(model) = model {
+                if normalized
+                    && let Some(model) = model
+                    && seen_messages.first_sight(message.id.as_deref())
+                {
                     // One assistant record is one billed request; classify its
-                    // own prompt context against the model's tier threshold.
+                    // own tokens against the model's tier threshold.
                     let above = classifier.as_mut().is_some_and(|classifier| {
                         usage.as_object().is_some_and(|usage_obj| {
-                            classifier.is_above(model, claude_request_context(usage_obj))
+                            classifier.is_above(model, claude_request_tokens(usage_obj))
                         })
                     });
                     process_claude_usage(&mut conversation_usage, model, usage, above);
@@ -175,7 +191,7 @@ where
                                         iter.as_object().is_some_and(|usage_obj| {
                                             classifier.is_above(
                                                 adv_model,
-                                                claude_request_context(usage_obj),
+                                                claude_request_tokens(usage_obj),
                                             )
                                         })
                                     });
@@ -364,6 +380,22 @@ struct PendingClaudeTool {
     input_supported: bool,
 }
 
+/// Classifies a logged Claude API error from its `status` and its innermost
+/// error `type` (a bare string is the type name itself, e.g. `rate_limit`).
+fn classify_claude_error(error: Option<&Value>) -> ApiErrorKind {
+    match error {
+        Some(Value::String(error_type)) => ApiErrorKind::classify(None, Some(error_type)),
+        Some(error) => {
+            let error_type = ["/error/error/type", "/error/type"]
+                .into_iter()
+                .filter_map(|pointer| error.pointer(pointer).and_then(Value::as_str))
+                .find(|error_type| *error_type != "error");
+            ApiErrorKind::classify(error.get("status").and_then(Value::as_u64), error_type)
+        }
+        None => ApiErrorKind::Other,
+    }
+}
+
 fn is_tracked_file_tool(name: &str) -> bool {
     matches!(name, "Read" | "Write" | "Edit")
 }
@@ -683,6 +715,28 @@ fn preserve_file_tool_counts(
 mod tests {
     use super::*;
 
+    #[test]
+    fn api_errors_classify_on_status_and_type_only() {
+        let error = serde_json::json!({
+            "status": 500,
+            "requestID": "req_429_529",
+            "error": { "type": "error", "error": { "type": "api_error", "message": "429" } }
+        });
+        assert_eq!(classify_claude_error(Some(&error)), ApiErrorKind::Other);
+
+        let error = serde_json::json!({
+            "error": { "type": "error", "error": { "type": "overloaded_error" } }
+        });
+        assert_eq!(
+            classify_claude_error(Some(&error)),
+            ApiErrorKind::Overloaded
+        );
+        assert_eq!(
+            classify_claude_error(Some(&serde_json::json!("rate_limit"))),
+            ApiErrorKind::RateLimit
+        );
+    }
+
     fn assistant_log(ts: &str, model: &str, content: serde_json::Value) -> ClaudeCodeLog {
         let raw = serde_json::json!({
             "type": "assistant",
@@ -730,9 +784,13 @@ mod tests {
         let tiers = crate::pricing::TierThresholds::from_entries(
             [("claude-sonnet-5", 200_000)].into_iter(),
         );
-        let parsed =
-            parse_claude_logs_with_diagnostics(logs.clone(), ParseMode::UsageOnly, Some(&tiers))
-                .unwrap();
+        let parsed = parse_claude_logs_with_diagnostics(
+            logs.clone(),
+            ParseMode::UsageOnly,
+            Some(&tiers),
+            None,
+        )
+        .unwrap();
         let usage = &parsed.analysis.records[0].conversation_usage["claude-sonnet-5"];
         // Totals cover both requests; the above_tier slice only the second.
         assert_eq!(usage["input_tokens"], 3_000);
@@ -742,7 +800,8 @@ mod tests {
         assert_eq!(usage["above_tier"]["output_tokens"], 300);
 
         // Without thresholds nothing is classified and the shape is unchanged.
-        let parsed = parse_claude_logs_with_diagnostics(logs, ParseMode::UsageOnly, None).unwrap();
+        let parsed =
+            parse_claude_logs_with_diagnostics(logs, ParseMode::UsageOnly, None, None).unwrap();
         let usage = &parsed.analysis.records[0].conversation_usage["claude-sonnet-5"];
         assert!(usage.get("above_tier").is_none());
     }
@@ -877,7 +936,7 @@ mod tests {
             .unwrap();
 
             let parsed =
-                parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None)
+                parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None, None)
                     .unwrap();
             assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
             let record = &parsed.analysis.records[0];
@@ -923,7 +982,8 @@ mod tests {
         .unwrap();
 
         let parsed =
-            parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None).unwrap();
+            parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None, None)
+                .unwrap();
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.read, 1);
@@ -965,7 +1025,8 @@ mod tests {
         .unwrap();
 
         let parsed =
-            parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None).unwrap();
+            parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None, None)
+                .unwrap();
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.read, 0);
@@ -1003,7 +1064,7 @@ mod tests {
             );
 
             let parsed =
-                parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None)
+                parse_claude_logs_with_diagnostics([assistant, user], ParseMode::Full, None, None)
                     .unwrap();
             assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
             assert!(!parsed.diagnostics.is_complete_failure());
@@ -1025,7 +1086,7 @@ mod tests {
         );
 
         let parsed =
-            parse_claude_logs_with_diagnostics([assistant], ParseMode::Full, None).unwrap();
+            parse_claude_logs_with_diagnostics([assistant], ParseMode::Full, None, None).unwrap();
         assert_eq!(parsed.diagnostics.partial_failure_count(), 1);
         assert_eq!(parsed.analysis.records[0].tool_call_counts.read, 0);
     }
@@ -1163,7 +1224,8 @@ mod tests {
         }))
         .unwrap();
 
-        let parsed = parse_claude_logs_with_diagnostics([log], ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_claude_logs_with_diagnostics([log], ParseMode::Full, None, None).unwrap();
         assert!(parsed.diagnostics.is_complete_failure());
         assert!(parsed.analysis.records[0].conversation_usage.is_empty());
     }
@@ -1237,7 +1299,8 @@ mod tests {
             }
         });
         let log: ClaudeCodeLog = serde_json::from_value(raw).unwrap();
-        let parsed = parse_claude_logs_with_diagnostics([log], ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_claude_logs_with_diagnostics([log], ParseMode::Full, None, None).unwrap();
 
         assert_eq!(parsed.diagnostics.partial_failure_count(), 1);
         assert!(parsed.analysis.records[0].advisor_usage.is_empty());
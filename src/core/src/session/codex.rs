@@ -10,17 +10,21 @@
 //! applied only when their paired output explicitly reports success.
 use crate::constants::FastHashMap;
 use crate::models::*;
-use crate::pricing::{TierClassifier, TierThresholds};
+use crate::pricing::TierClassifier;
 use crate::session::diagnostics::{ParseDiagnostics, ParsedAnalysis};
+use crate::session::parser::ParseOptions;
 use crate::session::state::{ParseMode, SessionParseState};
 use crate::utils::{
-    CodexTokenTotals, get_git_remote_url, parse_iso_timestamp, process_codex_usage,
+    CodexTokenTotals, codex_request_tokens, get_git_remote_url, parse_iso_timestamp,
+    process_codex_usage,
 };
+use crate::warnings::WarningSink;
 use anyhow::Result;
 use regex::Regex;
 use serde_json::Value;
 use std::borrow::Borrow;
 use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
 
 /// Parse Codex session records from a slice of pre-typed logs.
 ///
@@ -44,20 +48,20 @@ where
     I: IntoIterator,
     I::Item: Borrow<CodexLog>,
 {
-    Ok(parse_codex_log_iter_with_diagnostics(logs, mode, None)?.analysis)
+    Ok(parse_codex_log_iter_with_diagnostics(logs, mode, ParseOptions::default())?.analysis)
 }
 
 /// Streaming Codex parser with parser-only schema diagnostics.
 pub(crate) fn parse_codex_log_iter_with_diagnostics<I>(
     logs: I,
     mode: ParseMode,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<ParsedAnalysis>
 where
     I: IntoIterator,
     I::Item: Borrow<CodexLog>,
 {
-    let mut classifier = tiers.map(TierClassifier::new);
+    let mut classifier = options.tiers.map(TierClassifier::new);
     let mut state = SessionParseState::with_mode(mode);
     let mut conversation_usage: FastHashMap<String, Value> = FastHashMap::with_capacity(5);
     let mut current_model = String::new();
@@ -66,9 +70,10 @@ where
     // Pre-context snapshots (a resumed session's replayed totals) advance the
     // snapshot without attribution, replacing the old replay-baseline hack.
     let mut prev_totals: Option<CodexTokenTotals> = None;
-    let mut shell_calls: FastHashMap<String, PendingCodexShellCall> =
-        FastHashMap::with_capacity(50);
-    let mut custom_calls: FastHashMap<String, CodexCustomCall> = FastHashMap::with_capacity(32);
+    let mut shell_calls: PendingCalls<PendingCodexShellCall> =
+        PendingCalls::with_capacity(50, options.warnings);
+    let mut custom_calls: PendingCalls<CodexCustomCall> =
+        PendingCalls::with_capacity(32, options.warnings);
     // Call ids of direct `apply_patch` custom_tool_calls, so a paired
     // `patch_apply_end` event is not double counted (see the event_msg arm).
     // Call ids whose file ops have already been counted, by either the direct
@@ -103,10 +108,8 @@ where
 
         match entry.log_type.as_str() {
             "session_meta" => {
-                if state.folder_path.is_empty()
-                    && let Some(cwd) = &entry.payload.cwd
-                {
-                    state.folder_path.clone_from(cwd); // More efficient than clone()
+                if let Some(cwd) = &entry.payload.cwd {
+                    state.note_working_dir(cwd);
                 }
                 if state.task_id.is_empty()
                     && let Some(id) = &entry.payload.id
@@ -121,10 +124,8 @@ where
                 }
             }
             "turn_context" => {
-                if state.folder_path.is_empty()
-                    && let Some(cwd) = &entry.payload.cwd
-                {
-                    state.folder_path.clone_from(cwd);
+                if let Some(cwd) = &entry.payload.cwd {
+                    state.note_working_dir(cwd);
                 }
                 if let Some(model) = entry
                     .payload
@@ -157,19 +158,21 @@ where
                                     CodexTokenTotals::delta_fields(total, prev_totals.as_ref())
                                 })
                                 .unwrap_or_default();
-                            // One token_count is one turn; its request context
-                            // is the turn's own full prompt (cached included),
-                            // published as last_token_usage.input_tokens. Fall
-                            // back to the delta input when absent.
+                            // One token_count is one turn; its request is the
+                            // turn's own usage (cached input included in
+                            // `input_tokens`), published as last_token_usage.
+                            // Fall back to the delta when absent.
                             let above = classifier.as_mut().is_some_and(|classifier| {
-                                let request_context = info
+                                let last = info
                                     .get("last_token_usage")
-                                    .and_then(|last| last.get("input_tokens"))
-                                    .and_then(Value::as_i64)
-                                    .filter(|tokens| *tokens > 0)
-                                    .or_else(|| delta.get("input_tokens").and_then(Value::as_i64))
-                                    .unwrap_or(0);
-                                classifier.is_above(&current_model, request_context)
+                                    .and_then(Value::as_object)
+                                    .filter(|last| {
+                                        last.get("input_tokens")
+                                            .and_then(Value::as_i64)
+                                            .is_some_and(|tokens| tokens > 0)
+                                    })
+                                    .unwrap_or(&delta);
+                                classifier.is_above(&current_model, codex_request_tokens(last))
                             });
                             process_codex_usage(
                                 &mut conversation_usage,
@@ -185,6 +188,18 @@ where
                     }
                 }
 
+                // `error` is a turn that failed; `stream_error` is a dropped
+                // stream Codex is retrying. Both carry only a message.
+                if matches!(
+                    entry.payload.payload_type.as_deref(),
+                    Some("error" | "stream_error")
+                ) {
+                    let message = entry.payload.message.as_deref().unwrap_or_default();
+                    state
+                        .error_counts
+                        .record(ApiErrorKind::classify(logged_status(message), None));
+                }
+
                 // Modern Codex applies most edits inside opaque `exec` cells and
                 // records the resulting file changes in a `patch_apply_end` event.
                 // A successful one is the authoritative source of those file ops.
@@ -229,18 +244,14 @@ where
                                 if let Some(call_id) = entry.payload.call_id.as_deref() {
                                     if let Some(call) = call {
                                         diagnostics.record_relevant(true);
-                                        shell_calls.insert(
-                                            call_id.to_string(),
-                                            PendingCodexShellCall::Parsed(call),
-                                        );
+                                        shell_calls
+                                            .push(call_id, PendingCodexShellCall::Parsed(call));
                                     } else {
                                         // Defer the schema verdict until the paired output. Codex
                                         // persists model-generated argument errors as ordinary
                                         // lifecycle records even though no command ran.
-                                        shell_calls.insert(
-                                            call_id.to_string(),
-                                            PendingCodexShellCall::InvalidArguments,
-                                        );
+                                        shell_calls
+                                            .push(call_id, PendingCodexShellCall::InvalidArguments);
                                     }
                                 } else {
                                     diagnostics.record_relevant(false);
@@ -249,7 +260,7 @@ where
                         }
                         "function_call_output" => {
                             if let Some(call_id) = &entry.payload.call_id
-                                && let Some(call) = shell_calls.remove(call_id)
+                                && let Some(call) = shell_calls.pop(call_id)
                             {
                                 match call {
                                     PendingCodexShellCall::Parsed(call) => {
@@ -279,13 +290,13 @@ where
                                 if let (Some(call), Some(call_id)) =
                                     (call, entry.payload.call_id.as_deref())
                                 {
-                                    custom_calls.insert(call_id.to_string(), call);
+                                    custom_calls.push(call_id, call);
                                 }
                             }
                         }
                         "custom_tool_call_output" => {
                             if let Some(call_id) = entry.payload.call_id.as_deref()
-                                && let Some(call) = custom_calls.remove(call_id)
+                                && let Some(call) = custom_calls.pop(call_id)
                             {
                                 let normalized = dispatch_custom_call(
                                     &mut state,
@@ -305,7 +316,7 @@ where
         }
     }
 
-    for call in shell_calls.into_values() {
+    for call in shell_calls.into_unanswered() {
         if matches!(call, PendingCodexShellCall::InvalidArguments) {
             diagnostics.record_relevant(false);
         }
@@ -326,6 +337,18 @@ where
     Ok(ParsedAnalysis::new(analysis, diagnostics))
 }
 
+/// The HTTP status a Codex error message reports (`last status: 429 ...`,
+/// `unexpected status 500 ...`). Codex logs errors as text only, so this is
+/// the one structured field it carries; other digits in the text are ignored.
+fn logged_status(message: &str) -> Option<u64> {
+    let (_, rest) = message.rsplit_once("status")?;
+    let digits = rest.trim_start_matches([':', ' ']);
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    (end == 3).then(|| digits[..end].parse().ok()).flatten()
+}
+
 fn is_supported_codex_usage(info: &Value) -> bool {
     let Some(info) = info.as_object() else {
         return false;
@@ -384,6 +407,56 @@ enum PendingCodexShellCall {
     InvalidArguments,
 }
 
+/// Calls still waiting for their output, keyed by `call_id`.
+///
+/// A well-formed log answers each call before reusing its id, but a buggy
+/// producer can emit a second call under an id that is still pending. Each
+/// id therefore holds a stack: outputs pair with the newest pending call
+/// first (LIFO), so no call is silently overwritten, and the reuse is
+/// reported as a run warning.
+struct PendingCalls<'a, T> {
+    by_id: FastHashMap<String, Vec<T>>,
+    warnings: Option<&'a WarningSink>,
+}
+
+impl<'a, T> PendingCalls<'a, T> {
+    fn with_capacity(capacity: usize, warnings: Option<&'a WarningSink>) -> Self {
+        Self {
+            by_id: FastHashMap::with_capacity(capacity),
+            warnings,
+        }
+    }
+
+    /// Queues `call` under `call_id`, warning when the id is still pending.
+    fn push(&mut self, call_id: &str, call: T) {
+        let pending = self.by_id.entry(call_id.to_string()).or_default();
+        if !pending.is_empty() {
+            crate::warnings::warn_to(
+                self.warnings,
+                format!(
+                    "Codex session reuses pending call_id `{call_id}`; pairing its outputs with the newest call first"
+                ),
+            );
+        }
+        pending.push(call);
+    }
+
+    /// The newest pending call under `call_id`, if any.
+    fn pop(&mut self, call_id: &str) -> Option<T> {
+        let pending = self.by_id.get_mut(call_id)?;
+        let call = pending.pop();
+        if pending.is_empty() {
+            self.by_id.remove(call_id);
+        }
+        call
+    }
+
+    /// Every call that never received an output.
+    fn into_unanswered(self) -> impl Iterator<Item = T> {
+        self.by_id.into_values().flatten()
+    }
+}
+
 fn output_reports_argument_error(output: Option<&str>) -> bool {
     let output = shell_output(output).output;
     let output = strip_exec_command_metadata_prefix(&output)
@@ -458,6 +531,7 @@ fn parse_function_call(name: &str, args_str: &str, ts: i64) -> Option<CodexShell
                 timestamp: ts,
                 script,
                 full_command: args.command,
+                workdir: args.workdir,
             })
         }
         "exec_command" => {
@@ -467,6 +541,7 @@ fn parse_function_call(name: &str, args_str: &str, ts: i64) -> Option<CodexShell
                 timestamp: ts,
                 script: cmd.clone(),
                 full_command: vec![cmd],
+                workdir: args.workdir,
             })
         }
         _ => None,
@@ -614,16 +689,27 @@ impl CodexAnalysisExt for SessionParseState {
         // only what the model actually saw as the file body.
         let output_body = strip_exec_command_metadata_prefix(&output.output);
 
-        // Check for sed command
-        if let Some(path) = extract_sed_file_path(&call.script) {
-            self.add_read_detail(&path, output_body, call.timestamp);
-            return;
-        }
+        // Walk the script's commands in order so a read after `cd subdir &&`
+        // resolves under `subdir` rather than the session folder.
+        let mut cwd = ShellCwd::new(self, &call.workdir);
+        for command in split_shell_commands(&call.script) {
+            if cwd.apply(self, command) {
+                continue;
+            }
 
-        // Check for cat command
-        if let Some((path, content)) = extract_cat_read(&call.script, output_body) {
-            self.add_read_detail(&path, &content, call.timestamp);
-            return;
+            // Check for sed command
+            if let Some(path) = extract_sed_file_path(command) {
+                let path = cwd.resolve(self, &path);
+                self.add_read_detail(&path, output_body, call.timestamp);
+                return;
+            }
+
+            // Check for cat command
+            if let Some((path, content)) = extract_cat_read(command, output_body) {
+                let path = cwd.resolve(self, &path);
+                self.add_read_detail(&path, &content, call.timestamp);
+                return;
+            }
         }
 
         // Record as run command
@@ -679,6 +765,116 @@ struct CodexShellCall {
     script: String,
     /// The full argv as written by the model, for verbatim run-command display.
     full_command: Vec<String>,
+    /// Directory the call was launched in (empty: the session's `cwd`).
+    workdir: String,
+}
+
+/// Working directory of one shell call, followed through its `cd` / `pushd` /
+/// `popd` commands.
+///
+/// Every Codex shell call starts a fresh shell in its `workdir` (or the session
+/// `cwd`), so the tracked directory never carries over to the next call.
+struct ShellCwd {
+    /// Absolute directory, or empty when neither the call nor the session
+    /// recorded one (paths then resolve exactly as `normalize_path` does).
+    current: String,
+    /// Directories saved by `pushd`, restored by `popd`.
+    stack: Vec<String>,
+}
+
+impl ShellCwd {
+    fn new(state: &SessionParseState, workdir: &str) -> Self {
+        Self {
+            current: state.normalize_path(workdir),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Applies `command` when it changes directory, returning whether it did.
+    ///
+    /// Targets that cannot be resolved statically (`cd`, `cd ~`, `cd -`,
+    /// `cd "$DIR"`) leave the directory unchanged.
+    fn apply(&mut self, state: &SessionParseState, command: &str) -> bool {
+        let mut fields = command.split_whitespace();
+        let verb = fields.next().unwrap_or("");
+        let target = fields
+            .next()
+            .map(|field| field.trim_matches(|c| c == '"' || c == '\''));
+        match verb {
+            "cd" | "pushd" => {
+                let previous = self.current.clone();
+                if let Some(target) = target
+                    && !target.is_empty()
+                    && !target.starts_with(['~', '-', '$'])
+                {
+                    self.current = self.resolve(state, target);
+                }
+                if verb == "pushd" {
+                    self.stack.push(previous);
+                }
+                true
+            }
+            "popd" => {
+                if let Some(previous) = self.stack.pop() {
+                    self.current = previous;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Resolves `path` against the tracked directory, folding `.` and `..`.
+    fn resolve(&self, state: &SessionParseState, path: &str) -> String {
+        if self.current.is_empty() || Path::new(path).is_absolute() {
+            return state.normalize_path(path);
+        }
+        let mut resolved = PathBuf::from(&self.current);
+        for component in Path::new(path).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    resolved.pop();
+                }
+                other => resolved.push(other),
+            }
+        }
+        resolved.to_string_lossy().into_owned()
+    }
+}
+
+/// Splits a shell script into its commands at unquoted `&&`, `||`, `;`, `|`,
+/// and newlines, trimming each and dropping empty ones.
+fn split_shell_commands(script: &str) -> Vec<&str> {
+    let mut commands = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let mut chars = script.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                // `2>&1` / `<&3` are redirections, not command separators.
+                '&' if index > 0 && matches!(script.as_bytes()[index - 1], b'>' | b'<') => {}
+                ';' | '\n' | '&' | '|' => {
+                    // `&&` / `||` span two bytes; a lone `&` backgrounds.
+                    let mut end = index + 1;
+                    if matches!(c, '&' | '|') && chars.peek().is_some_and(|(_, next)| *next == c) {
+                        chars.next();
+                        end += 1;
+                    }
+                    commands.push(script[start..index].trim());
+                    start = end;
+                }
+                _ => {}
+            },
+        }
+    }
+    commands.push(script[start..].trim());
+    commands.retain(|command| !command.is_empty());
+    commands
 }
 
 /// One file hunk extracted from an `apply_patch` script.
@@ -994,6 +1190,57 @@ mod tests {
         assert_eq!(record.run_command_details[0].command, "pwd");
     }
 
+    fn exec_command_pair(cmd: &str, workdir: &str, output: &str) -> Vec<CodexLog> {
+        vec![
+            response_item(serde_json::json!({
+                "type": "function_call",
+                "name": "exec_command",
+                "arguments": serde_json::json!({ "cmd": cmd, "workdir": workdir }).to_string(),
+                "call_id": "exec-cd"
+            })),
+            response_item(serde_json::json!({
+                "type": "function_call_output",
+                "call_id": "exec-cd",
+                "output": output
+            })),
+        ]
+    }
+
+    #[test]
+    fn cd_before_cat_resolves_the_read_under_the_new_directory() {
+        let logs = exec_command_pair("cd subdir && cat notes.txt", "/repo", "one\ntwo\n");
+        let analysis = parse_codex_logs(&logs, ParseMode::Full).unwrap();
+        let record = &analysis.records[0];
+        assert_eq!(record.tool_call_counts.read, 1);
+        assert_eq!(
+            record.read_file_details[0].base.file_path,
+            "/repo/subdir/notes.txt"
+        );
+        assert_eq!(record.total_read_lines, 2);
+    }
+
+    #[test]
+    fn pushd_popd_and_parent_dirs_are_followed_within_one_call() {
+        let logs = exec_command_pair(
+            "pushd crates/core; popd; cd crates/cli/../core && sed -n '1,2p' 'src/lib.rs'",
+            "/repo",
+            "a\nb\n",
+        );
+        let analysis = parse_codex_logs(&logs, ParseMode::Full).unwrap();
+        assert_eq!(
+            analysis.records[0].read_file_details[0].base.file_path,
+            "/repo/crates/core/src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn split_shell_commands_respects_quotes_and_redirections() {
+        assert_eq!(
+            split_shell_commands("sed -n '1p;3p' a.rs 2>&1 | head; cd b || true"),
+            vec!["sed -n '1p;3p' a.rs 2>&1", "head", "cd b", "true"]
+        );
+    }
+
     #[test]
     fn unrelated_function_names_are_ignored() {
         // MCP tool calls, `update_plan`, etc. must not be treated as shell.
@@ -1024,8 +1271,12 @@ mod tests {
                 })),
             ];
 
-            let parsed =
-                parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+            let parsed = parse_codex_log_iter_with_diagnostics(
+                &logs,
+                ParseMode::Full,
+                ParseOptions::default(),
+            )
+            .unwrap();
             assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
             assert!(!parsed.diagnostics.is_complete_failure());
             assert_eq!(parsed.analysis.records[0].tool_call_counts.bash, 0);
@@ -1049,11 +1300,44 @@ mod tests {
             })),
         ];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert!(parsed.diagnostics.is_complete_failure());
         assert_eq!(parsed.analysis.records[0].tool_call_counts.bash, 0);
     }
 
+    #[test]
+    fn error_and_stream_error_events_are_counted_by_kind() {
+        let logs: Vec<CodexLog> = [
+            "stream error: exceeded retry limit, last status: 429 Too Many Requests; retrying 1/5",
+            "exceeded retry limit, last status: 429 Too Many Requests",
+            "unexpected status 500 Internal Server Error",
+            // Digits outside the status (a request id here) never classify.
+            "request req_429529 failed: connection reset",
+        ]
+        .into_iter()
+        .zip(["stream_error", "error", "error", "error"])
+        .map(|(message, kind)| {
+            serde_json::from_value(serde_json::json!({
+                "timestamp": "2026-07-12T00:00:00Z",
+                "type": "event_msg",
+                "payload": { "type": kind, "message": message }
+            }))
+            .unwrap()
+        })
+        .collect();
+
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
+        let counts = parsed.analysis.records[0].error_counts;
+        assert_eq!(
+            (counts.rate_limit, counts.overloaded, counts.other),
+            (2, 0, 2)
+        );
+    }
+
     #[test]
     fn valid_token_count_without_model_context_is_not_schema_failure() {
         let logs: Vec<CodexLog> = [
@@ -1079,7 +1363,9 @@ mod tests {
         .map(|value| serde_json::from_value(value).unwrap())
         .collect();
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert!(!parsed.diagnostics.is_complete_failure());
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
         assert_eq!(parsed.diagnostics.relevant_records, 1);
@@ -1159,7 +1445,9 @@ mod tests {
         .map(|value| serde_json::from_value(value).unwrap())
         .collect();
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert!(!parsed.diagnostics.is_complete_failure());
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
 
@@ -1194,7 +1482,9 @@ mod tests {
         .map(|value| serde_json::from_value(value).unwrap())
         .collect();
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
         assert!(parsed.analysis.records[0].conversation_usage.is_empty());
     }
@@ -1241,7 +1531,9 @@ mod tests {
         .map(|value| serde_json::from_value(value).unwrap())
         .collect();
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert_eq!(parsed.diagnostics.partial_failure_count(), 0);
 
         let usage = &parsed.analysis.records[0].conversation_usage;
@@ -1301,9 +1593,15 @@ mod tests {
 
         let tiers =
             crate::pricing::TierThresholds::from_entries([("gpt-5.4", 272_000)].into_iter());
-        let parsed =
-            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::UsageOnly, Some(&tiers))
-                .unwrap();
+        let parsed = parse_codex_log_iter_with_diagnostics(
+            &logs,
+            ParseMode::UsageOnly,
+            ParseOptions {
+                tiers: Some(&tiers),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
         let usage = &parsed.analysis.records[0].conversation_usage["gpt-5.4"];
         assert_eq!(usage["total_token_usage"]["total_tokens"], 502_500);
         // Only the second turn's delta (input 300k, output 1.5k) is above.
@@ -1329,7 +1627,9 @@ mod tests {
         .map(|value| serde_json::from_value(value).unwrap())
         .collect();
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert!(parsed.diagnostics.is_complete_failure());
         assert!(parsed.analysis.records[0].conversation_usage.is_empty());
     }
@@ -1493,7 +1793,9 @@ mod tests {
             custom_output("patch-failed", Value::String(wire_result)),
         ];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.edit, 0);
         assert!(record.edit_file_details.is_empty());
@@ -1521,8 +1823,12 @@ mod tests {
                 custom_output("patch-unknown", output),
             ];
 
-            let parsed =
-                parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+            let parsed = parse_codex_log_iter_with_diagnostics(
+                &logs,
+                ParseMode::Full,
+                ParseOptions::default(),
+            )
+            .unwrap();
             let record = &parsed.analysis.records[0];
             assert_eq!(record.tool_call_counts.edit, 0);
             assert!(record.edit_file_details.is_empty());
@@ -1654,7 +1960,9 @@ mod tests {
         });
         let logs = vec![patch_apply_end("call-nodiff", true, changes)];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.write, 1);
         assert_eq!(record.total_unique_files, 1);
@@ -1669,7 +1977,9 @@ mod tests {
         });
         let logs = vec![patch_apply_end("call-fail", false, changes)];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.write, 0);
         assert_eq!(record.total_unique_files, 0);
@@ -1688,7 +1998,9 @@ mod tests {
             Value::String("nope".into()),
         )];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         assert_eq!(parsed.analysis.records[0].tool_call_counts.edit, 0);
         assert_eq!(parsed.analysis.records[0].tool_call_counts.write, 0);
         assert!(parsed.diagnostics.is_complete_failure());
@@ -1775,7 +2087,9 @@ mod tests {
         });
         let logs = vec![patch_apply_end("call-del", true, changes)];
 
-        let parsed = parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, None).unwrap();
+        let parsed =
+            parse_codex_log_iter_with_diagnostics(&logs, ParseMode::Full, ParseOptions::default())
+                .unwrap();
         let record = &parsed.analysis.records[0];
         assert_eq!(record.tool_call_counts.edit, 1);
         assert_eq!(record.total_unique_files, 1);
@@ -760,9 +760,9 @@ mod tests {
 
     fn count_lines_after_trim(s: &str) -> usize {
         // Mirror src/session/state.rs count_lines + add_read_detail's
-        // trim_end_matches('\n') so the test reflects the actual line
+        // trim_end_matches(['\r', '\n']) so the test reflects the actual line
         // tally the analyzer would record.
-        let trimmed = s.trim_end_matches('\n');
+        let trimmed = s.trim_end_matches(['\r', '\n']);
         if trimmed.is_empty() {
             0
         } else {
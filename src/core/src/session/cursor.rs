@@ -63,6 +63,21 @@ pub fn read_cursor_usage(
     tracking_db: &Path,
     time_range: TimeRange,
 ) -> Result<Vec<(String, CodeAnalysis, f64)>> {
+    let user = get_current_user();
+    let machine = get_machine_id().to_string();
+    Ok(read_cursor_usage_rows(chats_dir, tracking_db, time_range)?
+        .into_iter()
+        .map(|row| row.into_public_row(ExtensionType::Cursor, &user, &machine))
+        .collect())
+}
+
+/// [`read_cursor_usage`] before the rows become public tuples: the compact
+/// rows, still carrying their conversation ids.
+pub(crate) fn read_cursor_usage_rows(
+    chats_dir: &Path,
+    tracking_db: &Path,
+    time_range: TimeRange,
+) -> Result<Vec<UsageContribution>> {
     let result = read_cursor_usage_with_diagnostics(chats_dir, tracking_db, time_range);
     for failure in &result.failures {
         log::warn!(
@@ -77,13 +92,7 @@ pub fn read_cursor_usage(
             result.candidates
         ));
     }
-    let user = get_current_user();
-    let machine = get_machine_id().to_string();
-    Ok(result
-        .rows
-        .into_iter()
-        .map(|row| row.into_public_row(ExtensionType::Cursor, &user, &machine))
-        .collect())
+    Ok(result.rows)
 }
 
 /// Per-store Cursor usage result used by diagnostics-aware collection.
@@ -261,12 +270,13 @@ pub(crate) fn read_cursor_analysis_with_diagnostics(
 // usage: local estimate
 // ===========================================================================
 
-/// One usage aggregation row keyed by `(date, model)`, so any time range can
-/// filter it locally. A purely in-memory intermediate — never serialized.
+/// One usage aggregation row keyed by `(date, conversation, model)`, so any
+/// time range can filter it locally. A purely in-memory intermediate — never serialized.
 #[derive(Debug)]
 struct UsageEvent {
     date: String,
     timestamp_ms: i64,
+    conversation: String,
     model: String,
     input: i64,
     output: i64,
@@ -293,6 +303,7 @@ fn aggregate_events(events: &[UsageEvent], time_range: TimeRange) -> Vec<UsageCo
         out.push(UsageContribution::single_model(
             e.date.clone(),
             e.timestamp_ms,
+            e.conversation.clone(),
             e.model.clone(),
             cursor_usage_value(e.input, e.output, e.cache_read, e.cache_write),
             e.cost,
@@ -319,6 +330,7 @@ pub(crate) fn read_cursor_usage_store(
             ms_to_local_date(timestamp_ms).map(|date| UsageEvent {
                 date,
                 timestamp_ms,
+                conversation: conv_id.clone(),
                 model: read.model.clone(),
                 input: 0,
                 output: 0,
@@ -361,8 +373,8 @@ fn approximation_events(chats_dir: &Path, tracking_db: &Path) -> CursorUsageEven
             }),
         ),
     };
-    // (date, model) -> (summed context-window gauge, latest timestamp)
-    let mut agg: HashMap<(String, String), (i64, i64)> = HashMap::new();
+    // (date, conversation, model) -> (summed context-window gauge, latest timestamp)
+    let mut agg: HashMap<(String, String, String), (i64, i64)> = HashMap::new();
     let discovery = discover_cursor_store_dbs(chats_dir);
     let candidates = discovery.stores.len() + discovery.failures.len();
     let mut parsed = 0usize;
@@ -406,7 +418,9 @@ fn approximation_events(chats_dir: &Path, tracking_db: &Path) -> CursorUsageEven
             let Some(date) = ms_to_local_date(ts) else {
                 continue;
             };
-            let entry = agg.entry((date, read.model.clone())).or_insert((0, ts));
+            let entry = agg
+                .entry((date, conv_id.clone(), read.model.clone()))
+                .or_insert((0, ts));
             entry.0 += ctx;
             entry.1 = entry.1.max(ts);
         }
@@ -415,16 +429,19 @@ fn approximation_events(chats_dir: &Path, tracking_db: &Path) -> CursorUsageEven
     CursorUsageEvents {
         events: agg
             .into_iter()
-            .map(|((date, model), (ctx, timestamp_ms))| UsageEvent {
-                date,
-                timestamp_ms,
-                model,
-                input: 0,
-                output: 0,
-                cache_read: ctx,
-                cache_write: 0,
-                cost: 0.0,
-            })
+            .map(
+                |((date, conversation, model), (ctx, timestamp_ms))| UsageEvent {
+                    date,
+                    timestamp_ms,
+                    conversation,
+                    model,
+                    input: 0,
+                    output: 0,
+                    cache_read: ctx,
+                    cache_write: 0,
+                    cost: 0.0,
+                },
+            )
             .collect(),
         candidates,
         parsed,
@@ -1407,6 +1424,7 @@ mod tests {
             UsageEvent {
                 date: "2999-01-01".to_string(),
                 timestamp_ms: 32_470_920_000_000,
+                conversation: "c1".to_string(),
                 model: "claude-sonnet-4.6".to_string(),
                 input: 100,
                 output: 20,
@@ -1417,6 +1435,7 @@ mod tests {
             UsageEvent {
                 date: "2000-01-01".to_string(),
                 timestamp_ms: 946_684_800_000,
+                conversation: "c2".to_string(),
                 model: "composer-2".to_string(),
                 input: 5,
                 output: 5,
@@ -6,10 +6,15 @@
 //! eagerly on a fully-materialised slice (the `Vec<Value>` fallback path),
 //! while [`classify_records`] returns `None` on indeterminate input so a
 //! streaming caller can keep peeking lines until a marker appears.
+use crate::constants::buffer;
 use crate::models::ExtensionType;
 use crate::session::grok::is_grok_signals;
-use anyhow::{Result, bail};
+use crate::utils::read_json;
+use anyhow::{Context, Result, bail};
 use serde_json::Value;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
 
 #[cfg(test)]
 std::thread_local! {
@@ -118,6 +123,54 @@ pub fn classify_records(data: &[Value]) -> Option<ExtensionType> {
     data.iter().find_map(|record| classifier.push(record))
 }
 
+/// Classifies a session file by content alone, without parsing it.
+///
+/// Peeks JSONL records through the same stateful classifier the streaming
+/// parser uses and stops at the first marker, so a typical file costs a line or
+/// two. A file whose first non-empty line is not JSON is read as one
+/// pretty-printed object (Grok's `signals.json`). Unlike
+/// [`detect_extension_type`] there is no Codex fallback: a file without any
+/// marker, or with nothing parseable at all, yields `None`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or read.
+pub fn classify_file(path: &Path) -> Result<Option<ExtensionType>> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
+    let mut reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
+    let mut classifier = RecordClassifier::default();
+    let mut seen_record = false;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .context("Failed to read line from session file")?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Value>(trimmed) {
+            Ok(record) => {
+                seen_record = true;
+                if let Some(found) = classifier.push(&record) {
+                    return Ok(Some(found));
+                }
+            }
+            Err(_) if !seen_record => {
+                return Ok(read_json(path)
+                    .ok()
+                    .and_then(|records| classify_records(&records)));
+            }
+            Err(_) => {}
+        }
+    }
+}
+
 /// Stateful provider classifier for a record stream.
 ///
 /// Each record is inspected exactly once. This keeps single-file auto
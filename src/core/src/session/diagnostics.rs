@@ -144,6 +144,9 @@ impl UsageTokenContribution {
 pub(crate) struct UsageContribution {
     pub(crate) date: String,
     pub(crate) timestamp_ms: i64,
+    /// The session the row belongs to, unique within its source; empty when
+    /// the whole source is one session (a Cursor store).
+    pub(crate) session: String,
     pub(crate) model: String,
     pub(crate) tokens: UsageTokenContribution,
     pub(crate) stored_cost: f64,
@@ -176,6 +179,7 @@ impl UsageContribution {
     pub(crate) fn single_model(
         date: String,
         timestamp_ms: i64,
+        session: String,
         model: String,
         tokens: UsageTokenContribution,
         stored_cost: f64,
@@ -183,6 +187,7 @@ impl UsageContribution {
         Self {
             date,
             timestamp_ms,
+            session,
             model,
             tokens,
             stored_cost,
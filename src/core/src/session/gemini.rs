@@ -11,7 +11,9 @@ use crate::models::*;
 use crate::pricing::{TierClassifier, TierThresholds};
 use crate::session::diagnostics::{ParseDiagnostics, ParsedAnalysis};
 use crate::session::state::{ParseMode, SessionParseState};
-use crate::utils::{get_git_remote_url, parse_iso_timestamp, process_gemini_usage};
+use crate::utils::{
+    gemini_request_tokens, get_git_remote_url, parse_iso_timestamp, process_gemini_usage,
+};
 use anyhow::Result;
 use serde::Deserialize;
 use serde_json::Value;
@@ -67,9 +69,9 @@ where
         if let (Some(tokens), Some(model)) = (&message.tokens, &message.model) {
             // One billed message is one request; `tokens.input` is its full
             // prompt count (cached subset included).
-            let above = classifier
-                .as_mut()
-                .is_some_and(|classifier| classifier.is_above(model, tokens.input));
+            let above = classifier.as_mut().is_some_and(|classifier| {
+                classifier.is_above(model, gemini_request_tokens(tokens))
+            });
             process_gemini_usage(&mut conversation_usage, model, tokens, above);
         }
         state.merge(message.state);
@@ -0,0 +1,239 @@
+//! Golden-file checks for the parser fixtures (`vct dev`).
+//!
+//! Every `<name>.expected.json` under the fixture directory is the committed
+//! analysis of its sibling session: `<name>.jsonl`, `<name>.json`, or — for a
+//! Grok session, which is a directory — `<name>/signals.json`. [`check_golden`]
+//! re-analyzes the session and compares it with the golden, skipping
+//! [`IGNORED_FIELDS`] (values that depend on the machine running the check);
+//! [`regen_golden`] rewrites the golden from the current parser output.
+
+use crate::session::parse_session_file_to_value;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Fields whose values depend on the build or machine, at any nesting level.
+pub const IGNORED_FIELDS: &[&str] = &["insightsVersion", "machineId", "user", "gitRemoteUrl"];
+
+/// Suffix that marks a golden file.
+const GOLDEN_SUFFIX: &str = ".expected.json";
+
+/// One session fixture and its committed golden output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenFixture {
+    /// Fixture name: the golden's file name without [`GOLDEN_SUFFIX`].
+    pub name: String,
+    /// Session file the golden was generated from.
+    pub input: PathBuf,
+    /// The `<name>.expected.json` file.
+    pub golden: PathBuf,
+}
+
+/// Lists the goldens in `dir` that have a session to re-analyze, by name.
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be read.
+pub fn discover_goldens(dir: &Path) -> Result<Vec<GoldenFixture>> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read fixture directory {}", dir.display()))?;
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let golden = entry?.path();
+        let Some(name) = golden
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(GOLDEN_SUFFIX))
+        else {
+            continue;
+        };
+        let input = [
+            dir.join(format!("{name}.jsonl")),
+            dir.join(format!("{name}.json")),
+            dir.join(name).join("signals.json"),
+        ]
+        .into_iter()
+        .find(|candidate| candidate.is_file());
+        if let Some(input) = input {
+            fixtures.push(GoldenFixture {
+                name: name.to_string(),
+                input,
+                golden,
+            });
+        }
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// Fields to skip for one golden: [`IGNORED_FIELDS`], plus `folderPath` for
+/// Gemini, whose logs carry no working directory, so the analyzer falls back
+/// to the current one.
+fn ignored_fields(golden: &Value) -> Vec<&'static str> {
+    let mut fields = IGNORED_FIELDS.to_vec();
+    if golden["extensionName"] == "Gemini" {
+        fields.push("folderPath");
+    }
+    fields
+}
+
+/// Re-analyzes `fixture` and compares it with its golden.
+///
+/// Returns `None` when they match, or a description of the first difference.
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be analyzed or the golden cannot be
+/// read as JSON.
+pub fn check_golden(fixture: &GoldenFixture) -> Result<Option<String>> {
+    let expected = read_golden(&fixture.golden)?;
+    let actual = parse_session_file_to_value(&fixture.input)?;
+    Ok(json_mismatch(
+        &actual,
+        &expected,
+        &ignored_fields(&expected),
+    ))
+}
+
+/// Rewrites the golden of `fixture` from the current parser output.
+///
+/// Ignored fields keep the values already committed, so regenerating on
+/// another machine only changes what the parser actually produces.
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be analyzed or the golden cannot be
+/// written.
+pub fn regen_golden(fixture: &GoldenFixture) -> Result<()> {
+    let mut actual = parse_session_file_to_value(&fixture.input)?;
+    if let Ok(previous) = read_golden(&fixture.golden) {
+        keep_ignored_fields(&mut actual, &previous, &ignored_fields(&previous));
+    }
+    let mut text = serde_json::to_string_pretty(&actual)?;
+    text.push('\n');
+    std::fs::write(&fixture.golden, text)
+        .with_context(|| format!("Failed to write {}", fixture.golden.display()))
+}
+
+fn read_golden(path: &Path) -> Result<Value> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&text).with_context(|| format!("{} is not valid JSON", path.display()))
+}
+
+/// Compares two JSON values, skipping object keys named in `ignore` at any
+/// depth.
+///
+/// Returns `None` when they match, or the path of the first difference
+/// (e.g. `records[0].toolCallCounts.Read: 3 != 2`).
+pub fn json_mismatch(actual: &Value, expected: &Value, ignore: &[&str]) -> Option<String> {
+    mismatch_at("", actual, expected, ignore)
+}
+
+fn mismatch_at(path: &str, actual: &Value, expected: &Value, ignore: &[&str]) -> Option<String> {
+    match (actual, expected) {
+        (Value::Object(actual_map), Value::Object(expected_map)) => {
+            let keys = |map: &serde_json::Map<String, Value>| {
+                map.keys()
+                    .filter(|key| !ignore.contains(&key.as_str()))
+                    .cloned()
+                    .collect::<std::collections::BTreeSet<_>>()
+            };
+            let (actual_keys, expected_keys) = (keys(actual_map), keys(expected_map));
+            if actual_keys != expected_keys {
+                let extra: Vec<_> = actual_keys.difference(&expected_keys).collect();
+                let missing: Vec<_> = expected_keys.difference(&actual_keys).collect();
+                return Some(format!(
+                    "{}: unexpected keys {extra:?}, missing keys {missing:?}",
+                    display_path(path)
+                ));
+            }
+            actual_keys.iter().find_map(|key| {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                mismatch_at(&child, &actual_map[key], &expected_map[key], ignore)
+            })
+        }
+        (Value::Array(actual_items), Value::Array(expected_items)) => {
+            if actual_items.len() != expected_items.len() {
+                return Some(format!(
+                    "{}: {} items != {}",
+                    display_path(path),
+                    actual_items.len(),
+                    expected_items.len()
+                ));
+            }
+            actual_items
+                .iter()
+                .zip(expected_items)
+                .enumerate()
+                .find_map(|(index, (actual, expected))| {
+                    mismatch_at(&format!("{path}[{index}]"), actual, expected, ignore)
+                })
+        }
+        _ if actual != expected => Some(format!("{}: {actual} != {expected}", display_path(path))),
+        _ => None,
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() { "(root)" } else { path }
+}
+
+/// Copies the values of ignored keys from `previous` into `actual` wherever
+/// both sides have the same shape.
+fn keep_ignored_fields(actual: &mut Value, previous: &Value, ignore: &[&str]) {
+    match (actual, previous) {
+        (Value::Object(actual_map), Value::Object(previous_map)) => {
+            for (key, value) in actual_map.iter_mut() {
+                let Some(previous_value) = previous_map.get(key) else {
+                    continue;
+                };
+                if ignore.contains(&key.as_str()) {
+                    *value = previous_value.clone();
+                } else {
+                    keep_ignored_fields(value, previous_value, ignore);
+                }
+            }
+        }
+        (Value::Array(actual_items), Value::Array(previous_items)) => {
+            for (value, previous_value) in actual_items.iter_mut().zip(previous_items) {
+                keep_ignored_fields(value, previous_value, ignore);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mismatch_names_the_first_differing_path_and_skips_ignored_keys() {
+        let expected = json!({ "user": "a", "records": [{ "toolCallCounts": { "Read": 2 } }] });
+        let same = json!({ "user": "b", "records": [{ "toolCallCounts": { "Read": 2 } }] });
+        let moved = json!({ "user": "a", "records": [{ "toolCallCounts": { "Read": 3 } }] });
+
+        assert_eq!(json_mismatch(&same, &expected, IGNORED_FIELDS), None);
+        assert_eq!(
+            json_mismatch(&moved, &expected, IGNORED_FIELDS).as_deref(),
+            Some("records[0].toolCallCounts.Read: 3 != 2")
+        );
+    }
+
+    #[test]
+    fn regenerated_output_keeps_the_committed_machine_fields() {
+        let previous = json!({ "user": "wei", "records": [{ "gitRemoteUrl": "old", "n": 1 }] });
+        let mut actual = json!({ "user": "root", "records": [{ "gitRemoteUrl": "new", "n": 2 }] });
+        keep_ignored_fields(&mut actual, &previous, IGNORED_FIELDS);
+        assert_eq!(
+            actual,
+            json!({ "user": "wei", "records": [{ "gitRemoteUrl": "old", "n": 2 }] })
+        );
+    }
+}
@@ -144,7 +144,7 @@ fn collect_per_model_rows(
 
         // Accumulate raw sums for the residual, even for rows outside the time
         // window — the residual is a session-level quantity.
-        let acc = summed.entry(session_id).or_default();
+        let acc = summed.entry(session_id.clone()).or_default();
         acc.input += input;
         acc.output += raw_output;
         acc.cache_read += cache_read;
@@ -174,6 +174,7 @@ fn collect_per_model_rows(
         out.push(UsageContribution::single_model(
             date,
             (seconds * 1000.0) as i64,
+            session_id,
             model.to_string(),
             session_usage_value(input, output, reasoning, cache_read, cache_write),
             cost,
@@ -262,6 +263,7 @@ fn reconcile_session_residuals(
         out.push(UsageContribution::single_model(
             date,
             (seconds * 1000.0) as i64,
+            id,
             model.to_string(),
             session_usage_value(input, output, reasoning, cache_read, cache_write),
             cost,
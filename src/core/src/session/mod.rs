@@ -19,15 +19,17 @@ pub mod cursor;
 pub mod detector;
 pub(crate) mod diagnostics;
 pub mod gemini;
+pub mod golden;
 pub mod grok;
 pub mod hermes;
 pub mod opencode;
 pub mod parser;
 pub(crate) mod sqlite;
 pub mod state;
+pub mod tool_version;
 
 pub use cursor::{read_cursor_analysis, read_cursor_usage};
-pub use detector::{classify_records, detect_extension_type};
+pub use detector::{classify_file, classify_records, detect_extension_type};
 pub use hermes::read_hermes_usage;
 pub use opencode::{read_opencode_analysis, read_opencode_usage};
 pub use parser::{
@@ -36,3 +38,4 @@ pub use parser::{
     parse_session_file_with_diagnostics,
 };
 pub use state::{ParseMode, SessionParseState};
+pub use tool_version::read_tool_version;
@@ -55,6 +55,18 @@ pub fn read_opencode_usage(
 ) -> Result<Vec<(String, CodeAnalysis, f64)>> {
     let user = get_current_user();
     let machine = get_machine_id().to_string();
+    Ok(read_opencode_usage_rows(db_path, time_range)?
+        .into_iter()
+        .map(|row| row.into_public_row(ExtensionType::OpenCode, &user, &machine))
+        .collect())
+}
+
+/// [`read_opencode_usage`] before the rows become public tuples: the compact
+/// rows, still carrying their session ids.
+pub(crate) fn read_opencode_usage_rows(
+    db_path: &Path,
+    time_range: TimeRange,
+) -> Result<Vec<UsageContribution>> {
     let read = read_opencode_usage_contributions(db_path, time_range)?;
     if read.expected_records > 0 && read.parsed_records == 0 {
         return Err(anyhow!(
@@ -68,11 +80,7 @@ pub fn read_opencode_usage(
             read.failed_records()
         );
     }
-    Ok(read
-        .rows
-        .into_iter()
-        .map(|row| row.into_public_row(ExtensionType::OpenCode, &user, &machine))
-        .collect())
+    Ok(read.rows)
 }
 
 /// Reads compact OpenCode usage rows for the summary aggregation path.
@@ -115,10 +123,7 @@ pub fn read_opencode_analysis(
         .saturating_sub(result.parsed_records)
         + result.failed_tool_parts;
     if failed_records > 0 {
-        log::warn!(
-            "skipped {} OpenCode analysis records with unsupported schema",
-            failed_records
-        );
+        log::warn!("skipped {failed_records} OpenCode analysis records with unsupported schema");
     }
     Ok(result
         .rows
@@ -241,12 +246,12 @@ fn collect_session_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
     let sql = match cutoff_ms {
         Some(_) => {
             "SELECT model, tokens_input, tokens_output, tokens_reasoning, \
-                    tokens_cache_read, tokens_cache_write, time_updated, cost \
+                    tokens_cache_read, tokens_cache_write, time_updated, cost, id \
              FROM session WHERE model IS NOT NULL AND model != '' AND time_updated >= ?1"
         }
         None => {
             "SELECT model, tokens_input, tokens_output, tokens_reasoning, \
-                    tokens_cache_read, tokens_cache_write, time_updated, cost \
+                    tokens_cache_read, tokens_cache_write, time_updated, cost, id \
              FROM session WHERE model IS NOT NULL AND model != ''"
         }
     };
@@ -268,6 +273,7 @@ fn collect_session_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
         let cache_write = row.get::<_, i64>(5)?;
         let time_updated = row.get::<_, i64>(6)?;
         let cost = row.get::<_, f64>(7)?;
+        let session_id = row.get::<_, String>(8)?;
         let Some(model_id) = parse_model_id(&model) else {
             continue;
         };
@@ -281,6 +287,7 @@ fn collect_session_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
         out.push(UsageContribution::single_model(
             date,
             time_updated,
+            session_id,
             model_id,
             session_usage_value(input, output, reasoning, cache_read, cache_write),
             cost,
@@ -302,7 +309,7 @@ fn collect_message_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
 
     let sql = match cutoff_ms {
         Some(_) => {
-            "SELECT session.time_updated, message.data \
+            "SELECT session.time_updated, message.data, message.session_id \
              FROM message \
              JOIN session ON session.id = message.session_id \
              WHERE json_extract(message.data, '$.role') = 'assistant' \
@@ -313,7 +320,7 @@ fn collect_message_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
                ) >= ?1"
         }
         None => {
-            "SELECT session.time_updated, message.data \
+            "SELECT session.time_updated, message.data, message.session_id \
              FROM message \
              JOIN session ON session.id = message.session_id \
              WHERE json_extract(message.data, '$.role') = 'assistant'"
@@ -331,6 +338,7 @@ fn collect_message_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
         expected_records += 1;
         let session_ts = row.get::<_, i64>(0)?;
         let data_text = row.get::<_, String>(1)?;
+        let session_id = row.get::<_, String>(2)?;
         let Some(message) = parse_message_usage(&data_text) else {
             continue;
         };
@@ -345,6 +353,7 @@ fn collect_message_usage(conn: &Connection, time_range: TimeRange) -> Result<Dat
         out.push(UsageContribution::single_model(
             date,
             message_ts,
+            session_id,
             message.model_id,
             message.usage,
             message.cost,
@@ -12,7 +12,10 @@ use crate::session::diagnostics::{ParseDiagnostics, ParsedAnalysis};
 use crate::session::gemini::parse_gemini_events_with_diagnostics;
 use crate::session::grok::{is_grok_signals, parse_grok_session};
 use crate::session::state::ParseMode;
-use crate::utils::{get_current_user, get_machine_id, read_json, read_jsonl};
+use crate::utils::{
+    DedupeBy, TextEncoding, get_current_user, get_machine_id, read_json_with, read_jsonl_with,
+};
+use crate::warnings::WarningSink;
 use anyhow::{Context, Result, bail};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
@@ -40,6 +43,20 @@ impl SessionFileParseDiagnostics {
     }
 }
 
+/// Parser settings shared by every source of one scan.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ParseOptions<'a> {
+    /// Per-request context-tier snapshot; usage scans pass it, analysis
+    /// scans leave it `None`.
+    pub(crate) tiers: Option<&'a TierThresholds>,
+    /// How bytes that are not valid UTF-8 are treated.
+    pub(crate) encoding: TextEncoding,
+    /// What repeated Claude token events are recognized by (`--dedupe-by`).
+    pub(crate) dedupe_by: Option<DedupeBy>,
+    /// Where the scan's run warnings go; `None` only logs them.
+    pub(crate) warnings: Option<&'a WarningSink>,
+}
+
 #[derive(Debug, Default)]
 struct ParseWarningSummary {
     unreadable_records: usize,
@@ -195,17 +212,33 @@ pub fn parse_session_file_typed_with_mode<P: AsRef<Path>>(
     path: P,
     mode: ParseMode,
 ) -> Result<CodeAnalysis> {
-    Ok(parse_session_file_with_diagnostics(path, mode)?.0)
+    let path = path.as_ref();
+    let parsed = parse_session_file_typed_with_mode_internal(path, mode, ParseOptions::default())?;
+    validate_parsed_source(path, &parsed.diagnostics)?;
+    Ok(parsed.analysis)
 }
 
 /// Single-file parse with a content-safe partial-failure summary for the CLI.
+///
+/// `encoding` is the `--encoding` choice for invalid UTF-8, and `dedupe_by`
+/// the `--dedupe-by` key repeated Claude token events are skipped by.
+/// Problems the parse works around are recorded in `warnings`.
 #[doc(hidden)]
 pub fn parse_session_file_with_diagnostics<P: AsRef<Path>>(
     path: P,
     mode: ParseMode,
+    encoding: TextEncoding,
+    dedupe_by: Option<DedupeBy>,
+    warnings: &WarningSink,
 ) -> Result<(CodeAnalysis, SessionFileParseDiagnostics)> {
     let path = path.as_ref();
-    let parsed = parse_session_file_typed_with_mode_internal(path, mode)?;
+    let options = ParseOptions {
+        tiers: None,
+        encoding,
+        dedupe_by,
+        warnings: Some(warnings),
+    };
+    let parsed = parse_session_file_typed_with_mode_internal(path, mode, options)?;
     validate_parsed_source(path, &parsed.diagnostics)?;
     let diagnostics = SessionFileParseDiagnostics {
         skipped_records: parsed.diagnostics.partial_failure_count(),
@@ -216,17 +249,23 @@ pub fn parse_session_file_with_diagnostics<P: AsRef<Path>>(
 fn parse_session_file_typed_with_mode_internal(
     path: &Path,
     mode: ParseMode,
+    options: ParseOptions<'_>,
 ) -> Result<ParsedAnalysis> {
-    if let Some(parsed) = stream_parse_autodetect(path, mode)? {
+    let encoding = options.encoding;
+    if encoding == TextEncoding::Utf8
+        && let Some(parsed) = stream_parse_autodetect(path, mode, options)?
+    {
         return Ok(parsed);
     }
 
     // Fallback for anything the streaming path could not peek (e.g. a
     // hand-edited file whose first line is not valid JSON). This is also the
-    // normal path for Grok's pretty-printed `signals.json` object.
-    let data = match read_jsonl(path) {
+    // normal path for Grok's pretty-printed `signals.json` object, and for
+    // every file under `--encoding lossy`, whose replacement lives in the
+    // whole-file readers.
+    let data = match read_jsonl_with(path, encoding, options.warnings) {
         Ok(data) => data,
-        Err(_) => read_json(path)?,
+        Err(_) => read_json_with(path, encoding, options.warnings)?,
     };
 
     if data.is_empty() {
@@ -234,7 +273,7 @@ fn parse_session_file_typed_with_mode_internal(
     }
 
     let ext_type = detect_extension_type(&data)?;
-    dispatch_by_vec(data, ext_type, mode, path, None)
+    dispatch_by_vec(data, ext_type, mode, path, options)
 }
 
 /// Typed entry point when the caller already knows the provider.
@@ -276,7 +315,35 @@ pub fn parse_session_file_typed_as<P: AsRef<Path>>(
     mode: ParseMode,
 ) -> Result<CodeAnalysis> {
     let path = path.as_ref();
-    let parsed = parse_session_file_typed_as_with_diagnostics(path, provider, mode, None)?;
+    let parsed = parse_session_file_typed_as_with_diagnostics(
+        path,
+        provider,
+        mode,
+        ParseOptions::default(),
+    )?;
+    validate_parsed_source(path, &parsed.diagnostics)?;
+    Ok(parsed.analysis)
+}
+
+/// Full parse of one file under an explicit `encoding`, detecting the
+/// provider from content when `provider` is `None`.
+///
+/// Backs the encoding-keyed [`FileParseCache`](crate::cache::FileParseCache).
+pub(crate) fn parse_session_file_with_encoding(
+    path: &Path,
+    provider: Option<ExtensionType>,
+    encoding: TextEncoding,
+) -> Result<CodeAnalysis> {
+    let options = ParseOptions {
+        encoding,
+        ..ParseOptions::default()
+    };
+    let parsed = match provider {
+        Some(provider) => {
+            parse_session_file_typed_as_with_diagnostics(path, provider, ParseMode::Full, options)?
+        }
+        None => parse_session_file_typed_with_mode_internal(path, ParseMode::Full, options)?,
+    };
     validate_parsed_source(path, &parsed.diagnostics)?;
     Ok(parsed.analysis)
 }
@@ -285,24 +352,28 @@ pub(crate) fn parse_session_file_typed_as_with_diagnostics(
     path: &Path,
     provider: ExtensionType,
     mode: ParseMode,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<ParsedAnalysis> {
-    if let Some(parsed) = stream_parse_known(path, provider, mode, tiers)? {
+    let encoding = options.encoding;
+    if encoding == TextEncoding::Utf8
+        && let Some(parsed) = stream_parse_known(path, provider, mode, options)?
+    {
         return Ok(parsed);
     }
 
-    // Fallback for empty files or anything the streaming peek could not
-    // parse on line one.
-    let data = match read_jsonl(path) {
+    // Fallback for empty files, anything the streaming peek could not parse
+    // on line one (including invalid UTF-8, which `read_jsonl` reports by
+    // byte offset), and `--encoding lossy`.
+    let data = match read_jsonl_with(path, encoding, options.warnings) {
         Ok(data) => data,
-        Err(_) => read_json(path)?,
+        Err(_) => read_json_with(path, encoding, options.warnings)?,
     };
 
     if data.is_empty() {
         return Ok(empty_parsed_analysis());
     }
 
-    dispatch_by_vec(data, provider, mode, path, tiers)
+    dispatch_by_vec(data, provider, mode, path, options)
 }
 
 /// Streaming path when the provider is known from the caller's source.
@@ -324,8 +395,11 @@ fn stream_parse_known(
     path: &Path,
     provider: ExtensionType,
     mode: ParseMode,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<Option<ParsedAnalysis>> {
+    let ParseOptions {
+        tiers, dedupe_by, ..
+    } = options;
     match provider {
         ExtensionType::ClaudeCode => {
             let Some(mut stream) = prepare_typed_stream::<ClaudeCodeLog>(path, provider)? else {
@@ -345,6 +419,7 @@ fn stream_parse_known(
                 stream.first.into_iter().chain(rest),
                 mode,
                 tiers,
+                dedupe_by,
             );
             warnings.borrow().emit(path);
             if let Some(error) = io_failure.borrow_mut().take() {
@@ -373,7 +448,7 @@ fn stream_parse_known(
             let parsed = parse_codex_log_iter_with_diagnostics(
                 stream.first.into_iter().chain(rest),
                 mode,
-                tiers,
+                options,
             );
             warnings.borrow().emit(path);
             if let Some(error) = io_failure.borrow_mut().take() {
@@ -411,7 +486,7 @@ fn stream_parse_known(
                 provider,
             )))
         }
-        _ => stream_parse_known_dynamic(path, provider, mode, tiers),
+        _ => stream_parse_known_dynamic(path, provider, mode, options),
     }
 }
 
@@ -420,18 +495,18 @@ fn stream_parse_known_dynamic(
     path: &Path,
     provider: ExtensionType,
     mode: ParseMode,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<Option<ParsedAnalysis>> {
     let file =
         File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
     let mut reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
+    let mut first_line = Vec::with_capacity(buffer::AVG_JSONL_LINE_SIZE);
 
-    let first_line = match read_next_non_empty_line(&mut reader)? {
-        Some(line) => line,
-        None => return Ok(None), // empty file — caller returns the empty shape
-    };
+    if !read_next_non_empty_bytes(&mut reader, &mut first_line)? {
+        return Ok(None); // empty file — caller returns the empty shape
+    }
 
-    let first_value: Value = match serde_json::from_str(first_line.trim()) {
+    let first_value: Value = match serde_json::from_slice(trim_ascii_whitespace(&first_line)) {
         Ok(v) => v,
         Err(_) => return Ok(None), // not JSONL → caller falls back to read_json
     };
@@ -444,7 +519,7 @@ fn stream_parse_known_dynamic(
         ParseDiagnostics::default(),
         Rc::new(RefCell::new(ParseWarningSummary::default())),
         path,
-        tiers,
+        options,
     )?;
     Ok(Some(finalize(parsed, provider)))
 }
@@ -529,7 +604,11 @@ where
 ///
 /// Returns an error if the file cannot be opened or a line cannot be read, or
 /// if the resolved provider's dispatch step fails.
-fn stream_parse_autodetect(path: &Path, mode: ParseMode) -> Result<Option<ParsedAnalysis>> {
+fn stream_parse_autodetect(
+    path: &Path,
+    mode: ParseMode,
+    options: ParseOptions<'_>,
+) -> Result<Option<ParsedAnalysis>> {
     let file =
         File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
     let mut reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
@@ -541,15 +620,14 @@ fn stream_parse_autodetect(path: &Path, mode: ParseMode) -> Result<Option<Parsed
     let mut classifier = RecordClassifier::default();
     let warnings = Rc::new(RefCell::new(ParseWarningSummary::default()));
     let mut line_number = 0_usize;
+    let mut line = Vec::with_capacity(buffer::AVG_JSONL_LINE_SIZE);
 
-    loop {
-        let line = match read_next_non_empty_line(&mut reader)? {
-            Some(line) => line,
-            None => break,
-        };
+    // Byte lines: a non-UTF-8 first line reads as "not JSONL" and falls back
+    // to `read_jsonl`, which names the file and offset instead of failing here.
+    while read_next_non_empty_bytes(&mut reader, &mut line)? {
         line_number += 1;
 
-        match serde_json::from_str::<Value>(line.trim()) {
+        match serde_json::from_slice::<Value>(trim_ascii_whitespace(&line)) {
             Ok(v) => {
                 first_line_was_json.get_or_insert(true);
                 let classification = classifier.push(&v);
@@ -599,34 +677,11 @@ fn stream_parse_autodetect(path: &Path, mode: ParseMode) -> Result<Option<Parsed
         initial_diagnostics,
         warnings,
         path,
-        None,
+        options,
     )?;
     Ok(Some(finalize(parsed, ext)))
 }
 
-/// Reads lines from `reader` until it finds a non-empty one. Returns `Ok(None)`
-/// at EOF.
-///
-/// # Errors
-///
-/// Returns an error if the underlying `read_line` fails (e.g. an I/O error or
-/// invalid UTF-8 in the stream).
-fn read_next_non_empty_line<R: BufRead>(reader: &mut R) -> Result<Option<String>> {
-    let mut line = String::new();
-    loop {
-        line.clear();
-        let n = reader
-            .read_line(&mut line)
-            .context("Failed to read line from session file")?;
-        if n == 0 {
-            return Ok(None);
-        }
-        if !line.trim().is_empty() {
-            return Ok(Some(line));
-        }
-    }
-}
-
 /// Reuses `line` while reading through blank lines to the next JSONL record.
 fn read_next_non_empty_bytes<R: BufRead>(reader: &mut R, line: &mut Vec<u8>) -> Result<bool> {
     loop {
@@ -669,8 +724,11 @@ fn dispatch_streaming_buffered(
     initial_diagnostics: ParseDiagnostics,
     warnings: Rc<RefCell<ParseWarningSummary>>,
     path: &Path,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<ParsedAnalysis> {
+    let ParseOptions {
+        tiers, dedupe_by, ..
+    } = options;
     let extra_diagnostics = Rc::new(RefCell::new(initial_diagnostics));
     let io_failure = Rc::new(RefCell::new(None));
     let parsed = match ext {
@@ -684,7 +742,7 @@ fn dispatch_streaming_buffered(
             let logs = buffered.into_iter().chain(rest).filter_map(|value| {
                 deserialize_record::<ClaudeCodeLog>(value, ext, &extra_diagnostics, &warnings)
             });
-            parse_claude_logs_with_diagnostics(logs, mode, tiers)
+            parse_claude_logs_with_diagnostics(logs, mode, tiers, dedupe_by)
                 .map(|parsed| merge_extra_diagnostics(parsed, &extra_diagnostics))
         }
         ExtensionType::Codex => {
@@ -697,7 +755,7 @@ fn dispatch_streaming_buffered(
             let logs = buffered.into_iter().chain(rest).filter_map(|value| {
                 deserialize_record::<CodexLog>(value, ext, &extra_diagnostics, &warnings)
             });
-            parse_codex_log_iter_with_diagnostics(logs, mode, tiers)
+            parse_codex_log_iter_with_diagnostics(logs, mode, options)
                 .map(|parsed| merge_extra_diagnostics(parsed, &extra_diagnostics))
         }
         ExtensionType::Copilot => {
@@ -1067,8 +1125,11 @@ fn dispatch_by_vec(
     ext_type: ExtensionType,
     mode: ParseMode,
     path: &Path,
-    tiers: Option<&TierThresholds>,
+    options: ParseOptions<'_>,
 ) -> Result<ParsedAnalysis> {
+    let ParseOptions {
+        tiers, dedupe_by, ..
+    } = options;
     let extra_diagnostics = Rc::new(RefCell::new(ParseDiagnostics::default()));
     let warnings = Rc::new(RefCell::new(ParseWarningSummary::default()));
     let parsed = match ext_type {
@@ -1076,13 +1137,13 @@ fn dispatch_by_vec(
             let logs = data.into_iter().filter_map(|value| {
                 deserialize_record::<ClaudeCodeLog>(value, ext_type, &extra_diagnostics, &warnings)
             });
-            parse_claude_logs_with_diagnostics(logs, mode, tiers)?
+            parse_claude_logs_with_diagnostics(logs, mode, tiers, dedupe_by)?
         }
         ExtensionType::Codex => {
             let logs = data.into_iter().filter_map(|value| {
                 deserialize_record::<CodexLog>(value, ext_type, &extra_diagnostics, &warnings)
             });
-            parse_codex_log_iter_with_diagnostics(logs, mode, tiers)?
+            parse_codex_log_iter_with_diagnostics(logs, mode, options)?
         }
         ExtensionType::Copilot => {
             let events = data.into_iter().filter_map(|value| {
@@ -1181,7 +1242,7 @@ mod tests {
         std::fs::write(&path, contents).unwrap();
 
         reset_record_inspections();
-        let parsed = stream_parse_autodetect(&path, ParseMode::UsageOnly)
+        let parsed = stream_parse_autodetect(&path, ParseMode::UsageOnly, ParseOptions::default())
             .unwrap()
             .expect("JSONL source should use streaming detection");
 
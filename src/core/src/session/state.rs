@@ -8,9 +8,13 @@
 //! [`CodeAnalysisRecord`] via [`SessionParseState::into_record`].
 use crate::constants::{FastHashMap, FastHashSet};
 use crate::models::*;
-use crate::utils::count_lines;
+use crate::utils::{count_lines, find_git_root};
 use serde_json::Value;
 
+/// Trailing characters stripped before a body's lines are counted, so a
+/// CRLF- or CR-terminated body tallies the same as its LF equivalent.
+const LINE_TERMINATORS: [char; 2] = ['\r', '\n'];
+
 /// Controls how much per-operation detail the session parser retains.
 ///
 /// `Full` keeps everything that ends up in the public JSON output
@@ -52,6 +56,8 @@ pub struct SessionParseState {
     pub run_details: Vec<CodeAnalysisRunCommandDetail>,
     /// Running per-tool call counts (always tallied, both modes).
     pub tool_counts: CodeAnalysisToolCalls,
+    /// API error events seen, by kind (always tallied, both modes).
+    pub error_counts: ErrorCounts,
     /// Distinct normalized file paths touched (populated in both parse modes).
     pub unique_files: FastHashSet<String>,
     /// Sum of lines written across all `Write` operations.
@@ -70,6 +76,9 @@ pub struct SessionParseState {
     pub folder_path: String,
     /// Git remote URL for the session's repository, when known.
     pub git_remote: String,
+    /// Every distinct working directory the session ran in, in first-seen
+    /// order; see [`SessionParseState::note_working_dir`].
+    pub working_dirs: Vec<String>,
     /// Provider-specific session identifier.
     pub task_id: String,
     /// Latest event timestamp seen, in epoch milliseconds.
@@ -127,6 +136,7 @@ impl SessionParseState {
                 Vec::new()
             },
             tool_counts: CodeAnalysisToolCalls::default(),
+            error_counts: ErrorCounts::default(),
             unique_files: FastHashSet::with_capacity(20),
             total_write_lines: 0,
             total_read_lines: 0,
@@ -136,11 +146,27 @@ impl SessionParseState {
             total_edit_characters: 0,
             folder_path: String::new(),
             git_remote: String::new(),
+            working_dirs: Vec::new(),
             task_id: String::new(),
             last_ts: 0,
         }
     }
 
+    /// Records that the session ran in `cwd`.
+    ///
+    /// The first directory seen also becomes `folder_path`. Later, different
+    /// directories are kept so [`SessionParseState::into_record`] can list
+    /// every repository a session crossed into. Empty `cwd`s are ignored.
+    pub fn note_working_dir(&mut self, cwd: &str) {
+        if cwd.is_empty() || self.working_dirs.iter().any(|dir| dir == cwd) {
+            return;
+        }
+        if self.folder_path.is_empty() {
+            self.folder_path = cwd.to_string();
+        }
+        self.working_dirs.push(cwd.to_string());
+    }
+
     /// Records a `Read` operation against `path` with the read `content`.
     ///
     /// Trailing newlines are stripped before counting. No-op (and no count
@@ -148,7 +174,7 @@ impl SessionParseState {
     /// an empty string. The detail record is only stored in
     /// [`ParseMode::Full`]; counts and unique-file tracking accrue in both modes.
     pub fn add_read_detail(&mut self, path: &str, content: &str, ts: i64) {
-        let trimmed = content.trim_end_matches('\n');
+        let trimmed = content.trim_end_matches(LINE_TERMINATORS);
         let line_count = count_lines(trimmed);
 
         if line_count == 0 {
@@ -194,7 +220,7 @@ impl SessionParseState {
     /// zero-line write). The full detail (including `content`) is stored only in
     /// [`ParseMode::Full`]; unique-file tracking remains active in both modes.
     pub fn add_write_detail(&mut self, path: &str, content: &str, ts: i64) {
-        let trimmed = content.trim_end_matches('\n');
+        let trimmed = content.trim_end_matches(LINE_TERMINATORS);
         let line_count = count_lines(trimmed);
         let char_count = trimmed.chars().count();
         let resolved = self.normalize_path(path);
@@ -229,7 +255,9 @@ impl SessionParseState {
     /// [`SessionParseState::add_edit_detail_raw`].
     pub fn add_edit_detail(&mut self, path: &str, old: &str, new: &str, ts: i64) {
         // If old is empty and new has content, treat as write
-        if old.trim_end_matches('\n').is_empty() && !new.trim_end_matches('\n').is_empty() {
+        if old.trim_end_matches(LINE_TERMINATORS).is_empty()
+            && !new.trim_end_matches(LINE_TERMINATORS).is_empty()
+        {
             self.add_write_detail(path, new, ts);
             return;
         }
@@ -245,8 +273,8 @@ impl SessionParseState {
     /// the trimmed `new` content. No-op when `path` normalizes to an empty
     /// string; full detail stored only in [`ParseMode::Full`].
     pub fn add_edit_detail_raw(&mut self, path: &str, old: &str, new: &str, ts: i64) {
-        let trimmed_new = new.trim_end_matches('\n');
-        let trimmed_old = old.trim_end_matches('\n');
+        let trimmed_new = new.trim_end_matches(LINE_TERMINATORS);
+        let trimmed_old = old.trim_end_matches(LINE_TERMINATORS);
 
         let line_count = count_lines(trimmed_new);
         let char_count = trimmed_new.chars().count();
@@ -346,6 +374,7 @@ impl SessionParseState {
         self.tool_counts.edit += other.tool_counts.edit;
         self.tool_counts.todo_write += other.tool_counts.todo_write;
         self.tool_counts.bash += other.tool_counts.bash;
+        self.error_counts.merge(&other.error_counts);
         self.unique_files.extend(other.unique_files);
 
         self.total_write_lines += other.total_write_lines;
@@ -362,6 +391,11 @@ impl SessionParseState {
         if self.git_remote.is_empty() {
             self.git_remote = other.git_remote;
         }
+        for dir in other.working_dirs {
+            if !self.working_dirs.contains(&dir) {
+                self.working_dirs.push(dir);
+            }
+        }
         if self.task_id.is_empty() {
             self.task_id = other.task_id;
         }
@@ -372,10 +406,12 @@ impl SessionParseState {
     /// `conversation_usage` is the per-model token map the provider parser
     /// accumulated separately; it is folded into the record verbatim.
     pub fn into_record(self, conversation_usage: FastHashMap<String, Value>) -> CodeAnalysisRecord {
+        let repositories = distinct_repositories(&self.working_dirs);
         CodeAnalysisRecord {
             total_unique_files: self.unique_files.len(),
             total_write_lines: self.total_write_lines,
             total_read_lines: self.total_read_lines,
+            unique_read_lines: None,
             total_edit_lines: self.total_edit_lines,
             total_write_characters: self.total_write_characters,
             total_read_characters: self.total_read_characters,
@@ -385,16 +421,40 @@ impl SessionParseState {
             edit_file_details: self.edit_details,
             run_command_details: self.run_details,
             tool_call_counts: self.tool_counts,
+            error_counts: self.error_counts,
             conversation_usage,
             advisor_usage: FastHashMap::default(),
             task_id: self.task_id,
             timestamp: self.last_ts,
             folder_path: self.folder_path,
             git_remote_url: self.git_remote,
+            repositories,
+            raw_source: None,
         }
     }
 }
 
+/// The git roots of `working_dirs`, deduplicated in first-seen order, when
+/// they span more than one repository; empty otherwise. A directory outside
+/// any repository counts as its own root.
+fn distinct_repositories(working_dirs: &[String]) -> Vec<String> {
+    if working_dirs.len() < 2 {
+        return Vec::new();
+    }
+    let mut roots: Vec<String> = Vec::new();
+    for dir in working_dirs {
+        let root = find_git_root(dir)
+            .map_or_else(|| dir.clone(), |root| root.to_string_lossy().into_owned());
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+    }
+    if roots.len() < 2 {
+        roots.clear();
+    }
+    roots
+}
+
 impl Default for SessionParseState {
     /// Equivalent to [`SessionParseState::new`] ([`ParseMode::Full`]).
     fn default() -> Self {
@@ -435,6 +495,29 @@ mod tests {
         assert!(state.unique_files.contains("/test/folder/test.rs"));
     }
 
+    #[test]
+    fn test_line_endings_tally_the_same() {
+        // CRLF, lone CR, and LF bodies (with or without a trailing terminator)
+        // all record three lines of the same three characters each.
+        for content in [
+            "one\ntwo\nsix\n",
+            "one\r\ntwo\r\nsix\r\n",
+            "one\rtwo\rsix\r",
+            "one\r\ntwo\r\nsix",
+        ] {
+            let mut state = SessionParseState::new();
+            state.add_read_detail("a.rs", content, 0);
+            state.add_write_detail("b.rs", content, 0);
+            assert_eq!(state.total_read_lines, 3, "{content:?}");
+            assert_eq!(state.total_write_lines, 3, "{content:?}");
+            assert_eq!(
+                state.total_read_characters,
+                content.trim_end_matches(['\r', '\n']).chars().count(),
+                "{content:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_add_read_detail_ignores_empty() {
         // Test that empty content is ignored
@@ -0,0 +1,63 @@
+//! Reads which version of the assistant CLI wrote a session file.
+//!
+//! Claude Code stamps `version` on every record, Codex records `cli_version`
+//! in its leading `session_meta`, and Copilot CLI records `copilotVersion` in
+//! its `session.start` event. Gemini and Grok sessions carry no version.
+//! Only a bounded prefix of the file is read, so the lookup stays cheap next
+//! to a full parse.
+
+use crate::models::ExtensionType;
+use serde_json::Value;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Lines scanned before giving up; Claude Code can open a file with a few
+/// version-less `summary` records.
+const MAX_SCANNED_LINES: usize = 64;
+
+/// Returns the tool version recorded in the session file at `path`, or `None`
+/// when `provider` records none, the file cannot be read, or no version
+/// appears within the first lines.
+pub fn read_tool_version(path: &Path, provider: ExtensionType) -> Option<String> {
+    let pointer = match provider {
+        ExtensionType::ClaudeCode => "/version",
+        ExtensionType::Codex => "/payload/cli_version",
+        ExtensionType::Copilot => "/data/copilotVersion",
+        _ => return None,
+    };
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let mut line = Vec::new();
+    for _ in 0..MAX_SCANNED_LINES {
+        line.clear();
+        if reader.read_until(b'\n', &mut line).ok()? == 0 {
+            break;
+        }
+        let Ok(record) = serde_json::from_slice::<Value>(&line) else {
+            continue;
+        };
+        if let Some(version) = record.pointer(pointer).and_then(Value::as_str) {
+            return Some(version.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn reads_the_first_recorded_version_per_provider() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"type":"summary"}}"#).unwrap();
+        writeln!(file, r#"{{"type":"user","version":"1.0.95"}}"#).unwrap();
+        assert_eq!(
+            read_tool_version(file.path(), ExtensionType::ClaudeCode).as_deref(),
+            Some("1.0.95")
+        );
+        assert_eq!(read_tool_version(file.path(), ExtensionType::Codex), None);
+        assert_eq!(read_tool_version(file.path(), ExtensionType::Gemini), None);
+    }
+}
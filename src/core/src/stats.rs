@@ -0,0 +1,234 @@
+//! The `vct stats` one-screen overview.
+//!
+//! Curates the handful of numbers most people want from both pipelines —
+//! today's and this month's cost, all-time tokens per provider, today's
+//! written lines, the most expensive model, and active days — so a first run
+//! does not have to pick between `usage` and `analysis`. Every figure comes
+//! from the same summaries those commands render, so the overview always
+//! agrees with them.
+
+use crate::analysis::{
+    AnalysisScanOptions, ScanDiagnostics, aggregate_sessions_by_model_with_diagnostics_opts,
+    calculate_analysis_provider_totals_from_per_provider,
+};
+use crate::config::ProvidersConfig;
+use crate::models::{Provider, TimeRange};
+use crate::pricing::{ModelPricingMap, ThresholdBasis};
+use crate::usage::summary::{UsageProviderTotals, UsageSummary, build_usage_summary};
+use crate::usage::{
+    UsageCollection, UsageScanOptions, aggregate_usage_from_home_with_diagnostics_opts,
+    cost_on_date, fetch_pricing_or_empty, priced_scan_options,
+};
+use crate::utils::{SessionIgnore, get_current_date};
+use crate::warnings::WarningSink;
+use anyhow::Result;
+use serde::Serialize;
+
+/// Footer order of the per-provider rows, matching the `usage` table.
+const PROVIDER_ORDER: [Provider; 8] = [
+    Provider::ClaudeCode,
+    Provider::Codex,
+    Provider::Copilot,
+    Provider::Gemini,
+    Provider::Grok,
+    Provider::OpenCode,
+    Provider::Cursor,
+    Provider::Hermes,
+];
+
+/// One provider's all-time token and cost totals.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderTokens {
+    /// Provider display name (e.g. `Claude`).
+    pub provider: String,
+    /// Total tokens across every model the provider recorded.
+    pub tokens: i64,
+    /// Cost in USD of those tokens.
+    pub cost_usd: f64,
+}
+
+/// The model with the highest all-time cost.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TopModel {
+    /// Model name as reported by the sessions.
+    pub model: String,
+    /// Total tokens for the model.
+    pub tokens: i64,
+    /// Cost in USD for the model.
+    pub cost_usd: f64,
+}
+
+/// The `vct stats` digest; also the `stats --json` payload.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsOverview {
+    /// Cost in USD of today's usage.
+    pub today_cost_usd: f64,
+    /// Cost in USD of this calendar month's usage.
+    pub month_cost_usd: f64,
+    /// All-time tokens across every provider.
+    pub total_tokens: i64,
+    /// All-time tokens and cost per provider with any activity, in the
+    /// `usage` footer's order.
+    pub tokens_by_provider: Vec<ProviderTokens>,
+    /// Lines added today by `Write` and `Edit` operations.
+    pub lines_written_today: usize,
+    /// Highest-cost model of all time; `None` when nothing was recorded.
+    pub top_model: Option<TopModel>,
+    /// Distinct days with any usage, across all providers.
+    pub active_days: usize,
+}
+
+/// A built [`StatsOverview`] plus what the caller should surface about it.
+pub struct StatsScan {
+    /// The digest itself.
+    pub overview: StatsOverview,
+    /// The pricing-fetch error when costs degraded to zero.
+    pub pricing_error: Option<String>,
+    /// Diagnostics of the all-time usage scan.
+    pub usage_diagnostics: ScanDiagnostics,
+    /// Diagnostics of today's analysis scan.
+    pub analysis_diagnostics: ScanDiagnostics,
+}
+
+/// Scans all-time usage and today's analysis, then folds them into a
+/// [`StatsOverview`].
+///
+/// Pricing is fetched once and degrades like
+/// [`scan_usage_priced`](crate::usage::scan_usage_priced). Usage is scanned
+/// once: today's and this month's costs are sliced from its per-date
+/// breakdown, priced like the `usage` footer's "Today" cost. Both scans skip
+/// the sessions matched by `ignore`, record run warnings in `warnings`, and
+/// run on `pool`.
+///
+/// # Errors
+///
+/// Propagates a hard scan failure (an all-failed collection).
+pub fn collect_stats(
+    providers: ProvidersConfig,
+    basis: ThresholdBasis,
+    ignore: &SessionIgnore,
+    warnings: &WarningSink,
+    pool: &rayon::ThreadPool,
+) -> Result<StatsScan> {
+    let (pricing, pricing_error) = fetch_pricing_or_empty(warnings);
+    let options = UsageScanOptions {
+        ignore: ignore.clone(),
+        warnings: warnings.clone(),
+        ..priced_scan_options(&pricing, basis)
+    };
+    let all = pool.install(|| {
+        aggregate_usage_from_home_with_diagnostics_opts(TimeRange::All, providers, &options)
+    })?;
+    let analysis_options = AnalysisScanOptions {
+        ignore: ignore.clone(),
+        warnings: warnings.clone(),
+        ..AnalysisScanOptions::default()
+    };
+    let analysis = pool.install(|| {
+        aggregate_sessions_by_model_with_diagnostics_opts(
+            TimeRange::Daily,
+            providers,
+            &analysis_options,
+        )
+    })?;
+
+    let written = calculate_analysis_provider_totals_from_per_provider(
+        &analysis.data.per_provider,
+        &analysis.data.provider_days,
+    )
+    .overall;
+
+    let today = get_current_date();
+    // `YYYY-MM-DD` keys sort chronologically, so "this month" is every date
+    // from the first of the month on.
+    let month_start = format!("{}-01", &today[..7]);
+    let cost_since = |first: &str| -> f64 {
+        all.data
+            .per_date
+            .keys()
+            .filter(|date| date.as_str() >= first)
+            .map(|date| cost_on_date(&all.data, date, &pricing))
+            .sum()
+    };
+
+    let all_summary = summarize(&all, &pricing);
+    let overview = StatsOverview {
+        today_cost_usd: cost_since(&today),
+        month_cost_usd: cost_since(&month_start),
+        total_tokens: all_summary.totals.total,
+        tokens_by_provider: provider_tokens(&all_summary.provider_totals),
+        lines_written_today: written.total_write_lines + written.total_edit_lines,
+        // Rows are sorted by ascending cost, so the last one is the top spender.
+        top_model: all_summary.rows.last().map(|row| TopModel {
+            model: row.model.clone(),
+            tokens: row.total,
+            cost_usd: row.cost,
+        }),
+        active_days: all.data.provider_days.total,
+    };
+
+    Ok(StatsScan {
+        overview,
+        pricing_error,
+        usage_diagnostics: all.diagnostics,
+        analysis_diagnostics: analysis.diagnostics,
+    })
+}
+
+/// Prices one usage window exactly as the `usage` table does.
+fn summarize(collection: &UsageCollection, pricing: &ModelPricingMap) -> UsageSummary {
+    let data = &collection.data;
+    build_usage_summary(
+        &data.models,
+        &data.per_provider,
+        &data.provider_days,
+        pricing,
+        &data.stored_costs,
+        &data.model_renames,
+        None,
+    )
+}
+
+/// The providers with at least one active day, in footer order.
+fn provider_tokens(totals: &UsageProviderTotals) -> Vec<ProviderTokens> {
+    PROVIDER_ORDER
+        .iter()
+        .map(|&provider| (provider, totals.get_stats(provider)))
+        .filter(|(_, stats)| stats.days_count > 0)
+        .map(|(provider, stats)| ProviderTokens {
+            provider: provider.display_name().to_string(),
+            tokens: stats.total_tokens,
+            cost_usd: stats.total_cost,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usage::summary::ProviderStats;
+
+    #[test]
+    fn provider_tokens_skips_idle_providers_and_keeps_footer_order() {
+        let active = |tokens| ProviderStats {
+            total_tokens: tokens,
+            total_cost: 1.5,
+            days_count: 2,
+        };
+        let totals = UsageProviderTotals {
+            grok: active(10),
+            claude: active(30),
+            // No active day: hidden.
+            codex: ProviderStats {
+                total_tokens: 99,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let rows = provider_tokens(&totals);
+        let names: Vec<&str> = rows.iter().map(|r| r.provider.as_str()).collect();
+        assert_eq!(names, ["Claude", "Grok"]);
+        assert_eq!(rows[0].tokens, 30);
+    }
+}
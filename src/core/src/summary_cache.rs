@@ -2,10 +2,13 @@
 
 use crate::constants::{FastHashMap, FastHashSet};
 use crate::models::TimeRange;
-use crate::models::{AggregatedAnalysisRow, CodeAnalysis, ExtensionType, UsageResult};
+use crate::models::{
+    AggregatedAnalysisRow, CodeAnalysis, CodeAnalysisRecord, ExtensionType, UNATTRIBUTED_MODEL,
+    UsageResult,
+};
 use crate::session::diagnostics::{UsageContribution, UsageTokenContribution};
 use crate::session::sqlite::{DatabaseFingerprint, append_suffix};
-use crate::utils::{extract_token_counts, merge_usage_values};
+use crate::utils::{DedupeBy, TextEncoding, extract_token_counts, merge_usage_values};
 use anyhow::Result;
 use std::collections::HashSet;
 use std::fs;
@@ -34,7 +37,7 @@ pub struct SummaryScanCache {
     entries: FastHashMap<SummaryCacheKey, CachedSourceSummary>,
     parsed_sources: usize,
     total_parsed_sources: usize,
-    tier_fingerprint: u64,
+    parse_settings: (u64, TextEncoding, Option<DedupeBy>),
 }
 
 impl SummaryScanCache {
@@ -48,16 +51,26 @@ impl SummaryScanCache {
         self.parsed_sources = 0;
     }
 
-    /// Drops every entry when the context-tier snapshot changed.
+    /// Drops every entry when the parse settings changed: the context-tier
+    /// snapshot's `tier_fingerprint`, the text `encoding`, or the `dedupe_by`
+    /// key.
     ///
     /// Cached summaries embed the per-request tier classification, so a new
     /// thresholds snapshot (daily pricing reload, or pricing becoming
-    /// available after an offline start) must invalidate them; unchanged
-    /// snapshots keep the incremental behavior.
-    pub(crate) fn ensure_tier_fingerprint(&mut self, fingerprint: u64) {
-        if self.tier_fingerprint != fingerprint {
+    /// available after an offline start) must invalidate them, a file that
+    /// failed as strict UTF-8 may parse under lossy decoding, and deduplication
+    /// changes the summed tokens; unchanged settings keep the incremental
+    /// behavior.
+    pub(crate) fn ensure_parse_settings(
+        &mut self,
+        tier_fingerprint: u64,
+        encoding: TextEncoding,
+        dedupe_by: Option<DedupeBy>,
+    ) {
+        let settings = (tier_fingerprint, encoding, dedupe_by);
+        if self.parse_settings != settings {
             self.entries.clear();
-            self.tier_fingerprint = fingerprint;
+            self.parse_settings = settings;
         }
     }
 
@@ -297,6 +310,11 @@ pub(crate) struct CompactSourceSummary {
     /// Typed database usage. SQLite rows never need a per-row JSON object or
     /// model map in the incremental path.
     pub(crate) database_usage: FastHashMap<String, UsageTokenContribution>,
+    /// `database_usage` and its stored cost split by `(date, model)`, for the
+    /// per-date breakdown; a file's usage all falls on its one usage date.
+    pub(crate) database_daily: FastHashMap<(String, String), (UsageTokenContribution, f64)>,
+    /// The sessions behind each `database_daily` entry.
+    pub(crate) database_sessions: FastHashMap<(String, String), FastHashSet<String>>,
     pub(crate) stored_costs: FastHashMap<String, f64>,
     pub(crate) usage_dates: HashSet<String>,
     pub(crate) analysis: FastHashMap<String, AggregatedAnalysisRow>,
@@ -316,22 +334,40 @@ impl CompactSourceSummary {
         let UsageContribution {
             date,
             timestamp_ms: _,
+            session,
             model,
             tokens,
             stored_cost,
         } = contribution;
         let date_has_usage = stored_cost != 0.0 || tokens.has_activity();
+        if !date_has_usage {
+            // Nothing billed: no zero-token usage row.
+            return;
+        }
         *self.stored_costs.entry(model.clone()).or_insert(0.0) += stored_cost;
         self.database_usage
-            .entry(model)
+            .entry(model.clone())
             .and_modify(|existing| existing.merge(tokens))
             .or_insert(tokens);
-        if date_has_usage {
-            self.usage_dates.insert(date);
-        }
+        self.database_sessions
+            .entry((date.clone(), model.clone()))
+            .or_default()
+            .insert(session);
+        let day = self
+            .database_daily
+            .entry((date.clone(), model))
+            .or_default();
+        day.0.merge(tokens);
+        day.1 += stored_cost;
+        self.usage_dates.insert(date);
     }
 
     /// Folds one owned analysis row and optional provider-stored cost.
+    ///
+    /// A model whose tokens are all zero (and that carries no stored cost)
+    /// adds no `usage` row. A tool-only record with no token events still
+    /// counts in `analysis`, under
+    /// [`UNATTRIBUTED_MODEL`](crate::models::UNATTRIBUTED_MODEL).
     pub(crate) fn add_analysis(
         &mut self,
         analysis: CodeAnalysis,
@@ -341,56 +377,31 @@ impl CompactSourceSummary {
     ) {
         let mut date_has_usage = stored_cost != 0.0;
         for record in analysis.records {
-            let counters = (
-                record.total_edit_lines,
-                record.total_read_lines,
-                record.total_write_lines,
-                record.tool_call_counts.bash,
-                record.tool_call_counts.edit,
-                record.tool_call_counts.read,
-                record.tool_call_counts.todo_write,
-                record.tool_call_counts.write,
-            );
+            let counters = AnalysisCounters::of(&record);
+            let unattributed = record.conversation_usage.is_empty() && record.has_tool_activity();
 
             for (model, usage) in record.conversation_usage {
-                if meaningful_usage(&usage) {
-                    date_has_usage = true;
-                }
+                let active = meaningful_usage(&usage);
+                date_has_usage |= active;
                 if stored_cost != 0.0 {
                     *self.stored_costs.entry(model.clone()).or_insert(0.0) += stored_cost;
                 }
-                merge_model_usage(&mut self.usage, model.clone(), usage);
-
                 if emit_analysis && !model.contains("<synthetic>") {
-                    let row = self.analysis.entry(model.clone()).or_insert_with(|| {
-                        AggregatedAnalysisRow {
-                            model,
-                            edit_lines: 0,
-                            read_lines: 0,
-                            write_lines: 0,
-                            bash_count: 0,
-                            edit_count: 0,
-                            read_count: 0,
-                            todo_write_count: 0,
-                            write_count: 0,
-                        }
-                    });
-                    row.edit_lines += counters.0;
-                    row.read_lines += counters.1;
-                    row.write_lines += counters.2;
-                    row.bash_count += counters.3;
-                    row.edit_count += counters.4;
-                    row.read_count += counters.5;
-                    row.todo_write_count += counters.6;
-                    row.write_count += counters.7;
+                    counters.add_to(&mut self.analysis, &model);
+                }
+                if active || stored_cost != 0.0 {
+                    merge_model_usage(&mut self.usage, model, usage);
                 }
             }
+            if emit_analysis && unattributed {
+                counters.add_to(&mut self.analysis, UNATTRIBUTED_MODEL);
+            }
 
             for (model, usage) in record.advisor_usage {
                 if meaningful_usage(&usage) {
                     date_has_usage = true;
+                    merge_model_usage(&mut self.usage, model, usage);
                 }
-                merge_model_usage(&mut self.usage, model, usage);
             }
         }
 
@@ -403,6 +414,58 @@ impl CompactSourceSummary {
     }
 }
 
+/// One record's line and tool-call counters, added to each model it ran under.
+#[derive(Clone, Copy)]
+struct AnalysisCounters {
+    edit_lines: usize,
+    read_lines: usize,
+    write_lines: usize,
+    bash_count: usize,
+    edit_count: usize,
+    read_count: usize,
+    todo_write_count: usize,
+    write_count: usize,
+}
+
+impl AnalysisCounters {
+    fn of(record: &CodeAnalysisRecord) -> Self {
+        Self {
+            edit_lines: record.total_edit_lines,
+            read_lines: record.total_read_lines,
+            write_lines: record.total_write_lines,
+            bash_count: record.tool_call_counts.bash,
+            edit_count: record.tool_call_counts.edit,
+            read_count: record.tool_call_counts.read,
+            todo_write_count: record.tool_call_counts.todo_write,
+            write_count: record.tool_call_counts.write,
+        }
+    }
+
+    fn add_to(self, rows: &mut FastHashMap<String, AggregatedAnalysisRow>, model: &str) {
+        let row = rows
+            .entry(model.to_string())
+            .or_insert_with(|| AggregatedAnalysisRow {
+                model: model.to_string(),
+                edit_lines: 0,
+                read_lines: 0,
+                write_lines: 0,
+                bash_count: 0,
+                edit_count: 0,
+                read_count: 0,
+                todo_write_count: 0,
+                write_count: 0,
+            });
+        row.edit_lines += self.edit_lines;
+        row.read_lines += self.read_lines;
+        row.write_lines += self.write_lines;
+        row.bash_count += self.bash_count;
+        row.edit_count += self.edit_count;
+        row.read_count += self.read_count;
+        row.todo_write_count += self.todo_write_count;
+        row.write_count += self.write_count;
+    }
+}
+
 fn merge_model_usage(result: &mut UsageResult, model: String, usage: serde_json::Value) {
     result
         .entry(model)
@@ -417,7 +480,7 @@ fn meaningful_usage(value: &serde_json::Value) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{CodeAnalysisRecord, CodeAnalysisToolCalls};
+    use crate::models::{CodeAnalysisRecord, CodeAnalysisToolCalls, ErrorCounts};
     use serde_json::json;
 
     fn analysis_with_usage(tokens: i64) -> CodeAnalysis {
@@ -436,6 +499,7 @@ mod tests {
                 total_unique_files: 0,
                 total_write_lines: 0,
                 total_read_lines: 0,
+                unique_read_lines: None,
                 total_edit_lines: 0,
                 total_write_characters: 0,
                 total_read_characters: 0,
@@ -446,11 +510,14 @@ mod tests {
                 run_command_details: Vec::new(),
                 tool_call_counts: CodeAnalysisToolCalls::default(),
                 conversation_usage: usage,
+                error_counts: ErrorCounts::default(),
                 advisor_usage: FastHashMap::default(),
                 task_id: String::new(),
                 timestamp: 0,
                 folder_path: String::new(),
                 git_remote_url: String::new(),
+                repositories: Vec::new(),
+                raw_source: None,
             }],
         }
     }
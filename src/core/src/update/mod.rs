@@ -80,18 +80,7 @@ fn get_version_comparison() -> Result<Option<(String, Version, Version, GitHubRe
     let release =
         github::fetch_latest_release().context("Failed to fetch latest release information")?;
 
-    let (current_version_display, current_version) = get_current_version()?;
-
-    // Remove 'v' prefix if present and parse as semver
-    let latest_version_str = release.tag_name.trim_start_matches('v');
-    let latest_version = Version::parse(latest_version_str).context(format!(
-        "Failed to parse latest version: {}",
-        latest_version_str
-    ))?;
-
-    // Record the check for the future auto-update prompt, regardless of whether
-    // a newer version exists. Best-effort: a write failure never blocks update.
-    let _ = version_cache::record_version_check(&latest_version.to_string());
+    let (current_version_display, current_version, latest_version) = compare_release(&release)?;
 
     if latest_version <= current_version {
         println!("Already on the latest version (v{})", current_version);
@@ -106,21 +95,97 @@ fn get_version_comparison() -> Result<Option<(String, Version, Version, GitHubRe
     )))
 }
 
+/// Parses `release`'s tag and the running version for comparison, returning
+/// `(current_display, current, latest)`.
+///
+/// Records the check for the future auto-update prompt, regardless of whether
+/// a newer version exists. Best-effort: a write failure never blocks update.
+fn compare_release(release: &GitHubRelease) -> Result<(String, Version, Version)> {
+    let (current_version_display, current_version) = get_current_version()?;
+    let latest_version = parse_release_tag(release)?;
+    let _ = version_cache::record_version_check(&latest_version.to_string());
+    Ok((current_version_display, current_version, latest_version))
+}
+
+/// Parses a release tag as semver, trimming its leading `v`.
+fn parse_release_tag(release: &GitHubRelease) -> Result<Version> {
+    let latest_version_str = release.tag_name.trim_start_matches('v');
+    Version::parse(latest_version_str).context(format!(
+        "Failed to parse latest version: {}",
+        latest_version_str
+    ))
+}
+
+/// Machine-readable result of an update check (`vct update --check --json`).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct UpdateStatus {
+    /// Running version, without any git suffix.
+    pub current: String,
+    /// Latest released version.
+    pub latest: String,
+    /// Whether `latest` is strictly newer than `current`.
+    pub update_available: bool,
+    /// The latest release's tag, as published (e.g. `"v0.6.0"`).
+    pub tag: String,
+}
+
+impl UpdateStatus {
+    /// Compares `release` against the running version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either version is not valid semver.
+    pub fn from_release(release: &GitHubRelease) -> Result<Self> {
+        let (_, current) = get_current_version()?;
+        let latest = parse_release_tag(release)?;
+        Ok(Self {
+            current: current.to_string(),
+            latest: latest.to_string(),
+            update_available: latest > current,
+            tag: release.tag_name.clone(),
+        })
+    }
+}
+
+/// [`check_update`] without any printing, for scripted callers.
+///
+/// Like [`check_update`], offline mode (`VCT_OFFLINE`) is an error here: a
+/// script asking whether an update exists must not read "no" from a check
+/// that never ran.
+///
+/// # Errors
+///
+/// Returns an [`Offline`](crate::utils::Offline) error if network access is
+/// disabled, or an error if the GitHub fetch fails or a version cannot be
+/// parsed.
+pub fn check_update_status() -> Result<UpdateStatus> {
+    ensure_online()?;
+    let release =
+        github::fetch_latest_release().context("Failed to fetch latest release information")?;
+    let status = UpdateStatus::from_release(&release)?;
+    let _ = version_cache::record_version_check(&status.latest);
+    Ok(status)
+}
+
 /// Probes for a newer release without installing anything.
 ///
-/// Prints an "update available" line and returns `Some(tag_name)` when a newer
-/// release exists, or `None` when already current. This is the read-only path
-/// behind `vct update --check`.
+/// Prints an "update available" line and returns the newer release (tag and
+/// notes) when one exists, or `None` when already current. This is the
+/// read-only path behind `vct update --check`.
 ///
 /// # Errors
 ///
-/// Returns an error if the version comparison fails — i.e. the GitHub fetch or
-/// any version parse fails (see `get_version_comparison`).
-pub fn check_update() -> Result<Option<String>> {
-    // Offline mode: skip the GitHub Releases probe entirely.
-    if crate::utils::network_disabled() {
-        return Ok(None);
-    }
+/// Returns an [`Offline`](crate::utils::Offline) error if network access is
+/// disabled (`VCT_OFFLINE`), or an error if the version comparison fails —
+/// i.e. the GitHub fetch or any version parse fails (see
+/// `get_version_comparison`).
+pub fn check_update() -> Result<Option<GitHubRelease>> {
+    ensure_online()?;
+    probe_update()
+}
+
+/// [`check_update`] without the offline guard, for `vct update` itself.
+fn probe_update() -> Result<Option<GitHubRelease>> {
     match get_version_comparison()? {
         Some((current_version, _, latest_version, release)) => {
             println!(
@@ -128,7 +193,7 @@ pub fn check_update() -> Result<Option<String>> {
                 extract_semver_version(&current_version),
                 latest_version
             );
-            Ok(Some(release.tag_name))
+            Ok(Some(release))
         }
         None => Ok(None),
     }
@@ -275,19 +340,31 @@ pub fn perform_force_update() -> Result<()> {
     perform_installation(&current_version_display, &latest_version, &release)
 }
 
+/// Fails with [`Offline`](crate::utils::Offline) when `VCT_OFFLINE` disables
+/// the GitHub Releases probe, so no update check ever reports "up to date"
+/// without having run.
+fn ensure_online() -> Result<()> {
+    if crate::utils::network_disabled() {
+        return Err(crate::utils::Offline("Cannot check for updates".to_string()).into());
+    }
+    Ok(())
+}
+
 /// Runs the `vct update` flow, optionally prompting for confirmation.
 ///
 /// With `force` set, skips the freshness check and the prompt and reinstalls
 /// the latest release outright. Otherwise it checks for a newer version and,
 /// only if one exists, asks for `y`/`N` confirmation on stdin before
-/// installing — anything other than `y` cancels.
+/// installing — anything other than `y` cancels. When `show_notes` is given,
+/// it receives the newer release's notes ahead of the prompt (the
+/// `--preview` flag; the presentation layer decides how to render them).
 ///
 /// # Errors
 ///
 /// Returns an error if the update check or install fails (network, version
 /// parse, asset selection, extraction, or binary swap), or if reading the
 /// confirmation from stdin fails.
-pub fn update_interactive(force: bool) -> Result<()> {
+pub fn update_interactive(force: bool, show_notes: Option<&dyn Fn(&str)>) -> Result<()> {
     println!("Checking for updates...");
 
     if force {
@@ -295,7 +372,10 @@ pub fn update_interactive(force: bool) -> Result<()> {
         perform_force_update()
     } else {
         // Normal update: check version and prompt for confirmation
-        if check_update()?.is_some() {
+        if let Some(release) = probe_update()? {
+            if let (Some(show_notes), Some(body)) = (show_notes, &release.body) {
+                show_notes(body);
+            }
             print!("Continue? (y/N): ");
             std::io::Write::flush(&mut std::io::stdout())?;
 
@@ -323,6 +403,43 @@ pub use platform::get_asset_pattern;
 mod tests {
     use super::*;
 
+    #[test]
+    fn update_status_serializes_the_comparison_against_a_mocked_release() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/releases/latest");
+            then.status(200).json_body(serde_json::json!({
+                "tag_name": "v999.0.0", "name": "Release 999", "body": null, "assets": []
+            }));
+        });
+        let release = github::fetch_latest_release_from(&server.url("/releases/latest")).unwrap();
+
+        let status = UpdateStatus::from_release(&release).unwrap();
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            serde_json::json!({
+                "current": extract_semver_version(crate::VERSION),
+                "latest": "999.0.0",
+                "update_available": true,
+                "tag": "v999.0.0"
+            })
+        );
+
+        let current = GitHubRelease {
+            tag_name: format!("v{}", extract_semver_version(crate::VERSION)),
+            name: String::new(),
+            body: None,
+            assets: Vec::new(),
+        };
+        assert!(
+            !UpdateStatus::from_release(&current)
+                .unwrap()
+                .update_available
+        );
+    }
+
     #[test]
     fn test_extract_semver_version_clean() {
         // Test extracting clean semver version
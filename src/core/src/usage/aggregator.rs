@@ -15,31 +15,34 @@ use crate::models::{
     CodeAnalysis, ExtensionType, PerProviderUsage, Provider, ProviderActiveDays, UsageResult,
 };
 use crate::pricing::TierThresholds;
+use crate::session::cursor::read_cursor_usage_rows;
 use crate::session::cursor::{
     discover_cursor_store_dbs, load_conversation_model_snapshot, read_cursor_usage_store,
 };
-use crate::session::diagnostics::DatabaseUsageRead;
+use crate::session::diagnostics::{DatabaseUsageRead, UsageContribution};
 use crate::session::hermes::read_hermes_usage_contributions;
-use crate::session::opencode::read_opencode_usage_contributions;
+use crate::session::opencode::{read_opencode_usage_contributions, read_opencode_usage_rows};
+use crate::session::parser::ParseOptions;
 use crate::session::sqlite::is_cacheable_sqlite_failure;
-use crate::session::{
-    ParseMode, parse_session_file_typed_as, read_cursor_usage, read_hermes_usage,
-    read_opencode_usage,
-};
+use crate::session::{ParseMode, parse_session_file_typed_as};
 use crate::summary_cache::{
     CompactSourceSummary, SourceFingerprint, SummaryCacheKey, SummaryKind, SummaryScanCache,
 };
+use crate::utils::fold_case::fold_keys;
+use crate::utils::model_fixup::{ModelFixups, rewrite_model_name};
 use crate::utils::{
-    COPILOT_SESSION_MAX_DEPTH, GROK_SESSION_MAX_DEPTH, HelperPaths, collect_files_with_max_depth,
-    is_claude_session_file, is_codex_session_file, is_copilot_session_file, is_gemini_session_file,
-    is_grok_session_file, merge_usage_values, resolve_paths,
+    COPILOT_SESSION_MAX_DEPTH, DedupeBy, GROK_SESSION_MAX_DEPTH, HelperPaths, SessionIgnore,
+    TextEncoding, collect_files_with_max_depth, extract_token_counts, is_claude_session_file,
+    is_codex_session_file, is_copilot_session_file, is_gemini_session_file, is_grok_session_file,
+    merge_usage_values, resolve_paths,
 };
+use crate::warnings::WarningSink;
 use anyhow::Result;
 use rayon::prelude::*;
 use serde::Serialize;
 use serde_json::Value;
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Aggregated token usage plus the per-provider active-day counts.
@@ -82,6 +85,142 @@ pub struct UsageData {
     pub provider_days: ProviderActiveDays,
     /// Provider-authoritative per-model cost (USD), summed from the source.
     pub stored_costs: StoredCosts,
+    /// The distinct sessions that contributed each model, per local date.
+    pub sessions: SessionIndex,
+    /// The same per-provider usage and stored costs, split by local
+    /// `YYYY-MM-DD` date (a session file's modified date, a database row's own
+    /// date). Feeds `usage --group-by`; filled by the diagnostics-aware scans
+    /// and left empty by the legacy [`aggregate_usage_from_paths`] family.
+    pub per_date: BTreeMap<String, DailyUsage>,
+    /// The name each `--fixup-model`-renamed model is shown under. The maps
+    /// above keep the reported names so every model is priced under its own
+    /// LiteLLM entry; the priced rows are renamed and merged afterwards.
+    #[serde(skip_serializing_if = "ModelRenames::is_empty")]
+    pub model_renames: ModelRenames,
+}
+
+/// The distinct sessions that contributed usage, keyed by local `YYYY-MM-DD`
+/// date and then model, each with the display name of the provider that
+/// recorded it.
+///
+/// A session file is one session, named by its path; a database-backed
+/// provider contributes one session per session id it records (a Cursor store
+/// is one conversation). Every count is the size of a union of ids, so a
+/// session spanning two dates, or reporting two models later merged under one
+/// name, still counts once.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SessionIndex(BTreeMap<String, FastHashMap<String, BTreeMap<String, &'static str>>>);
+
+impl SessionIndex {
+    /// Records that `session`, from `provider`, reported `model` on `date`.
+    fn record(&mut self, date: &str, model: &str, session: &str, provider: Provider) {
+        self.0
+            .entry(date.to_string())
+            .or_default()
+            .entry(model.to_string())
+            .or_default()
+            .insert(session.to_string(), provider.display_name());
+    }
+
+    /// The sessions that reported `model` on any date.
+    pub fn for_model(&self, model: &str) -> BTreeSet<String> {
+        self.0
+            .values()
+            .filter_map(|models| models.get(model))
+            .flat_map(BTreeMap::keys)
+            .cloned()
+            .collect()
+    }
+
+    /// The sessions that reported `model` on `date`, each with its provider's
+    /// display name.
+    pub fn on_date(&self, date: &str, model: &str) -> impl Iterator<Item = (&str, &'static str)> {
+        self.0
+            .get(date)
+            .and_then(|models| models.get(model))
+            .into_iter()
+            .flatten()
+            .map(|(session, provider)| (session.as_str(), *provider))
+    }
+
+    /// Number of distinct sessions that reported any model.
+    pub fn total(&self) -> usize {
+        self.0
+            .values()
+            .flat_map(|models| models.values())
+            .flat_map(BTreeMap::keys)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Re-keys every model by `rekey(model)`, uniting the sessions of models
+    /// that land on the same name.
+    fn rekey(&mut self, rekey: &dyn Fn(&str) -> String) {
+        for models in self.0.values_mut() {
+            *models = fold_keys(std::mem::take(models), rekey, |a, b| a.extend(b));
+        }
+    }
+}
+
+/// Reported model name → the name its rows are shown under
+/// (`--fixup-model`), for the renamed models only.
+///
+/// [`UsageData`] keeps the reported names so pricing sees them; every row
+/// builder maps a priced row through [`ModelRenames::shown`] and merges the rows
+/// that share a shown name. The [`SessionIndex`] is keyed by shown name already.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ModelRenames(BTreeMap<String, String>);
+
+impl ModelRenames {
+    /// The renames `fixups` makes among `models`, lowercasing each target when
+    /// `fold_case` is on.
+    fn new<'a>(
+        models: impl IntoIterator<Item = &'a String>,
+        fixups: &ModelFixups,
+        fold_case: bool,
+    ) -> Self {
+        let mut renames = BTreeMap::new();
+        if fixups.is_empty() {
+            return Self(renames);
+        }
+        for model in models {
+            let shown = rewrite_model_name(model, fixups, fold_case);
+            if shown != *model {
+                renames.insert(model.clone(), shown);
+            }
+        }
+        Self(renames)
+    }
+
+    /// Whether no model is renamed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The name `model`'s rows are shown under.
+    pub fn shown<'a>(&'a self, model: &'a str) -> &'a str {
+        self.0.get(model).map_or(model, String::as_str)
+    }
+}
+
+/// The id a session is counted under: the source path alone for a session
+/// file, or the path and the session id for a database row.
+fn session_key(source: &Path, session: &str) -> String {
+    if session.is_empty() {
+        source.display().to_string()
+    } else {
+        format!("{}#{session}", source.display())
+    }
+}
+
+/// One date's slice of [`UsageData`]: usage per provider → model plus the
+/// provider-stored costs, priced the same way as the whole.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DailyUsage {
+    /// Per-model usage attributed to each provider on this date.
+    pub per_provider: PerProviderUsage,
+    /// Provider-authoritative costs recorded on this date.
+    pub stored_costs: StoredCosts,
 }
 
 // Usage and analysis both report the one unified scan-diagnostics type; it is
@@ -112,6 +251,19 @@ pub struct StoredCosts {
     pub hermes: FastHashMap<String, f64>,
 }
 
+impl StoredCosts {
+    /// The stored-cost map of `provider`, or `None` for a provider that
+    /// records no cost of its own.
+    pub(crate) fn get_mut(&mut self, provider: Provider) -> Option<&mut FastHashMap<String, f64>> {
+        match provider {
+            Provider::OpenCode => Some(&mut self.opencode),
+            Provider::Cursor => Some(&mut self.cursor),
+            Provider::Hermes => Some(&mut self.hermes),
+            _ => None,
+        }
+    }
+}
+
 /// Extracts token usage data from a typed `CodeAnalysis`.
 ///
 /// Reads directly from the typed `conversation_usage` map instead of walking
@@ -208,6 +360,7 @@ pub fn aggregate_usage_from_paths_with_providers(
     let mut result = FastHashMap::with_capacity(capacity::MODEL_COMBINATIONS);
     let mut per_provider = PerProviderUsage::default();
     let mut stored_costs = StoredCosts::default();
+    let mut sessions = SessionIndex::default();
 
     let mut claude_dates: HashSet<String> = HashSet::new();
     let mut codex_dates: HashSet<String> = HashSet::new();
@@ -226,6 +379,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             ExtensionType::ClaudeCode,
             &mut result,
             &mut per_provider.claude,
+            &mut sessions,
             &mut claude_dates,
             is_claude_session_file,
             time_range,
@@ -239,6 +393,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             ExtensionType::Codex,
             &mut result,
             &mut per_provider.codex,
+            &mut sessions,
             &mut codex_dates,
             is_codex_session_file,
             time_range,
@@ -257,6 +412,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             ExtensionType::Copilot,
             &mut result,
             &mut per_provider.copilot,
+            &mut sessions,
             &mut copilot_dates,
             is_copilot_session_file,
             time_range,
@@ -270,6 +426,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             ExtensionType::Gemini,
             &mut result,
             &mut per_provider.gemini,
+            &mut sessions,
             &mut gemini_dates,
             is_gemini_session_file,
             time_range,
@@ -283,6 +440,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             ExtensionType::Grok,
             &mut result,
             &mut per_provider.grok,
+            &mut sessions,
             &mut grok_dates,
             is_grok_session_file,
             time_range,
@@ -298,6 +456,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             &paths.opencode_db,
             &mut result,
             &mut per_provider.opencode,
+            &mut sessions,
             &mut stored_costs.opencode,
             &mut opencode_dates,
             time_range,
@@ -319,6 +478,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             &paths.cursor_tracking_db,
             &mut result,
             &mut per_provider.cursor,
+            &mut sessions,
             &mut stored_costs.cursor,
             &mut cursor_dates,
             time_range,
@@ -334,6 +494,7 @@ pub fn aggregate_usage_from_paths_with_providers(
             &paths.hermes_db,
             &mut result,
             &mut per_provider.hermes,
+            &mut sessions,
             &mut stored_costs.hermes,
             &mut hermes_dates,
             time_range,
@@ -372,6 +533,9 @@ pub fn aggregate_usage_from_paths_with_providers(
         per_provider,
         provider_days,
         stored_costs,
+        sessions,
+        per_date: BTreeMap::new(),
+        model_renames: ModelRenames::default(),
     })
 }
 
@@ -379,11 +543,38 @@ pub fn aggregate_usage_from_paths_with_providers(
 ///
 /// `tiers` is the per-request context-tier snapshot derived from the current
 /// pricing map (see [`TierThresholds`]); `None` (the default) classifies
-/// nothing and every request bills at base rates.
+/// nothing and every request bills at base rates. `files` replaces directory
+/// discovery with an explicit list of session files (`--files-from`).
+/// `encoding` is how invalid UTF-8 in a session file is read (`--encoding`).
+/// `fold_case` merges case variants of a model name (`--fold-case`),
+/// `fixups` renames models in the priced rows (`--fixup-model`), `ignore`
+/// skips sessions (`.vctignore`, `--ignore-file`), `dedupe_by` counts a
+/// repeated Claude token event once (`--dedupe-by`), and `warnings` collects
+/// the run warnings the scan records.
 #[derive(Debug, Default, Clone)]
 pub struct UsageScanOptions {
     /// "Model → lowest tier threshold" snapshot for per-request classification.
     pub tiers: Option<Arc<TierThresholds>>,
+    /// How bytes that are not valid UTF-8 are treated.
+    pub encoding: TextEncoding,
+    /// Merge models whose names differ only in case under the lowercased name.
+    pub fold_case: bool,
+    /// Model renaming rules, recorded in [`UsageData::model_renames`].
+    pub fixups: ModelFixups,
+    /// Build the pricing map with
+    /// [`with_family_estimates`](crate::pricing::ModelPricingMap::with_family_estimates)
+    /// on, for the pipelines that fetch pricing before scanning.
+    pub estimate_missing_pricing: bool,
+    /// Sessions excluded from discovery (`.vctignore`, `--ignore-file`).
+    pub ignore: SessionIgnore,
+    /// What repeated Claude token events are recognized by.
+    pub dedupe_by: Option<DedupeBy>,
+    /// Session files to scan instead of the provider directories and
+    /// databases. Each file's provider is detected from its content, the time
+    /// range is not applied, and the scan cache is bypassed.
+    pub files: Option<Arc<[PathBuf]>>,
+    /// Where problems the scan works around are recorded.
+    pub warnings: WarningSink,
 }
 
 /// Diagnostics-aware usage scan rooted at the current user's provider paths.
@@ -462,10 +653,33 @@ fn aggregate_usage_from_paths_with_cache_inner(
     cache: &mut SummaryScanCache,
     options: &UsageScanOptions,
 ) -> Result<UsageCollection> {
-    // Cached summaries embed the tier classification, so a changed threshold
-    // snapshot (daily pricing reload) invalidates every cached entry.
     let tiers = options.tiers.as_deref();
-    cache.ensure_tier_fingerprint(tiers.map_or(0, TierThresholds::fingerprint));
+    let parse = ParseOptions {
+        tiers,
+        encoding: options.encoding,
+        dedupe_by: options.dedupe_by,
+        warnings: Some(&options.warnings),
+    };
+    if let Some(files) = &options.files {
+        let mut accumulator = UsageAccumulator::default();
+        let mut diagnostics = ScanDiagnostics::default();
+        let groups = crate::scan::classify_listed_files(files, providers)?;
+        crate::scan::scan_listed_files(groups, &mut accumulator, &mut diagnostics, parse);
+        diagnostics.finalize();
+        return Ok(UsageCollection {
+            data: accumulator.finish(options),
+            diagnostics,
+        });
+    }
+
+    // Cached summaries embed the tier classification and the decoded text, so
+    // a changed threshold snapshot (daily pricing reload) or encoding
+    // invalidates every cached entry.
+    cache.ensure_parse_settings(
+        tiers.map_or(0, TierThresholds::fingerprint),
+        options.encoding,
+        options.dedupe_by,
+    );
     cache.begin_scan();
     let mut accumulator = UsageAccumulator::default();
     let mut diagnostics = ScanDiagnostics::default();
@@ -475,11 +689,12 @@ fn aggregate_usage_from_paths_with_cache_inner(
         paths,
         providers,
         time_range,
+        &options.ignore,
         cache,
         &mut seen,
         &mut accumulator,
         &mut diagnostics,
-        tiers,
+        parse,
     )?;
 
     if providers.opencode && paths.opencode_db.exists() {
@@ -523,7 +738,7 @@ fn aggregate_usage_from_paths_with_cache_inner(
     cache.retain_kinds(&seen, &[SummaryKind::File, SummaryKind::UsageDatabase]);
     diagnostics.finalize();
     Ok(UsageCollection {
-        data: accumulator.finish(),
+        data: accumulator.finish(options),
         diagnostics,
     })
 }
@@ -740,6 +955,8 @@ struct UsageAccumulator {
     models: UsageResult,
     per_provider: PerProviderUsage,
     stored_costs: StoredCosts,
+    sessions: SessionIndex,
+    per_date: BTreeMap<String, DailyUsage>,
     claude_dates: HashSet<String>,
     codex_dates: HashSet<String>,
     copilot_dates: HashSet<String>,
@@ -751,13 +968,13 @@ struct UsageAccumulator {
 }
 
 impl crate::scan::CompactSink for UsageAccumulator {
-    fn fold(&mut self, provider: ExtensionType, summary: &CompactSourceSummary) {
-        self.add(provider, summary);
+    fn fold(&mut self, provider: ExtensionType, source: &Path, summary: &CompactSourceSummary) {
+        self.add(provider, source, summary);
     }
 }
 
 impl UsageAccumulator {
-    fn add(&mut self, provider: ExtensionType, summary: &CompactSourceSummary) {
+    fn add(&mut self, provider: ExtensionType, source: &Path, summary: &CompactSourceSummary) {
         let provider_result = match provider {
             ExtensionType::ClaudeCode => &mut self.per_provider.claude,
             ExtensionType::Codex => &mut self.per_provider.codex,
@@ -824,9 +1041,61 @@ impl UsageAccumulator {
             ExtensionType::Hermes => &mut self.hermes_dates,
         };
         dates.extend(summary.usage_dates.iter().cloned());
+
+        self.add_per_date(provider.into(), summary);
+        self.add_sessions(provider.into(), source, summary);
+    }
+
+    /// Records the sessions behind one source's usage in [`UsageData::sessions`].
+    fn add_sessions(&mut self, provider: Provider, source: &Path, summary: &CompactSourceSummary) {
+        if let Some(date) = summary.usage_dates.iter().max()
+            && !summary.usage.is_empty()
+        {
+            let session = session_key(source, "");
+            for model in summary.usage.keys() {
+                self.sessions.record(date, model, &session, provider);
+            }
+        }
+        for ((date, model), ids) in &summary.database_sessions {
+            for id in ids {
+                let session = session_key(source, id);
+                self.sessions.record(date, model, &session, provider);
+            }
+        }
+    }
+
+    /// Splits one source's usage across [`UsageData::per_date`].
+    fn add_per_date(&mut self, provider: Provider, summary: &CompactSourceSummary) {
+        // A usage file's tokens all fall on its one modified date.
+        if let Some(date) = summary.usage_dates.iter().max()
+            && !summary.usage.is_empty()
+        {
+            let day = self.per_date.entry(date.clone()).or_default();
+            if let Some(models) = day.per_provider.get_mut(provider) {
+                for (model, usage) in &summary.usage {
+                    models
+                        .entry(model.clone())
+                        .and_modify(|existing| merge_usage_values(existing, usage))
+                        .or_insert_with(|| usage.clone());
+                }
+            }
+        }
+        for ((date, model), (tokens, stored_cost)) in &summary.database_daily {
+            let day = self.per_date.entry(date.clone()).or_default();
+            let usage = tokens.into_value();
+            if let Some(models) = day.per_provider.get_mut(provider) {
+                models
+                    .entry(model.clone())
+                    .and_modify(|existing| merge_usage_values(existing, &usage))
+                    .or_insert(usage);
+            }
+            if let Some(stored) = day.stored_costs.get_mut(provider) {
+                *stored.entry(model.clone()).or_insert(0.0) += stored_cost;
+            }
+        }
     }
 
-    fn finish(self) -> UsageData {
+    fn finish(self, options: &UsageScanOptions) -> UsageData {
         // Only the union's cardinality is needed, so union references rather
         // than cloning every date string across the eight per-provider sets.
         let mut all_dates: HashSet<&String> = HashSet::new();
@@ -854,7 +1123,11 @@ impl UsageAccumulator {
                 total: total_days,
             },
             stored_costs: self.stored_costs,
+            sessions: self.sessions,
+            per_date: self.per_date,
+            model_renames: ModelRenames::default(),
         }
+        .with_model_names(options)
     }
 }
 
@@ -879,6 +1152,7 @@ fn process_usage_directory<P, F>(
     provider: ExtensionType,
     global_result: &mut UsageResult,
     provider_result: &mut UsageResult,
+    sessions: &mut SessionIndex,
     unique_dates: &mut HashSet<String>,
     filter_fn: F,
     time_range: TimeRange,
@@ -900,13 +1174,13 @@ where
     // the `usage` path never needs the heavy `write_file_details` /
     // `edit_file_details` payloads, so caching the full analysis would waste
     // the memory win from `UsageOnly`.
-    let file_results: Vec<(String, FastHashMap<String, Value>)> = files
+    let file_results: Vec<(PathBuf, String, FastHashMap<String, Value>)> = files
         .into_par_iter()
         .filter_map(|file_info| {
             match parse_session_file_typed_as(&file_info.path, provider, ParseMode::UsageOnly) {
                 Ok(analysis) => {
                     let conversation_usage = extract_conversation_usage_from_analysis(analysis);
-                    Some((file_info.modified_date, conversation_usage))
+                    Some((file_info.path, file_info.modified_date, conversation_usage))
                 }
                 Err(e) => {
                     log::warn!("failed to analyze {}: {e}", file_info.path.display());
@@ -923,7 +1197,13 @@ where
     //   - `provider_result` keeps the same tokens scoped to this provider
     //     so the summary footer can attribute them to the right source
     //     directory without having to guess from the model name.
-    for (date, conversation_usage) in file_results {
+    for (path, date, mut conversation_usage) in file_results {
+        // A model with no tokens (e.g. a tool-only session) adds no row.
+        conversation_usage.retain(|_, usage| extract_token_counts(usage).has_activity());
+        let session = session_key(&path, "");
+        for model in conversation_usage.keys() {
+            sessions.record(&date, model, &session, provider.into());
+        }
         if usage_map_has_activity(&conversation_usage, 0.0) {
             unique_dates.insert(date);
         }
@@ -960,15 +1240,19 @@ fn process_opencode_usage(
     db_path: &Path,
     global_result: &mut UsageResult,
     provider_result: &mut UsageResult,
+    sessions: &mut SessionIndex,
     stored_costs: &mut FastHashMap<String, f64>,
     unique_dates: &mut HashSet<String>,
     time_range: TimeRange,
 ) -> Result<()> {
-    let sessions = read_opencode_usage(db_path, time_range)?;
+    let rows = read_opencode_usage_rows(db_path, time_range)?;
     fold_stored_cost_sessions(
-        sessions,
+        Provider::OpenCode,
+        db_path,
+        rows,
         global_result,
         provider_result,
+        sessions,
         stored_costs,
         unique_dates,
     );
@@ -981,20 +1265,25 @@ fn process_opencode_usage(
 /// Mirrors [`process_opencode_usage`]: the estimate carries its own per-model
 /// tuple shape as stored-cost readers. Its zero stored cost lets the display
 /// layer accept only an exact LiteLLM match rather than a fuzzy price guess.
+#[allow(clippy::too_many_arguments)] // per-provider helper, like `process_usage_directory`
 fn process_cursor_usage(
     chats_dir: &Path,
     tracking_db: &Path,
     global_result: &mut UsageResult,
     provider_result: &mut UsageResult,
+    sessions: &mut SessionIndex,
     stored_costs: &mut FastHashMap<String, f64>,
     unique_dates: &mut HashSet<String>,
     time_range: TimeRange,
 ) -> Result<()> {
-    let sessions = read_cursor_usage(chats_dir, tracking_db, time_range)?;
+    let rows = read_cursor_usage_rows(chats_dir, tracking_db, time_range)?;
     fold_stored_cost_sessions(
-        sessions,
+        Provider::Cursor,
+        chats_dir,
+        rows,
         global_result,
         provider_result,
+        sessions,
         stored_costs,
         unique_dates,
     );
@@ -1014,48 +1303,60 @@ fn process_hermes_usage(
     db_path: &Path,
     global_result: &mut UsageResult,
     provider_result: &mut UsageResult,
+    sessions: &mut SessionIndex,
     stored_costs: &mut FastHashMap<String, f64>,
     unique_dates: &mut HashSet<String>,
     time_range: TimeRange,
 ) -> Result<()> {
-    let sessions = read_hermes_usage(db_path, time_range)?;
+    let rows = read_hermes_usage_contributions(db_path, time_range)?.rows;
     fold_stored_cost_sessions(
-        sessions,
+        Provider::Hermes,
+        db_path,
+        rows,
         global_result,
         provider_result,
+        sessions,
         stored_costs,
         unique_dates,
     );
     Ok(())
 }
 
-/// Folds `(date, analysis, cost)` rows from a stored-cost provider (OpenCode /
-/// Cursor) into the global + provider-scoped maps and the stored-cost table.
+/// Folds the compact rows of a stored-cost provider (OpenCode / Cursor /
+/// Hermes) read from `source` into the global + provider-scoped maps and the
+/// stored-cost table.
+///
+/// Each row's session id is recorded under `source`, matching the cached scan.
+#[allow(clippy::too_many_arguments)] // per-provider helper, like `process_usage_directory`
 fn fold_stored_cost_sessions(
-    sessions: Vec<(String, CodeAnalysis, f64)>,
+    provider: Provider,
+    source: &Path,
+    rows: Vec<UsageContribution>,
     global_result: &mut UsageResult,
     provider_result: &mut UsageResult,
+    sessions: &mut SessionIndex,
     stored_costs: &mut FastHashMap<String, f64>,
     unique_dates: &mut HashSet<String>,
 ) {
-    for (date, analysis, session_cost) in sessions {
-        let conversation_usage = extract_conversation_usage_from_analysis(analysis);
-        if usage_map_has_activity(&conversation_usage, session_cost) {
-            unique_dates.insert(date);
-        }
-        for (model, usage_value) in conversation_usage {
-            *stored_costs.entry(model.clone()).or_insert(0.0) += session_cost;
-
-            provider_result
-                .entry(model.clone())
-                .and_modify(|existing| merge_usage_values(existing, &usage_value))
-                .or_insert_with(|| usage_value.clone());
-
-            global_result
-                .entry(model)
-                .and_modify(|existing| merge_usage_values(existing, &usage_value))
-                .or_insert(usage_value);
+    for row in rows {
+        if row.stored_cost == 0.0 && !row.tokens.has_activity() {
+            continue;
         }
+        let session = session_key(source, &row.session);
+        sessions.record(&row.date, &row.model, &session, provider);
+        unique_dates.insert(row.date);
+        *stored_costs.entry(row.model.clone()).or_insert(0.0) += row.stored_cost;
+
+        let usage_value = row.tokens.into_value();
+        provider_result
+            .entry(row.model.clone())
+            .and_modify(|existing| merge_usage_values(existing, &usage_value))
+            .or_insert_with(|| usage_value.clone());
+
+        global_result
+            .entry(row.model)
+            .and_modify(|existing| merge_usage_values(existing, &usage_value))
+            .or_insert(usage_value);
     }
 }
 
@@ -1063,7 +1364,7 @@ fn usage_map_has_activity(usage: &FastHashMap<String, Value>, stored_cost: f64)
     stored_cost != 0.0
         || usage
             .values()
-            .any(|value| crate::utils::extract_token_counts(value).has_activity())
+            .any(|value| extract_token_counts(value).has_activity())
 }
 
 impl UsageData {
@@ -1074,6 +1375,74 @@ impl UsageData {
     pub fn provider_usage(&self, provider: Provider) -> Option<&UsageResult> {
         self.per_provider.get(provider)
     }
+
+    /// Merges models whose names differ only in case under the lowercased
+    /// name, across every model-keyed map (`--fold-case`).
+    ///
+    /// A session that reported both case variants still counts once.
+    pub fn fold_model_case(&mut self) {
+        self.rekey_models(&str::to_lowercase);
+    }
+
+    /// Re-keys every model-keyed map by `rekey(model)`, merging models that
+    /// land on the same name.
+    fn rekey_models(&mut self, rekey: &dyn Fn(&str) -> String) {
+        self.models = fold_usage(std::mem::take(&mut self.models), rekey);
+        fold_per_provider(&mut self.per_provider, rekey);
+        fold_stored_costs(&mut self.stored_costs, rekey);
+        self.sessions.rekey(rekey);
+        for day in self.per_date.values_mut() {
+            fold_per_provider(&mut day.per_provider, rekey);
+            fold_stored_costs(&mut day.stored_costs, rekey);
+        }
+    }
+
+    /// Folds model case when `options.fold_case` is on, then records the
+    /// renames `options.fixups` makes in [`Self::model_renames`] and keys the
+    /// session index by the renamed models.
+    fn with_model_names(mut self, options: &UsageScanOptions) -> Self {
+        if options.fold_case {
+            self.fold_model_case();
+        }
+        let renames = ModelRenames::new(self.models.keys(), &options.fixups, options.fold_case);
+        if !renames.is_empty() {
+            self.sessions
+                .rekey(&|model| renames.shown(model).to_string());
+        }
+        self.model_renames = renames;
+        self
+    }
+}
+
+fn fold_usage(usage: UsageResult, rekey: &dyn Fn(&str) -> String) -> UsageResult {
+    fold_keys(usage, rekey, |existing, incoming| {
+        merge_usage_values(existing, &incoming)
+    })
+}
+
+fn fold_per_provider(per_provider: &mut PerProviderUsage, rekey: &dyn Fn(&str) -> String) {
+    for usage in [
+        &mut per_provider.claude,
+        &mut per_provider.codex,
+        &mut per_provider.copilot,
+        &mut per_provider.gemini,
+        &mut per_provider.opencode,
+        &mut per_provider.cursor,
+        &mut per_provider.hermes,
+        &mut per_provider.grok,
+    ] {
+        *usage = fold_usage(std::mem::take(usage), rekey);
+    }
+}
+
+fn fold_stored_costs(stored_costs: &mut StoredCosts, rekey: &dyn Fn(&str) -> String) {
+    for costs in [
+        &mut stored_costs.opencode,
+        &mut stored_costs.cursor,
+        &mut stored_costs.hermes,
+    ] {
+        *costs = fold_keys(std::mem::take(costs), rekey, |a, b| *a += b);
+    }
 }
 
 #[cfg(test)]
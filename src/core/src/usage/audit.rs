@@ -0,0 +1,151 @@
+//! Pricing-override audit: which override keys no session ever used.
+//!
+//! An override file is a LiteLLM-shaped JSON object keyed by model name. A key
+//! counts as used only when a distinct model from a usage scan carries exactly
+//! that name. No normalized, substring, or fuzzy matching is applied, so a
+//! misspelled key is reported even when it resembles a model that was used.
+//!
+//! The model set itself comes from [`distinct_models`], which scans every
+//! session through the caller's [`SummaryScanCache`], so repeated audits only
+//! reparse sources that changed.
+
+use crate::config::ProvidersConfig;
+use crate::models::TimeRange;
+use crate::summary_cache::SummaryScanCache;
+use crate::usage::aggregator::aggregate_usage_from_paths_with_cache_opts;
+use crate::usage::{UsageData, UsageScanOptions};
+use crate::utils::{HelperPaths, resolve_paths};
+use anyhow::{Context, Result, bail};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+impl UsageData {
+    /// Returns the distinct model names that contributed usage, sorted.
+    ///
+    /// This is the merged cross-provider key set of [`UsageData::models`], so a
+    /// model reported by several providers appears once.
+    pub fn distinct_models(&self) -> BTreeSet<&str> {
+        self.models.keys().map(String::as_str).collect()
+    }
+}
+
+/// Every model name seen across all discovered sessions of the enabled
+/// `providers`, for all time, scanned as `options` says (ignore patterns,
+/// encoding, model fixups).
+///
+/// Sources already in `cache` are not reparsed, so features that each need the
+/// model list (override audits, pricing coverage) can share one cache.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be resolved or the scan
+/// fails.
+pub fn distinct_models(
+    providers: ProvidersConfig,
+    cache: &mut SummaryScanCache,
+    options: &UsageScanOptions,
+) -> Result<BTreeSet<String>> {
+    distinct_models_from_paths(&resolve_paths()?, providers, cache, options)
+}
+
+/// [`distinct_models`] rooted at explicit provider paths.
+///
+/// Sources already in `cache` are not reparsed, so a caller that keeps a
+/// [`SummaryScanCache`] across refreshes gets the model set almost for free.
+///
+/// # Errors
+///
+/// Returns an error if the usage scan fails.
+pub fn distinct_models_from_paths(
+    paths: &HelperPaths,
+    providers: ProvidersConfig,
+    cache: &mut SummaryScanCache,
+    options: &UsageScanOptions,
+) -> Result<BTreeSet<String>> {
+    let collection = aggregate_usage_from_paths_with_cache_opts(
+        paths,
+        TimeRange::All,
+        providers,
+        cache,
+        options,
+    )?;
+    Ok(collection
+        .data
+        .distinct_models()
+        .into_iter()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Reads the model keys of a pricing/override file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is not valid JSON, or its top
+/// level is not an object.
+pub fn load_override_keys(path: &Path) -> Result<BTreeSet<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read override file {}", path.display()))?;
+    let value: serde_json::Value = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse override file {}", path.display()))?;
+    let Some(object) = value.as_object() else {
+        bail!(
+            "override file {} must be a JSON object keyed by model name",
+            path.display()
+        );
+    };
+    Ok(object.keys().cloned().collect())
+}
+
+/// Returns the override keys that none of `models` names exactly, sorted.
+pub fn unused_override_keys<'a>(
+    override_keys: &BTreeSet<String>,
+    models: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let used: BTreeSet<&str> = models
+        .into_iter()
+        .filter(|model| override_keys.contains(*model))
+        .collect();
+    override_keys
+        .iter()
+        .filter(|key| !used.contains(key.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn only_exact_names_count_as_used() {
+        let overrides = keys(&[
+            "claude-sonnet-4",
+            "gpt-5.5",
+            "gpt-5.6-typo",
+            "never-seen-model",
+        ]);
+        let unused = unused_override_keys(&overrides, ["claude-sonnet-4-20250514", "gpt-5.5"]);
+        // A dated model does not keep its undated key alive, and a key that
+        // would fuzzy-match a used model is still reported.
+        assert_eq!(
+            unused,
+            vec![
+                "claude-sonnet-4".to_string(),
+                "gpt-5.6-typo".to_string(),
+                "never-seen-model".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn no_models_leaves_every_key_unused() {
+        let overrides = keys(&["b-model", "a-model"]);
+        let unused = unused_override_keys(&overrides, std::iter::empty());
+        assert_eq!(unused, vec!["a-model".to_string(), "b-model".to_string()]);
+    }
+}
@@ -0,0 +1,101 @@
+//! Per-model daily cost caps (`usage --model-budget opus=10`).
+//!
+//! Each cap names a model by a case-insensitive substring of its name, so
+//! `opus` covers every Opus version at once. A day's spend against the cap is
+//! the summed cost of every model the name matches that day, priced like the
+//! `--group-by date,model` rows.
+
+use crate::pricing::ModelPricingMap;
+use crate::usage::UsageData;
+use crate::usage::grouping::{GroupBy, GroupKey, group_usage};
+use anyhow::{Result, bail};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// One `MODEL=USD` cap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelBudget {
+    /// Case-insensitive substring of the model names the cap covers.
+    pub model: String,
+    /// Daily cap in USD.
+    pub cap_usd: f64,
+}
+
+impl FromStr for ModelBudget {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let Some((model, cap)) = value.rsplit_once('=') else {
+            bail!("expected `MODEL=USD`, got `{value}`");
+        };
+        let model = model.trim();
+        if model.is_empty() {
+            bail!("`{value}` names no model");
+        }
+        let cap = cap.trim().trim_start_matches('$');
+        match cap.parse::<f64>() {
+            Ok(cap_usd) if cap_usd.is_finite() && cap_usd >= 0.0 => Ok(Self {
+                model: model.to_string(),
+                cap_usd,
+            }),
+            _ => bail!("`{cap}` is not a non-negative amount"),
+        }
+    }
+}
+
+impl ModelBudget {
+    fn covers(&self, model: &str) -> bool {
+        model.to_lowercase().contains(&self.model.to_lowercase())
+    }
+}
+
+/// One capped model's spend on one day.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelBudgetDay {
+    /// Local `YYYY-MM-DD` date.
+    pub date: String,
+    /// The cap's model name, as given.
+    pub model: String,
+    /// Cost in USD of the matching models that day.
+    pub cost_usd: f64,
+    /// The cap in USD.
+    pub cap_usd: f64,
+    /// Whether `cost_usd` is above `cap_usd`.
+    pub over_model_budget: bool,
+}
+
+/// Every day each cap in `budgets` saw spend, by date and then in the order
+/// the caps were given. Days a capped model did not cost anything are left
+/// out.
+pub fn check_model_budgets(
+    data: &UsageData,
+    budgets: &[ModelBudget],
+    pricing: &ModelPricingMap,
+) -> Vec<ModelBudgetDay> {
+    let rows = group_usage(
+        data,
+        &GroupBy(vec![GroupKey::Date, GroupKey::Model]),
+        pricing,
+    );
+    let mut days = Vec::new();
+    for chunk in rows.chunk_by(|a, b| a.date == b.date) {
+        let date = chunk[0].date.clone().unwrap_or_default();
+        for budget in budgets {
+            let cost_usd: f64 = chunk
+                .iter()
+                .filter(|row| budget.covers(row.model.as_deref().unwrap_or_default()))
+                .map(|row| row.cost_usd)
+                .sum();
+            if cost_usd > 0.0 {
+                days.push(ModelBudgetDay {
+                    date: date.clone(),
+                    model: budget.model.clone(),
+                    cost_usd,
+                    cap_usd: budget.cap_usd,
+                    over_model_budget: cost_usd > budget.cap_usd,
+                });
+            }
+        }
+    }
+    days
+}
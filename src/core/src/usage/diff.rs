@@ -0,0 +1,116 @@
+//! Period-over-period comparison of two saved `usage --json` exports
+//! (`usage --diff`).
+//!
+//! Each export is the [`PricedUsageRow`] array the CLI writes, so it carries
+//! per-model tokens and cost but no dates: a dump *is* one period. Rows are
+//! therefore keyed by model alone; a model present in only one export diffs
+//! against zero on the other side.
+
+use crate::usage::PricedUsageRow;
+use crate::utils::extract_token_counts;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// One model's change between the two exports.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageDiffRow {
+    /// Model name as written in the exports.
+    pub model: String,
+    /// Total tokens in the first (`before`) export.
+    pub tokens_before: i64,
+    /// Total tokens in the second (`after`) export.
+    pub tokens_after: i64,
+    /// `tokens_after - tokens_before`.
+    pub tokens_delta: i64,
+    /// Cost in USD in the first export.
+    pub cost_before: f64,
+    /// Cost in USD in the second export.
+    pub cost_after: f64,
+    /// `cost_after - cost_before`.
+    pub cost_delta: f64,
+}
+
+/// Per-model deltas plus their totals.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageDiff {
+    /// Changed and unchanged models, largest absolute cost change first.
+    pub models: Vec<UsageDiffRow>,
+    /// Sum over every model; `model` is `"TOTAL"`.
+    pub total: UsageDiffRow,
+}
+
+/// Reads one saved `usage --json` export.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is not a `usage --json`
+/// row array.
+pub fn load_usage_export(path: &Path) -> Result<Vec<PricedUsageRow>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read usage export {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse usage export {}", path.display()))
+}
+
+/// Diffs two exports model by model.
+///
+/// Rows sharing a model within one export (e.g. a hand-concatenated dump) are
+/// summed first. Models are ordered by absolute cost change, then absolute
+/// token change, then name, so the biggest movers lead.
+pub fn diff_usage_exports(before: &[PricedUsageRow], after: &[PricedUsageRow]) -> UsageDiff {
+    // (tokens, cost) per side.
+    let mut sides: BTreeMap<&str, [(i64, f64); 2]> = BTreeMap::new();
+    for (side, rows) in [before, after].into_iter().enumerate() {
+        for row in rows {
+            let entry = &mut sides.entry(row.model.as_str()).or_default()[side];
+            entry.0 += extract_token_counts(&row.usage).total;
+            entry.1 += row.cost_usd;
+        }
+    }
+
+    let mut models: Vec<UsageDiffRow> = sides
+        .into_iter()
+        .map(
+            |(model, [(tokens_before, cost_before), (tokens_after, cost_after)])| {
+                diff_row(model, tokens_before, tokens_after, cost_before, cost_after)
+            },
+        )
+        .collect();
+    models.sort_by(|left, right| {
+        right
+            .cost_delta
+            .abs()
+            .total_cmp(&left.cost_delta.abs())
+            .then_with(|| right.tokens_delta.abs().cmp(&left.tokens_delta.abs()))
+            .then_with(|| left.model.cmp(&right.model))
+    });
+
+    let total = diff_row(
+        "TOTAL",
+        models.iter().map(|row| row.tokens_before).sum(),
+        models.iter().map(|row| row.tokens_after).sum(),
+        models.iter().map(|row| row.cost_before).sum(),
+        models.iter().map(|row| row.cost_after).sum(),
+    );
+    UsageDiff { models, total }
+}
+
+fn diff_row(
+    model: &str,
+    tokens_before: i64,
+    tokens_after: i64,
+    cost_before: f64,
+    cost_after: f64,
+) -> UsageDiffRow {
+    UsageDiffRow {
+        model: model.to_string(),
+        tokens_before,
+        tokens_after,
+        tokens_delta: tokens_after - tokens_before,
+        cost_before,
+        cost_after,
+        cost_delta: cost_after - cost_before,
+    }
+}
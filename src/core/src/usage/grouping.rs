@@ -0,0 +1,245 @@
+//! Composite usage grouping (`usage --group-by date,provider`).
+//!
+//! The `usage` table is one row per model. Grouping re-slices the same
+//! [`UsageData::per_date`] breakdown by any ordered subset of date, provider,
+//! and model, summing tokens and cost within each group. Every piece is
+//! priced under its own provider's cost basis, exactly as the merged table
+//! prices it, so the groups always sum to the table's total.
+
+use crate::pricing::ModelPricingMap;
+use crate::usage::UsageData;
+use crate::usage::priced::{PRICING_ORDER, price_usage_value, provider_cost_source};
+use crate::utils::extract_token_counts;
+use anyhow::{Result, bail};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+/// One dimension a `--group-by` key can name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKey {
+    /// Local `YYYY-MM-DD` date of the usage.
+    Date,
+    /// Provider the usage was recorded by.
+    Provider,
+    /// Model name.
+    Model,
+}
+
+impl FromStr for GroupKey {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "date" => Ok(Self::Date),
+            "provider" => Ok(Self::Provider),
+            "model" => Ok(Self::Model),
+            other => bail!("unknown group key `{other}` (expected date, provider, or model)"),
+        }
+    }
+}
+
+/// An ordered, duplicate-free list of [`GroupKey`]s, parsed from a
+/// comma-separated list such as `date,provider`.
+///
+/// The order sets both the row sort and the column order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupBy(pub(crate) Vec<GroupKey>);
+
+impl GroupBy {
+    /// The selected keys in the order given.
+    pub fn keys(&self) -> &[GroupKey] {
+        &self.0
+    }
+}
+
+impl FromStr for GroupBy {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut keys = Vec::new();
+        for part in value.split(',') {
+            let key: GroupKey = part.trim().parse()?;
+            if keys.contains(&key) {
+                bail!("group key `{}` is given twice", part.trim());
+            }
+            keys.push(key);
+        }
+        Ok(Self(keys))
+    }
+}
+
+/// One group's summed usage; a dimension left out of the grouping is `None`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GroupedUsageRow {
+    /// Local `YYYY-MM-DD` date, when grouping by date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Provider display name (e.g. `Claude`), when grouping by provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Model name, when grouping by model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Total tokens in the group.
+    pub tokens: i64,
+    /// Cost in USD of the group.
+    pub cost_usd: f64,
+    /// Distinct sessions that contributed to the group.
+    pub sessions: usize,
+}
+
+/// One group's running sums while [`group_usage`] walks the pieces.
+#[derive(Default)]
+struct GroupSums<'a> {
+    tokens: i64,
+    cost: f64,
+    sessions: BTreeSet<&'a str>,
+}
+
+/// One position of a group's sort key; providers sort in footer order.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum KeyPart {
+    Date(String),
+    Provider(usize),
+    Model(String),
+}
+
+/// Sums `data` into one row per distinct value of the `group_by` keys.
+///
+/// Rows are sorted by the keys in the order given (dates ascending,
+/// providers in the `usage` footer order, models by name). Groups with no
+/// tokens and no cost are dropped. A group's `sessions` counts each session
+/// once, however many of the group's dates or models it reported. Each piece
+/// is priced under its reported model name and grouped under its shown one.
+pub fn group_usage(
+    data: &UsageData,
+    group_by: &GroupBy,
+    pricing: &ModelPricingMap,
+) -> Vec<GroupedUsageRow> {
+    let mut groups: BTreeMap<Vec<KeyPart>, GroupSums> = BTreeMap::new();
+
+    for (date, day) in &data.per_date {
+        for (rank, provider) in PRICING_ORDER.into_iter().enumerate() {
+            let Some(models) = day.per_provider.get(provider) else {
+                continue;
+            };
+            for (model, usage) in models {
+                let source = provider_cost_source(provider, model, &day.stored_costs);
+                let (cost, _) = price_usage_value(model, usage, pricing, source);
+                let model = data.model_renames.shown(model);
+                let key = group_by
+                    .keys()
+                    .iter()
+                    .map(|key| match key {
+                        GroupKey::Date => KeyPart::Date(date.clone()),
+                        GroupKey::Provider => KeyPart::Provider(rank),
+                        GroupKey::Model => KeyPart::Model(model.to_string()),
+                    })
+                    .collect();
+                let group = groups.entry(key).or_default();
+                group.tokens += extract_token_counts(usage).total;
+                group.cost += cost;
+                group.sessions.extend(
+                    data.sessions
+                        .on_date(date, model)
+                        .filter(|(_, name)| *name == provider.display_name())
+                        .map(|(session, _)| session),
+                );
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, group)| group.tokens != 0 || group.cost != 0.0)
+        .map(|(key, group)| {
+            let mut row = GroupedUsageRow {
+                date: None,
+                provider: None,
+                model: None,
+                tokens: group.tokens,
+                cost_usd: group.cost,
+                sessions: group.sessions.len(),
+            };
+            for part in key {
+                match part {
+                    KeyPart::Date(date) => row.date = Some(date),
+                    KeyPart::Provider(rank) => {
+                        row.provider = Some(PRICING_ORDER[rank].display_name().to_string());
+                    }
+                    KeyPart::Model(model) => row.model = Some(model),
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+/// Cost in USD of the usage recorded on one local `YYYY-MM-DD` `date`,
+/// priced like the date groups of [`group_usage`]; `0.0` for a date with no
+/// usage. Drives the "Today" cost in the `usage` footers.
+pub fn cost_on_date(data: &UsageData, date: &str, pricing: &ModelPricingMap) -> f64 {
+    let Some(day) = data.per_date.get(date) else {
+        return 0.0;
+    };
+    let mut cost = 0.0;
+    for provider in PRICING_ORDER {
+        let Some(models) = day.per_provider.get(provider) else {
+            continue;
+        };
+        for (model, usage) in models {
+            let source = provider_cost_source(provider, model, &day.stored_costs);
+            cost += price_usage_value(model, usage, pricing, source).0;
+        }
+    }
+    cost
+}
+
+/// Total tokens recorded on one local `YYYY-MM-DD` `date`, summed like the
+/// date groups of [`group_usage`]; `0` for a date with no usage. Pairs with
+/// [`cost_on_date`] for `usage --live-total`.
+pub fn tokens_on_date(data: &UsageData, date: &str) -> i64 {
+    let Some(day) = data.per_date.get(date) else {
+        return 0;
+    };
+    PRICING_ORDER
+        .into_iter()
+        .filter_map(|provider| day.per_provider.get(provider))
+        .flat_map(|models| models.values())
+        .map(|usage| extract_token_counts(usage).total)
+        .sum()
+}
+
+/// Total tokens per local `(date, model)`, summed across providers like
+/// [`tokens_on_date`], under each model's shown name (see
+/// [`UsageData::model_renames`]). The usage side of `usage --cross-check`.
+pub fn tokens_by_date_and_model(data: &UsageData) -> BTreeMap<(String, String), i64> {
+    let mut totals = BTreeMap::new();
+    for (date, day) in &data.per_date {
+        for models in PRICING_ORDER
+            .into_iter()
+            .filter_map(|provider| day.per_provider.get(provider))
+        {
+            for (model, usage) in models {
+                let model = data.model_renames.shown(model).to_string();
+                *totals.entry((date.clone(), model)).or_insert(0) +=
+                    extract_token_counts(usage).total;
+            }
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_parses_an_ordered_key_list() {
+        let group_by: GroupBy = "date, provider".parse().unwrap();
+        assert_eq!(group_by.keys(), [GroupKey::Date, GroupKey::Provider]);
+        assert!("date,date".parse::<GroupBy>().is_err());
+        assert!("date,week".parse::<GroupBy>().is_err());
+    }
+}
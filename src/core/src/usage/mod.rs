@@ -4,17 +4,41 @@
 //! [`UsageData`] for the `usage` view. [`aggregate_usage_from_home`] is the
 //! home-resolved entry point and [`aggregate_usage_from_paths`] its
 //! test/injection twin; [`scan_usage_priced`] wraps the pricing-then-scan
-//! pipeline, [`price_usage_data`] builds the priced JSON payload, and
-//! [`summary`] builds the aggregated view the display renders.
+//! pipeline and [`LiveTotalScan`] its incremental today-only variant,
+//! [`price_usage_data`] builds the priced JSON payload, [`summary`] builds the
+//! aggregated view the display renders, [`audit`] cross-checks a
+//! pricing-override file against the models actually seen, [`diff`] compares
+//! two saved JSON exports, [`grouping`] re-slices usage by date, provider, and
+//! model, [`budget`] checks per-model daily cost caps, [`split`] cuts the
+//! usage into per-week or per-month sections, and [`webhook`] posts the
+//! results to a chat webhook.
 
 pub mod aggregator;
+pub mod audit;
+pub mod budget;
+pub mod diff;
+pub mod grouping;
 pub mod pipeline;
 pub mod priced;
+pub mod split;
 pub mod summary;
+pub mod webhook;
 
 pub use aggregator::*;
-pub use pipeline::{PricedUsageScan, scan_usage_priced};
-pub use priced::{PricedUsageRow, price_usage_data};
+pub use audit::{
+    distinct_models, distinct_models_from_paths, load_override_keys, unused_override_keys,
+};
+pub use budget::{ModelBudget, ModelBudgetDay, check_model_budgets};
+pub use diff::{UsageDiff, UsageDiffRow, diff_usage_exports, load_usage_export};
+pub use grouping::{
+    GroupBy, GroupKey, GroupedUsageRow, cost_on_date, group_usage, tokens_by_date_and_model,
+    tokens_on_date,
+};
+pub use pipeline::{LiveTotalScan, PricedUsageScan, scan_usage_estimate, scan_usage_priced};
+pub(crate) use pipeline::{fetch_pricing_or_empty, priced_scan_options};
+pub use priced::{PricedUsageRow, add_cost_breakdowns, price_usage_data};
+pub use split::{SplitPeriod, UsageSection, split_usage};
+pub use webhook::{DEFAULT_WEBHOOK_TIMEOUT_SECS, WebhookFormat, WebhookSummary, post_webhook};
 // Shared merged-cost resolver used by both the JSON payload and the display
 // summaries.
 pub(crate) use priced::resolve_merged_model_cost;
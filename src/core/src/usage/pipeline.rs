@@ -8,11 +8,18 @@
 
 use crate::config::ProvidersConfig;
 use crate::models::TimeRange;
-use crate::pricing::{ModelPricingMap, fetch_model_pricing};
+use crate::pricing::{
+    ModelPricingMap, ThresholdBasis, fetch_model_pricing, load_cached_model_pricing,
+    pricing_cache_date,
+};
+use crate::summary_cache::SummaryScanCache;
 use crate::usage::{
     UsageCollection, UsageScanOptions, aggregate_usage_from_home_with_diagnostics_opts,
+    aggregate_usage_from_paths_with_cache_opts, cost_on_date, tokens_on_date,
 };
-use anyhow::Result;
+use crate::utils::{HelperPaths, get_current_date, resolve_paths};
+use crate::warnings::WarningSink;
+use anyhow::{Result, bail};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -30,11 +37,15 @@ pub struct PricedUsageScan {
 
 /// Fetches pricing, derives the context-tier thresholds, and scans usage.
 ///
-/// A failed pricing fetch is logged and downgraded to an empty map (the scan
-/// still runs, classifying every request at the base rate) rather than aborting;
-/// the returned [`PricedUsageScan::pricing_error`] carries the concrete cause so
-/// the caller can surface it however it wants. The scan runs on `pool` so it
-/// never touches Rayon's global pool.
+/// `basis` picks which of a request's tokens are compared against each
+/// model's threshold (see [`ThresholdBasis`]). A failed pricing fetch is
+/// logged and downgraded to an empty map (the scan still runs, classifying
+/// every request at the base rate) rather than aborting; the returned
+/// [`PricedUsageScan::pricing_error`] carries the concrete cause so the caller
+/// can surface it however it wants. The scan runs on `pool` so it never
+/// touches Rayon's global pool. `options` carries the caller's scan knobs
+/// (`files`, `encoding`) and whether the fetched map estimates missing
+/// prices; its `tiers` is replaced by the fetched pricing's.
 ///
 /// # Errors
 ///
@@ -43,20 +54,204 @@ pub struct PricedUsageScan {
 pub fn scan_usage_priced(
     time_range: TimeRange,
     providers: ProvidersConfig,
+    basis: ThresholdBasis,
+    options: UsageScanOptions,
     pool: &rayon::ThreadPool,
 ) -> Result<PricedUsageScan> {
-    let (pricing, pricing_error) = match fetch_model_pricing() {
+    let (pricing, pricing_error) = fetch_pricing_or_empty(&options.warnings);
+    let pricing = pricing.with_family_estimates(options.estimate_missing_pricing);
+    let options = UsageScanOptions {
+        tiers: priced_scan_options(&pricing, basis).tiers,
+        ..options
+    };
+    let collection = pool.install(|| {
+        aggregate_usage_from_home_with_diagnostics_opts(time_range, providers, &options)
+    })?;
+    Ok(PricedUsageScan {
+        collection,
+        pricing,
+        pricing_error,
+    })
+}
+
+/// Today's running cost and token total, rescanned incrementally per tick
+/// (`usage --live-total`).
+///
+/// Pricing is fetched with the same degrade policy as [`scan_usage_priced`]
+/// and fetched again on the first tick after the pricing cache expires (the
+/// UTC day rolls over) or after a failed fetch; each [`tick`](Self::tick)
+/// rescans only the sources that changed since the previous one, limited to
+/// today's sessions.
+pub struct LiveTotalScan {
+    paths: HelperPaths,
+    providers: ProvidersConfig,
+    basis: ThresholdBasis,
+    pricing: ModelPricingMap,
+    /// The pricing cache date `pricing` was loaded for; empty after a failed
+    /// fetch, so the next tick retries.
+    pricing_date: String,
+    options: UsageScanOptions,
+    cache: SummaryScanCache,
+    /// The pricing-fetch error when it degraded to an empty map, as in
+    /// [`PricedUsageScan::pricing_error`].
+    pub pricing_error: Option<String>,
+}
+
+impl LiveTotalScan {
+    /// Resolves the provider paths and fetches pricing; `options` is as in
+    /// [`scan_usage_priced`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider paths cannot be resolved.
+    pub fn new(
+        providers: ProvidersConfig,
+        basis: ThresholdBasis,
+        options: UsageScanOptions,
+    ) -> Result<Self> {
+        let (pricing, pricing_error) = fetch_pricing_or_empty(&options.warnings);
+        let pricing = pricing.with_family_estimates(options.estimate_missing_pricing);
+        Ok(Self {
+            paths: resolve_paths()?,
+            providers,
+            basis,
+            options: UsageScanOptions {
+                tiers: priced_scan_options(&pricing, basis).tiers,
+                ..options
+            },
+            pricing_date: if pricing_error.is_none() {
+                pricing_cache_date()
+            } else {
+                String::new()
+            },
+            pricing,
+            cache: SummaryScanCache::new(),
+            pricing_error,
+        })
+    }
+
+    /// Rescans and returns today's `(cost in USD, total tokens)`, refreshing
+    /// pricing first when it is stale.
+    ///
+    /// A failed refresh keeps the previous pricing and sets
+    /// [`pricing_error`](Self::pricing_error).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when every usage source failed to parse.
+    pub fn tick(&mut self, pool: &rayon::ThreadPool) -> Result<(f64, i64)> {
+        self.refresh_stale_pricing();
+        let collection = pool.install(|| {
+            aggregate_usage_from_paths_with_cache_opts(
+                &self.paths,
+                TimeRange::Daily,
+                self.providers,
+                &mut self.cache,
+                &self.options,
+            )
+        })?;
+        if collection.diagnostics.all_failed() {
+            bail!(
+                "failed to parse all {} usage sources",
+                collection.diagnostics.candidates
+            );
+        }
+        let today = get_current_date();
+        Ok((
+            cost_on_date(&collection.data, &today, &self.pricing),
+            tokens_on_date(&collection.data, &today),
+        ))
+    }
+}
+
+impl LiveTotalScan {
+    /// Refetches pricing once the cache date it was loaded for has passed.
+    fn refresh_stale_pricing(&mut self) {
+        let today = pricing_cache_date();
+        if self.pricing_date == today {
+            return;
+        }
+        match fetch_model_pricing(&self.options.warnings) {
+            Ok(pricing) => {
+                let pricing = pricing.with_family_estimates(self.options.estimate_missing_pricing);
+                // New thresholds change the fingerprint, so the scan cache
+                // reclassifies every source on this tick.
+                self.options.tiers = priced_scan_options(&pricing, self.basis).tiers;
+                self.pricing = pricing;
+                self.pricing_date = today;
+                self.pricing_error = None;
+            }
+            Err(e) => {
+                log::warn!("failed to refresh pricing data: {e}; keeping the previous prices");
+                self.pricing_error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+/// Fetches pricing, degrading a failure to an empty map plus its error text.
+///
+/// The degrade policy shared by [`scan_usage_priced`] and `vct stats`.
+pub(crate) fn fetch_pricing_or_empty(warnings: &WarningSink) -> (ModelPricingMap, Option<String>) {
+    match fetch_model_pricing(warnings) {
         Ok(map) => (map, None),
         Err(e) => {
             log::warn!("failed to fetch pricing data: {e}; costs unavailable");
             (ModelPricingMap::new(HashMap::new()), Some(e.to_string()))
         }
+    }
+}
+
+/// Scan options that classify each request's context tier against `pricing`.
+pub(crate) fn priced_scan_options(
+    pricing: &ModelPricingMap,
+    basis: ThresholdBasis,
+) -> UsageScanOptions {
+    UsageScanOptions {
+        tiers: Some(Arc::new(pricing.tier_thresholds().with_basis(basis))),
+        ..UsageScanOptions::default()
+    }
+}
+
+/// The latency-first variant of [`scan_usage_priced`] behind
+/// `usage --estimate-only`.
+///
+/// Pricing comes from the newest on-disk cache of any date (never the network),
+/// and the scan skips per-request context-tier classification, so every request
+/// is priced at the base rate. Parsing is not approximated: every source is
+/// read as in [`scan_usage_priced`] and keeps the same per-date buckets, so
+/// token counts are exact and only costs are rough. Callers should label the
+/// result as an estimate. `options` works as in [`scan_usage_priced`], except
+/// that its `tiers` is ignored.
+///
+/// # Errors
+///
+/// Propagates only a hard scan failure (an all-failed collection); a missing
+/// pricing cache degrades to an empty map with
+/// [`PricedUsageScan::pricing_error`] set.
+pub fn scan_usage_estimate(
+    time_range: TimeRange,
+    providers: ProvidersConfig,
+    options: UsageScanOptions,
+    pool: &rayon::ThreadPool,
+) -> Result<PricedUsageScan> {
+    let (pricing, pricing_error) = match load_cached_model_pricing() {
+        Ok(map) => (map, None),
+        Err(e) => {
+            log::warn!("no usable pricing cache: {e}; costs unavailable");
+            (ModelPricingMap::new(HashMap::new()), Some(e.to_string()))
+        }
     };
-    let options = UsageScanOptions {
-        tiers: Some(Arc::new(pricing.tier_thresholds())),
-    };
+    let pricing = pricing.with_family_estimates(options.estimate_missing_pricing);
     let collection = pool.install(|| {
-        aggregate_usage_from_home_with_diagnostics_opts(time_range, providers, &options)
+        aggregate_usage_from_home_with_diagnostics_opts(
+            time_range,
+            providers,
+            &UsageScanOptions {
+                tiers: None,
+                ..options
+            },
+        )
     })?;
     Ok(PricedUsageScan {
         collection,
@@ -6,11 +6,14 @@
 //! the same `matched_model`-only-when-present behavior the CLI has always
 //! emitted.
 
-use crate::models::PerProviderUsage;
-use crate::pricing::{CostSource, ModelPricingMap, resolve_model_cost};
+use crate::models::{PerProviderUsage, Provider};
+use crate::pricing::{
+    CostComponent, CostSource, ModelPricingMap, calculate_cost_breakdown, resolve_model_cost,
+};
+use crate::usage::summary::family_estimated;
 use crate::usage::{StoredCosts, UsageData};
-use crate::utils::{extract_token_counts, normalize_usage_value};
-use serde::Serialize;
+use crate::utils::{extract_token_counts, merge_usage_values, normalize_usage_value};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// One priced model row of the `usage --json` output.
@@ -18,17 +21,30 @@ use serde_json::Value;
 /// The old binary built each row as a `serde_json::Value` object, whose
 /// `serde_json::Map` (this crate does not enable `preserve_order`) serializes
 /// keys alphabetically. Fields are declared in that same alphabetical order
-/// (`cost_usd`, `matched_model`, `model`, `usage`) so the derived output is
-/// byte-for-byte identical to what the CLI has always emitted.
-#[derive(Debug, Clone, Serialize)]
+/// (`cost_breakdown`, `cost_usd`, `estimated`, `matched_model`, `model`,
+/// `sessions`, `usage`) so the derived output keeps the key order the CLI has always
+/// emitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PricedUsageRow {
+    /// Per-token-type cost split with the tier each slice was billed at; only
+    /// present when requested (see [`add_cost_breakdowns`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_breakdown: Option<Vec<CostComponent>>,
     /// Resolved cost in USD.
     pub cost_usd: f64,
+    /// `true` when the price is a `--estimate-missing-pricing` family
+    /// average; omitted otherwise.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub estimated: bool,
     /// The LiteLLM key actually used, when it differed from `model`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub matched_model: Option<String>,
     /// Model name (merged across providers).
     pub model: String,
+    /// Distinct sessions that contributed this model. Defaults to `0`
+    /// when reading an export from before the field existed.
+    #[serde(default)]
+    pub sessions: usize,
     /// Token counts normalized to the flat key set (see [`normalize_usage_value`]).
     pub usage: Value,
 }
@@ -41,12 +57,14 @@ pub struct PricedUsageRow {
 /// report their own stored cost for their own portion of a merged row rather
 /// than applying it to other providers with the same model name. Rows follow the
 /// insertion order of `usage_data.models` (deliberately unsorted, matching the
-/// historical output).
+/// historical output). Each model is priced under its reported name and then
+/// renamed per [`UsageData::model_renames`]; rows renamed onto one model merge,
+/// dropping a `matched_model` that only one of them had.
 pub fn price_usage_data(
     usage_data: &UsageData,
     pricing_map: &ModelPricingMap,
 ) -> Vec<PricedUsageRow> {
-    let mut rows = Vec::with_capacity(usage_data.models.len());
+    let mut rows: Vec<PricedUsageRow> = Vec::with_capacity(usage_data.models.len());
 
     for (model, usage) in usage_data.models.iter() {
         let (cost, matched_model) = resolve_merged_model_cost(
@@ -56,9 +74,21 @@ pub fn price_usage_data(
             &usage_data.stored_costs,
         )
         .unwrap_or_else(|| price_usage_value(model, usage, pricing_map, CostSource::Litellm));
+        let estimated = family_estimated(model, matched_model.as_deref(), pricing_map);
+        let model = usage_data.model_renames.shown(model);
 
+        if let Some(row) = rows.iter_mut().find(|row| row.model == model) {
+            merge_usage_values(&mut row.usage, &normalize_usage_value(usage));
+            row.cost_usd += cost;
+            row.estimated |= estimated;
+            row.matched_model = None;
+            continue;
+        }
         rows.push(PricedUsageRow {
-            model: model.clone(),
+            cost_breakdown: None,
+            estimated,
+            model: model.to_string(),
+            sessions: usage_data.sessions.for_model(model).len(),
             usage: normalize_usage_value(usage),
             cost_usd: cost,
             matched_model,
@@ -68,6 +98,46 @@ pub fn price_usage_data(
     rows
 }
 
+/// Fills each row's `cost_breakdown` (`usage --json --cost-breakdown`).
+///
+/// Splits the row's tokens by type and by the price tier
+/// [`calculate_cost_breakdown`] bills them at (`base` or `above_200k`),
+/// against the LiteLLM price of each reported model behind the row, so a row
+/// merged by `--fixup-model` sums the split of every model renamed into it.
+/// `rows` must come from [`price_usage_data`] over the same `usage_data`.
+/// Stored-cost portions (OpenCode / Hermes models without an exact price) have
+/// no per-token split, so for those rows the components need not sum to
+/// `cost_usd`.
+pub fn add_cost_breakdowns(
+    rows: &mut [PricedUsageRow],
+    usage_data: &UsageData,
+    pricing_map: &ModelPricingMap,
+) {
+    for row in rows {
+        let mut breakdown: Vec<CostComponent> = Vec::new();
+        let sources = usage_data
+            .models
+            .iter()
+            .filter(|(model, _)| usage_data.model_renames.shown(model) == row.model);
+        for (model, usage) in sources {
+            let pricing = pricing_map.get(model).pricing;
+            let counts = extract_token_counts(usage);
+            for component in calculate_cost_breakdown(&counts, &pricing) {
+                match breakdown.iter_mut().find(|existing| {
+                    existing.token_type == component.token_type && existing.tier == component.tier
+                }) {
+                    Some(existing) => {
+                        existing.tokens += component.tokens;
+                        existing.cost_usd += component.cost_usd;
+                    }
+                    None => breakdown.push(component),
+                }
+            }
+        }
+        row.cost_breakdown = Some(breakdown);
+    }
+}
+
 /// Resolves cost for one merged per-model row from its provider-scoped usage
 /// pieces.
 ///
@@ -86,51 +156,58 @@ pub(crate) fn resolve_merged_model_cost(
     let mut matched_model = None;
     let mut found = false;
 
-    for usage in [
-        &per_provider.claude,
-        &per_provider.codex,
-        &per_provider.copilot,
-        &per_provider.gemini,
-    ] {
-        if let Some(raw_usage) = usage.get(model) {
-            found = true;
-            let (cost, matched) =
-                price_usage_value(model, raw_usage, pricing_map, CostSource::Litellm);
-            total_cost += cost;
-            if matched_model.is_none() {
-                matched_model = matched;
-            }
+    for provider in PRICING_ORDER {
+        let Some(raw_usage) = per_provider
+            .get(provider)
+            .and_then(|usage| usage.get(model))
+        else {
+            continue;
+        };
+        found = true;
+        let source = provider_cost_source(provider, model, stored_costs);
+        let (cost, matched) = price_usage_value(model, raw_usage, pricing_map, source);
+        total_cost += cost;
+        if matched_model.is_none() {
+            matched_model = matched;
         }
     }
 
-    // OpenCode and Hermes prefer an exact LiteLLM match before their stored
-    // costs. Cursor is a local token estimate, so it uses an exact LiteLLM
-    // price when available and otherwise remains unpriced.
+    found.then_some((total_cost, matched_model))
+}
+
+/// Provider order in which a merged row's portions are priced; the first
+/// portion with a LiteLLM match names the row's `matched_model`.
+pub(crate) const PRICING_ORDER: [Provider; 8] = [
+    Provider::ClaudeCode,
+    Provider::Codex,
+    Provider::Copilot,
+    Provider::Gemini,
+    Provider::Grok,
+    Provider::OpenCode,
+    Provider::Cursor,
+    Provider::Hermes,
+];
+
+/// The cost basis of `provider`'s own tokens of `model`.
+///
+/// OpenCode and Hermes prefer an exact LiteLLM match before their stored
+/// costs. Cursor is a local token estimate, so it uses an exact LiteLLM price
+/// when available and otherwise remains unpriced. Grok's gauge floors at the
+/// input rate; everything else is plain LiteLLM.
+pub(crate) fn provider_cost_source(
+    provider: Provider,
+    model: &str,
+    stored_costs: &StoredCosts,
+) -> CostSource {
     let stored =
         |m: &crate::constants::FastHashMap<String, f64>| m.get(model).copied().unwrap_or(0.0);
-    for (usage, source) in [
-        (&per_provider.grok, CostSource::GrokGauge),
-        (
-            &per_provider.opencode,
-            CostSource::OpenCodeStored(stored(&stored_costs.opencode)),
-        ),
-        (&per_provider.cursor, CostSource::OpenCodeStored(0.0)),
-        (
-            &per_provider.hermes,
-            CostSource::HermesStored(stored(&stored_costs.hermes)),
-        ),
-    ] {
-        if let Some(raw_usage) = usage.get(model) {
-            found = true;
-            let (cost, matched) = price_usage_value(model, raw_usage, pricing_map, source);
-            total_cost += cost;
-            if matched_model.is_none() {
-                matched_model = matched;
-            }
-        }
+    match provider {
+        Provider::Grok => CostSource::GrokGauge,
+        Provider::OpenCode => CostSource::OpenCodeStored(stored(&stored_costs.opencode)),
+        Provider::Cursor => CostSource::OpenCodeStored(0.0),
+        Provider::Hermes => CostSource::HermesStored(stored(&stored_costs.hermes)),
+        _ => CostSource::Litellm,
     }
-
-    found.then_some((total_cost, matched_model))
 }
 
 /// Prices one raw usage value under `source`.
@@ -179,6 +256,9 @@ mod tests {
             per_provider,
             provider_days: ProviderActiveDays::default(),
             stored_costs: StoredCosts::default(),
+            sessions: Default::default(),
+            per_date: Default::default(),
+            model_renames: Default::default(),
         };
 
         let rows = price_usage_data(&usage_data, &pricing_map);
@@ -219,6 +299,9 @@ mod tests {
             per_provider,
             provider_days: ProviderActiveDays::default(),
             stored_costs,
+            sessions: Default::default(),
+            per_date: Default::default(),
+            model_renames: Default::default(),
         };
 
         let rows = price_usage_data(&usage_data, &pricing_map);
@@ -0,0 +1,99 @@
+//! Per-period usage sections (`usage --split-by week`).
+//!
+//! Unlike a single `--group-by date,model` table, the split keeps the same
+//! per-day, per-model rows but cuts them into one section per ISO week or
+//! calendar month, each with its own subtotal, for reviewing one period at a
+//! time.
+
+use crate::pricing::ModelPricingMap;
+use crate::usage::UsageData;
+use crate::usage::grouping::{GroupBy, GroupKey, GroupedUsageRow, group_usage};
+use anyhow::{Result, bail};
+use chrono::{Datelike, NaiveDate};
+use std::str::FromStr;
+
+/// The period `--split-by` cuts the usage into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPeriod {
+    /// ISO week (Monday through Sunday).
+    Week,
+    /// Calendar month.
+    Month,
+}
+
+impl FromStr for SplitPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            other => bail!("unknown split period `{other}` (expected week or month)"),
+        }
+    }
+}
+
+impl SplitPeriod {
+    /// The period a local `YYYY-MM-DD` date falls in: `2025-W41` for a
+    /// week, `2025-10` for a month. An unparsable date is its own period.
+    pub fn label(self, date: &str) -> String {
+        let Ok(day) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else {
+            return date.to_string();
+        };
+        match self {
+            Self::Week => {
+                let week = day.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Self::Month => day.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+/// One period's per-day, per-model rows and their subtotal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageSection {
+    /// The period's label (see [`SplitPeriod::label`]).
+    pub period: String,
+    /// One row per date and model, by date and then model name.
+    pub rows: Vec<GroupedUsageRow>,
+    /// Total tokens of the rows.
+    pub tokens: i64,
+    /// Total cost in USD of the rows.
+    pub cost_usd: f64,
+}
+
+/// Splits `data` into one section per `period` that saw usage, oldest
+/// first. Rows are priced like the `--group-by date,model` rows, so the
+/// subtotals add up to the `usage` table's total.
+pub fn split_usage(
+    data: &UsageData,
+    period: SplitPeriod,
+    pricing: &ModelPricingMap,
+) -> Vec<UsageSection> {
+    let rows = group_usage(
+        data,
+        &GroupBy(vec![GroupKey::Date, GroupKey::Model]),
+        pricing,
+    );
+    let mut sections: Vec<UsageSection> = Vec::new();
+    for row in rows {
+        let label = period.label(row.date.as_deref().unwrap_or_default());
+        let section = match sections.last_mut() {
+            Some(section) if section.period == label => section,
+            _ => {
+                sections.push(UsageSection {
+                    period: label,
+                    rows: Vec::new(),
+                    tokens: 0,
+                    cost_usd: 0.0,
+                });
+                sections.last_mut().expect("a section was just pushed")
+            }
+        };
+        section.tokens += row.tokens;
+        section.cost_usd += row.cost_usd;
+        section.rows.push(row);
+    }
+    sections
+}
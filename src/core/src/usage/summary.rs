@@ -8,9 +8,12 @@
 
 use crate::models::{PerProviderUsage, ProviderActiveDays, UsageResult};
 use crate::pricing::CostSource;
-use crate::usage::StoredCosts;
+use crate::usage::{ModelRenames, StoredCosts, UsageData, cost_on_date};
+use crate::utils::ModelNames;
+use serde::Serialize;
 use serde_json::Value;
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 
 /// Per-provider cost basis, resolved to a [`CostSource`] per model.
 #[derive(Clone, Copy)]
@@ -55,11 +58,18 @@ impl ProviderPricing<'_> {
 /// `calculate_cost`.
 #[derive(Default, Clone)]
 pub struct UsageRow {
-    /// Raw model name as reported by the session (the pricing-lookup key).
+    /// Model name as reported by the session, or the `--fixup-model` name it
+    /// was renamed to after pricing.
     pub model: String, // 原始模型名稱
-    /// Name shown in the table; appends the fuzzy-matched pricing model in
-    /// parentheses when the lookup was not exact.
-    pub display_model: String, // 可能含 fuzzy match 提示的顯示名稱
+    /// The pricing model the lookup matched when it was not exact, shown in
+    /// parentheses after the name (see [`model_label`](Self::model_label)).
+    pub matched_model: Option<String>, // fuzzy match 提示
+    /// Jaro-Winkler similarity of the pricing model when it was only
+    /// fuzzy-matched; `None` for exact, normalized, and substring matches.
+    pub match_similarity: Option<f64>,
+    /// Whether the price is a `--estimate-missing-pricing` family average
+    /// rather than a real match.
+    pub estimated: bool,
     /// Prompt (input) tokens.
     pub input_tokens: i64,
     /// User-visible response tokens, excluding reasoning.
@@ -74,9 +84,37 @@ pub struct UsageRow {
     pub total: i64,
     /// LiteLLM-priced cost in USD for this model's tokens.
     pub cost: f64,
+    /// Distinct sessions that contributed this model (see
+    /// [`UsageSummary::attach_session_counts`]); the size of `session_ids`.
+    pub sessions: usize,
+    /// The ids of those sessions, kept so merged rows can count their union.
+    pub session_ids: BTreeSet<String>,
+    /// The models folded into this `Other (N models)` row by
+    /// `--group-threshold`; empty for a model's own row.
+    pub grouped_models: Vec<String>,
 }
 
 impl UsageRow {
+    /// Whether the pricing model was fuzzy-matched below `threshold`, so the
+    /// `(matched)` hint is more likely wrong than informative.
+    pub fn is_low_confidence_match(&self, threshold: f64) -> bool {
+        self.match_similarity
+            .is_some_and(|similarity| similarity < threshold)
+    }
+
+    /// The name to show for this row: the model as `names` displays it, with
+    /// its [`matched_model`](Self::matched_model) hint unless the match is
+    /// low-confidence.
+    pub fn model_label(&self, threshold: f64, names: &ModelNames) -> Cow<'_, str> {
+        let name = names.display(&self.model);
+        match &self.matched_model {
+            Some(matched) if !self.is_low_confidence_match(threshold) => {
+                Cow::Owned(format!("{name} ({matched})"))
+            }
+            _ => name,
+        }
+    }
+
     /// Sum of output and reasoning tokens — the "total model-emitted
     /// tokens" figure most display tables want to show in an Output
     /// column so the row adds up to `total`.
@@ -103,6 +141,9 @@ pub struct UsageTotals {
     pub total: i64,
     /// Summed cost in USD.
     pub cost: f64,
+    /// Distinct session sources across all rows. Not a row-wise sum: one
+    /// session reporting two models still counts once.
+    pub sessions: usize,
 }
 
 impl UsageTotals {
@@ -159,6 +200,27 @@ pub struct UsageSummary {
     pub provider_totals: UsageProviderTotals,
 }
 
+impl UsageSummary {
+    /// Fills each row's sessions and the totals' session count from the
+    /// session ids the aggregator collected.
+    ///
+    /// Call before [`merge_rows_by_base_model`], which unites the per-row ids.
+    pub fn attach_session_counts(&mut self, usage_data: &UsageData) {
+        for row in &mut self.rows {
+            row.session_ids = if row.grouped_models.is_empty() {
+                usage_data.sessions.for_model(&row.model)
+            } else {
+                row.grouped_models
+                    .iter()
+                    .flat_map(|model| usage_data.sessions.for_model(model))
+                    .collect()
+            };
+            row.sessions = row.session_ids.len();
+        }
+        self.totals.sessions = usage_data.sessions.total();
+    }
+}
+
 /// Calculate per-provider totals using **source-directory** attribution.
 ///
 /// Token aggregation is fed directly from the `per_provider` map that
@@ -284,12 +346,22 @@ fn accumulate_provider(
 /// per-provider footer). Keeping the two aggregations independent is what
 /// lets Copilot-originated Claude tokens stay attributed to Copilot even
 /// though they share a row with Claude Code tokens in the main table.
+///
+/// Every model is priced under its reported name first; only then is each row
+/// renamed per `model_renames` (`--fixup-model`), and rows that share a name
+/// are merged, so a rename never changes which price applies.
+///
+/// With a `group_threshold` (`usage --group-threshold`), once the totals are
+/// known every model costing less than that percentage of the total cost is
+/// folded into one `Other (N models)` row, placed last.
 pub fn build_usage_summary(
     usage_data: &UsageResult,
     per_provider: &PerProviderUsage,
     provider_days: &ProviderActiveDays,
     pricing_map: &crate::pricing::ModelPricingMap,
     stored_costs: &StoredCosts,
+    model_renames: &ModelRenames,
+    group_threshold: Option<f64>,
 ) -> UsageSummary {
     if usage_data.is_empty() {
         return UsageSummary::default();
@@ -305,9 +377,16 @@ pub fn build_usage_summary(
         let (cost, matched_model) =
             crate::usage::resolve_merged_model_cost(model, per_provider, pricing_map, stored_costs)
                 .unwrap_or_else(|| price_usage(model, usage, pricing_map, CostSource::Litellm));
-        let row = build_usage_row(model, usage, cost, matched_model);
+        let similarity = fuzzy_similarity(model, matched_model.as_deref(), pricing_map);
+        let estimated = family_estimated(model, matched_model.as_deref(), pricing_map);
+        let mut row = build_usage_row(model_renames.shown(model), usage, cost, matched_model);
+        row.match_similarity = similarity;
+        row.estimated = estimated;
         summary.rows.push(row);
     }
+    if !model_renames.is_empty() {
+        summary.rows = merge_renamed_rows(std::mem::take(&mut summary.rows));
+    }
 
     // Sort by cost ascending (higher cost at the bottom); tie-break by model name for stability
     summary.rows.sort_by(|a, b| {
@@ -320,6 +399,13 @@ pub fn build_usage_summary(
     for row in &summary.rows {
         summary.totals.accumulate(row);
     }
+    if let Some(threshold) = group_threshold {
+        summary.rows = group_rows_below_share(
+            std::mem::take(&mut summary.rows),
+            summary.totals.cost,
+            threshold,
+        );
+    }
 
     summary.provider_totals = calculate_provider_totals_from_per_provider(
         per_provider,
@@ -330,13 +416,37 @@ pub fn build_usage_summary(
     summary
 }
 
+/// Merges the priced rows that were renamed onto the same model, summing their
+/// token buckets and costs. A merged row drops the `(matched)` hints of its
+/// parts, which may each have matched a different pricing model.
+fn merge_renamed_rows(rows: Vec<UsageRow>) -> Vec<UsageRow> {
+    let mut merged: Vec<UsageRow> = Vec::with_capacity(rows.len());
+    for row in rows {
+        let Some(acc) = merged.iter_mut().find(|acc| acc.model == row.model) else {
+            merged.push(row);
+            continue;
+        };
+        acc.matched_model = None;
+        acc.match_similarity = None;
+        acc.estimated |= row.estimated;
+        acc.input_tokens += row.input_tokens;
+        acc.output_tokens += row.output_tokens;
+        acc.reasoning_tokens += row.reasoning_tokens;
+        acc.cache_read += row.cache_read;
+        acc.cache_creation += row.cache_creation;
+        acc.total += row.total;
+        acc.cost += row.cost;
+    }
+    merged
+}
+
 /// Builds one priced [`UsageRow`] from a model's raw usage `Value`.
 ///
 /// Token counts come from [`extract_token_counts`](crate::utils::extract_token_counts);
 /// cost is resolved by [`resolve_model_cost`](crate::pricing::resolve_model_cost)
 /// under `source` (LiteLLM for file providers, the stored cost for OpenCode /
 /// Cursor). When a non-exact LiteLLM key was used, the matched model name is
-/// appended to `display_model` in parentheses.
+/// kept in `matched_model`.
 fn extract_usage_row(
     model: &str,
     usage: &Value,
@@ -349,7 +459,35 @@ fn extract_usage_row(
     // Extract once and reuse for both pricing and the row (was extracted twice).
     let counts = extract_token_counts(usage);
     let (cost, matched_model) = resolve_model_cost(model, &counts, pricing_map, source);
-    build_usage_row_from_counts(model, &counts, cost, matched_model)
+    let similarity = fuzzy_similarity(model, matched_model.as_deref(), pricing_map);
+    let estimated = family_estimated(model, matched_model.as_deref(), pricing_map);
+    let mut row = build_usage_row_from_counts(model, &counts, cost, matched_model);
+    row.match_similarity = similarity;
+    row.estimated = estimated;
+    row
+}
+
+/// The similarity of `model`'s pricing match when it was annotated with
+/// `matched_model` by the fuzzy tier. The lookup is memoized per map, so
+/// asking again after pricing is cheap.
+fn fuzzy_similarity(
+    model: &str,
+    matched_model: Option<&str>,
+    pricing_map: &crate::pricing::ModelPricingMap,
+) -> Option<f64> {
+    matched_model?;
+    pricing_map.get(model).match_kind.fuzzy_similarity()
+}
+
+/// Whether `model` was priced (annotated with `matched_model`) by the
+/// `--estimate-missing-pricing` family average.
+pub(crate) fn family_estimated(
+    model: &str,
+    matched_model: Option<&str>,
+    pricing_map: &crate::pricing::ModelPricingMap,
+) -> bool {
+    matched_model.is_some()
+        && pricing_map.get(model).match_kind == crate::pricing::MatchKind::Estimated
 }
 
 /// Prices one raw usage value under `source`.
@@ -385,16 +523,11 @@ fn build_usage_row_from_counts(
     cost: f64,
     matched_model: Option<String>,
 ) -> UsageRow {
-    // Use Cow<str> for display_model to avoid allocation when no annotation
-    let display_model = if let Some(matched) = &matched_model {
-        Cow::Owned(format!("{} ({})", model, matched))
-    } else {
-        Cow::Borrowed(model)
-    };
-
     UsageRow {
         model: model.to_string(),
-        display_model: display_model.into_owned(),
+        matched_model,
+        match_similarity: None,
+        estimated: false,
         input_tokens: counts.input_tokens,
         output_tokens: counts.output_tokens,
         reasoning_tokens: counts.reasoning_tokens,
@@ -402,6 +535,9 @@ fn build_usage_row_from_counts(
         cache_creation: counts.cache_creation,
         total: counts.total,
         cost,
+        sessions: 0,
+        session_ids: BTreeSet::new(),
+        grouped_models: Vec::new(),
     }
 }
 
@@ -425,6 +561,8 @@ fn base_model_key(model: &str) -> &str {
 /// model has no duplicate, e.g. `opencode/big-pickle` -> `big-pickle`) with no
 /// count suffix, so the merged view reads uniformly. The result is re-sorted by
 /// ascending cost, tie-broken by model name, matching [`build_usage_summary`].
+/// Session ids are united, so a session that reported two merged variants
+/// counts once in the merged row.
 pub fn merge_rows_by_base_model(rows: &[UsageRow]) -> Vec<UsageRow> {
     use std::collections::HashMap;
 
@@ -440,7 +578,6 @@ pub fn merge_rows_by_base_model(rows: &[UsageRow]) -> Vec<UsageRow> {
     for (key, members) in groups {
         let mut acc = UsageRow {
             model: key.to_string(),
-            display_model: key.to_string(),
             ..UsageRow::default()
         };
         for m in members {
@@ -451,20 +588,126 @@ pub fn merge_rows_by_base_model(rows: &[UsageRow]) -> Vec<UsageRow> {
             acc.cache_creation += m.cache_creation;
             acc.total += m.total;
             acc.cost += m.cost;
+            acc.session_ids.extend(m.session_ids.iter().cloned());
+            acc.grouped_models.extend(m.grouped_models.iter().cloned());
+            acc.estimated |= m.estimated;
         }
+        acc.sessions = acc.session_ids.len();
         merged.push(acc);
     }
 
-    // Same ordering as build_usage_summary so the merged view reads identically.
+    // Same ordering as build_usage_summary so the merged view reads identically,
+    // including a `--group-threshold` `Other` row staying last.
     merged.sort_by(|a, b| {
-        a.cost
-            .partial_cmp(&b.cost)
-            .unwrap_or(std::cmp::Ordering::Equal)
+        (!a.grouped_models.is_empty())
+            .cmp(&!b.grouped_models.is_empty())
+            .then_with(|| {
+                a.cost
+                    .partial_cmp(&b.cost)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
             .then_with(|| a.model.cmp(&b.model))
     });
     merged
 }
 
+/// Folds every row costing less than `threshold_pct` percent of `total_cost`
+/// into one `Other (N models)` row (`usage --group-threshold`), summing its
+/// token buckets and cost and uniting its session ids.
+///
+/// Shares are taken against the summary's `total_cost`, so the grand total is
+/// unchanged. The remaining rows keep their existing order and the `Other` row
+/// comes last. Nothing is folded when fewer than two rows fall below the
+/// threshold, or when nothing cost anything.
+fn group_rows_below_share(
+    rows: Vec<UsageRow>,
+    total_cost: f64,
+    threshold_pct: f64,
+) -> Vec<UsageRow> {
+    if total_cost <= 0.0 {
+        return rows;
+    }
+    let is_small = |row: &UsageRow| row.cost / total_cost * 100.0 < threshold_pct;
+    let small = rows.iter().filter(|row| is_small(row)).count();
+    if small < 2 {
+        return rows;
+    }
+
+    let mut other = UsageRow {
+        model: format!("Other ({small} models)"),
+        ..UsageRow::default()
+    };
+    let mut grouped = Vec::with_capacity(rows.len() - small + 1);
+    for row in rows {
+        if !is_small(&row) {
+            grouped.push(row);
+            continue;
+        }
+        other.input_tokens += row.input_tokens;
+        other.output_tokens += row.output_tokens;
+        other.reasoning_tokens += row.reasoning_tokens;
+        other.cache_read += row.cache_read;
+        other.cache_creation += row.cache_creation;
+        other.total += row.total;
+        other.cost += row.cost;
+        other.session_ids.extend(row.session_ids);
+        other.grouped_models.push(row.model);
+    }
+    other.sessions = other.session_ids.len();
+    grouped.push(other);
+    grouped
+}
+
+/// The headline figures of the usage footer, printed alone by
+/// `usage --summary-only`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageFooter {
+    /// Summed cost in USD.
+    pub total_cost: f64,
+    /// Cost in USD of the usage recorded today.
+    pub today_cost: f64,
+    /// Summed total tokens.
+    pub total_tokens: i64,
+    /// Model rows the table would show.
+    pub models: usize,
+    /// Distinct session sources.
+    pub sessions: usize,
+}
+
+/// Prices `usage_data` and keeps only the footer figures.
+///
+/// `merge` counts models the way `--merge-providers` would show them; the
+/// totals are the same either way.
+pub fn usage_footer(
+    usage_data: &UsageData,
+    pricing_map: &crate::pricing::ModelPricingMap,
+    merge: bool,
+) -> UsageFooter {
+    let mut summary = build_usage_summary(
+        &usage_data.models,
+        &usage_data.per_provider,
+        &usage_data.provider_days,
+        pricing_map,
+        &usage_data.stored_costs,
+        &usage_data.model_renames,
+        None,
+    );
+    summary.attach_session_counts(usage_data);
+    let models = if merge {
+        merge_rows_by_base_model(&summary.rows).len()
+    } else {
+        summary.rows.len()
+    };
+    UsageFooter {
+        total_cost: summary.totals.cost,
+        today_cost: cost_on_date(usage_data, &crate::utils::get_current_date(), pricing_map),
+        total_tokens: summary.totals.total,
+        models,
+        sessions: summary.totals.sessions,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,6 +742,8 @@ mod tests {
             &ProviderActiveDays::default(),
             &pricing_map,
             &StoredCosts::default(),
+            &ModelRenames::default(),
+            None,
         );
 
         assert!((summary.rows[0].cost - 2.0).abs() < 1e-9);
@@ -538,11 +783,13 @@ mod tests {
             &ProviderActiveDays::default(),
             &pricing_map,
             &stored_costs,
+            &ModelRenames::default(),
+            None,
         );
 
         assert_eq!(summary.rows.len(), 1);
         assert!((summary.rows[0].cost - 8.0).abs() < 1e-9);
-        assert_eq!(summary.rows[0].display_model, "shared-pro (shared)");
+        assert_eq!(summary.rows[0].matched_model.as_deref(), Some("shared"));
     }
 
     #[test]
@@ -580,6 +827,8 @@ mod tests {
             &ProviderActiveDays::default(),
             &pricing_map,
             &stored_costs,
+            &ModelRenames::default(),
+            None,
         );
 
         assert_eq!(summary.rows.len(), 1);
@@ -615,6 +864,8 @@ mod tests {
             &ProviderActiveDays::default(),
             &pricing_map,
             &stored_costs,
+            &ModelRenames::default(),
+            None,
         );
 
         assert_eq!(summary.rows.len(), 1);
@@ -623,10 +874,68 @@ mod tests {
         assert!(summary.provider_totals.cursor.total_cost.abs() < 1e-9);
     }
 
+    #[test]
+    fn borderline_fuzzy_match_is_flagged_apart_from_a_confident_one() {
+        clear_pricing_cache();
+        let raw_pricing = ["acme-large-lm", "zeta-coder-pro-v2"]
+            .into_iter()
+            .map(|model| {
+                (
+                    model.to_string(),
+                    ModelPricing {
+                        input_cost_per_token: 0.01,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+        let pricing_map = ModelPricingMap::new(raw_pricing);
+        let mut usage_data = UsageResult::default();
+        let mut per_provider = PerProviderUsage::default();
+        for model in ["acme-turbo-lm", "zeta-coder-x", "acme-large-lm"] {
+            usage_data.insert(model.to_string(), json!({"input_tokens": 10}));
+            per_provider
+                .claude
+                .insert(model.to_string(), json!({"input_tokens": 10}));
+        }
+
+        let summary = build_usage_summary(
+            &usage_data,
+            &per_provider,
+            &ProviderActiveDays::default(),
+            &pricing_map,
+            &StoredCosts::default(),
+            &ModelRenames::default(),
+            None,
+        );
+        let row = |model: &str| summary.rows.iter().find(|row| row.model == model).unwrap();
+
+        // ~0.88 similar: priced, but shown bare and flagged.
+        let borderline = row("acme-turbo-lm");
+        assert!(borderline.match_similarity.is_some());
+        assert!(borderline.is_low_confidence_match(0.9));
+        assert_eq!(
+            borderline.model_label(0.9, &ModelNames::default()),
+            "acme-turbo-lm"
+        );
+        assert!(!borderline.is_low_confidence_match(0.8));
+
+        // ~0.91 similar: keeps the hint.
+        let confident = row("zeta-coder-x");
+        assert!(!confident.is_low_confidence_match(0.9));
+        assert_eq!(
+            confident.model_label(0.9, &ModelNames::default()),
+            "zeta-coder-x (zeta-coder-pro-v2)"
+        );
+
+        let exact = row("acme-large-lm");
+        assert_eq!(exact.match_similarity, None);
+        assert!(!exact.is_low_confidence_match(1.0));
+    }
+
     fn row(model: &str, input: i64, total: i64, cost: f64) -> UsageRow {
         UsageRow {
             model: model.to_string(),
-            display_model: model.to_string(),
             input_tokens: input,
             total,
             cost,
@@ -647,7 +956,7 @@ mod tests {
         assert_eq!(merged.len(), 1);
         let m = &merged[0];
         assert_eq!(m.model, "gpt-5.5");
-        assert_eq!(m.display_model, "gpt-5.5");
+        assert_eq!(m.matched_model, None);
         assert_eq!(m.input_tokens, 600);
         assert_eq!(m.total, 600);
         assert!((m.cost - 8.20).abs() < 1e-9);
@@ -668,11 +977,9 @@ mod tests {
         assert_eq!(merged.len(), 2);
         // The two gpt-5.5 rows collapse to one base row; gpt-5.4 stays separate.
         let five_five = merged.iter().find(|r| r.model == "gpt-5.5").unwrap();
-        assert_eq!(five_five.display_model, "gpt-5.5");
         assert_eq!(five_five.total, 40);
         // The lone 5.4 also shows under its bare base name, keeping its tokens.
         let five_four = merged.iter().find(|r| r.model == "gpt-5.4").unwrap();
-        assert_eq!(five_four.display_model, "gpt-5.4");
         assert_eq!(five_four.total, 20);
     }
 
@@ -681,13 +988,13 @@ mod tests {
         // Even a model with no duplicate drops its provider prefix (and any
         // fuzzy-match hint) so the merged view reads uniformly.
         let mut only = row("deepseek/deepseek-v4-pro", 5, 5, 1.5);
-        only.display_model = "deepseek/deepseek-v4-pro (deepseek-v4)".to_string();
+        only.matched_model = Some("deepseek-v4".to_string());
 
         let merged = merge_rows_by_base_model(std::slice::from_ref(&only));
 
         assert_eq!(merged.len(), 1);
         assert_eq!(merged[0].model, "deepseek-v4-pro");
-        assert_eq!(merged[0].display_model, "deepseek-v4-pro");
+        assert_eq!(merged[0].matched_model, None);
         assert_eq!(merged[0].total, 5);
     }
 
@@ -714,4 +1021,58 @@ mod tests {
         assert_eq!(merged[1].model, "gpt-5.5");
         assert!((merged[1].cost - 18.0).abs() < 1e-9);
     }
+
+    #[test]
+    fn models_under_the_threshold_collapse_into_one_other_row() {
+        clear_pricing_cache();
+        let mut raw_pricing = std::collections::HashMap::new();
+        let mut usage_data = UsageResult::default();
+        // Costs $60, $36, $2, $1.50, and $0.50 at $0.01 per input token.
+        for (model, tokens) in [
+            ("big-a", 6_000),
+            ("big-b", 3_600),
+            ("tiny-a", 200),
+            ("tiny-b", 150),
+            ("tiny-c", 50),
+        ] {
+            raw_pricing.insert(
+                model.to_string(),
+                ModelPricing {
+                    input_cost_per_token: 0.01,
+                    ..Default::default()
+                },
+            );
+            usage_data.insert(model.to_string(), json!({ "input_tokens": tokens }));
+        }
+        let pricing = ModelPricingMap::new(raw_pricing);
+        let summary = |group_threshold| {
+            build_usage_summary(
+                &usage_data,
+                &PerProviderUsage::default(),
+                &ProviderActiveDays::default(),
+                &pricing,
+                &StoredCosts::default(),
+                &ModelRenames::default(),
+                group_threshold,
+            )
+        };
+
+        let grouped = summary(Some(5.0));
+        assert!((grouped.totals.cost - 100.0).abs() < 1e-9);
+        let models: Vec<&str> = grouped.rows.iter().map(|row| row.model.as_str()).collect();
+        assert_eq!(models, ["big-b", "big-a", "Other (3 models)"]);
+        let other = &grouped.rows[2];
+        assert_eq!(other.input_tokens, 400);
+        assert!((other.cost - 4.0).abs() < 1e-9);
+        assert_eq!(other.grouped_models, ["tiny-c", "tiny-b", "tiny-a"]);
+        let cost: f64 = grouped.rows.iter().map(|row| row.cost).sum();
+        assert!((cost - grouped.totals.cost).abs() < 1e-9);
+
+        // The Other row stays last in the merged view.
+        let merged = merge_rows_by_base_model(&grouped.rows);
+        assert_eq!(merged.last().unwrap().model, "Other (3 models)");
+
+        // A lone model under the threshold keeps its own row.
+        assert_eq!(summary(Some(1.0)).rows.len(), summary(None).rows.len());
+    }
 }
@@ -0,0 +1,115 @@
+//! Pushing `usage` results to a chat webhook (`usage --webhook`).
+//!
+//! The payload is either the same priced rows `usage --json` prints or, for
+//! Slack/Teams-style incoming webhooks, a compact summary whose `text` field
+//! those services render as the message. The POST goes through the shared
+//! blocking client ([`crate::quota::http::build_client`]) with a caller-chosen
+//! timeout.
+
+use crate::usage::summary::UsageFooter;
+use crate::utils::format_number;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+const USER_AGENT: &str = concat!("vibe_coding_tracker/", env!("CARGO_PKG_VERSION"));
+
+/// Default `--webhook-timeout`, in seconds.
+pub const DEFAULT_WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// What `--webhook` posts (`--webhook-format`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WebhookFormat {
+    /// The priced per-model rows, as `usage --json` prints them (the default).
+    #[default]
+    Json,
+    /// The footer totals plus a one-line `text` message.
+    Summary,
+}
+
+impl FromStr for WebhookFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "json" => Ok(Self::Json),
+            "summary" => Ok(Self::Summary),
+            other => bail!("unknown webhook format `{other}` (expected json or summary)"),
+        }
+    }
+}
+
+/// The `--webhook-format summary` payload: the footer figures with a
+/// human-readable `text` line chat services show as the message body.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSummary {
+    /// One-line rendering of the figures below.
+    pub text: String,
+    /// The footer figures `usage --summary-only --json` prints.
+    #[serde(flatten)]
+    pub footer: UsageFooter,
+}
+
+impl From<UsageFooter> for WebhookSummary {
+    fn from(footer: UsageFooter) -> Self {
+        let text = format!(
+            "Vibe Coding Tracker: ${:.2} total (${:.2} today), {} tokens across {} model(s) in {} session(s)",
+            footer.total_cost,
+            footer.today_cost,
+            format_number(footer.total_tokens),
+            footer.models,
+            footer.sessions,
+        );
+        Self { text, footer }
+    }
+}
+
+/// POSTs `payload` as JSON to `url`, giving up after `timeout`.
+///
+/// Returns the response status when it is a success (2xx).
+///
+/// # Errors
+///
+/// Returns an error when the request cannot be sent or times out, when the
+/// server answers with a non-success status (named in the message), or when
+/// `VCT_OFFLINE` disables the network. Errors name only the URL's scheme and
+/// host, since its path or query usually carries the webhook's secret token.
+pub fn post_webhook(
+    url: &str,
+    payload: &impl Serialize,
+    timeout: Duration,
+) -> Result<reqwest::StatusCode> {
+    let origin = url_origin(url);
+    if crate::utils::network_disabled() {
+        return Err(crate::utils::Offline(format!("Not posting to webhook {origin}")).into());
+    }
+    // The shared client carries no UA, and the per-request timeout overrides
+    // its default one.
+    let response = crate::quota::http::build_client()?
+        .post(url)
+        .header(reqwest::header::USER_AGENT, USER_AGENT)
+        .timeout(timeout)
+        .json(payload)
+        .send()
+        .map_err(reqwest::Error::without_url)
+        .with_context(|| format!("Failed to POST usage to webhook {origin}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("Webhook {origin} answered HTTP {status}");
+    }
+    Ok(status)
+}
+
+/// `url` cut down to `scheme://host` for error messages.
+fn url_origin(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => format!(
+            "{}://{}",
+            parsed.scheme(),
+            parsed.host_str().unwrap_or_default()
+        ),
+        Err(_) => "<invalid URL>".to_string(),
+    }
+}
@@ -1,5 +1,7 @@
 use crate::models::TimeRange;
+use crate::utils::session_ignore::SessionIgnore;
 use anyhow::Result;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
@@ -78,15 +80,24 @@ where
     P: AsRef<Path>,
     F: Fn(&Path) -> bool,
 {
-    Ok(collect_files_with_max_depth_diagnostics(dir, filter_fn, time_range, max_depth).files)
+    Ok(collect_files_with_max_depth_diagnostics(
+        dir,
+        filter_fn,
+        time_range,
+        max_depth,
+        &SessionIgnore::default(),
+    )
+    .files)
 }
 
-/// Discovers matching files while retaining partial traversal failures.
+/// Discovers matching files while retaining partial traversal failures,
+/// skipping the sessions `ignore` excludes.
 pub(crate) fn collect_files_with_max_depth_diagnostics<P, F>(
     dir: P,
     filter_fn: F,
     time_range: TimeRange,
     max_depth: Option<usize>,
+    ignore: &SessionIgnore,
 ) -> FileDiscovery
 where
     P: AsRef<Path>,
@@ -120,14 +131,25 @@ where
     let mut results = Vec::with_capacity(20);
     let mut failures = Vec::new();
 
-    let mut walker = WalkDir::new(dir);
+    // Follow symlinked directories (a synced `~/.codex`, a project folder
+    // linked into `~/.claude/projects/`), visiting each real directory once:
+    // two links to the same directory would otherwise double-count its
+    // sessions. A link back to an ancestor is reported by walkdir as a loop
+    // error before the filter sees it; that is expected, not a failure.
+    let mut walker = WalkDir::new(dir).follow_links(true);
     if let Some(depth) = max_depth {
         walker = walker.max_depth(depth);
     }
+    let mut visited_dirs = HashSet::new();
+    let walker = walker.into_iter().filter_entry(|entry| {
+        !entry.file_type().is_dir()
+            || dir_identity(entry).is_none_or(|identity| visited_dirs.insert(identity))
+    });
 
     for entry in walker {
         let entry = match entry {
             Ok(entry) => entry,
+            Err(error) if error.loop_ancestor().is_some() => continue,
             Err(error) => {
                 failures.push(FileDiscoveryFailure {
                     path: error.path().unwrap_or(dir).to_path_buf(),
@@ -143,7 +165,7 @@ where
         let path = entry.path();
 
         // Apply filter
-        if !filter_fn(path) {
+        if !filter_fn(path) || ignore.is_ignored(path) {
             continue;
         }
 
@@ -196,6 +218,22 @@ where
     }
 }
 
+/// Identity of the directory `entry` resolves to, for cycle and alias
+/// detection: device and inode on Unix, the canonical path elsewhere.
+#[cfg(unix)]
+fn dir_identity(entry: &walkdir::DirEntry) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = entry.metadata().ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Identity of the directory `entry` resolves to, for cycle and alias
+/// detection: device and inode on Unix, the canonical path elsewhere.
+#[cfg(not(unix))]
+fn dir_identity(entry: &walkdir::DirEntry) -> Option<PathBuf> {
+    fs::canonicalize(entry.path()).ok()
+}
+
 /// Maximum traversal depth for Copilot CLI session scans.
 ///
 /// Copilot writes `~/.copilot/session-state/<sessionId>/events.jsonl`, so
@@ -639,6 +677,28 @@ mod tests {
         assert_eq!(results.len(), 3);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_collect_files_survives_symlink_cycles_and_aliases() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        File::create(project.join("session.jsonl")).unwrap();
+        // A link back to the root and a second name for `project`.
+        std::os::unix::fs::symlink(dir.path(), project.join("loop")).unwrap();
+        std::os::unix::fs::symlink(&project, dir.path().join("alias")).unwrap();
+
+        let discovery = collect_files_with_max_depth_diagnostics(
+            dir.path(),
+            is_claude_session_file,
+            TimeRange::All,
+            None,
+            &SessionIgnore::default(),
+        );
+        assert_eq!(discovery.files.len(), 1);
+        assert!(discovery.failures.is_empty());
+    }
+
     #[test]
     fn test_collect_files_with_dates_filter_function() {
         // Test that filter function works correctly
@@ -0,0 +1,90 @@
+//! Friendly model names for shared reports (`--pretty-models`).
+//!
+//! Raw ids such as `claude-3-5-sonnet-20241022` read poorly in a table passed
+//! around a team. With the option on, the table and text renderers show
+//! `Claude 3.5 Sonnet` instead; JSON always keeps the raw id, so exports stay
+//! joinable. Names come from `[display] model_names` in the config first, then
+//! the built-in table below; an id neither knows is shown as is.
+
+use crate::pricing::normalize_model_name;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Built-in names, keyed by the id without provider prefix or date suffix.
+const BUILTIN_DISPLAY_NAMES: &[(&str, &str)] = &[
+    ("claude-3-opus", "Claude 3 Opus"),
+    ("claude-3-5-haiku", "Claude 3.5 Haiku"),
+    ("claude-3-5-sonnet", "Claude 3.5 Sonnet"),
+    ("claude-3-7-sonnet", "Claude 3.7 Sonnet"),
+    ("claude-sonnet-4", "Claude Sonnet 4"),
+    ("claude-opus-4", "Claude Opus 4"),
+    ("claude-opus-4-1", "Claude Opus 4.1"),
+    ("claude-sonnet-4-5", "Claude Sonnet 4.5"),
+    ("claude-haiku-4-5", "Claude Haiku 4.5"),
+    ("gpt-4o", "GPT-4o"),
+    ("gpt-4o-mini", "GPT-4o mini"),
+    ("gpt-4.1", "GPT-4.1"),
+    ("gpt-5", "GPT-5"),
+    ("gpt-5-mini", "GPT-5 mini"),
+    ("gpt-5-nano", "GPT-5 nano"),
+    ("gpt-5-codex", "GPT-5 Codex"),
+    ("gemini-2.5-pro", "Gemini 2.5 Pro"),
+    ("gemini-2.5-flash", "Gemini 2.5 Flash"),
+    ("gemini-2.5-flash-lite", "Gemini 2.5 Flash-Lite"),
+    ("grok-4", "Grok 4"),
+    ("grok-code-fast-1", "Grok Code Fast 1"),
+];
+
+/// How model ids are shown: as is, or under their friendly names.
+///
+/// The default shows ids as is. Renderers take this explicitly rather than
+/// reading a process-wide setting, so two reports in one process can name
+/// models differently.
+#[derive(Debug, Clone, Default)]
+pub struct ModelNames {
+    /// The configured overrides (model id → name) when friendly names are on.
+    pretty: Option<Arc<BTreeMap<String, String>>>,
+}
+
+impl ModelNames {
+    /// Friendly names when `pretty` is set (`--pretty-models`), preferring
+    /// `overrides` (model id → name) over the built-in table; ids as is
+    /// otherwise.
+    pub fn new(pretty: bool, overrides: BTreeMap<String, String>) -> Self {
+        Self {
+            pretty: pretty.then(|| Arc::new(overrides)),
+        }
+    }
+
+    /// The name `model` is displayed under: its configured or built-in
+    /// friendly name when friendly names are on, otherwise (or for an unknown
+    /// id) the id itself.
+    ///
+    /// An override must name the id exactly; the built-in table also matches
+    /// ids carrying a provider prefix or a date suffix
+    /// (`bedrock/claude-3-5-sonnet`, `claude-3-5-sonnet-20241022`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vct_core::utils::ModelNames;
+    ///
+    /// let names = ModelNames::new(true, Default::default());
+    /// assert_eq!(names.display("claude-3-5-sonnet-20241022"), "Claude 3.5 Sonnet");
+    /// assert_eq!(ModelNames::default().display("gpt-5"), "gpt-5");
+    /// ```
+    pub fn display<'a>(&self, model: &'a str) -> Cow<'a, str> {
+        let Some(overrides) = &self.pretty else {
+            return Cow::Borrowed(model);
+        };
+        if let Some(name) = overrides.get(model) {
+            return Cow::Owned(name.clone());
+        }
+        let normalized = normalize_model_name(model);
+        BUILTIN_DISPLAY_NAMES
+            .iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(&normalized))
+            .map_or(Cow::Borrowed(model), |(_, name)| Cow::Borrowed(*name))
+    }
+}
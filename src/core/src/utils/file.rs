@@ -1,9 +1,79 @@
 use crate::constants::buffer;
-use anyhow::{Context, Result};
+use crate::warnings::WarningSink;
+use anyhow::{Context, Result, bail};
 use serde_json::Value;
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
+use std::str::FromStr;
+
+/// How [`read_jsonl_with`] and [`read_json_with`] treat bytes that are not
+/// valid UTF-8.
+///
+/// Scans carry it as a parse option (`UsageScanOptions::encoding`,
+/// `AnalysisScanOptions::encoding`); the plain readers use the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextEncoding {
+    /// Reject the file with an error naming it and the first invalid byte.
+    #[default]
+    Utf8,
+    /// Replace invalid sequences with U+FFFD and log a warning.
+    Lossy,
+}
+
+impl FromStr for TextEncoding {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "utf8" | "utf-8" => Ok(Self::Utf8),
+            "lossy" => Ok(Self::Lossy),
+            other => bail!("unknown encoding `{other}` (expected utf8 or lossy)"),
+        }
+    }
+}
+
+/// Decodes one chunk of `path` that starts at byte `offset`.
+///
+/// Under [`TextEncoding::Lossy`] the first replaced offset is remembered in
+/// `replaced` so the caller can warn once per file.
+fn decode_utf8<'a>(
+    bytes: &'a [u8],
+    path: &Path,
+    offset: usize,
+    encoding: TextEncoding,
+    replaced: &mut Option<usize>,
+) -> Result<Cow<'a, str>> {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Ok(Cow::Borrowed(text)),
+        Err(error) => {
+            let at = offset + error.valid_up_to();
+            match encoding {
+                TextEncoding::Utf8 => bail!(
+                    "file is not valid UTF-8: {} (invalid byte at offset {at}); use `--encoding lossy` to replace invalid sequences",
+                    path.display()
+                ),
+                TextEncoding::Lossy => {
+                    replaced.get_or_insert(at);
+                    Ok(String::from_utf8_lossy(bytes))
+                }
+            }
+        }
+    }
+}
+
+fn warn_replaced(path: &Path, replaced: Option<usize>, warnings: Option<&WarningSink>) {
+    if let Some(at) = replaced {
+        crate::warnings::warn_to(
+            warnings,
+            format!(
+                "replaced invalid UTF-8 in {} (first at byte offset {at})",
+                path.display()
+            ),
+        );
+    }
+}
 
 /// Reads a JSONL file and returns one [`Value`] per non-empty line.
 ///
@@ -14,12 +84,28 @@ use std::path::Path;
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be opened, if reading any line fails
-/// (e.g. invalid UTF-8 or an I/O error), or if any non-empty line is not
-/// valid JSON. The error context names the offending line number.
+/// Returns an error if the file cannot be opened, if reading any line fails,
+/// if the file is not valid UTF-8, or if any non-empty line is not valid JSON.
+/// The error context names the offending line number.
 pub fn read_jsonl<P: AsRef<Path>>(path: P) -> Result<Vec<Value>> {
-    let file = File::open(path.as_ref())
-        .with_context(|| format!("Failed to open file: {}", path.as_ref().display()))?;
+    read_jsonl_with(path, TextEncoding::Utf8, None)
+}
+
+/// [`read_jsonl`] with an explicit [`TextEncoding`]. A lossy replacement is
+/// reported to `warnings`, or only logged without one.
+///
+/// # Errors
+///
+/// As [`read_jsonl`]; invalid UTF-8 is only an error under
+/// [`TextEncoding::Utf8`], where the message names the file and byte offset.
+pub fn read_jsonl_with<P: AsRef<Path>>(
+    path: P,
+    encoding: TextEncoding,
+    warnings: Option<&WarningSink>,
+) -> Result<Vec<Value>> {
+    let path = path.as_ref();
+    let file =
+        File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
 
     // Pre-allocate Vec capacity based on estimated line count
     // This reduces allocations and improves performance significantly
@@ -33,20 +119,32 @@ pub fn read_jsonl<P: AsRef<Path>>(path: P) -> Result<Vec<Value>> {
     let mut results = Vec::with_capacity(estimated_lines);
 
     // Use centralized buffer size constant for optimal I/O performance
-    let reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
-
-    for (index, line) in reader.lines().enumerate() {
-        let line = line.with_context(|| format!("Failed to read line {}", index + 1))?;
+    let mut reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
+    let mut line = Vec::with_capacity(buffer::AVG_JSONL_LINE_SIZE);
+    let mut offset = 0;
+    let mut replaced = None;
+
+    for line_number in 1.. {
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .with_context(|| format!("Failed to read line {line_number}"))?;
+        if read == 0 {
+            break;
+        }
+        let text = decode_utf8(&line, path, offset, encoding, &mut replaced)?;
+        offset += read;
 
-        if line.trim().is_empty() {
+        if text.trim().is_empty() {
             continue;
         }
 
-        let obj: Value = serde_json::from_str(&line)
-            .with_context(|| format!("Failed to parse JSON at line {}", index + 1))?;
+        let obj: Value = serde_json::from_str(&text)
+            .with_context(|| format!("Failed to parse JSON at line {line_number}"))?;
 
         results.push(obj);
     }
+    warn_replaced(path, replaced, warnings);
 
     // Shrink capacity to actual size to free excess memory
     results.shrink_to_fit();
@@ -148,38 +246,55 @@ fn persist_atomic(
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be opened, if it cannot be read to a
-/// string, or if its contents are not valid JSON.
+/// Returns an error if the file cannot be opened or read, if it is not valid
+/// UTF-8, or if its contents are not valid JSON.
 pub fn read_json<P: AsRef<Path>>(path: P) -> Result<Vec<Value>> {
-    let file = File::open(path.as_ref())
-        .with_context(|| format!("Failed to open file: {}", path.as_ref().display()))?;
+    read_json_with(path, TextEncoding::Utf8, None)
+}
 
-    // Pre-allocate String capacity based on file size to reduce allocations
+/// [`read_json`] with an explicit [`TextEncoding`]. A lossy replacement is
+/// reported to `warnings`, or only logged without one.
+///
+/// # Errors
+///
+/// As [`read_json`]; invalid UTF-8 is only an error under
+/// [`TextEncoding::Utf8`], where the message names the file and byte offset.
+pub fn read_json_with<P: AsRef<Path>>(
+    path: P,
+    encoding: TextEncoding,
+    warnings: Option<&WarningSink>,
+) -> Result<Vec<Value>> {
+    let path = path.as_ref();
+    let file =
+        File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
+
+    // Pre-allocate capacity based on file size to reduce allocations
     let file_size = file.metadata().ok().map(|m| m.len() as usize).unwrap_or(0);
-    let mut contents = String::with_capacity(file_size);
+    let mut contents = Vec::with_capacity(file_size);
 
     // Use centralized buffer size constant for optimal I/O performance
     let mut reader = BufReader::with_capacity(buffer::FILE_READ_BUFFER, file);
     reader
-        .read_to_string(&mut contents)
-        .with_context(|| format!("Failed to read file: {}", path.as_ref().display()))?;
+        .read_to_end(&mut contents)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
 
-    let obj: Value = serde_json::from_str(&contents).with_context(|| {
-        format!(
-            "Failed to parse JSON from file: {}",
-            path.as_ref().display()
-        )
-    })?;
+    let mut replaced = None;
+    let text = decode_utf8(&contents, path, 0, encoding, &mut replaced)?;
+    warn_replaced(path, replaced, warnings);
+
+    let obj: Value = serde_json::from_str(&text)
+        .with_context(|| format!("Failed to parse JSON from file: {}", path.display()))?;
 
     Ok(vec![obj])
 }
 
 /// Counts the lines in `text`.
 ///
-/// A line is a `\n`-terminated run; a trailing partial line (text not ending
-/// in `\n`) counts as one more. The empty string is zero lines. Newline
-/// counting uses the SIMD-accelerated `bytecount` crate rather than iterating
-/// chars.
+/// A line is a run terminated by `\n`, `\r\n`, or a lone `\r` (classic Mac
+/// OS); a trailing partial line (text not ending in a terminator) counts as one
+/// more. The empty string is zero lines. Newline counting uses the
+/// SIMD-accelerated `bytecount` crate rather than iterating chars, and text
+/// without any `\r` never pays for the CR pass.
 ///
 /// # Examples
 ///
@@ -190,18 +305,28 @@ pub fn read_json<P: AsRef<Path>>(path: P) -> Result<Vec<Value>> {
 /// assert_eq!(count_lines("one line"), 1);
 /// assert_eq!(count_lines("a\nb\nc"), 3);
 /// assert_eq!(count_lines("a\nb\n"), 2);
+/// assert_eq!(count_lines("a\r\nb\r\n"), 2);
+/// assert_eq!(count_lines("a\rb"), 2);
 /// ```
 pub fn count_lines(text: &str) -> usize {
     if text.is_empty() {
         return 0;
     }
-    // Use bytecount for much faster line counting (SIMD-accelerated)
-    // Count newlines and add 1 if text doesn't end with newline
-    let newline_count = bytecount::count(text.as_bytes(), b'\n');
-    if text.ends_with('\n') {
-        newline_count
+    let bytes = text.as_bytes();
+    let newline_count = bytecount::count(bytes, b'\n');
+    let cr_count = bytecount::count(bytes, b'\r');
+    // A `\r` ends a line on its own only when it is not the first half of a
+    // `\r\n` pair, whose `\n` is already counted.
+    let lone_cr_count = if cr_count == 0 {
+        0
+    } else {
+        cr_count - text.matches("\r\n").count()
+    };
+    let terminators = newline_count + lone_cr_count;
+    if text.ends_with(['\n', '\r']) {
+        terminators
     } else {
-        newline_count + 1
+        terminators + 1
     }
 }
 
@@ -260,6 +385,18 @@ mod tests {
         assert_eq!(count_lines("line1\nline2\nline3\n"), 3);
     }
 
+    #[test]
+    fn test_count_lines_mixed_terminators() {
+        // CRLF, lone CR, and LF each end one line; a final unterminated line
+        // still counts.
+        assert_eq!(count_lines("a\r\nb\r\nc\r\n"), 3);
+        assert_eq!(count_lines("a\r\nb\r\nc"), 3);
+        assert_eq!(count_lines("a\rb\rc\r"), 3);
+        assert_eq!(count_lines("a\rb\rc"), 3);
+        assert_eq!(count_lines("a\nb\r\nc\rd"), 4);
+        assert_eq!(count_lines("\r\n"), 1);
+    }
+
     #[test]
     fn test_count_lines_empty_lines() {
         // Test with empty lines in between
@@ -384,6 +521,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_read_jsonl_invalid_utf8_names_file_and_offset() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("latin1.jsonl");
+        // `caf\xe9` is latin-1 for "café"; the bad byte sits at offset 22.
+        std::fs::write(&file_path, b"{\"a\": 1}\n{\"name\": \"caf\xe9\"}\n").unwrap();
+
+        let error = read_jsonl_with(&file_path, TextEncoding::Utf8, None)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("not valid UTF-8"), "{error}");
+        assert!(error.contains("latin1.jsonl"), "{error}");
+        assert!(error.contains("offset 22"), "{error}");
+
+        let error = read_json_with(&file_path, TextEncoding::Utf8, None)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("offset 22"), "{error}");
+    }
+
+    #[test]
+    fn test_read_jsonl_lossy_replaces_invalid_utf8() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("latin1.jsonl");
+        std::fs::write(&file_path, b"{\"a\": 1}\n{\"name\": \"caf\xe9\"}\n").unwrap();
+
+        let result = read_jsonl_with(&file_path, TextEncoding::Lossy, None).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1]["name"], "caf\u{FFFD}");
+    }
+
+    #[test]
+    fn test_text_encoding_from_str() {
+        assert_eq!("utf8".parse::<TextEncoding>().unwrap(), TextEncoding::Utf8);
+        assert_eq!(
+            "lossy".parse::<TextEncoding>().unwrap(),
+            TextEncoding::Lossy
+        );
+        assert!("latin1".parse::<TextEncoding>().is_err());
+    }
+
     #[test]
     fn test_count_lines_unicode() {
         // Test counting lines with unicode characters
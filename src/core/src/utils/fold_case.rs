@@ -0,0 +1,53 @@
+//! Case-insensitive model keys (`--fold-case`).
+//!
+//! Sessions sometimes report one model under different spellings (`GPT-4o`
+//! vs `gpt-4o`), which otherwise land on separate rows. With folding on, the
+//! usage and analysis roll-ups re-key every model map by the lowercased name
+//! as they finish, so case variants merge into one row shown under that
+//! lowercased name (the form LiteLLM prices are keyed by). Scans take the
+//! toggle as `UsageScanOptions::fold_case` / `AnalysisScanOptions::fold_case`.
+//! The summary cache keeps the raw names, so toggling it never invalidates
+//! the cache.
+
+use crate::constants::FastHashMap;
+use std::collections::hash_map::Entry;
+
+/// Re-keys `map` by `rekey(key)` (e.g. the lowercased key), combining values
+/// that land on the same key with `merge(existing, incoming)`.
+pub(crate) fn fold_keys<V>(
+    map: FastHashMap<String, V>,
+    rekey: impl Fn(&str) -> String,
+    mut merge: impl FnMut(&mut V, V),
+) -> FastHashMap<String, V> {
+    let mut folded = FastHashMap::with_capacity(map.len());
+    for (key, value) in map {
+        match folded.entry(rekey(&key)) {
+            Entry::Occupied(mut entry) => merge(entry.get_mut(), value),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fold_keys_merges_case_variants_under_the_lowercased_key() {
+        let mut map = FastHashMap::default();
+        map.insert("GPT-4o".to_string(), 2);
+        map.insert("gpt-4o".to_string(), 3);
+        map.insert("o3".to_string(), 1);
+
+        let folded = fold_keys(map, str::to_lowercase, |existing, incoming| {
+            *existing += incoming
+        });
+
+        assert_eq!(folded.len(), 2);
+        assert_eq!(folded["gpt-4o"], 5);
+        assert_eq!(folded["o3"], 1);
+    }
+}
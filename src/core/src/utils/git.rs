@@ -1,7 +1,10 @@
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::{LazyLock, RwLock};
 
 // Global cache for Git remote URLs (thread-safe)
@@ -105,6 +108,74 @@ fn get_git_remote_url_impl(cwd: &Path) -> String {
     String::new()
 }
 
+/// Returns the root of the git working tree containing `dir`: the nearest of
+/// `dir` and its ancestors holding a `.git` entry (a directory, or the file a
+/// worktree or submodule uses). `None` when no ancestor is a repository.
+pub fn find_git_root<P: AsRef<Path>>(dir: P) -> Option<PathBuf> {
+    dir.as_ref()
+        .ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// One commit as `git log` lists it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommit {
+    /// Full commit hash.
+    pub hash: String,
+    /// Unix epoch timestamp (milliseconds) of the committer date.
+    pub timestamp: i64,
+    /// First line of the commit message.
+    pub subject: String,
+}
+
+/// Runs `git log` in the repository at `repo` and returns the commits whose
+/// committer date falls within `since_ms..=until_ms` (Unix epoch
+/// milliseconds; git compares whole seconds), newest first.
+///
+/// Fails when `git` cannot be started or exits unsuccessfully, e.g. in a
+/// repository without any commit yet.
+pub fn git_log_between<P: AsRef<Path>>(
+    repo: P,
+    since_ms: i64,
+    until_ms: i64,
+) -> Result<Vec<GitCommit>> {
+    let repo = repo.as_ref();
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args([
+            "log",
+            "--no-show-signature",
+            &format!("--since=@{}", since_ms.div_euclid(1000)),
+            &format!("--until=@{}", until_ms.div_euclid(1000)),
+            "--format=%H%x1f%ct%x1f%s",
+        ])
+        .output()
+        .with_context(|| format!("failed to run git log in {}", repo.display()))?;
+    if !output.status.success() {
+        bail!(
+            "git log failed in {}: {}",
+            repo.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\x1f');
+            let hash = fields.next()?.to_string();
+            let seconds: i64 = fields.next()?.parse().ok()?;
+            Some(GitCommit {
+                hash,
+                timestamp: seconds * 1000,
+                subject: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,141 @@
+//! Model renaming rules (`--fixup-model FROM=TO`).
+//!
+//! Providers report dated snapshots of one model under different names
+//! (`claude-3-5-sonnet-20240620`, `claude-3-5-sonnet-20241022`). A fixup rule
+//! renames matching models in the usage and analysis rows, so the variants
+//! merge into one row shown under the target name. `FROM` is an exact model
+//! name, or a regex between slashes (`/claude-3-5-sonnet-\d{8}/`) that must
+//! match the whole name; a regex target may use `$1`-style capture
+//! references. The rule splits on its last `=`, so a regex may contain `=`
+//! but a target may not. Usage rows are priced under their reported names
+//! before they are renamed, and like case folding the summary cache keeps the
+//! raw names, so changing the rules never invalidates it.
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// One `FROM=TO` model renaming rule.
+#[derive(Debug, Clone)]
+pub struct ModelFixup {
+    pattern: Regex,
+    to: String,
+    /// Whether `FROM` was a `/regex/`; an exact rule's target is literal.
+    regex: bool,
+}
+
+impl FromStr for ModelFixup {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let Some((from, to)) = value.rsplit_once('=') else {
+            bail!("model fixup `{value}` must look like FROM=TO");
+        };
+        if from.is_empty() || to.is_empty() {
+            bail!("model fixup `{value}` needs both a FROM and a TO model name");
+        }
+        let regex = from
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'));
+        let pattern = match regex {
+            Some(regex) => format!("^(?:{regex})$"),
+            None => format!("^{}$", regex::escape(from)),
+        };
+        Ok(Self {
+            pattern: Regex::new(&pattern)
+                .with_context(|| format!("invalid model fixup pattern `{from}`"))?,
+            to: to.to_string(),
+            regex: regex.is_some(),
+        })
+    }
+}
+
+impl ModelFixup {
+    /// The renamed model when `name` matches this rule, else `None`.
+    pub fn apply(&self, name: &str) -> Option<String> {
+        if !self.pattern.is_match(name) {
+            return None;
+        }
+        Some(if self.regex {
+            self.pattern.replace(name, self.to.as_str()).into_owned()
+        } else {
+            self.to.clone()
+        })
+    }
+}
+
+/// The `--fixup-model` rules of one scan, first match wins.
+#[derive(Debug, Clone, Default)]
+pub struct ModelFixups(Arc<[ModelFixup]>);
+
+impl ModelFixups {
+    /// Whether there are no rules.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// `model` renamed by the first matching rule, else `None`.
+    pub fn apply(&self, model: &str) -> Option<String> {
+        self.0.iter().find_map(|rule| rule.apply(model))
+    }
+}
+
+impl From<Vec<ModelFixup>> for ModelFixups {
+    fn from(rules: Vec<ModelFixup>) -> Self {
+        Self(rules.into())
+    }
+}
+
+/// Whether any model rename is on: a fixup rule or `fold_case`
+/// (`--fold-case`).
+pub(crate) fn model_names_rewritten(fixups: &ModelFixups, fold_case: bool) -> bool {
+    fold_case || !fixups.is_empty()
+}
+
+/// The name `model` is shown under: lowercased when `fold_case` is on, then
+/// renamed by the first matching fixup rule, whose target is lowercased too.
+pub(crate) fn rewrite_model_name(model: &str, fixups: &ModelFixups, fold_case: bool) -> String {
+    let folded = if fold_case {
+        model.to_lowercase()
+    } else {
+        model.to_string()
+    };
+    match fixups.apply(&folded) {
+        Some(renamed) if fold_case => renamed.to_lowercase(),
+        Some(renamed) => renamed,
+        None => folded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_and_regex_rules_rename_whole_names_only() {
+        let exact: ModelFixup = "gpt-4o-2024-08-06=gpt-4o".parse().unwrap();
+        assert_eq!(exact.apply("gpt-4o-2024-08-06").as_deref(), Some("gpt-4o"));
+        assert_eq!(exact.apply("gpt-4o-2024-08-06-mini"), None);
+
+        let dated: ModelFixup = r"/(claude-3-5-sonnet)-\d{8}/=$1".parse().unwrap();
+        assert_eq!(
+            dated.apply("claude-3-5-sonnet-20241022").as_deref(),
+            Some("claude-3-5-sonnet")
+        );
+        assert_eq!(dated.apply("claude-3-5-sonnet"), None);
+
+        let literal: ModelFixup = "gpt-4o-2024-08-06=gpt-4o-$1".parse().unwrap();
+        assert_eq!(
+            literal.apply("gpt-4o-2024-08-06").as_deref(),
+            Some("gpt-4o-$1")
+        );
+
+        let with_equals: ModelFixup = "/model=(v\\d)/=model-$1".parse().unwrap();
+        assert_eq!(with_equals.apply("model=v2").as_deref(), Some("model-v2"));
+
+        assert!("no-separator".parse::<ModelFixup>().is_err());
+        assert!("=target".parse::<ModelFixup>().is_err());
+        assert!("/(unclosed/=x".parse::<ModelFixup>().is_err());
+    }
+}
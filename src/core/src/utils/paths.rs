@@ -2,6 +2,8 @@
 //! user's home, the tool's own cache directory, and the dated pricing-cache
 //! file naming scheme.
 
+use crate::config::ProvidersConfig;
+use crate::models::ExtensionType;
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -63,6 +65,46 @@ pub struct HelperPaths {
     pub cache_dir: PathBuf,
 }
 
+impl HelperPaths {
+    /// Every provider's default session locations, in provider scan order.
+    ///
+    /// File-backed providers list the directory their walker scans; the SQLite
+    /// providers list their database file (Cursor also its chat stores root).
+    pub fn provider_locations(&self) -> [(ExtensionType, Vec<&Path>); 8] {
+        [
+            (ExtensionType::ClaudeCode, vec![&self.claude_session_dir]),
+            (ExtensionType::Codex, vec![&self.codex_session_dir]),
+            (ExtensionType::Copilot, vec![&self.copilot_session_dir]),
+            (ExtensionType::Gemini, vec![&self.gemini_session_dir]),
+            (ExtensionType::Grok, vec![&self.grok_session_dir]),
+            (ExtensionType::OpenCode, vec![&self.opencode_db]),
+            (
+                ExtensionType::Cursor,
+                vec![&self.cursor_chats_dir, &self.cursor_tracking_db],
+            ),
+            (ExtensionType::Hermes, vec![&self.hermes_db]),
+        ]
+    }
+
+    /// Enabled providers none of whose session locations exist, each with the
+    /// locations that were checked, in provider order.
+    ///
+    /// Lets an empty scan explain itself: a provider whose directory is
+    /// missing was never installed (or lives elsewhere), which is different
+    /// from one that simply has no sessions in the selected range.
+    pub fn missing_providers(
+        &self,
+        providers: ProvidersConfig,
+    ) -> Vec<(ExtensionType, Vec<&Path>)> {
+        self.provider_locations()
+            .into_iter()
+            .filter(|(provider, paths)| {
+                providers.is_enabled(*provider) && paths.iter().all(|path| !path.exists())
+            })
+            .collect()
+    }
+}
+
 /// Builds a [`HelperPaths`] from the current user's home directory.
 ///
 /// The returned paths are computed by joining well-known suffixes onto the
@@ -240,12 +282,49 @@ fn build_paths(
 ///
 /// When set to a non-empty value the tool stays fully offline: the pricing
 /// fetch, the Cursor usage API, and the update check each skip the network and
-/// degrade to a cache/empty/local result. The integration tests set this (plus
-/// an isolated `HOME`) so `cargo test` never reaches an external API.
+/// degrade to a cache/empty/local result, and `usage --webhook` posts nothing.
+/// The integration tests set this (plus an isolated `HOME`) so `cargo test`
+/// never reaches an external API.
 pub fn network_disabled() -> bool {
     std::env::var_os("VCT_OFFLINE").is_some_and(|v| !v.is_empty())
 }
 
+/// Error for a network request refused because `VCT_OFFLINE` is set.
+///
+/// Callers that cannot degrade without the network return it, so the binary
+/// reports the refusal with the same exit code as a failed request.
+#[derive(Debug)]
+pub struct Offline(pub String);
+
+impl std::fmt::Display for Offline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: network access is disabled (VCT_OFFLINE)", self.0)
+    }
+}
+
+impl std::error::Error for Offline {}
+
+/// Whether `error` was caused by a failed HTTP request anywhere in its chain.
+///
+/// Matches the `reqwest` transport errors (DNS, connect, TLS, timeout, body)
+/// raised under the update check, the pricing fetch, and the quota clients,
+/// so the binary can report them with a dedicated exit code.
+pub fn is_network_error(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.downcast_ref::<reqwest::Error>().is_some())
+}
+
+/// Whether `machineId` is blanked in analysis output via `VCT_DISABLE_MACHINE_ID`.
+///
+/// Only `1` or `true` (any case) opts out, so `VCT_DISABLE_MACHINE_ID=0` keeps
+/// the id. The CLI ORs this with `--no-machine-id` and
+/// `[analysis] hide_machine_id`.
+pub fn machine_id_disabled() -> bool {
+    std::env::var("VCT_DISABLE_MACHINE_ID")
+        .is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
 /// Returns the current username from the environment (cached after first call).
 ///
 /// Reads `USER`, falling back to `USERNAME` (Windows), and finally to the
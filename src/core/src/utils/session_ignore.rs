@@ -0,0 +1,91 @@
+//! Session paths excluded from every scan (`.vctignore`, `--ignore-file`).
+//!
+//! The ignore file holds gitignore-style globs, one per line: `scratch/`
+//! skips every session under any directory named `scratch`, `*-test.jsonl`
+//! skips matching files anywhere, a leading `/` anchors a pattern to the
+//! filesystem root, and `!pattern` re-includes a path an earlier line
+//! excluded. The scan options carry the compiled [`SessionIgnore`] to the
+//! walker behind every provider's discovery, so `usage` and `analysis` skip
+//! the same files. An explicit `--ignore-file` replaces the
+//! `~/.vct/.vctignore` default rather than adding to it.
+
+use crate::utils::get_cache_dir;
+use anyhow::{Context, Result};
+use ignore::Match;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Name of the ignore file looked up in the config directory.
+pub const IGNORE_FILE_NAME: &str = ".vctignore";
+
+/// Compiled session ignore patterns; the default ignores nothing.
+#[derive(Debug, Clone, Default)]
+pub struct SessionIgnore(Option<Arc<Gitignore>>);
+
+impl SessionIgnore {
+    /// Loads the patterns from `path` when given, else from
+    /// `~/.vct/.vctignore` if it exists, else ignores nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ignore file cannot be read or holds an invalid
+    /// glob, or if the config directory cannot be resolved.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => Some(path.to_path_buf()),
+            None => Some(get_cache_dir()?.join(IGNORE_FILE_NAME)).filter(|path| path.is_file()),
+        };
+        match path {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Parses the ignore file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or holds an invalid glob.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let mut builder = GitignoreBuilder::new(filesystem_root(path));
+        if let Some(error) = builder.add(path) {
+            return Err(error).with_context(|| format!("invalid ignore file {}", path.display()));
+        }
+        let patterns = builder
+            .build()
+            .with_context(|| format!("invalid ignore file {}", path.display()))?;
+        Ok(Self(Some(Arc::new(patterns))))
+    }
+
+    /// Whether the patterns exclude the session file `path`, either directly
+    /// or through one of its parent directories.
+    pub(crate) fn is_ignored(&self, path: &Path) -> bool {
+        let Some(ignore) = self.0.as_deref() else {
+            return false;
+        };
+        // The file itself decides first, then the nearest parent with a
+        // verdict, as git does.
+        for (depth, candidate) in path.ancestors().enumerate() {
+            if candidate.parent().is_none() {
+                break;
+            }
+            match ignore.matched(candidate, depth > 0) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+}
+
+/// The root component of `path` (`/` on Unix, `C:\` on Windows), against
+/// which anchored patterns resolve.
+fn filesystem_root(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    absolute
+        .ancestors()
+        .last()
+        .map_or_else(|| PathBuf::from("/"), Path::to_path_buf)
+}
@@ -2,8 +2,11 @@
 //! record into a running per-model map, normalising provider-specific shapes
 //! along the way.
 
-use crate::constants::FastHashMap;
+use crate::constants::{FastHashMap, FastHashSet};
+use crate::pricing::RequestTokens;
+use anyhow::{Result, bail};
 use serde_json::Value;
+use std::str::FromStr;
 
 /// Adds the named `i64` fields from `source` into `target`, in place.
 ///
@@ -126,18 +129,86 @@ impl AboveTierSlice {
     }
 }
 
-/// The full prompt context of one Claude request: non-cached input plus
-/// cache reads plus cache writes — what the provider compares against the
-/// "above Nk tokens" threshold.
-pub fn claude_request_context(usage_obj: &serde_json::Map<String, Value>) -> i64 {
+/// The cache-write total of one Claude request.
+///
+/// Claude Code reports cache writes twice: the scalar
+/// `cache_creation_input_tokens` and the `cache_creation` TTL split
+/// (`ephemeral_5m_input_tokens` / `ephemeral_1h_input_tokens`). Both describe
+/// the same tokens, so the total is the larger of the two rather than their
+/// sum; a record carrying only one form still counts in full.
+fn claude_cache_creation_total(usage_obj: &serde_json::Map<String, Value>) -> i64 {
+    let scalar = usage_obj
+        .get("cache_creation_input_tokens")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    let split: i64 = usage_obj
+        .get("cache_creation")
+        .and_then(|v| v.as_object())
+        .map(|split| split.values().filter_map(|v| v.as_i64()).sum())
+        .unwrap_or(0);
+    scalar.max(split)
+}
+
+/// The tokens of one Claude request in the buckets a tier threshold is
+/// measured on: non-cached input, cache reads, cache writes, and output.
+pub fn claude_request_tokens(usage_obj: &serde_json::Map<String, Value>) -> RequestTokens {
     let field = |key: &str| usage_obj.get(key).and_then(|v| v.as_i64()).unwrap_or(0);
-    let mut cache_creation = field("cache_creation_input_tokens");
-    if cache_creation == 0
-        && let Some(split) = usage_obj.get("cache_creation").and_then(|v| v.as_object())
-    {
-        cache_creation = split.values().filter_map(|v| v.as_i64()).sum();
+    RequestTokens {
+        input: field("input_tokens"),
+        cache_read: field("cache_read_input_tokens"),
+        cache_creation: claude_cache_creation_total(usage_obj),
+        output: field("output_tokens"),
+    }
+}
+
+/// What repeated token events are recognized by (`--dedupe-by`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupeBy {
+    /// Claude's `message.id`: a message Claude Code logs again (on a retry,
+    /// or once per content block) counts its usage only the first time.
+    MessageId,
+}
+
+impl FromStr for DedupeBy {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "message-id" => Ok(Self::MessageId),
+            other => bail!("unknown dedupe key `{other}` (expected message-id)"),
+        }
+    }
+}
+
+/// The Claude message ids one session has already counted usage for.
+///
+/// Created per session; it only remembers ids while
+/// [`DedupeBy::MessageId`] is on, so the default scan pays nothing.
+#[derive(Debug)]
+pub struct SeenClaudeMessages {
+    enabled: bool,
+    ids: FastHashSet<String>,
+}
+
+impl SeenClaudeMessages {
+    /// An empty set skipping repeats by `key` (`--dedupe-by`); `None`
+    /// counts every record.
+    pub fn new(key: Option<DedupeBy>) -> Self {
+        Self {
+            enabled: key == Some(DedupeBy::MessageId),
+            ids: FastHashSet::default(),
+        }
+    }
+
+    /// Whether the usage of message `id` should be counted: always when
+    /// deduplication is off or the record carries no id, otherwise only the
+    /// first time `id` is seen.
+    pub fn first_sight(&mut self, id: Option<&str>) -> bool {
+        match id {
+            Some(id) if self.enabled && !id.is_empty() => self.ids.insert(id.to_string()),
+            _ => true,
+        }
     }
-    field("input_tokens") + field("cache_read_input_tokens") + cache_creation
 }
 
 /// Merges one Claude usage record into `conversation_usage`, keyed by `model`.
@@ -145,8 +216,11 @@ pub fn claude_request_context(usage_obj: &serde_json::Map<String, Value>) -> i64
 /// Token fields accumulate across calls (the per-model entry is created on
 /// first sight). `service_tier` is overwritten with the latest value rather
 /// than accumulated, and the `cache_creation` TTL split is merged via
-/// [`accumulate_nested_object`]. Records for synthetic models (whose name
-/// contains `<synthetic>`) and non-object `usage` payloads are ignored.
+/// [`accumulate_nested_object`]. `cache_creation_input_tokens` accumulates
+/// each record's cache-write total (scalar or split, never both), so it stays
+/// the authoritative sum even when records disagree on which form they carry.
+/// Records for synthetic models (whose name contains `<synthetic>`) and
+/// non-object `usage` payloads are ignored.
 ///
 /// `above_tier` marks this record (one request) as classified above the
 /// model's context-tier threshold: its buckets are additionally accumulated
@@ -189,12 +263,16 @@ pub fn process_claude_usage(
     accumulate_i64_fields(
         existing_obj,
         usage_obj,
-        &[
-            "input_tokens",
-            "cache_creation_input_tokens",
-            "cache_read_input_tokens",
-            "output_tokens",
-        ],
+        &["input_tokens", "cache_read_input_tokens", "output_tokens"],
+    );
+    let cache_creation = claude_cache_creation_total(usage_obj);
+    let total = existing_obj
+        .get("cache_creation_input_tokens")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    existing_obj.insert(
+        "cache_creation_input_tokens".to_string(),
+        (total + cache_creation).into(),
     );
 
     // Handle service_tier
@@ -216,29 +294,21 @@ pub fn process_claude_usage(
 
     if above_tier {
         let field = |key: &str| usage_obj.get(key).and_then(|v| v.as_i64()).unwrap_or(0);
-        let scalar_cc = field("cache_creation_input_tokens");
-        // Mirror `extract_token_counts`: the scalar total is authoritative, the
-        // 1h portion comes from the split, and the remainder bills at 5m so no
-        // cache-creation token is dropped from the above-tier slice either.
+        // Mirror `extract_token_counts`: the record's total is authoritative,
+        // the 1h portion comes from the split, and the remainder bills at 5m so
+        // no cache-creation token is dropped from the above-tier slice either.
         let cc_1h = usage_obj
             .get("cache_creation")
             .and_then(|v| v.as_object())
             .and_then(|split| split.get("ephemeral_1h_input_tokens"))
             .and_then(|v| v.as_i64())
             .unwrap_or(0);
-        let ephemeral_5m = usage_obj
-            .get("cache_creation")
-            .and_then(|v| v.as_object())
-            .and_then(|split| split.get("ephemeral_5m_input_tokens"))
-            .and_then(|v| v.as_i64())
-            .unwrap_or(0);
-        let total_cc = scalar_cc.max(ephemeral_5m + cc_1h);
         AboveTierSlice {
             input: field("input_tokens"),
             output: field("output_tokens"),
             reasoning: 0,
             cache_read: field("cache_read_input_tokens"),
-            cache_creation_5m: total_cc - cc_1h,
+            cache_creation_5m: cache_creation - cc_1h,
             cache_creation_1h: cc_1h,
         }
         .accumulate_into(existing_obj);
@@ -254,6 +324,31 @@ pub const CODEX_TOKEN_FIELDS: [&str; 5] = [
     "total_tokens",
 ];
 
+/// The tokens of one Codex turn in the buckets a tier threshold is measured
+/// on. Codex's `input_tokens` includes the cached subset, and its
+/// `output_tokens` already includes reasoning.
+pub fn codex_request_tokens(usage_obj: &serde_json::Map<String, Value>) -> RequestTokens {
+    let field = |key: &str| usage_obj.get(key).and_then(|v| v.as_i64()).unwrap_or(0);
+    let cached = field("cached_input_tokens");
+    RequestTokens {
+        input: (field("input_tokens") - cached).max(0),
+        cache_read: cached,
+        cache_creation: 0,
+        output: field("output_tokens"),
+    }
+}
+
+/// The tokens of one Gemini message in the buckets a tier threshold is
+/// measured on. `tokens.input` includes the cached subset; thoughts are output.
+pub fn gemini_request_tokens(tokens: &crate::models::GeminiTokens) -> RequestTokens {
+    RequestTokens {
+        input: (tokens.input - tokens.cached).max(0),
+        cache_read: tokens.cached,
+        cache_creation: 0,
+        output: tokens.output + tokens.thoughts,
+    }
+}
+
 /// Snapshot of Codex's cumulative `total_token_usage` counters.
 ///
 /// Codex publishes a whole-session running total on every `token_count`
@@ -678,6 +773,50 @@ mod tests {
         assert_eq!(stu["web_fetch_requests"].as_i64().unwrap(), 1);
     }
 
+    #[test]
+    fn claude_cache_creation_counts_each_record_once_in_either_form() {
+        let mut conversation_usage = FastHashMap::default();
+        let model = "claude-sonnet-4-6";
+
+        // Scalar and split together describe the same 100 tokens.
+        process_claude_usage(
+            &mut conversation_usage,
+            model,
+            &json!({
+                "cache_creation_input_tokens": 100,
+                "cache_creation": {
+                    "ephemeral_5m_input_tokens": 40,
+                    "ephemeral_1h_input_tokens": 60
+                }
+            }),
+            false,
+        );
+        // Scalar only: all 30 tokens at the default 5m TTL.
+        process_claude_usage(
+            &mut conversation_usage,
+            model,
+            &json!({ "cache_creation_input_tokens": 30 }),
+            false,
+        );
+        // Split only: the 50 tokens must not be hidden by earlier scalars.
+        process_claude_usage(
+            &mut conversation_usage,
+            model,
+            &json!({
+                "cache_creation": {
+                    "ephemeral_5m_input_tokens": 0,
+                    "ephemeral_1h_input_tokens": 50
+                }
+            }),
+            false,
+        );
+
+        let counts = crate::utils::extract_token_counts(&conversation_usage[model]);
+        assert_eq!(counts.cache_creation, 180);
+        assert_eq!(counts.cache_creation_1h, 110);
+        assert_eq!(counts.cache_creation_5m, 70);
+    }
+
     #[test]
     fn test_process_claude_usage_skip_synthetic() {
         let mut conversation_usage = FastHashMap::default();
@@ -0,0 +1,132 @@
+//! Run-level warning collector.
+//!
+//! Problems a command works around — an unreadable source skipped, pricing
+//! unavailable, invalid UTF-8 replaced — are recorded here instead of being
+//! printed where they happen, where they would interleave with the output or
+//! vanish under the TUI. The caller owns the collector: the CLI creates one
+//! [`WarningSink`] per run, hands it to the scans through their options, and
+//! drains it once the command ends to print a one-line count to stderr, or
+//! every message under `--verbose`. Each warning is also written to the log
+//! file as it is recorded. A message recorded again (say, on every TUI
+//! refresh) is counted rather than repeated.
+
+use std::sync::{Arc, Mutex};
+
+/// One distinct warning message and how many times it was recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// The message, without a `Warning:` prefix.
+    pub message: String,
+    /// Times the same message was recorded; at least 1.
+    pub count: usize,
+}
+
+/// Distinct warnings in first-seen order, each with its repeat count.
+#[derive(Debug, Default)]
+pub struct Warnings {
+    entries: Vec<Warning>,
+}
+
+impl Warnings {
+    /// An empty collector.
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Records `message`, counting it when it was recorded before.
+    pub fn record(&mut self, message: String) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|warning| warning.message == message)
+        {
+            Some(warning) => warning.count += 1,
+            None => self.entries.push(Warning { message, count: 1 }),
+        }
+    }
+
+    /// Removes and returns every warning recorded so far, in first-seen order.
+    pub fn take(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.entries)
+    }
+}
+
+/// A shared handle to one run's [`Warnings`].
+///
+/// Clones record into the same collector, so one sink can be handed to every
+/// scan, refresh, and pricing fetch of a command (including parallel parsers)
+/// and drained once at the end. A default sink is a fresh, empty collector.
+#[derive(Debug, Clone, Default)]
+pub struct WarningSink(Arc<Mutex<Warnings>>);
+
+impl WarningSink {
+    /// An empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `message` for the end-of-run summary and logs it.
+    pub fn warn(&self, message: impl Into<String>) {
+        let message = message.into();
+        log::warn!("{message}");
+        if let Ok(mut warnings) = self.0.lock() {
+            warnings.record(message);
+        }
+    }
+
+    /// Removes and returns every warning recorded so far, in first-seen order.
+    pub fn take(&self) -> Vec<Warning> {
+        self.0
+            .lock()
+            .map(|mut warnings| warnings.take())
+            .unwrap_or_default()
+    }
+}
+
+/// Records `message` in `sink`, or only logs it when the caller passed none
+/// (a library call outside any run).
+pub(crate) fn warn_to(sink: Option<&WarningSink>, message: impl Into<String>) {
+    match sink {
+        Some(sink) => sink.warn(message),
+        None => log::warn!("{}", message.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_messages_are_counted_once_in_first_seen_order() {
+        let mut warnings = Warnings::new();
+        warnings.record("skipped a.jsonl".to_string());
+        warnings.record("pricing unavailable".to_string());
+        warnings.record("skipped a.jsonl".to_string());
+        let recorded: Vec<(String, usize)> = warnings
+            .take()
+            .into_iter()
+            .map(|warning| (warning.message, warning.count))
+            .collect();
+        assert_eq!(
+            recorded,
+            [
+                ("skipped a.jsonl".to_string(), 2),
+                ("pricing unavailable".to_string(), 1),
+            ]
+        );
+        assert!(warnings.take().is_empty());
+    }
+
+    #[test]
+    fn cloned_sinks_share_one_collector() {
+        let sink = WarningSink::new();
+        sink.clone().warn("skipped a.jsonl");
+        sink.warn("skipped a.jsonl");
+        let recorded = sink.take();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].count, 2);
+        assert!(WarningSink::new().take().is_empty());
+    }
+}
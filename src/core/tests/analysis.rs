@@ -19,6 +19,10 @@ use vct_core::analysis::aggregator::{
     aggregate_sessions_by_model_from_paths_with_providers,
     collect_analysis_sessions_from_paths_with, project_code_analysis,
 };
+use vct_core::analysis::{
+    AnalysisDataset, AnalysisSession, HourActivity, activity_by_hour, correlate_git,
+    session_window, validate_analysis,
+};
 use vct_core::config::ProvidersConfig;
 use vct_core::models::ExtensionType;
 use vct_core::session::parser::{
@@ -27,7 +31,9 @@ use vct_core::session::parser::{
 };
 use vct_core::session::state::ParseMode;
 use vct_core::summary_cache::SummaryScanCache;
-use vct_test_support::{TempHome, append_cursor_json_blob, fixture, fixture_str};
+use vct_core::utils::TextEncoding;
+use vct_core::warnings::WarningSink;
+use vct_test_support::{TempHome, append_cursor_json_blob, backdate, fixture, fixture_str};
 
 fn providers_only(provider: ExtensionType) -> ProvidersConfig {
     ProvidersConfig {
@@ -206,6 +212,404 @@ fn test_single_file_analysis_grok() {
     assert!(record["conversationUsage"].get("grok-secondary").is_none());
 }
 
+#[test]
+fn claude_api_error_events_are_counted_by_kind() {
+    let analysis = parse_session_file_typed(fixture("sessions/claude_code_api_errors.jsonl"))
+        .expect("should successfully analyze Claude file");
+    let counts = analysis.records[0].error_counts;
+    // Two rate limits (a retried attempt and the final error message), one
+    // overload, one internal error.
+    assert_eq!(
+        (counts.rate_limit, counts.overloaded, counts.other),
+        (2, 1, 1)
+    );
+    let json = serde_json::to_value(&analysis).unwrap();
+    assert_eq!(json["records"][0]["errorCounts"]["rateLimit"], 2);
+
+    // Sessions without errors keep the golden JSON shape.
+    let clean = parse_session_file_to_value(fixture("sessions/claude_code.jsonl")).unwrap();
+    assert!(clean["records"][0].get("errorCounts").is_none());
+
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "errors.jsonl",
+        &fixture_str("sessions/claude_code_api_errors.jsonl"),
+    );
+    home.put_codex_session("2025/01/01/a.jsonl", &fixture_str("sessions/codex.jsonl"));
+    let dataset = collect_analysis_sessions_from_paths_with(
+        &home.paths,
+        TimeRange::All,
+        ProvidersConfig::default(),
+        ParseMode::UsageOnly,
+    )
+    .unwrap();
+    let report = vct_core::analysis::error_report(&dataset);
+    let rows: Vec<(&str, usize, usize)> = report
+        .providers
+        .iter()
+        .map(|row| {
+            (
+                row.provider.as_str(),
+                row.sessions_with_errors,
+                row.error_counts.total(),
+            )
+        })
+        .collect();
+    assert_eq!(rows, [("Claude-Code", 1, 4), ("Codex", 0, 0)]);
+    assert_eq!(report.total.rate_limit, 2);
+}
+
+#[test]
+fn session_that_cds_into_a_second_repository_lists_both() {
+    // The fixture's `cwd`s are placeholders for two real repositories, the
+    // second entered through a subdirectory.
+    let home = TempHome::new();
+    let repo_a = home.home().join("repo-a");
+    let repo_b = home.home().join("repo-b");
+    home.put("repo-a/.git/HEAD", "ref: refs/heads/main\n");
+    home.put("repo-b/.git/HEAD", "ref: refs/heads/main\n");
+    home.put("repo-b/src/lib.rs", "");
+    let session = fixture_str("sessions/claude_code_two_repos.jsonl")
+        .replace("__REPO_A__", &repo_a.to_string_lossy())
+        .replace("__REPO_B__", &repo_b.to_string_lossy());
+    let path = home.put("two_repos.jsonl", &session);
+
+    let analysis = parse_session_file_typed(&path).unwrap();
+    let record = &analysis.records[0];
+    assert_eq!(record.folder_path, repo_a.to_string_lossy());
+    assert_eq!(
+        record.repositories,
+        [repo_a.to_string_lossy(), repo_b.to_string_lossy()]
+    );
+    let json = serde_json::to_value(&analysis).unwrap();
+    assert_eq!(
+        json["records"][0]["repositories"].as_array().unwrap().len(),
+        2
+    );
+
+    // A single-repository session keeps the golden JSON shape.
+    let clean = parse_session_file_to_value(fixture("sessions/claude_code.jsonl")).unwrap();
+    assert!(clean["records"][0].get("repositories").is_none());
+}
+
+#[test]
+fn codex_duplicate_call_ids_pair_newest_first_and_warn() {
+    let warnings = WarningSink::new();
+    let (analysis, _) = parse_session_file_with_diagnostics(
+        fixture("sessions/codex_duplicate_call_ids.jsonl"),
+        ParseMode::Full,
+        TextEncoding::Utf8,
+        None,
+        &warnings,
+    )
+    .expect("should analyze a Codex session with reused call ids");
+    let reads: Vec<(&str, usize)> = analysis.records[0]
+        .read_file_details
+        .iter()
+        .map(|read| (read.base.file_path.as_str(), read.base.line_count))
+        .collect();
+    // The first output answers the newest `cat b.txt`, the second the older
+    // `cat a.txt`; the id is free again by the time `cat c.txt` reuses it.
+    assert_eq!(
+        reads,
+        [
+            ("/tmp/dup-proj/b.txt", 3),
+            ("/tmp/dup-proj/a.txt", 1),
+            ("/tmp/dup-proj/c.txt", 2),
+        ]
+    );
+
+    assert!(
+        warnings.take().iter().any(|warning| warning
+            .message
+            .contains("reuses pending call_id `call_dup`")),
+        "reusing a pending call id should be reported to the run's warnings"
+    );
+}
+
+/// Runs `git` in `repo` with a fixed identity, committing at `date` (Unix
+/// seconds) when one is given.
+fn git(repo: &Path, args: &[&str], date: Option<i64>) {
+    let mut cmd = std::process::Command::new("git");
+    cmd.arg("-C").arg(repo).args([
+        "-c",
+        "user.name=Test",
+        "-c",
+        "user.email=test@example.com",
+        "-c",
+        "commit.gpgsign=false",
+    ]);
+    if let Some(date) = date {
+        let date = format!("@{date} +0000");
+        cmd.env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date);
+    }
+    let status = cmd.args(args).status().unwrap();
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn correlate_git_lists_only_the_commits_made_while_the_session_ran() {
+    let analysis = parse_session_file_typed(fixture("sessions/claude_code.jsonl")).unwrap();
+    let (start, end) = session_window(&analysis.records[0]);
+    assert!(start < end, "the fixture should span more than an instant");
+
+    let repo = TempDir::new().unwrap();
+    git(repo.path(), &["init", "-q"], None);
+    for (subject, at) in [
+        ("before the session", start / 1000 - 3600),
+        ("during the session", end / 1000),
+        ("after the session", end / 1000 + 3600),
+    ] {
+        git(
+            repo.path(),
+            &["commit", "-q", "--allow-empty", "-m", subject],
+            Some(at),
+        );
+    }
+    let plain = TempDir::new().unwrap();
+
+    let session_in = |folder: &Path| {
+        let mut analysis = analysis.clone();
+        analysis.records[0].folder_path = folder.display().to_string();
+        AnalysisSession {
+            provider: ExtensionType::ClaudeCode,
+            date: String::new(),
+            source: None,
+            analysis,
+        }
+    };
+    let dataset = AnalysisDataset {
+        // A subfolder of the repository still finds it.
+        sessions: vec![
+            session_in(&repo.path().join("src")),
+            session_in(plain.path()),
+        ],
+        diagnostics: Default::default(),
+    };
+    let sessions = correlate_git(&dataset, &WarningSink::new());
+
+    assert_eq!(
+        sessions[0].repository.as_deref(),
+        Some(repo.path().to_str().unwrap())
+    );
+    let subjects: Vec<&str> = sessions[0]
+        .commits
+        .iter()
+        .map(|commit| commit.subject.as_str())
+        .collect();
+    assert_eq!(subjects, ["during the session"]);
+    assert_eq!(sessions[0].commits[0].timestamp, end / 1000 * 1000);
+    assert_eq!(
+        sessions[0].lines_written,
+        analysis.records[0].total_write_lines + analysis.records[0].total_edit_lines
+    );
+
+    assert_eq!(sessions[1].repository, None);
+    assert!(sessions[1].commits.is_empty());
+}
+
+#[test]
+fn activity_by_hour_bins_each_operation_at_its_own_hour() {
+    let analysis = parse_session_file_typed(fixture("sessions/claude_code.jsonl")).unwrap();
+    let dataset = AnalysisDataset {
+        sessions: vec![AnalysisSession {
+            provider: ExtensionType::ClaudeCode,
+            date: String::new(),
+            source: None,
+            analysis,
+        }],
+        diagnostics: Default::default(),
+    };
+    let busy = |zone: &str| -> Vec<HourActivity> {
+        let hours = activity_by_hour(&dataset, zone.parse().unwrap());
+        assert_eq!(hours.len(), 24);
+        assert!(
+            hours
+                .iter()
+                .enumerate()
+                .all(|(hour, row)| row.hour == hour as u32)
+        );
+        hours.into_iter().filter(|row| row.total > 0).collect()
+    };
+    let row = |hour, edits, reads, writes, commands| HourActivity {
+        hour,
+        edits,
+        reads,
+        writes,
+        commands,
+        total: edits + reads + writes + commands,
+    };
+
+    // The fixture reads and edits at 12:xx UTC, then writes and runs a
+    // command at 13:xx UTC.
+    assert_eq!(busy("utc"), [row(12, 2, 2, 0, 0), row(13, 0, 0, 3, 1)]);
+    assert_eq!(busy("+08:00"), [row(20, 2, 2, 0, 0), row(21, 0, 0, 3, 1)]);
+}
+
+#[test]
+fn every_fixture_session_satisfies_analysis_invariants() {
+    for fixture_name in [
+        "sessions/claude_code.jsonl",
+        "sessions/claude_code_api_errors.jsonl",
+        "sessions/codex.jsonl",
+        "sessions/codex_duplicate_call_ids.jsonl",
+        "sessions/copilot.jsonl",
+        "sessions/gemini.jsonl",
+        "sessions/grok/signals.json",
+    ] {
+        let analysis = parse_session_file_typed(fixture(fixture_name)).unwrap();
+        assert_eq!(
+            validate_analysis(&analysis),
+            Vec::new(),
+            "{fixture_name} violated an analysis invariant"
+        );
+    }
+}
+
+#[test]
+fn provider_detect_stats_counts_content_verdicts_including_unknown() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "a.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    // A Codex log that landed in the Claude directory is counted as Codex.
+    home.put_claude_session(
+        "project",
+        "stray.jsonl",
+        &fixture_str("sessions/codex.jsonl"),
+    );
+    home.put_codex_session("2025/01/01/a.jsonl", &fixture_str("sessions/codex.jsonl"));
+    home.put_codex_session("2025/01/01/blank.jsonl", "{\"note\":\"no marker\"}\n");
+    home.put_codex_session("2025/01/01/garbage.json", "not json at all");
+    home.put_gemini_session(
+        "project",
+        "session-1.jsonl",
+        &fixture_str("sessions/gemini.jsonl"),
+    );
+    home.put_grok_fixture_session("workspace", "grok-session");
+
+    let stats = vct_core::scan::provider_detect_stats_from_paths(
+        &home.paths,
+        ProvidersConfig::default(),
+        TimeRange::All,
+        &Default::default(),
+    );
+
+    let counts: Vec<(&str, usize)> = stats
+        .providers
+        .iter()
+        .map(|row| (row.provider.as_str(), row.files))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("Claude-Code", 1),
+            ("Codex", 2),
+            ("Gemini", 1),
+            ("Grok", 1),
+            ("Unknown", 2),
+        ]
+    );
+    assert_eq!(stats.total_files, 7);
+    let percent_sum: f64 = stats.providers.iter().map(|row| row.percent).sum();
+    assert!((percent_sum - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn sessions_per_day_counts_distinct_session_files_by_file_date() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "a.jsonl", &claude);
+    home.put_claude_session("other", "b.jsonl", &claude);
+    backdate(
+        &home.put_codex_session("2025/01/01/a.jsonl", &fixture_str("sessions/codex.jsonl")),
+        2,
+    );
+    backdate(
+        &home.put_gemini_session(
+            "project",
+            "session-1.jsonl",
+            &fixture_str("sessions/gemini.jsonl"),
+        ),
+        2,
+    );
+
+    let counts = vct_core::scan::sessions_per_day_from_paths(
+        &home.paths,
+        ProvidersConfig::default(),
+        TimeRange::All,
+        &Default::default(),
+    );
+
+    let day = |days_ago: i64| {
+        (chrono::Local::now() - chrono::Duration::days(days_ago))
+            .format("%Y-%m-%d")
+            .to_string()
+    };
+    let expected: std::collections::BTreeMap<String, usize> =
+        [(day(2), 2), (day(0), 2)].into_iter().collect();
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn tool_version_filter_keeps_sessions_in_the_range() {
+    use vct_core::analysis::{ToolVersionFilter, retain_tool_version};
+
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "old.jsonl", &claude);
+    home.put_claude_session(
+        "project",
+        "new.jsonl",
+        &claude.replace(r#""version":"1.0.95""#, r#""version":"1.1.2""#),
+    );
+    home.put_codex_session("2025/01/01/a.jsonl", &fixture_str("sessions/codex.jsonl"));
+    home.put_gemini_session(
+        "project",
+        "session-1.jsonl",
+        &fixture_str("sessions/gemini.jsonl"),
+    );
+    let dataset = collect_analysis_sessions_from_paths_with(
+        &home.paths,
+        TimeRange::All,
+        ProvidersConfig::default(),
+        ParseMode::Full,
+    )
+    .unwrap();
+    assert_eq!(dataset.len(), 4);
+
+    let kept = |range: &str, include_unversioned: bool| {
+        let mut filtered = dataset.clone();
+        retain_tool_version(
+            &mut filtered,
+            &ToolVersionFilter {
+                req: range.parse().unwrap(),
+                include_unversioned,
+            },
+        );
+        filtered
+            .sessions
+            .iter()
+            .map(|session| {
+                let name = session.source.as_ref().unwrap().file_name().unwrap();
+                name.to_string_lossy().into_owned()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(kept(">=1.0.90, <1.1", false), ["old.jsonl"]);
+    assert_eq!(kept(">=1.1", false), ["new.jsonl"]);
+    assert_eq!(kept("^0.39", false), ["a.jsonl"]);
+    assert_eq!(
+        kept(">=1.0.90, <1.1", true),
+        ["old.jsonl", "session-1.jsonl"]
+    );
+}
+
 #[test]
 fn batch_analysis_attributes_grok_tools_to_the_grok_provider() {
     let home = TempHome::new();
@@ -1273,8 +1677,14 @@ fn codex_unknown_apply_patch_output_surfaces_source_diagnostics() {
         ),
     );
 
-    let (analysis, diagnostics) =
-        parse_session_file_with_diagnostics(&path, ParseMode::Full).unwrap();
+    let (analysis, diagnostics) = parse_session_file_with_diagnostics(
+        &path,
+        ParseMode::Full,
+        TextEncoding::Utf8,
+        None,
+        &WarningSink::new(),
+    )
+    .unwrap();
     assert_eq!(analysis.records[0].tool_call_counts.edit, 0);
     assert_eq!(diagnostics.skipped_records(), 1);
 
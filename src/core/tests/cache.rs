@@ -8,6 +8,7 @@ use std::path::PathBuf;
 use tempfile::TempDir;
 use vct_core::cache::{FileParseCache, global_cache};
 use vct_core::pricing::clear_pricing_cache;
+use vct_core::utils::TextEncoding;
 use vct_test_support::fixture;
 
 #[test]
@@ -90,6 +91,33 @@ fn test_file_cache_invalidation() {
     assert!(result2.is_ok());
 }
 
+#[test]
+fn test_file_cache_keys_entries_by_encoding() {
+    let temp_dir = TempDir::new().unwrap();
+    let session = temp_dir.path().join("session.jsonl");
+    let mut bytes = std::fs::read(fixture("sessions/claude_code.jsonl")).unwrap();
+    let at = bytes.windows(11).position(|w| w == b"claude-code").unwrap() + 8;
+    bytes[at] = 0xE9;
+    std::fs::write(&session, &bytes).unwrap();
+    let cache = FileParseCache::new();
+
+    let err = cache
+        .get_or_parse_with(&session, None, TextEncoding::Utf8)
+        .unwrap_err();
+    assert!(format!("{err:#}").contains("not valid UTF-8"), "{err:#}");
+
+    // A strict failure is never served for a lossy read, nor the reverse.
+    let lossy = cache
+        .get_or_parse_with(&session, None, TextEncoding::Lossy)
+        .unwrap();
+    assert!(!lossy.records.is_empty());
+    assert!(cache.get_or_parse(&session).is_err());
+    assert_eq!(cache.get_cached_paths(), vec![session.clone()]);
+
+    cache.invalidate(&session);
+    assert_eq!(cache.stats().entry_count, 0);
+}
+
 #[test]
 fn test_grok_cache_tracks_sibling_files() {
     let temp_dir = TempDir::new().unwrap();
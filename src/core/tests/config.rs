@@ -283,3 +283,33 @@ fn time_range_default_precedence() {
         TimeRange::Monthly
     );
 }
+
+#[test]
+fn validate_config_file_reports_unknown_keys() {
+    let th = TempHome::new();
+    let dir = &th.paths.cache_dir;
+    fs::create_dir_all(dir).unwrap();
+    let path = dir.join("config.toml");
+
+    fs::write(
+        &path,
+        "[usage]\nmerge_modles = true\n\n[display.model_names]\n\"claude-sonnet-4\" = \"Sonnet\"\n",
+    )
+    .unwrap();
+    let error = config::validate_config_file(&path).unwrap_err().to_string();
+    assert!(error.contains("usage.merge_modles"), "{error}");
+    assert!(!error.contains("claude-sonnet-4"), "{error}");
+
+    // Keys the migration upgrades are not unknown.
+    fs::write(
+        &path,
+        "[usage]\nquota_panels = [\"claude\"]\nrefresh_interval_secs = 15\n",
+    )
+    .unwrap();
+    config::validate_config_file(&path).unwrap();
+
+    // Neither is anything in the generated default.
+    fs::remove_file(&path).unwrap();
+    config::migrate_config_file(&path).unwrap();
+    config::validate_config_file(&path).unwrap();
+}
@@ -0,0 +1,73 @@
+// Integration tests for `--dedupe-by message-id`
+
+use vct_core::TimeRange;
+use vct_core::config::ProvidersConfig;
+use vct_core::session::{ParseMode, parse_session_file_with_diagnostics};
+use vct_core::summary_cache::SummaryScanCache;
+use vct_core::usage::UsageScanOptions;
+use vct_core::usage::aggregator::aggregate_usage_from_paths_with_cache_opts;
+use vct_core::utils::{DedupeBy, TextEncoding};
+use vct_core::warnings::WarningSink;
+use vct_test_support::{TempHome, fixture, fixture_str};
+
+const MODEL: &str = "claude-sonnet-4-20250514";
+const COUNTED_TWICE: (i64, i64, i64) = (120 * 2 + 80, 15_000 * 2 + 17_000, 340 * 2 + 60);
+const COUNTED_ONCE: (i64, i64, i64) = (120 + 80, 15_000 + 17_000, 340 + 60);
+
+fn tokens(usage: &serde_json::Value) -> (i64, i64, i64) {
+    (
+        usage["input_tokens"].as_i64().unwrap(),
+        usage["cache_read_input_tokens"].as_i64().unwrap(),
+        usage["output_tokens"].as_i64().unwrap(),
+    )
+}
+
+#[test]
+fn re_logged_claude_message_counts_its_tokens_once() {
+    let parsed = |dedupe_by| {
+        let (analysis, _) = parse_session_file_with_diagnostics(
+            fixture("sessions/claude_code_duplicate_message_id.jsonl"),
+            ParseMode::Full,
+            TextEncoding::Utf8,
+            dedupe_by,
+            &WarningSink::new(),
+        )
+        .unwrap();
+        tokens(&analysis.records[0].conversation_usage[MODEL])
+    };
+
+    // Off by default: the retried message is counted twice.
+    assert_eq!(parsed(None), COUNTED_TWICE);
+    assert_eq!(parsed(Some(DedupeBy::MessageId)), COUNTED_ONCE);
+}
+
+#[test]
+fn usage_scan_dedupes_per_scan_options_through_one_cache() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "retried.jsonl",
+        &fixture_str("sessions/claude_code_duplicate_message_id.jsonl"),
+    );
+    let mut cache = SummaryScanCache::new();
+    let mut scan = |dedupe_by| {
+        let data = aggregate_usage_from_paths_with_cache_opts(
+            &home.paths,
+            TimeRange::All,
+            ProvidersConfig::default(),
+            &mut cache,
+            &UsageScanOptions {
+                dedupe_by,
+                ..UsageScanOptions::default()
+            },
+        )
+        .unwrap()
+        .data;
+        tokens(&data.models[MODEL])
+    };
+
+    // A cached summary parsed without deduplication is not reused with it.
+    assert_eq!(scan(None), COUNTED_TWICE);
+    assert_eq!(scan(Some(DedupeBy::MessageId)), COUNTED_ONCE);
+    assert_eq!(scan(None), COUNTED_TWICE);
+}
@@ -8,79 +8,22 @@
 // - gitRemoteUrl: git remote URL may differ
 
 use serde_json::Value;
+use vct_core::session::golden::json_mismatch;
 use vct_core::session::parser::parse_session_file_to_value;
 use vct_test_support::fixture;
 
 /// Compare two JSON values while ignoring specific fields
 ///
-/// This function recursively compares two JSON values, ignoring the specified fields
-/// at any level of nesting.
+/// Thin wrapper over [`json_mismatch`] (the comparator `vct dev` uses) that
+/// prints the first difference, ignoring the specified fields at any level of
+/// nesting.
 fn compare_json_ignore_fields(actual: &Value, expected: &Value, ignore_fields: &[&str]) -> bool {
-    match (actual, expected) {
-        (Value::Object(actual_map), Value::Object(expected_map)) => {
-            // Get all keys from both objects (excluding ignored fields)
-            let actual_keys: std::collections::HashSet<_> = actual_map
-                .keys()
-                .filter(|k| !ignore_fields.contains(&k.as_str()))
-                .collect();
-
-            let expected_keys: std::collections::HashSet<_> = expected_map
-                .keys()
-                .filter(|k| !ignore_fields.contains(&k.as_str()))
-                .collect();
-
-            // Check that both have the same keys (excluding ignored fields)
-            if actual_keys != expected_keys {
-                eprintln!("Key mismatch:");
-                eprintln!("  Actual keys: {:?}", actual_keys);
-                eprintln!("  Expected keys: {:?}", expected_keys);
-                return false;
-            }
-
-            // Recursively compare each value
-            for key in actual_keys {
-                let actual_value = &actual_map[key.as_str()];
-                let expected_value = &expected_map[key.as_str()];
-
-                if !compare_json_ignore_fields(actual_value, expected_value, ignore_fields) {
-                    eprintln!("Mismatch at key: {}", key);
-                    return false;
-                }
-            }
-
-            true
-        }
-        (Value::Array(actual_arr), Value::Array(expected_arr)) => {
-            if actual_arr.len() != expected_arr.len() {
-                eprintln!(
-                    "Array length mismatch: {} vs {}",
-                    actual_arr.len(),
-                    expected_arr.len()
-                );
-                return false;
-            }
-
-            for (i, (actual_item, expected_item)) in
-                actual_arr.iter().zip(expected_arr.iter()).enumerate()
-            {
-                if !compare_json_ignore_fields(actual_item, expected_item, ignore_fields) {
-                    eprintln!("Mismatch at array index: {}", i);
-                    return false;
-                }
-            }
-
-            true
-        }
-        _ => {
-            if actual != expected {
-                eprintln!("Value mismatch:");
-                eprintln!("  Actual: {}", actual);
-                eprintln!("  Expected: {}", expected);
-                false
-            } else {
-                true
-            }
+    match json_mismatch(actual, expected, ignore_fields) {
+        Some(mismatch) => {
+            eprintln!("Mismatch at {mismatch}");
+            false
         }
+        None => true,
     }
 }
 
@@ -9,10 +9,11 @@ use serde_json::json;
 use std::collections::HashMap;
 use tempfile::TempDir;
 use vct_core::pricing::{
-    ModelPricing, ModelPricingMap, ThresholdTier, TierRange, calculate_cost, clear_pricing_cache,
-    fetch_model_pricing_with, normalize_model_name,
+    BUILTIN_PRICING_VERSION, MatchKind, ModelPricing, ModelPricingMap, ThresholdTier, TierRange,
+    calculate_cost, clear_pricing_cache, fetch_model_pricing_with, normalize_model_name,
 };
 use vct_core::utils::get_pricing_cache_path_in;
+use vct_core::warnings::WarningSink;
 
 /// Builds normalized counts for the positional (input, output, reasoning,
 /// cache_read, cache_creation_5m, cache_creation_1h) test shorthand.
@@ -69,8 +70,12 @@ fn fetch_pricing_from_mock_parses_and_caches() {
     });
     let cache_dir = TempDir::new().unwrap();
 
-    let map = fetch_model_pricing_with(&server.url("/pricing"), cache_dir.path())
-        .expect("fetch pricing from mock server");
+    let map = fetch_model_pricing_with(
+        &server.url("/pricing"),
+        cache_dir.path(),
+        &WarningSink::new(),
+    )
+    .expect("fetch pricing from mock server");
 
     endpoint.assert(); // the mock endpoint was reached
 
@@ -112,8 +117,12 @@ fn fetch_pricing_prefers_cache_over_network() {
     )
     .unwrap();
 
-    let map = fetch_model_pricing_with(&server.url("/pricing"), cache_dir.path())
-        .expect("cache hit should succeed without a request");
+    let map = fetch_model_pricing_with(
+        &server.url("/pricing"),
+        cache_dir.path(),
+        &WarningSink::new(),
+    )
+    .expect("cache hit should succeed without a request");
 
     assert_eq!(
         endpoint.calls(),
@@ -135,8 +144,12 @@ fn fetch_pricing_rejects_http_errors_without_caching() {
         });
         let cache_dir = TempDir::new().unwrap();
 
-        let error = fetch_model_pricing_with(&server.url("/pricing"), cache_dir.path())
-            .expect_err("non-success responses must fail before parsing or caching");
+        let error = fetch_model_pricing_with(
+            &server.url("/pricing"),
+            cache_dir.path(),
+            &WarningSink::new(),
+        )
+        .expect_err("non-success responses must fail before parsing or caching");
 
         assert!(error.to_string().contains(&status.to_string()));
         endpoint.assert();
@@ -162,8 +175,12 @@ fn fetch_pricing_rejects_unpriced_payloads_without_caching() {
         });
         let cache_dir = TempDir::new().unwrap();
 
-        fetch_model_pricing_with(&server.url("/pricing"), cache_dir.path())
-            .expect_err("invalid or unpriced payloads must not be cached");
+        fetch_model_pricing_with(
+            &server.url("/pricing"),
+            cache_dir.path(),
+            &WarningSink::new(),
+        )
+        .expect_err("invalid or unpriced payloads must not be cached");
 
         endpoint.assert();
         let cache_file = get_pricing_cache_path_in(cache_dir.path(), &pricing_cache_date());
@@ -183,8 +200,12 @@ fn fetch_pricing_rejects_negative_rates_without_caching() {
     });
     let cache_dir = TempDir::new().unwrap();
 
-    let error = fetch_model_pricing_with(&server.url("/pricing"), cache_dir.path())
-        .expect_err("a negative price must reject the entire payload");
+    let error = fetch_model_pricing_with(
+        &server.url("/pricing"),
+        cache_dir.path(),
+        &WarningSink::new(),
+    )
+    .expect_err("a negative price must reject the entire payload");
 
     assert!(error.to_string().contains("negative or non-finite"));
     endpoint.assert();
@@ -202,8 +223,9 @@ fn fetch_pricing_backs_off_after_failure() {
     let cache_dir = TempDir::new().unwrap();
     let url = server.url("/pricing");
 
-    fetch_model_pricing_with(&url, cache_dir.path()).expect_err("first request should fail");
-    let retry = fetch_model_pricing_with(&url, cache_dir.path())
+    fetch_model_pricing_with(&url, cache_dir.path(), &WarningSink::new())
+        .expect_err("first request should fail");
+    let retry = fetch_model_pricing_with(&url, cache_dir.path(), &WarningSink::new())
         .expect_err("an immediate retry should be backed off");
 
     assert!(retry.to_string().contains("failure backoff"));
@@ -368,6 +390,48 @@ fn substring_specificity_ignores_provider_prefix_length() {
     assert_eq!(result.matched_model.as_deref(), Some("gpt-4o"));
 }
 
+#[test]
+fn models_missing_from_litellm_resolve_via_the_builtin_table() {
+    let mut raw_map = HashMap::new();
+    raw_map.insert("gpt-5".to_string(), pricing_at(5.0));
+    let pricing_map = ModelPricingMap::new(raw_map);
+
+    // No LiteLLM tier matches: priced from the built-in table.
+    let result = pricing_map.get("claude-haiku-4-5-20251001");
+    assert_eq!(result.match_kind, MatchKind::Builtin);
+    assert!((result.pricing.input_cost_per_token - 1e-6).abs() < 1e-15);
+    assert!((result.pricing.output_cost_per_token - 5e-6).abs() < 1e-15);
+    let note = result.matched_model.unwrap();
+    assert!(note.starts_with("claude-haiku-4-5 "), "{note}");
+    assert!(note.contains(BUILTIN_PRICING_VERSION), "{note}");
+
+    // A LiteLLM entry always wins over the built-in rate.
+    let result = pricing_map.get("gpt-5");
+    assert_eq!(result.match_kind, MatchKind::Exact);
+    assert_eq!(result.pricing.input_cost_per_token, 5.0);
+}
+
+#[test]
+fn loose_litellm_matches_win_over_the_builtin_table() {
+    let mut raw_map = HashMap::new();
+    raw_map.insert("claude-haiku-4".to_string(), pricing_at(4.0));
+    let pricing_map = ModelPricingMap::new(raw_map);
+
+    // Both the built-in table and a substring match could price this model;
+    // every LiteLLM tier runs before the built-in table.
+    let result = pricing_map.get("claude-haiku-4-5-20251001");
+    assert!(
+        matches!(
+            result.match_kind,
+            MatchKind::Substring | MatchKind::Fuzzy { .. }
+        ),
+        "{:?}",
+        result.match_kind
+    );
+    assert_eq!(result.pricing.input_cost_per_token, 4.0);
+    assert_eq!(result.matched_model.as_deref(), Some("claude-haiku-4"));
+}
+
 #[test]
 fn pricing_lookup_caches_are_isolated_per_map() {
     let mut first = HashMap::new();
@@ -668,6 +732,7 @@ fn test_pricing_result_structure() {
     let result = ModelPricingResult {
         pricing,
         matched_model: Some("test-model".to_string()),
+        match_kind: MatchKind::Substring,
     };
 
     assert_eq!(result.matched_model, Some("test-model".to_string()));
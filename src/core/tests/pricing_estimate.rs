@@ -0,0 +1,43 @@
+// Integration tests for `--estimate-missing-pricing`
+
+use std::collections::HashMap;
+use vct_core::pricing::{MatchKind, ModelPricing, ModelPricingMap, family_of};
+
+fn pricing(input_cost_per_token: f64, output_cost_per_token: f64) -> ModelPricing {
+    ModelPricing {
+        input_cost_per_token,
+        output_cost_per_token,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn unknown_sonnet_variant_gets_the_sonnet_family_average() {
+    let mut raw_map = HashMap::new();
+    raw_map.insert(
+        "claude-3-5-sonnet-20241022".to_string(),
+        pricing(2e-6, 10e-6),
+    );
+    raw_map.insert("claude-sonnet-4-20250514".to_string(), pricing(4e-6, 20e-6));
+    raw_map.insert("claude-3-opus-20240229".to_string(), pricing(15e-6, 75e-6));
+    raw_map.insert("gpt-4o".to_string(), pricing(2.5e-6, 10e-6));
+    let model = "vertex_ai/sonnet@preview-x";
+    assert_eq!(family_of(model).as_deref(), Some("claude-sonnet"));
+
+    // Off by default: no tier prices the variant.
+    let pricing_map = ModelPricingMap::new(raw_map.clone());
+    let result = pricing_map.get(model);
+    assert_eq!(result.match_kind, MatchKind::Unmatched);
+    assert_eq!(result.pricing.input_cost_per_token, 0.0);
+
+    let pricing_map = ModelPricingMap::new(raw_map).with_family_estimates(true);
+    let result = pricing_map.get(model);
+
+    assert_eq!(result.match_kind, MatchKind::Estimated);
+    assert!((result.pricing.input_cost_per_token - 3e-6).abs() < 1e-15);
+    assert!((result.pricing.output_cost_per_token - 15e-6).abs() < 1e-15);
+    assert_eq!(
+        result.matched_model.as_deref(),
+        Some("claude-sonnet family average of 2")
+    );
+}
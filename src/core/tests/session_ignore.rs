@@ -0,0 +1,139 @@
+// Integration tests for `.vctignore` / `--ignore-file`
+
+use vct_core::TimeRange;
+use vct_core::analysis::AnalysisScanOptions;
+use vct_core::analysis::aggregator::collect_analysis_sessions_from_paths_with_opts;
+use vct_core::config::ProvidersConfig;
+use vct_core::scan::{provider_detect_stats_from_paths, sessions_per_day_from_paths};
+use vct_core::session::ParseMode;
+use vct_core::summary_cache::SummaryScanCache;
+use vct_core::usage::aggregator::aggregate_usage_from_paths_with_cache_opts;
+use vct_core::usage::{UsageScanOptions, distinct_models_from_paths};
+use vct_core::utils::SessionIgnore;
+use vct_test_support::{TempHome, fixture_str};
+
+#[test]
+fn ignored_sessions_are_skipped_by_usage_and_analysis() {
+    let home = TempHome::new();
+    let session = fixture_str("sessions/claude_code.jsonl");
+    let kept = home.put_claude_session("project", "kept.jsonl", &session);
+    home.put_claude_session("scratch", "trial.jsonl", &session);
+    home.put_claude_session("project", "trial-test.jsonl", &session);
+    let ignore_file = home.put("vctignore", "# scratch sessions\nscratch/\n*-test.jsonl\n");
+
+    let usage = |ignore: SessionIgnore| {
+        aggregate_usage_from_paths_with_cache_opts(
+            &home.paths,
+            TimeRange::All,
+            ProvidersConfig::default(),
+            &mut SummaryScanCache::new(),
+            &UsageScanOptions {
+                ignore,
+                ..UsageScanOptions::default()
+            },
+        )
+        .unwrap()
+    };
+    assert_eq!(usage(SessionIgnore::default()).data.sessions.total(), 3);
+
+    let ignore = SessionIgnore::from_file(&ignore_file).unwrap();
+    assert_eq!(usage(ignore.clone()).data.sessions.total(), 1);
+    let analysis = collect_analysis_sessions_from_paths_with_opts(
+        &home.paths,
+        TimeRange::All,
+        ProvidersConfig::default(),
+        ParseMode::UsageOnly,
+        &AnalysisScanOptions {
+            ignore,
+            ..AnalysisScanOptions::default()
+        },
+    );
+
+    let sources: Vec<_> = analysis
+        .unwrap()
+        .sessions
+        .into_iter()
+        .filter_map(|session| session.source)
+        .collect();
+    assert_eq!(sources, [kept]);
+}
+
+#[test]
+fn ignored_sessions_are_skipped_by_sessions_per_day() {
+    let home = TempHome::new();
+    let session = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "kept.jsonl", &session);
+    home.put_claude_session("scratch", "trial.jsonl", &session);
+    let ignore_file = home.put("vctignore", "scratch/\n");
+
+    let sessions = |ignore: &SessionIgnore| {
+        sessions_per_day_from_paths(
+            &home.paths,
+            ProvidersConfig::default(),
+            TimeRange::All,
+            ignore,
+        )
+        .values()
+        .sum::<usize>()
+    };
+    assert_eq!(sessions(&SessionIgnore::default()), 2);
+    assert_eq!(
+        sessions(&SessionIgnore::from_file(&ignore_file).unwrap()),
+        1
+    );
+}
+
+#[test]
+fn ignored_sessions_are_skipped_by_provider_detect_stats() {
+    let home = TempHome::new();
+    let session = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "kept.jsonl", &session);
+    home.put_claude_session("scratch", "trial.jsonl", &session);
+    let ignore_file = home.put("vctignore", "scratch/\n");
+
+    let total_files = |ignore: &SessionIgnore| {
+        provider_detect_stats_from_paths(
+            &home.paths,
+            ProvidersConfig::default(),
+            TimeRange::All,
+            ignore,
+        )
+        .total_files
+    };
+    assert_eq!(total_files(&SessionIgnore::default()), 2);
+    assert_eq!(
+        total_files(&SessionIgnore::from_file(&ignore_file).unwrap()),
+        1
+    );
+}
+
+#[test]
+fn ignored_sessions_are_skipped_by_distinct_models() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "kept.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    home.put_gemini_session(
+        "scratch",
+        "chat.jsonl",
+        &fixture_str("sessions/gemini.jsonl"),
+    );
+    let ignore_file = home.put("vctignore", "scratch/\n");
+
+    let models = distinct_models_from_paths(
+        &home.paths,
+        ProvidersConfig::default(),
+        &mut SummaryScanCache::new(),
+        &UsageScanOptions {
+            ignore: SessionIgnore::from_file(&ignore_file).unwrap(),
+            ..UsageScanOptions::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        models.iter().map(String::as_str).collect::<Vec<_>>(),
+        ["claude-sonnet-4-20250514"]
+    );
+}
@@ -6,17 +6,32 @@
 // is reached. The remaining tests are pure in-memory cost / JSON math.
 
 use rusqlite::{Connection, params};
+use std::collections::HashMap;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use vct_core::TimeRange;
+use vct_core::analysis::aggregator::{
+    aggregate_sessions_by_model_from_paths_with_diagnostics,
+    collect_analysis_sessions_from_paths_with,
+};
 use vct_core::config::ProvidersConfig;
-use vct_core::models::ExtensionType;
+use vct_core::models::{ExtensionType, UNATTRIBUTED_MODEL};
+use vct_core::pricing::ModelPricingMap;
+use vct_core::session::ParseMode;
 use vct_core::summary_cache::SummaryScanCache;
 use vct_core::usage::aggregator::{
-    UsageData, aggregate_usage_from_paths, aggregate_usage_from_paths_with_cache,
-    aggregate_usage_from_paths_with_diagnostics, aggregate_usage_from_paths_with_providers,
+    UsageData, UsageScanOptions, aggregate_usage_from_paths, aggregate_usage_from_paths_with_cache,
+    aggregate_usage_from_paths_with_cache_opts, aggregate_usage_from_paths_with_diagnostics,
+    aggregate_usage_from_paths_with_providers,
+};
+use vct_core::usage::summary::build_usage_summary;
+use vct_core::usage::{
+    GroupBy, ModelBudget, SplitPeriod, check_model_budgets, cost_on_date, diff_usage_exports,
+    distinct_models_from_paths, group_usage, load_override_keys, load_usage_export,
+    price_usage_data, split_usage, tokens_on_date, unused_override_keys,
 };
-use vct_test_support::{TempHome, append_cursor_json_blob, fixture_str};
+use vct_core::utils::TextEncoding;
+use vct_test_support::{TempHome, append_cursor_json_blob, backdate, fixture_str};
 
 fn claude_only() -> ProvidersConfig {
     ProvidersConfig {
@@ -153,6 +168,7 @@ fn assert_usage_data_eq(actual: &UsageData, expected: &UsageData) {
     assert_eq!(actual.per_provider.opencode, expected.per_provider.opencode);
     assert_eq!(actual.per_provider.cursor, expected.per_provider.cursor);
     assert_eq!(actual.per_provider.hermes, expected.per_provider.hermes);
+    assert_eq!(actual.sessions.total(), expected.sessions.total());
     assert_eq!(
         (
             actual.provider_days.claude,
@@ -260,6 +276,86 @@ fn cached_usage_matches_uncached_for_every_provider_source() {
     assert_usage_data_eq(&warm.data, &uncached);
 }
 
+#[test]
+fn distinct_models_spans_every_provider_and_reuses_the_parse_cache() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "project",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    backdate(
+        &home.put_codex_session(
+            "2025/01/01/rollout.jsonl",
+            &fixture_str("sessions/codex.jsonl"),
+        ),
+        400,
+    );
+    home.put_gemini_session(
+        "project-hash",
+        "chat.jsonl",
+        &fixture_str("sessions/gemini.jsonl"),
+    );
+
+    let mut cache = SummaryScanCache::new();
+    let models = distinct_models_from_paths(
+        &home.paths,
+        ProvidersConfig::default(),
+        &mut cache,
+        &UsageScanOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        models.iter().map(String::as_str).collect::<Vec<_>>(),
+        [
+            "aide-gpt-5",
+            "claude-sonnet-4-20250514",
+            "gemini-3-flash-preview",
+            "gemini-3.1-pro-preview",
+        ]
+    );
+
+    let again = distinct_models_from_paths(
+        &home.paths,
+        ProvidersConfig::default(),
+        &mut cache,
+        &UsageScanOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(again, models);
+    assert_eq!(cache.stats().parsed_sources, 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn usage_follows_symlinked_session_directories() {
+    let home = TempHome::new();
+    let synced = tempfile::tempdir().unwrap();
+    let day = synced.path().join("2026/06/06");
+    std::fs::create_dir_all(&day).unwrap();
+    std::fs::write(
+        day.join("rollout.jsonl"),
+        fixture_str("sessions/codex.jsonl"),
+    )
+    .unwrap();
+    std::fs::create_dir_all(&home.paths.codex_session_dir).unwrap();
+    std::os::unix::fs::symlink(
+        synced.path().join("2026"),
+        home.paths.codex_session_dir.join("2026"),
+    )
+    .unwrap();
+
+    let providers = ProvidersConfig {
+        codex: true,
+        ..claude_only()
+    };
+    let scan = aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, providers)
+        .unwrap();
+    assert_eq!(scan.diagnostics.parsed, 1);
+    assert!(scan.diagnostics.failures.is_empty());
+    assert!(!scan.data.per_provider.codex.is_empty());
+}
+
 #[cfg(unix)]
 #[test]
 fn usage_cache_preserves_entries_after_partial_directory_discovery() {
@@ -558,6 +654,69 @@ fn zero_usage_metadata_does_not_increment_active_days() {
     assert_eq!(cached.data.provider_days.claude, 0);
 }
 
+#[test]
+fn tool_only_session_is_in_analysis_but_not_usage() {
+    let home = TempHome::new();
+    // One Bash call, but no assistant message ever reports token usage.
+    home.put_claude_session(
+        "proj",
+        "tool-only.jsonl",
+        concat!(
+            r#"{"type":"assistant","timestamp":"2026-07-12T00:00:00Z","cwd":"/repo","sessionId":"s1","message":{"role":"assistant","model":"claude-sonnet-4-20250514","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"ls"}}]}}"#,
+            "\n",
+            r#"{"type":"user","timestamp":"2026-07-12T00:00:01Z","cwd":"/repo","sessionId":"s1","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_1","content":"a"}]}}"#,
+            "\n",
+        ),
+    );
+    // A reported but all-zero usage block must not create a zero-token row either.
+    home.put_claude_session(
+        "proj",
+        "zero-usage.jsonl",
+        r#"{"type":"assistant","timestamp":"2026-07-12T00:00:00Z","cwd":"/repo","sessionId":"s2","message":{"role":"assistant","model":"claude-opus-4-1","usage":{"input_tokens":0,"output_tokens":0},"content":[]}}"#,
+    );
+
+    let dataset = collect_analysis_sessions_from_paths_with(
+        &home.paths,
+        TimeRange::All,
+        claude_only(),
+        ParseMode::Full,
+    )
+    .unwrap();
+    let tool_only = dataset
+        .sessions
+        .iter()
+        .find(|session| session.analysis.records[0].task_id == "s1")
+        .expect("tool-only session is in the analysis dataset");
+    assert!(tool_only.analysis.records[0].conversation_usage.is_empty());
+
+    let analysis = aggregate_sessions_by_model_from_paths_with_diagnostics(
+        &home.paths,
+        TimeRange::All,
+        claude_only(),
+    )
+    .unwrap();
+    let row = analysis
+        .data
+        .rows
+        .iter()
+        .find(|row| row.model == UNATTRIBUTED_MODEL)
+        .expect("tool-only activity keeps an analysis row");
+    assert_eq!(row.bash_count, 1);
+
+    let legacy =
+        aggregate_usage_from_paths_with_providers(&home.paths, TimeRange::All, claude_only())
+            .unwrap();
+    let cached =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    for usage in [&legacy, &cached] {
+        assert!(usage.models.is_empty(), "no usage rows: {:?}", usage.models);
+        assert_eq!(usage.sessions.total(), 0);
+        assert_eq!(usage.provider_days.claude, 0);
+    }
+}
+
 #[test]
 fn usage_database_failures_preserve_all_failed_and_partial_diagnostics() {
     let home = TempHome::new();
@@ -833,6 +992,134 @@ fn merges_multiple_providers_from_paths() {
     assert!(data.provider_days.gemini >= 1);
 }
 
+#[test]
+fn override_audit_lists_only_keys_no_session_used() {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let overrides = home.home().join("overrides.json");
+    std::fs::write(
+        &overrides,
+        r#"{
+            "claude-sonnet-4-20250514": {"input_cost_per_token": 3e-6},
+            "gpt-4o-stale-override": {"input_cost_per_token": 5e-6},
+            "gemini-1.0-pro-retired": {"input_cost_per_token": 1e-6}
+        }"#,
+    )
+    .unwrap();
+
+    let data = aggregate_usage_from_paths(&home.paths, TimeRange::All).expect("aggregate claude");
+    let keys = load_override_keys(&overrides).expect("read override keys");
+    let unused = unused_override_keys(&keys, data.distinct_models());
+
+    assert_eq!(
+        unused,
+        vec![
+            "gemini-1.0-pro-retired".to_string(),
+            "gpt-4o-stale-override".to_string()
+        ]
+    );
+}
+
+#[test]
+fn session_counts_match_contributing_files() {
+    let home = TempHome::new();
+    let session = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("proj-a", "first.jsonl", &session);
+    home.put_claude_session("proj-b", "second.jsonl", &session);
+
+    let legacy = aggregate_usage_from_paths(&home.paths, TimeRange::All).expect("aggregate claude");
+    let cached =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .expect("aggregate claude with diagnostics")
+            .data;
+
+    for data in [&legacy, &cached] {
+        assert!(!data.models.is_empty());
+        for model in data.models.keys() {
+            assert_eq!(data.sessions.for_model(model).len(), 2, "{model}");
+        }
+        assert_eq!(data.sessions.total(), 2);
+    }
+    // Both files share one modified date, so the date+model count matches.
+    let date = cached.per_date.keys().next().expect("a usage date");
+    let model = cached.models.keys().next().unwrap();
+    assert_eq!(cached.sessions.on_date(date, model).count(), 2);
+}
+
+#[test]
+fn database_sessions_count_once_each() {
+    let home = TempHome::new();
+    seed_opencode_usage_db(&home.paths.opencode_db);
+    Connection::open(&home.paths.opencode_db)
+        .unwrap()
+        .execute_batch(
+            r#"INSERT INTO session (id, directory, time_updated)
+               VALUES ('second-session', '/repo', 1780757089000);
+               INSERT INTO message (id, session_id, data)
+               VALUES (
+                   'second-message',
+                   'second-session',
+                   '{"role":"assistant","providerID":"openai","modelID":"open-model","cost":0.5,"tokens":{"input":3,"output":1,"reasoning":0,"cache":{"read":0,"write":0}},"time":{"created":1780757088000,"completed":1780757089000}}'
+               );
+               INSERT INTO message (id, session_id, data)
+               VALUES (
+                   'second-message-2',
+                   'second-session',
+                   '{"role":"assistant","providerID":"openai","modelID":"open-model","cost":0.5,"tokens":{"input":3,"output":1,"reasoning":0,"cache":{"read":0,"write":0}},"time":{"created":1780757088000,"completed":1780757089000}}'
+               );"#,
+        )
+        .unwrap();
+    let providers = opencode_only();
+
+    let legacy =
+        aggregate_usage_from_paths_with_providers(&home.paths, TimeRange::All, providers).unwrap();
+    let cached =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, providers)
+            .unwrap()
+            .data;
+
+    for data in [&legacy, &cached] {
+        assert_eq!(data.sessions.for_model("openai/open-model").len(), 2);
+        assert_eq!(data.sessions.total(), 2);
+    }
+}
+
+#[test]
+fn one_session_reporting_two_folded_variants_counts_once() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    let both = format!(
+        "{claude}\n{}",
+        claude.replace("claude-sonnet-4-20250514", "Claude-Sonnet-4-20250514")
+    );
+    home.put_claude_session("project", "both.jsonl", &both);
+
+    let mut data =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    let mut summary = build_usage_summary(
+        &data.models,
+        &data.per_provider,
+        &data.provider_days,
+        &ModelPricingMap::new(HashMap::new()),
+        &data.stored_costs,
+        &data.model_renames,
+        None,
+    );
+    summary.attach_session_counts(&data);
+    assert!(summary.rows.iter().all(|row| row.sessions == 1));
+
+    data.fold_model_case();
+
+    assert_eq!(data.sessions.for_model("claude-sonnet-4-20250514").len(), 1);
+    assert_eq!(data.sessions.total(), 1);
+}
+
 #[test]
 fn aggregates_grok_context_estimate_without_model_or_compaction_duplication() {
     let home = TempHome::new();
@@ -1064,3 +1351,402 @@ fn test_usage_handles_missing_cache_tokens() {
         0
     );
 }
+
+#[test]
+fn usage_diff_compares_two_saved_exports_by_model() {
+    let home = TempHome::new();
+    let before = home.home().join("before.json");
+    let after = home.home().join("after.json");
+    // `before` predates the `sessions` field; the loader defaults it.
+    std::fs::write(
+        &before,
+        r#"[
+            {"cost_usd": 1.5, "model": "claude-3-5-sonnet",
+             "usage": {"input_tokens": 10000, "output_tokens": 5000}},
+            {"cost_usd": 0.25, "model": "gpt-retired",
+             "usage": {"input_tokens": 2000, "output_tokens": 0}}
+        ]"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &after,
+        r#"[
+            {"cost_usd": 3.6, "model": "claude-3-5-sonnet", "sessions": 4,
+             "usage": {"input_tokens": 50000, "output_tokens": 10000,
+                       "cache_read_input_tokens": 0, "total_tokens": 60000}},
+            {"cost_usd": 0.1, "matched_model": "gemini-2.5-pro", "model": "gemini-new",
+             "sessions": 1, "usage": {"input_tokens": 800, "output_tokens": 200}}
+        ]"#,
+    )
+    .unwrap();
+
+    let diff = diff_usage_exports(
+        &load_usage_export(&before).expect("read before"),
+        &load_usage_export(&after).expect("read after"),
+    );
+
+    let models: Vec<(&str, i64, f64)> = diff
+        .models
+        .iter()
+        .map(|row| (row.model.as_str(), row.tokens_delta, row.cost_delta))
+        .collect();
+    assert_eq!(models.len(), 3);
+    // Largest absolute cost change leads; a vanished model diffs against zero.
+    assert_eq!(models[0].0, "claude-3-5-sonnet");
+    assert_eq!(models[0].1, 45_000);
+    assert!((models[0].2 - 2.1).abs() < 1e-9);
+    assert_eq!(models[1].0, "gpt-retired");
+    assert_eq!(models[1].1, -2_000);
+    assert!((models[1].2 + 0.25).abs() < 1e-9);
+    assert_eq!(models[2].0, "gemini-new");
+    assert_eq!(diff.models[2].tokens_before, 0);
+    assert_eq!(diff.models[2].tokens_after, 1_000);
+
+    assert_eq!(diff.total.tokens_before, 17_000);
+    assert_eq!(diff.total.tokens_after, 61_000);
+    assert_eq!(diff.total.tokens_delta, 44_000);
+    assert!((diff.total.cost_delta - 1.95).abs() < 1e-9);
+}
+
+#[test]
+fn usage_diff_rejects_a_file_that_is_not_an_export() {
+    let home = TempHome::new();
+    let path = home.home().join("not-an-export.json");
+    std::fs::write(&path, r#"{"claude": 1}"#).unwrap();
+    let error = load_usage_export(&path).unwrap_err();
+    assert!(format!("{error:#}").contains("Failed to parse usage export"));
+}
+
+#[test]
+fn group_by_date_and_provider_yields_one_row_per_pair() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    let codex = fixture_str("sessions/codex.jsonl");
+    home.put_claude_session("project", "today.jsonl", &claude);
+    backdate(
+        &home.put_claude_session("project", "yesterday.jsonl", &claude),
+        1,
+    );
+    home.put_codex_session("2026/06/06/today.jsonl", &codex);
+    backdate(
+        &home.put_codex_session("2026/06/05/yesterday.jsonl", &codex),
+        1,
+    );
+
+    let providers = ProvidersConfig {
+        claude: true,
+        codex: true,
+        ..claude_only()
+    };
+    let data = aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, providers)
+        .unwrap()
+        .data;
+    let pricing = ModelPricingMap::new(std::collections::HashMap::new());
+    let group_by: GroupBy = "date,provider".parse().unwrap();
+    let rows = group_usage(&data, &group_by, &pricing);
+
+    let keys: Vec<(&str, &str)> = rows
+        .iter()
+        .map(|row| {
+            (
+                row.date.as_deref().unwrap(),
+                row.provider.as_deref().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(rows.len(), 4, "{keys:?}");
+    assert!(rows.iter().all(|row| row.model.is_none()));
+    assert_eq!(keys[0].0, keys[1].0);
+    assert!(keys[0].0 < keys[2].0);
+    assert_eq!([keys[0].1, keys[1].1], ["Claude", "Codex"]);
+    // Each day holds one copy of each session, so the days match.
+    assert_eq!(rows[0].tokens, rows[2].tokens);
+    assert_eq!(rows[1].tokens, rows[3].tokens);
+    // Each date+provider group is one session file.
+    assert!(rows.iter().all(|row| row.sessions == 1));
+    let total: i64 = rows.iter().map(|row| row.tokens).sum();
+    let all_models: i64 = data
+        .models
+        .values()
+        .map(|usage| vct_core::utils::extract_token_counts(usage).total)
+        .sum();
+    assert_eq!(total, all_models);
+}
+
+#[test]
+fn model_budget_flags_only_the_day_over_the_cap() {
+    use vct_core::pricing::ModelPricing;
+
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    // Today holds a shorter session than yesterday, so it costs less.
+    let today: String = claude
+        .lines()
+        .take(10)
+        .map(|line| format!("{line}\n"))
+        .collect();
+    home.put_claude_session("project", "today.jsonl", &today);
+    backdate(
+        &home.put_claude_session("project", "yesterday.jsonl", &claude),
+        1,
+    );
+
+    let data =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    let pricing = ModelPricingMap::new(std::collections::HashMap::from([(
+        "claude-sonnet-4-20250514".to_string(),
+        ModelPricing {
+            input_cost_per_token: 3e-6,
+            output_cost_per_token: 15e-6,
+            cache_read_input_token_cost: 0.3e-6,
+            cache_creation_input_token_cost: 3.75e-6,
+            ..Default::default()
+        },
+    )]));
+    let dates: Vec<&String> = data.per_date.keys().collect();
+    assert_eq!(dates.len(), 2);
+    let (earlier, later) = (
+        cost_on_date(&data, dates[0], &pricing),
+        cost_on_date(&data, dates[1], &pricing),
+    );
+    assert!(earlier > later, "{earlier} vs {later}");
+
+    let budgets = [
+        ModelBudget {
+            model: "Sonnet".to_string(),
+            cap_usd: (earlier + later) / 2.0,
+        },
+        "opus=1".parse().unwrap(),
+    ];
+    let days = check_model_budgets(&data, &budgets, &pricing);
+    let flags: Vec<(&str, &str, bool)> = days
+        .iter()
+        .map(|day| (day.date.as_str(), day.model.as_str(), day.over_model_budget))
+        .collect();
+    // No opus usage, so that cap yields no rows.
+    assert_eq!(
+        flags,
+        [
+            (dates[0].as_str(), "Sonnet", true),
+            (dates[1].as_str(), "Sonnet", false)
+        ]
+    );
+    assert!((days[0].cost_usd - earlier).abs() < 1e-12);
+
+    let json = serde_json::to_value(&days[0]).unwrap();
+    assert_eq!(json["over_model_budget"], true);
+    assert!("opus".parse::<ModelBudget>().is_err());
+    assert!("opus=-1".parse::<ModelBudget>().is_err());
+}
+
+#[test]
+fn tokens_on_date_counts_only_that_day() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "today.jsonl", &claude);
+    backdate(
+        &home.put_claude_session("project", "yesterday.jsonl", &claude),
+        1,
+    );
+
+    let data =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    let total: i64 = data
+        .models
+        .values()
+        .map(|usage| vct_core::utils::extract_token_counts(usage).total)
+        .sum();
+    let today = vct_core::utils::get_current_date();
+    assert!(total > 0);
+    assert_eq!(tokens_on_date(&data, &today) * 2, total);
+    assert_eq!(tokens_on_date(&data, "1999-01-01"), 0);
+}
+
+#[test]
+fn fold_model_case_merges_case_variant_model_rows() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "lower.jsonl", &claude);
+    home.put_claude_session(
+        "project",
+        "upper.jsonl",
+        &claude.replace("claude-sonnet-4-20250514", "Claude-Sonnet-4-20250514"),
+    );
+
+    let mut data =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    assert_eq!(data.models.len(), 2);
+    let single = vct_core::utils::extract_token_counts(&data.models["claude-sonnet-4-20250514"]);
+
+    data.fold_model_case();
+
+    assert_eq!(
+        data.models.keys().collect::<Vec<_>>(),
+        ["claude-sonnet-4-20250514"]
+    );
+    let merged = vct_core::utils::extract_token_counts(&data.models["claude-sonnet-4-20250514"]);
+    assert_eq!(merged.total, 2 * single.total);
+    assert_eq!(data.per_provider.claude.len(), 1);
+    assert_eq!(data.sessions.for_model("claude-sonnet-4-20250514").len(), 2);
+
+    let scanned = aggregate_usage_from_paths_with_cache_opts(
+        &home.paths,
+        TimeRange::All,
+        claude_only(),
+        &mut SummaryScanCache::new(),
+        &UsageScanOptions {
+            fold_case: true,
+            ..UsageScanOptions::default()
+        },
+    )
+    .unwrap()
+    .data;
+    assert_eq!(scanned.models, data.models);
+}
+
+#[test]
+fn fixup_model_renames_rows_only_after_pricing_each_reported_model() {
+    use vct_core::pricing::ModelPricing;
+
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    home.put_claude_session("project", "june.jsonl", &claude);
+    home.put_claude_session(
+        "project",
+        "january.jsonl",
+        &claude.replace("claude-sonnet-4-20250514", "claude-sonnet-4-20250101"),
+    );
+    let priced = |scale: f64| ModelPricing {
+        input_cost_per_token: 3e-6 * scale,
+        output_cost_per_token: 15e-6 * scale,
+        cache_read_input_token_cost: 0.3e-6 * scale,
+        cache_creation_input_token_cost: 3.75e-6 * scale,
+        ..Default::default()
+    };
+    // Neither target name is in the pricing map, so a rename applied before
+    // pricing would leave the merged row unpriced.
+    let pricing = ModelPricingMap::new(HashMap::from([
+        ("claude-sonnet-4-20250514".to_string(), priced(1.0)),
+        ("claude-sonnet-4-20250101".to_string(), priced(2.0)),
+    ]));
+    let options = UsageScanOptions {
+        fixups: vec![r"/(claude-sonnet-4)-\d{8}/=$1-dated".parse().unwrap()].into(),
+        ..UsageScanOptions::default()
+    };
+    let data = aggregate_usage_from_paths_with_cache_opts(
+        &home.paths,
+        TimeRange::All,
+        claude_only(),
+        &mut SummaryScanCache::new(),
+        &options,
+    )
+    .unwrap()
+    .data;
+    assert_eq!(data.models.len(), 2);
+
+    let summary = |data: &UsageData| {
+        let mut summary = build_usage_summary(
+            &data.models,
+            &data.per_provider,
+            &data.provider_days,
+            &pricing,
+            &data.stored_costs,
+            &data.model_renames,
+            None,
+        );
+        summary.attach_session_counts(data);
+        summary
+    };
+    let renamed = summary(&data);
+    let raw = summary(&UsageData {
+        model_renames: Default::default(),
+        ..data.clone()
+    });
+    assert_eq!(raw.rows.len(), 2);
+    assert!(raw.rows.iter().all(|row| row.cost > 0.0));
+    assert_eq!(renamed.rows.len(), 1);
+    let row = &renamed.rows[0];
+    assert_eq!(row.model, "claude-sonnet-4-dated");
+    assert_eq!(row.matched_model, None);
+    assert_eq!(row.sessions, 2);
+    let raw_cost: f64 = raw.rows.iter().map(|row| row.cost).sum();
+    assert!(
+        (row.cost - raw_cost).abs() < 1e-12,
+        "{} vs {raw_cost}",
+        row.cost
+    );
+
+    let json = price_usage_data(&data, &pricing);
+    assert_eq!(json.len(), 1);
+    assert_eq!(json[0].model, "claude-sonnet-4-dated");
+    assert!((json[0].cost_usd - raw_cost).abs() < 1e-12);
+    assert_eq!(json[0].sessions, 2);
+}
+
+#[test]
+fn split_by_week_gives_one_section_per_iso_week_with_its_own_subtotal() {
+    let home = TempHome::new();
+    let claude = fixture_str("sessions/claude_code.jsonl");
+    // A week apart, so each session lands in a different ISO week.
+    for (name, days_ago) in [("now.jsonl", 0), ("last.jsonl", 7), ("before.jsonl", 14)] {
+        backdate(&home.put_claude_session("project", name, &claude), days_ago);
+    }
+    let data =
+        aggregate_usage_from_paths_with_diagnostics(&home.paths, TimeRange::All, claude_only())
+            .unwrap()
+            .data;
+    let pricing = ModelPricingMap::new(std::collections::HashMap::new());
+
+    let sections = split_usage(&data, SplitPeriod::Week, &pricing);
+    assert_eq!(sections.len(), 3);
+    assert!(
+        sections
+            .windows(2)
+            .all(|pair| pair[0].period < pair[1].period)
+    );
+    for section in &sections {
+        assert_eq!(section.rows.len(), 1, "{}", section.period);
+        assert_eq!(section.tokens, section.rows[0].tokens);
+    }
+    let split_tokens: i64 = sections.iter().map(|section| section.tokens).sum();
+    let dates: Vec<String> = data.per_date.keys().cloned().collect();
+    let total_tokens: i64 = dates.iter().map(|date| tokens_on_date(&data, date)).sum();
+    assert_eq!(split_tokens, total_tokens);
+
+    // ISO weeks belong to the year their Thursday falls in.
+    assert_eq!(SplitPeriod::Week.label("2024-12-30"), "2025-W01");
+    assert_eq!(SplitPeriod::Month.label("2024-12-30"), "2024-12");
+}
+
+#[test]
+fn scan_warnings_are_recorded_in_the_options_sink() {
+    let home = TempHome::new();
+    let mut bytes = fixture_str("sessions/claude_code.jsonl").into_bytes();
+    let at = bytes.windows(11).position(|w| w == b"claude-code").unwrap() + 8;
+    bytes[at] = 0xE9;
+    let session = home.put_claude_session("project", "latin1.jsonl", "");
+    std::fs::write(&session, &bytes).unwrap();
+
+    let options = UsageScanOptions {
+        encoding: TextEncoding::Lossy,
+        ..UsageScanOptions::default()
+    };
+    let collection = aggregate_usage_from_paths_with_cache_opts(
+        &home.paths,
+        TimeRange::All,
+        ProvidersConfig::default(),
+        &mut SummaryScanCache::new(),
+        &options,
+    )
+    .unwrap();
+    assert!(!collection.data.models.is_empty());
+    let warnings = options.warnings.take();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.starts_with("replaced invalid UTF-8"));
+}
@@ -0,0 +1,136 @@
+//! `usage --webhook` posts against a local `httpmock` server.
+//!
+//! The payloads are built from a `TempHome` session the way the CLI builds
+//! them, so the asserted bodies are the exact shapes a webhook receives.
+
+use httpmock::prelude::*;
+use serde_json::json;
+use std::time::Duration;
+use vct_core::TimeRange;
+use vct_core::pricing::{ModelPricing, ModelPricingMap};
+use vct_core::usage::summary::usage_footer;
+use vct_core::usage::{
+    UsageData, WebhookSummary, aggregate_usage_from_paths, post_webhook, price_usage_data,
+};
+use vct_test_support::{TempHome, fixture_str};
+
+fn priced_session() -> (UsageData, ModelPricingMap) {
+    let home = TempHome::new();
+    home.put_claude_session(
+        "proj",
+        "session.jsonl",
+        &fixture_str("sessions/claude_code.jsonl"),
+    );
+    let data = aggregate_usage_from_paths(&home.paths, TimeRange::All).unwrap();
+    let pricing = ModelPricingMap::new(
+        data.models
+            .keys()
+            .map(|model| {
+                let pricing = ModelPricing {
+                    input_cost_per_token: 1e-6,
+                    output_cost_per_token: 1e-6,
+                    ..Default::default()
+                };
+                (model.clone(), pricing)
+            })
+            .collect(),
+    );
+    (data, pricing)
+}
+
+#[test]
+fn json_webhook_posts_the_priced_rows_as_json() {
+    let (data, pricing) = priced_session();
+    let rows = price_usage_data(&data, &pricing);
+    let server = MockServer::start();
+    let hook = server.mock(|when, then| {
+        when.method(POST)
+            .path("/hook")
+            .header("content-type", "application/json")
+            .header_prefix("user-agent", "vibe_coding_tracker/")
+            .json_body(serde_json::to_value(&rows).unwrap());
+        then.status(200);
+    });
+
+    let status = post_webhook(&server.url("/hook"), &rows, Duration::from_secs(5)).unwrap();
+
+    hook.assert();
+    assert_eq!(status.as_u16(), 200);
+    let body = serde_json::to_value(&rows).unwrap();
+    assert!(body[0]["model"].is_string());
+    assert!(body[0]["cost_usd"].as_f64().unwrap() > 0.0);
+    assert!(body[0]["usage"]["total_tokens"].as_i64().unwrap() > 0);
+}
+
+#[test]
+fn summary_webhook_posts_the_footer_with_a_text_line() {
+    let (data, pricing) = priced_session();
+    let footer = usage_footer(&data, &pricing, false);
+    let expected = json!({
+        "text": format!(
+            "Vibe Coding Tracker: ${:.2} total (${:.2} today), {} tokens across 1 model(s) in 1 session(s)",
+            footer.total_cost,
+            footer.today_cost,
+            vct_core::utils::format_number(footer.total_tokens),
+        ),
+        "totalCost": footer.total_cost,
+        "todayCost": footer.today_cost,
+        "totalTokens": footer.total_tokens,
+        "models": 1,
+        "sessions": 1,
+    });
+    let server = MockServer::start();
+    let hook = server.mock(|when, then| {
+        when.method(POST)
+            .path("/hook")
+            .header("content-type", "application/json")
+            .json_body(expected);
+        then.status(204);
+    });
+
+    let status = post_webhook(
+        &server.url("/hook"),
+        &WebhookSummary::from(footer),
+        Duration::from_secs(5),
+    )
+    .unwrap();
+
+    hook.assert();
+    assert_eq!(status.as_u16(), 204);
+}
+
+#[test]
+fn webhook_error_status_is_an_error_naming_it() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/hook/secret-token");
+        then.status(500).body("boom");
+    });
+
+    let err = post_webhook(
+        &server.url("/hook/secret-token"),
+        &json!([]),
+        Duration::from_secs(5),
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("HTTP 500"), "{err:#}");
+    assert!(err.to_string().contains("http://127.0.0.1"), "{err:#}");
+    assert!(!format!("{err:#}").contains("secret-token"), "{err:#}");
+}
+
+#[test]
+fn unreachable_webhook_error_hides_the_url_path() {
+    // Port 9 (discard) is closed on the test host, so the connect fails.
+    let err = post_webhook(
+        "http://127.0.0.1:9/hook/secret-token?key=hunter2",
+        &json!([]),
+        Duration::from_secs(5),
+    )
+    .unwrap_err();
+
+    let message = format!("{err:#}");
+    assert!(message.contains("webhook http://127.0.0.1"), "{message}");
+    assert!(!message.contains("secret-token"), "{message}");
+    assert!(!message.contains("hunter2"), "{message}");
+}
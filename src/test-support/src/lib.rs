@@ -38,6 +38,17 @@ pub fn fixture_str(name: &str) -> String {
         .unwrap_or_else(|e| panic!("failed to read fixture {name}: {e}"))
 }
 
+/// Moves `path`'s modified time `days` whole days into the past, so a session
+/// file counts toward an earlier local date.
+pub fn backdate(path: &Path, days: u64) {
+    let when = std::time::SystemTime::now() - std::time::Duration::from_secs(days * 24 * 60 * 60);
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(when))
+        .expect("set file modified time");
+}
+
 /// Appends a valid standalone JSON blob large enough to change a Cursor store fingerprint.
 pub fn append_cursor_json_blob(path: &Path, id: &str) {
     let connection = rusqlite::Connection::open(path).expect("open Cursor store");
@@ -0,0 +1,93 @@
+//! Static table renderer for `analysis --correlate-git`.
+
+use crate::display::common::table::create_comfy_table;
+use chrono::{Local, TimeZone};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::analysis::SessionCommits;
+use vct_core::utils::format_number;
+
+/// Print one row per session: provider, folder, local time window, lines
+/// written, and the commits made in the window (short hash and subject, one
+/// per line).
+///
+/// Sessions outside a git repository say so in the commits cell.
+pub fn display_session_commits_table(sessions: &[SessionCommits]) {
+    if sessions.is_empty() {
+        println!("No sessions found");
+        return;
+    }
+
+    println!("{}", "Commits per Session".bright_cyan().bold());
+    println!();
+
+    // The commits column is text again, so only "Lines Written" is numeric.
+    let mut table = create_comfy_table(
+        vec!["Provider", "Folder", "Window", "Lines Written", "Commits"],
+        Color::Yellow,
+        |i| {
+            if i == 3 {
+                CellAlignment::Right
+            } else {
+                CellAlignment::Left
+            }
+        },
+    );
+    for session in sessions {
+        let commits = if session.repository.is_none() {
+            Cell::new("not a git repository").fg(Color::DarkGrey)
+        } else if session.commits.is_empty() {
+            Cell::new("-").fg(Color::DarkGrey)
+        } else {
+            let lines: Vec<String> = session
+                .commits
+                .iter()
+                .map(|commit| {
+                    let short = commit.hash.get(..7).unwrap_or(&commit.hash);
+                    format!("{short} {}", commit.subject)
+                })
+                .collect();
+            Cell::new(lines.join("\n")).fg(Color::Green)
+        };
+        table.add_row([
+            Cell::new(&session.provider).fg(Color::Cyan),
+            Cell::new(&session.folder_path).fg(Color::White),
+            Cell::new(window(session.start, session.end)).fg(Color::White),
+            Cell::new(format_number(session.lines_written))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            commits,
+        ]);
+    }
+    println!("{table}");
+
+    let total: usize = sessions.iter().map(|session| session.commits.len()).sum();
+    println!(
+        "{}",
+        format!("{total} commit(s) across {} session(s)", sessions.len()).bright_black()
+    );
+}
+
+/// `start`–`end` in local time, dropping the end's date when both fall on the
+/// same day.
+fn window(start: i64, end: i64) -> String {
+    let (Some(start), Some(end)) = (
+        Local.timestamp_millis_opt(start).single(),
+        Local.timestamp_millis_opt(end).single(),
+    ) else {
+        return "-".to_string();
+    };
+    if start.date_naive() == end.date_naive() {
+        format!(
+            "{} – {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%H:%M")
+        )
+    } else {
+        format!(
+            "{} – {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%Y-%m-%d %H:%M")
+        )
+    }
+}
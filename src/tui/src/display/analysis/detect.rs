@@ -0,0 +1,57 @@
+//! Static table renderer for `analysis --provider-detect-stats`.
+
+use crate::display::common::table::{add_totals_row, create_comfy_table, leading_left};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::scan::{ProviderDetectStats, UNKNOWN_PROVIDER_LABEL};
+use vct_core::utils::format_number;
+
+/// Print the per-provider file classification counts as a static table.
+///
+/// `Unknown` is drawn in red when it holds any files, since those are the
+/// sessions every other view silently skips.
+pub fn display_provider_detect_table(stats: &ProviderDetectStats) {
+    if stats.total_files == 0 {
+        println!("No session files found");
+        return;
+    }
+
+    println!("{}", "Provider Detection".bright_cyan().bold());
+    println!();
+
+    let mut table = create_comfy_table(
+        vec!["Provider", "Files", "Share"],
+        Color::Yellow,
+        leading_left(1),
+    );
+    for row in &stats.providers {
+        let color = if row.provider == UNKNOWN_PROVIDER_LABEL && row.files > 0 {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        table.add_row(vec![
+            Cell::new(&row.provider)
+                .fg(color)
+                .set_alignment(CellAlignment::Left),
+            Cell::new(format_number(row.files))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format!("{:.1}%", row.percent))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    add_totals_row(
+        &mut table,
+        vec![
+            "TOTAL".to_string(),
+            format_number(stats.total_files),
+            "100.0%".to_string(),
+        ],
+        Color::Red,
+        leading_left(1),
+    );
+
+    println!("{table}");
+}
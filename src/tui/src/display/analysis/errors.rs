@@ -0,0 +1,86 @@
+//! Static table renderer for `analysis --error-report`.
+
+use crate::display::common::table::{add_totals_row, create_comfy_table, leading_left};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::analysis::ErrorReport;
+use vct_core::utils::format_number;
+
+/// Print the per-provider API error counts as a static table.
+///
+/// Providers whose sessions logged any error are drawn in red.
+pub fn display_error_report_table(report: &ErrorReport) {
+    if report.providers.is_empty() {
+        println!("No sessions found");
+        return;
+    }
+
+    println!("{}", "API Errors".bright_cyan().bold());
+    println!();
+
+    let mut table = create_comfy_table(
+        vec![
+            "Provider",
+            "Sessions",
+            "With Errors",
+            "Rate Limit",
+            "Overloaded",
+            "Other",
+            "Total",
+        ],
+        Color::Yellow,
+        leading_left(1),
+    );
+    for row in &report.providers {
+        let color = if row.error_counts.is_empty() {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        let counts = &row.error_counts;
+        let mut cells = vec![
+            Cell::new(&row.provider)
+                .fg(color)
+                .set_alignment(CellAlignment::Left),
+        ];
+        cells.extend(
+            [
+                row.sessions,
+                row.sessions_with_errors,
+                counts.rate_limit,
+                counts.overloaded,
+                counts.other,
+                counts.total(),
+            ]
+            .into_iter()
+            .map(|value| {
+                Cell::new(format_number(value))
+                    .fg(Color::White)
+                    .set_alignment(CellAlignment::Right)
+            }),
+        );
+        table.add_row(cells);
+    }
+    let sessions: usize = report.providers.iter().map(|row| row.sessions).sum();
+    let with_errors: usize = report
+        .providers
+        .iter()
+        .map(|row| row.sessions_with_errors)
+        .sum();
+    add_totals_row(
+        &mut table,
+        vec![
+            "TOTAL".to_string(),
+            format_number(sessions),
+            format_number(with_errors),
+            format_number(report.total.rate_limit),
+            format_number(report.total.overloaded),
+            format_number(report.total.other),
+            format_number(report.total.total()),
+        ],
+        Color::Red,
+        leading_left(1),
+    );
+
+    println!("{table}");
+}
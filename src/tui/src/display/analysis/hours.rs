@@ -0,0 +1,77 @@
+//! Static histogram renderer for `analysis --hod`.
+
+use crate::display::common::table::{add_totals_row, create_comfy_table, leading_left};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::analysis::HourActivity;
+use vct_core::utils::format_number;
+
+/// Widest bar, drawn for the busiest hour; other hours scale against it.
+const BAR_WIDTH: usize = 40;
+
+/// Print one row per hour of the day with its operation counts and a bar
+/// proportional to the hour's total.
+pub fn display_activity_by_hour_table(hours: &[HourActivity]) {
+    if hours.iter().all(|hour| hour.total == 0) {
+        println!("No tool activity found");
+        return;
+    }
+
+    println!("{}", "Activity by Hour of Day".bright_cyan().bold());
+    println!();
+
+    let busiest = hours.iter().map(|hour| hour.total).max().unwrap_or(0);
+    let mut table = create_comfy_table(
+        vec!["Hour", "Edit", "Read", "Write", "Bash", "Total", ""],
+        Color::Yellow,
+        leading_left(1),
+    );
+    let count = |value: usize| {
+        Cell::new(format_number(value))
+            .fg(Color::White)
+            .set_alignment(CellAlignment::Right)
+    };
+    for hour in hours {
+        table.add_row(vec![
+            Cell::new(format!("{:02}:00", hour.hour))
+                .fg(Color::Green)
+                .set_alignment(CellAlignment::Left),
+            count(hour.edits),
+            count(hour.reads),
+            count(hour.writes),
+            count(hour.commands),
+            count(hour.total),
+            Cell::new(bar(hour.total, busiest))
+                .fg(Color::Cyan)
+                .set_alignment(CellAlignment::Left),
+        ]);
+    }
+    let sum =
+        |field: fn(&HourActivity) -> usize| format_number(hours.iter().map(field).sum::<usize>());
+    add_totals_row(
+        &mut table,
+        vec![
+            "TOTAL".to_string(),
+            sum(|hour| hour.edits),
+            sum(|hour| hour.reads),
+            sum(|hour| hour.writes),
+            sum(|hour| hour.commands),
+            sum(|hour| hour.total),
+            String::new(),
+        ],
+        Color::Red,
+        leading_left(1),
+    );
+
+    println!("{table}");
+}
+
+/// A bar of `count / busiest` of [`BAR_WIDTH`]; an hour with any activity
+/// gets at least one cell, an idle hour none.
+fn bar(count: usize, busiest: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let width = (count * BAR_WIDTH).div_ceil(busiest.max(1)).max(1);
+    "█".repeat(width)
+}
@@ -8,19 +8,21 @@ use crate::display::analysis::averages::{
     AnalysisProviderTotals, AnalysisRow, build_analysis_provider_rows,
     calculate_analysis_provider_totals_from_per_provider, convert_to_analysis_rows,
 };
+use crate::display::common::summary_format::SummaryMetric;
 use crate::display::common::table::{
-    create_controls_with_status, create_provider_row, create_ratatui_table, create_summary,
+    COMPACT_MIN_H, compact_layout, create_compact_status, create_controls_with_status,
+    create_provider_row, create_ratatui_table, create_summary, fit_model_name,
     init_process_metrics, main_layout, refresh_process_metrics, render_scrollable_table,
     render_too_small, styled_row,
 };
 use crate::display::common::tui::{
-    InputAction, RefreshWorker, RefreshWorkerError, ScrollState, TerminalSession, UpdateTracker,
-    handle_input, overlay_repo_hyperlink, refresh_status, render_loading_frame,
+    InputAction, RefreshWorker, RefreshWorkerError, ScrollState, TerminalSession, TuiOptions,
+    UpdateTracker, handle_input, overlay_repo_hyperlink, refresh_status, render_loading_frame,
 };
 use ratatui::{
-    Terminal,
-    backend::CrosstermBackend,
-    layout::Constraint,
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Rect},
     style::{Color as RatatuiColor, Style, Stylize},
     widgets::Row as RatatuiRow,
 };
@@ -28,7 +30,7 @@ use std::io;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use sysinfo::{Pid, System};
-use vct_core::analysis::AnalysisData;
+use vct_core::analysis::{AnalysisData, AnalysisScanOptions};
 use vct_core::config::ProvidersConfig;
 use vct_core::utils::format_compact;
 
@@ -65,16 +67,20 @@ struct AnalysisUiState {
     provider_totals: AnalysisProviderTotals,
     update_tracker: UpdateTracker,
     scroll: ScrollState,
+    compact: bool,
+    tui: TuiOptions,
 }
 
 impl AnalysisUiState {
-    fn new() -> Self {
+    fn new(tui: TuiOptions) -> Self {
         Self {
             rows: Vec::new(),
             totals: AnalysisRow::default(),
             provider_totals: AnalysisProviderTotals::default(),
             update_tracker: UpdateTracker::new(MAX_TRACKED_ANALYSIS_ROWS, 1000),
             scroll: ScrollState::new(),
+            compact: tui.compact,
+            tui,
         }
     }
 
@@ -157,7 +163,10 @@ impl AnalysisUiState {
             sys,
             pid,
             &mut self.scroll,
+            self.compact,
+            &self.tui,
             status,
+            true,
         )
     }
 }
@@ -170,23 +179,43 @@ pub fn display_analysis_interactive_loading(
 ) -> anyhow::Result<()> {
     let threads = vct_core::config::PerformanceConfig::default().resolved_scan_threads();
     let pool = Arc::new(vct_core::scan::build_scan_pool(threads)?);
-    display_analysis_interactive_loading_with_pool(time_range, providers, refresh_secs, pool)
+    display_analysis_interactive_loading_with_pool(
+        time_range,
+        providers,
+        AnalysisScanOptions::default(),
+        TuiOptions::default(),
+        refresh_secs,
+        pool,
+    )
 }
 
-/// [`display_analysis_interactive_loading`] with a caller-owned scan pool.
+/// [`display_analysis_interactive_loading`] with caller-supplied scan options,
+/// dashboard settings, and a caller-owned scan pool.
 pub fn display_analysis_interactive_loading_with_pool(
     time_range: vct_core::models::TimeRange,
     providers: ProvidersConfig,
+    scan_options: AnalysisScanOptions,
+    tui: TuiOptions,
     refresh_secs: u64,
     scan_pool: Arc<rayon::ThreadPool>,
 ) -> anyhow::Result<()> {
-    run_analysis_interactive(None, time_range, providers, refresh_secs, scan_pool)
+    run_analysis_interactive(
+        None,
+        time_range,
+        providers,
+        scan_options,
+        tui,
+        refresh_secs,
+        scan_pool,
+    )
 }
 
 fn run_analysis_interactive(
     initial_data: Option<AnalysisData>,
     time_range: vct_core::models::TimeRange,
     providers: ProvidersConfig,
+    scan_options: AnalysisScanOptions,
+    tui: TuiOptions,
     refresh_secs: u64,
     scan_pool: Arc<rayon::ThreadPool>,
 ) -> anyhow::Result<()> {
@@ -206,11 +235,12 @@ fn run_analysis_interactive(
             let mut cache = vct_core::summary_cache::SummaryScanCache::new();
             move || {
                 let aggregation = worker_pool.install(|| {
-                    vct_core::analysis::aggregate_sessions_by_model_from_paths_with_cache(
+                    vct_core::analysis::aggregate_sessions_by_model_from_paths_with_cache_opts(
                         &worker_paths,
                         time_range,
                         providers,
                         &mut cache,
+                        &scan_options,
                     )
                 })?;
                 if aggregation.diagnostics.all_failed() {
@@ -243,7 +273,7 @@ fn run_analysis_interactive(
             Duration::from_millis(vct_core::constants::refresh::METRICS_REFRESH_MS);
         let mut last_metrics = Instant::now();
         let mut last_spinner = Instant::now();
-        let mut state = AnalysisUiState::new();
+        let mut state = AnalysisUiState::new(tui);
         let mut failure_until = None;
 
         if let Some(data) = initial_data {
@@ -309,9 +339,9 @@ fn run_analysis_interactive(
                 state.render(terminal.terminal_mut(), &sys, pid, status)?;
             }
 
-            match handle_input()? {
+            match handle_input(&state.tui.keymap)? {
                 InputAction::Quit => break,
-                InputAction::Refresh => {
+                InputAction::Refresh | InputAction::RefreshPricing => {
                     worker.request();
                     if loaded {
                         state.render(
@@ -323,6 +353,17 @@ fn run_analysis_interactive(
                     }
                 }
                 InputAction::ToggleMerge => {}
+                InputAction::ToggleCompact => {
+                    state.compact = !state.compact;
+                    if loaded {
+                        state.render(
+                            terminal.terminal_mut(),
+                            &sys,
+                            pid,
+                            refresh_status(worker.is_active(), failure_until),
+                        )?;
+                    }
+                }
                 InputAction::Navigate(delta) if loaded => {
                     state.scroll.apply(delta, state.rows.len());
                     state.render(
@@ -402,14 +443,16 @@ pub fn display_analysis_interactive(
         Some(initial_data),
         time_range,
         providers,
+        AnalysisScanOptions::default(),
+        TuiOptions::default(),
         refresh_secs,
         pool,
     )
 }
 
 #[allow(clippy::too_many_arguments)]
-fn render_analysis_frame_with_status(
-    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+fn render_analysis_frame_with_status<B: Backend>(
+    terminal: &mut Terminal<B>,
     rows_data: &[AnalysisRow],
     totals: &AnalysisRow,
     provider_totals: &AnalysisProviderTotals,
@@ -417,83 +460,74 @@ fn render_analysis_frame_with_status(
     sys: &System,
     pid: Pid,
     scroll: &mut ScrollState,
+    compact: bool,
+    tui: &TuiOptions,
     status: Option<&str>,
+    write_hyperlink: bool,
 ) -> anyhow::Result<()> {
     let provider_rows = build_analysis_provider_rows(provider_totals);
 
     let completed = terminal.draw(|f| {
         let area = f.area();
-        if area.width < ANALYSIS_MIN_W || area.height < ANALYSIS_MIN_H {
-            render_too_small(f, ANALYSIS_MIN_W, ANALYSIS_MIN_H);
+        let min_h = if compact {
+            COMPACT_MIN_H
+        } else {
+            ANALYSIS_MIN_H
+        };
+        if area.width < ANALYSIS_MIN_W || area.height < min_h {
+            render_too_small(f, ANALYSIS_MIN_W, min_h);
             return;
         }
 
-        let panels_height = analysis_panels_height(area.height, provider_rows.len());
-        let chunks = main_layout(area, panels_height);
+        // Summary
+        let total_lines_str =
+            format_compact((totals.edit_lines + totals.read_lines + totals.write_lines) as i64);
+        let total_tools_str = format_compact(
+            (totals.bash_count
+                + totals.edit_count
+                + totals.read_count
+                + totals.todo_write_count
+                + totals.write_count) as i64,
+        );
+        let entries_str = format!("{}", rows_data.len());
 
-        let header = vec![
-            "Model",
-            "Edit Lines",
-            "Read Lines",
-            "Write Lines",
-            "Bash",
-            "Edit",
-            "Read",
-            "TodoWrite",
-            "Write",
+        let summary_items = [
+            (
+                SummaryMetric::Volume,
+                "Total Lines:",
+                total_lines_str.as_str(),
+                RatatuiColor::Yellow,
+            ),
+            (
+                SummaryMetric::Count,
+                "Total Tools:",
+                total_tools_str.as_str(),
+                RatatuiColor::Cyan,
+            ),
+            (
+                SummaryMetric::Count,
+                "Models:",
+                entries_str.as_str(),
+                RatatuiColor::Blue,
+            ),
         ];
+        let summary_format = tui.summary_format;
+
+        // Compact: the table and one status line carrying the summary's totals.
+        if compact {
+            let chunks = compact_layout(area);
+            render_analysis_table(f, chunks.table, rows_data, update_tracker, scroll, tui);
+            f.render_widget(
+                create_compact_status(&summary_items, summary_format, status, &tui.keymap),
+                chunks.status,
+            );
+            return;
+        }
 
-        // One selectable row per model; the grand total lives only in the
-        // summary bar below. Compact K/M/B numbers keep long counts in-column.
-        let rows: Vec<RatatuiRow> = rows_data
-            .iter()
-            .map(|row| {
-                let style = if update_tracker.is_recently_updated(&row.model) {
-                    Style::default().bg(RatatuiColor::Rgb(60, 80, 60)).bold()
-                } else {
-                    Style::default()
-                };
-                styled_row(
-                    vec![
-                        row.model.clone(),
-                        format_compact(row.edit_lines as i64),
-                        format_compact(row.read_lines as i64),
-                        format_compact(row.write_lines as i64),
-                        format_compact(row.bash_count as i64),
-                        format_compact(row.edit_count as i64),
-                        format_compact(row.read_count as i64),
-                        format_compact(row.todo_write_count as i64),
-                        format_compact(row.write_count as i64),
-                    ],
-                    style,
-                    1,
-                )
-            })
-            .collect();
-
-        let widths = [
-            Constraint::Min(16),    // Model
-            Constraint::Length(11), // Edit Lines
-            Constraint::Length(11), // Read Lines
-            Constraint::Length(11), // Write Lines
-            Constraint::Length(7),  // Bash
-            Constraint::Length(7),  // Edit
-            Constraint::Length(7),  // Read
-            Constraint::Length(10), // TodoWrite
-            Constraint::Length(7),  // Write
-        ];
+        let panels_height = analysis_panels_height(area.height, provider_rows.len());
+        let chunks = main_layout(area, panels_height);
 
-        let row_count = rows.len();
-        render_scrollable_table(
-            f,
-            chunks.table,
-            header,
-            rows,
-            &widths,
-            RatatuiColor::Green,
-            row_count,
-            scroll,
-        );
+        render_analysis_table(f, chunks.table, rows_data, update_tracker, scroll, tui);
 
         if let Some(panel_area) = chunks.panels {
             // Drop the "All Providers" aggregate; the summary bar already
@@ -574,41 +608,107 @@ fn render_analysis_frame_with_status(
             f.render_widget(totals_table, panel_area);
         }
 
-        // Summary
-        let total_lines_str =
-            format_compact((totals.edit_lines + totals.read_lines + totals.write_lines) as i64);
-        let total_tools_str = format_compact(
-            (totals.bash_count
-                + totals.edit_count
-                + totals.read_count
-                + totals.todo_write_count
-                + totals.write_count) as i64,
+        let summary = create_summary(
+            &summary_items,
+            summary_format,
+            sys,
+            pid,
+            chunks.summary.width,
         );
-        let entries_str = format!("{}", rows_data.len());
-
-        let summary_items = vec![
-            (
-                "Total Lines:",
-                total_lines_str.as_str(),
-                RatatuiColor::Yellow,
-            ),
-            ("Total Tools:", total_tools_str.as_str(), RatatuiColor::Cyan),
-            ("Models:", entries_str.as_str(), RatatuiColor::Blue),
-        ];
-
-        let summary = create_summary(summary_items, sys, pid, chunks.summary.width);
         f.render_widget(summary, chunks.summary);
 
-        f.render_widget(create_controls_with_status(&[], status), chunks.controls);
+        f.render_widget(
+            create_controls_with_status(&[("c", " compact  ")], status, &tui.keymap),
+            chunks.controls,
+        );
     })?;
 
     // ratatui can't embed the OSC 8 escape itself, so hyperlink the repo label
     // it just drew (a no-op on terminals without hyperlink support).
-    overlay_repo_hyperlink(completed.buffer)?;
+    if write_hyperlink {
+        overlay_repo_hyperlink(completed.buffer)?;
+    }
 
     Ok(())
 }
 
+/// Draws the scrollable per-model table into `area`, highlighting rows whose
+/// counters changed recently. Shared by the full and the compact layout;
+/// models are named and cut to width per `tui`.
+fn render_analysis_table(
+    f: &mut Frame,
+    area: Rect,
+    rows_data: &[AnalysisRow],
+    update_tracker: &UpdateTracker,
+    scroll: &mut ScrollState,
+    tui: &TuiOptions,
+) {
+    let header = vec![
+        "Model",
+        "Edit Lines",
+        "Read Lines",
+        "Write Lines",
+        "Bash",
+        "Edit",
+        "Read",
+        "TodoWrite",
+        "Write",
+    ];
+
+    // One selectable row per model; the grand total lives only in the
+    // summary bar. Compact K/M/B numbers keep long counts in-column.
+    let rows: Vec<RatatuiRow> = rows_data
+        .iter()
+        .map(|row| {
+            let style = if update_tracker.is_recently_updated(&row.model) {
+                Style::default().bg(RatatuiColor::Rgb(60, 80, 60)).bold()
+            } else {
+                Style::default()
+            };
+            styled_row(
+                vec![
+                    fit_model_name(&tui.model_names.display(&row.model), tui.model_width)
+                        .into_owned(),
+                    format_compact(row.edit_lines as i64),
+                    format_compact(row.read_lines as i64),
+                    format_compact(row.write_lines as i64),
+                    format_compact(row.bash_count as i64),
+                    format_compact(row.edit_count as i64),
+                    format_compact(row.read_count as i64),
+                    format_compact(row.todo_write_count as i64),
+                    format_compact(row.write_count as i64),
+                ],
+                style,
+                1,
+            )
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(16),    // Model
+        Constraint::Length(11), // Edit Lines
+        Constraint::Length(11), // Read Lines
+        Constraint::Length(11), // Write Lines
+        Constraint::Length(7),  // Bash
+        Constraint::Length(7),  // Edit
+        Constraint::Length(7),  // Read
+        Constraint::Length(10), // TodoWrite
+        Constraint::Length(7),  // Write
+    ];
+
+    let row_count = rows.len();
+    render_scrollable_table(
+        f,
+        area,
+        header,
+        rows,
+        &widths,
+        RatatuiColor::Green,
+        row_count,
+        scroll,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,14 +4,24 @@
 //! per-provider total helpers in `averages` shared across them.
 
 mod averages;
+mod commits;
+mod detect;
+mod errors;
+mod hours;
 mod interactive;
+mod per_day;
 mod table;
 mod text;
 
 pub use averages::*;
+pub use commits::display_session_commits_table;
+pub use detect::display_provider_detect_table;
+pub use errors::display_error_report_table;
+pub use hours::display_activity_by_hour_table;
 pub use interactive::{
     display_analysis_interactive, display_analysis_interactive_loading,
     display_analysis_interactive_loading_with_pool,
 };
+pub use per_day::display_sessions_per_day_table;
 pub use table::display_analysis_table;
-pub use text::display_analysis_text;
+pub use text::{display_analysis_text, display_analysis_totals};
@@ -0,0 +1,57 @@
+//! Static histogram renderer for `analysis --sessions-per-day`.
+
+use crate::display::common::table::{add_totals_row, create_comfy_table, leading_left};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
+use vct_core::utils::format_number;
+
+/// Widest bar, drawn for the busiest day; other days scale against it.
+const BAR_WIDTH: usize = 40;
+
+/// Print one row per date with its session count and a proportional bar.
+pub fn display_sessions_per_day_table(counts: &BTreeMap<String, usize>) {
+    if counts.is_empty() {
+        println!("No session files found");
+        return;
+    }
+
+    println!("{}", "Sessions per Day".bright_cyan().bold());
+    println!();
+
+    let busiest = counts.values().copied().max().unwrap_or(0);
+    let mut table =
+        create_comfy_table(vec!["Date", "Sessions", ""], Color::Yellow, leading_left(1));
+    for (date, &sessions) in counts {
+        table.add_row(vec![
+            Cell::new(date)
+                .fg(Color::Green)
+                .set_alignment(CellAlignment::Left),
+            Cell::new(format_number(sessions))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(bar(sessions, busiest))
+                .fg(Color::Cyan)
+                .set_alignment(CellAlignment::Left),
+        ]);
+    }
+    add_totals_row(
+        &mut table,
+        vec![
+            "TOTAL".to_string(),
+            format_number(counts.values().sum::<usize>()),
+            String::new(),
+        ],
+        Color::Red,
+        leading_left(1),
+    );
+
+    println!("{table}");
+}
+
+/// A bar of `count / busiest` of [`BAR_WIDTH`], at least one cell wide so a
+/// day with any session stays visible.
+fn bar(count: usize, busiest: usize) -> String {
+    let width = (count * BAR_WIDTH).div_ceil(busiest.max(1)).max(1);
+    "█".repeat(width)
+}
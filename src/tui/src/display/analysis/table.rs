@@ -7,19 +7,21 @@ use crate::display::analysis::averages::{
     AnalysisRow, build_analysis_provider_rows, calculate_analysis_provider_totals_from_per_provider,
 };
 use crate::display::common::table::{
-    add_totals_row, create_comfy_table, create_metric_cell, create_provider_cell,
+    add_totals_row, create_comfy_table, create_metric_cell, create_provider_cell, fit_model_name,
+    leading_left,
 };
 use comfy_table::{Cell, CellAlignment, Color, Table, presets::UTF8_FULL};
 use owo_colors::OwoColorize;
 use vct_core::analysis::AnalysisData;
-use vct_core::utils::format_number;
+use vct_core::utils::{ModelNames, format_number};
 
 /// Print the `analysis` data as static, colored tables to stdout.
 ///
 /// Renders a per-model metrics table with a `TOTAL` summary row, then a
-/// per-provider totals table. Prints a placeholder line and returns early when
+/// per-provider totals table, models named by `names` and cut to
+/// `model_width` columns. Prints a placeholder line and returns early when
 /// there are no rows.
-pub fn display_analysis_table(analysis: &AnalysisData) {
+pub fn display_analysis_table(analysis: &AnalysisData, names: &ModelNames, model_width: usize) {
     let data = &analysis.rows;
     if data.is_empty() {
         println!("No analysis data found");
@@ -42,13 +44,14 @@ pub fn display_analysis_table(analysis: &AnalysisData) {
             "Write",
         ],
         Color::Yellow,
+        leading_left(1),
     );
 
     let mut totals = AnalysisRow::default();
 
     for row in data {
         table.add_row(vec![
-            Cell::new(&row.model)
+            Cell::new(fit_model_name(&names.display(&row.model), model_width))
                 .fg(Color::Green)
                 .set_alignment(CellAlignment::Left),
             Cell::new(format_number(row.edit_lines))
@@ -102,6 +105,7 @@ pub fn display_analysis_table(analysis: &AnalysisData) {
             format_number(totals.write_count),
         ],
         Color::Red,
+        leading_left(1),
     );
 
     println!("{table}");
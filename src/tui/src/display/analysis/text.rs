@@ -1,18 +1,20 @@
 //! Plain-text renderer for the `analysis` view.
 //!
 //! Emits one machine-parseable `key=value` line per model, suited for piping
-//! into scripts.
+//! into scripts, or a single `TOTAL` line for `--summary-only`.
 
-use vct_core::analysis::AnalysisData;
+use vct_core::analysis::{AnalysisData, AnalysisTotals};
+use vct_core::utils::ModelNames;
 
-/// Displays aggregated analysis data as plain text (one model per line, key=value pairs).
+/// Displays aggregated analysis data as plain text (one model per line, key=value pairs),
+/// models named by `names`.
 ///
 /// Output format (script-friendly, raw integers without thousand separators):
 ///
 /// ```text
 /// {model}: editLines={N} readLines={N} writeLines={N} bash={N} edit={N} read={N} todoWrite={N} write={N}
 /// ```
-pub fn display_analysis_text(analysis: &AnalysisData) {
+pub fn display_analysis_text(analysis: &AnalysisData, names: &ModelNames) {
     if analysis.rows.is_empty() {
         println!("No analysis data found");
         return;
@@ -21,7 +23,7 @@ pub fn display_analysis_text(analysis: &AnalysisData) {
     for row in &analysis.rows {
         println!(
             "{}: editLines={} readLines={} writeLines={} bash={} edit={} read={} todoWrite={} write={}",
-            row.model,
+            names.display(&row.model),
             row.edit_lines,
             row.read_lines,
             row.write_lines,
@@ -33,3 +35,24 @@ pub fn display_analysis_text(analysis: &AnalysisData) {
         );
     }
 }
+
+/// Displays only the column totals as one `key=value` line, in the same
+/// format as [`display_analysis_text`] plus the number of models summed:
+///
+/// ```text
+/// TOTAL: editLines={N} readLines={N} writeLines={N} bash={N} edit={N} read={N} todoWrite={N} write={N} models={N}
+/// ```
+pub fn display_analysis_totals(totals: &AnalysisTotals) {
+    println!(
+        "TOTAL: editLines={} readLines={} writeLines={} bash={} edit={} read={} todoWrite={} write={} models={}",
+        totals.edit_lines,
+        totals.read_lines,
+        totals.write_lines,
+        totals.bash_count,
+        totals.edit_count,
+        totals.read_count,
+        totals.todo_write_count,
+        totals.write_count,
+        totals.models,
+    );
+}
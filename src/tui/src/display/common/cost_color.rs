@@ -0,0 +1,122 @@
+//! Traffic-light coloring of a cost against two breakpoints
+//! (`usage --cost-thresholds <low,high>`).
+
+use anyhow::{Result, bail};
+use comfy_table::Color as TableColor;
+use ratatui::style::Color as RatatuiColor;
+use std::str::FromStr;
+
+/// The two cost breakpoints in USD: below `low` is green, below `high` is
+/// yellow, anything else red. Parsed from `low,high` (e.g. `5,20`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostThresholds {
+    /// Upper bound (exclusive) of the green band.
+    pub low: f64,
+    /// Upper bound (exclusive) of the yellow band.
+    pub high: f64,
+}
+
+impl Default for CostThresholds {
+    fn default() -> Self {
+        Self {
+            low: 5.0,
+            high: 20.0,
+        }
+    }
+}
+
+impl FromStr for CostThresholds {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let Some((low, high)) = value.split_once(',') else {
+            bail!("expected two comma-separated amounts `low,high`, got `{value}`");
+        };
+        let parse = |part: &str| -> Result<f64> {
+            let part = part.trim().trim_start_matches('$');
+            match part.parse::<f64>() {
+                Ok(amount) if amount.is_finite() && amount >= 0.0 => Ok(amount),
+                _ => bail!("`{part}` is not a non-negative amount"),
+            }
+        };
+        let (low, high) = (parse(low)?, parse(high)?);
+        if low > high {
+            bail!("the low threshold ({low}) is above the high one ({high})");
+        }
+        Ok(Self { low, high })
+    }
+}
+
+/// One band of [`cost_color`], with the matching color of each renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostColor {
+    /// Below the low threshold.
+    Green,
+    /// At or above the low threshold, below the high one.
+    Yellow,
+    /// At or above the high threshold.
+    Red,
+}
+
+impl CostColor {
+    /// The foreground color for a static comfy-table cell.
+    pub fn table_color(self) -> TableColor {
+        match self {
+            Self::Green => TableColor::Green,
+            Self::Yellow => TableColor::Yellow,
+            Self::Red => TableColor::Red,
+        }
+    }
+
+    /// The foreground color for a ratatui span.
+    pub fn tui_color(self) -> RatatuiColor {
+        match self {
+            Self::Green => RatatuiColor::Green,
+            Self::Yellow => RatatuiColor::Yellow,
+            Self::Red => RatatuiColor::Red,
+        }
+    }
+}
+
+/// Picks the band of `value`: green below `low`, yellow below `high`,
+/// red otherwise. A breakpoint belongs to the band above it.
+pub fn cost_color(value: f64, low: f64, high: f64) -> CostColor {
+    if value < low {
+        CostColor::Green
+    } else if value < high {
+        CostColor::Yellow
+    } else {
+        CostColor::Red
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakpoints_belong_to_the_band_above() {
+        assert_eq!(cost_color(0.0, 5.0, 20.0), CostColor::Green);
+        assert_eq!(cost_color(4.99, 5.0, 20.0), CostColor::Green);
+        assert_eq!(cost_color(5.0, 5.0, 20.0), CostColor::Yellow);
+        assert_eq!(cost_color(19.99, 5.0, 20.0), CostColor::Yellow);
+        assert_eq!(cost_color(20.0, 5.0, 20.0), CostColor::Red);
+        // Equal breakpoints leave no yellow band.
+        assert_eq!(cost_color(5.0, 5.0, 5.0), CostColor::Red);
+    }
+
+    #[test]
+    fn thresholds_parse_from_low_comma_high() {
+        let parsed: CostThresholds = "2.5, $10".parse().unwrap();
+        assert_eq!(
+            parsed,
+            CostThresholds {
+                low: 2.5,
+                high: 10.0
+            }
+        );
+        assert!("10,2".parse::<CostThresholds>().is_err());
+        assert!("5".parse::<CostThresholds>().is_err());
+        assert!("-1,5".parse::<CostThresholds>().is_err());
+    }
+}
@@ -0,0 +1,187 @@
+//! Configurable dashboard key bindings (`[tui]` in `config.toml`).
+//!
+//! Only quit and refresh are configurable; navigation, `R` (reprice), `m`
+//! (merge toggle), and `c` (compact layout) stay fixed. The CLI builds the
+//! configured [`Keymap`] and hands it to a dashboard through its
+//! [`TuiOptions`](crate::display::common::tui::TuiOptions); the default keymap
+//! is `q` / `Q` / `Esc` / `Ctrl+C` quit and `r` refresh.
+
+use crate::display::common::tui::InputAction;
+use anyhow::{Result, bail};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::str::FromStr;
+use vct_core::config::TuiConfig;
+use vct_core::warnings::WarningSink;
+
+/// One key, optionally with Ctrl held.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    ctrl: bool,
+    /// The configured spelling, shown in the controls footer.
+    label: String,
+}
+
+impl FromStr for KeyBinding {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (ctrl, key) = match value.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("ctrl+") => (true, &value[5..]),
+            _ => (false, value),
+        };
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            // Terminals report Ctrl+letter as the lowercase letter.
+            (Some(c), None) if ctrl => KeyCode::Char(c.to_ascii_lowercase()),
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "esc" => KeyCode::Esc,
+                "enter" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "backspace" => KeyCode::Backspace,
+                _ => bail!("unknown key `{value}`"),
+            },
+        };
+        Ok(Self {
+            code,
+            ctrl,
+            label: value.to_string(),
+        })
+    }
+}
+
+impl KeyBinding {
+    fn matches(&self, key: &KeyEvent) -> bool {
+        key.code == self.code && key.modifiers.contains(KeyModifiers::CONTROL) == self.ctrl
+    }
+}
+
+/// The quit and refresh bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    quit: Vec<KeyBinding>,
+    refresh: Vec<KeyBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&TuiConfig::default(), &WarningSink::new())
+    }
+}
+
+impl Keymap {
+    /// Parses the configured keys. An unrecognized key is recorded in
+    /// `warnings` and skipped; a list left with no usable key falls back to its
+    /// default so the dashboard can always be quit.
+    pub fn from_config(config: &TuiConfig, warnings: &WarningSink) -> Self {
+        let defaults = TuiConfig::default();
+        Self {
+            quit: parse_bindings(&config.quit_keys, &defaults.quit_keys, warnings),
+            refresh: parse_bindings(&config.refresh_keys, &defaults.refresh_keys, warnings),
+        }
+    }
+
+    /// The action `key` is bound to, if any. Quit wins over refresh when a key
+    /// is in both lists.
+    pub fn action(&self, key: &KeyEvent) -> Option<InputAction> {
+        if self.quit.iter().any(|binding| binding.matches(key)) {
+            Some(InputAction::Quit)
+        } else if self.refresh.iter().any(|binding| binding.matches(key)) {
+            Some(InputAction::Refresh)
+        } else {
+            None
+        }
+    }
+
+    /// Footer hint for quitting: the first quit key.
+    pub fn quit_hint(&self) -> &str {
+        &self.quit[0].label
+    }
+
+    /// Footer hint for refreshing: the first refresh key.
+    pub fn refresh_hint(&self) -> &str {
+        &self.refresh[0].label
+    }
+}
+
+fn parse_bindings(keys: &[String], defaults: &[String], warnings: &WarningSink) -> Vec<KeyBinding> {
+    let parse = |keys: &[String]| -> Vec<KeyBinding> {
+        keys.iter()
+            .filter_map(|key| {
+                key.parse()
+                    .inspect_err(|error| {
+                        warnings.warn(format!("ignoring [tui] key binding: {error}"));
+                    })
+                    .ok()
+            })
+            .collect()
+    };
+    let bindings = parse(keys);
+    if bindings.is_empty() {
+        parse(defaults)
+    } else {
+        bindings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn default_keymap_quits_on_both_cases_escape_and_ctrl_c() {
+        let keymap = Keymap::default();
+        for key in [
+            press(KeyCode::Char('q'), KeyModifiers::NONE),
+            press(KeyCode::Char('Q'), KeyModifiers::SHIFT),
+            press(KeyCode::Esc, KeyModifiers::NONE),
+            press(KeyCode::Char('c'), KeyModifiers::CONTROL),
+        ] {
+            assert_eq!(keymap.action(&key), Some(InputAction::Quit), "{key:?}");
+        }
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('r'), KeyModifiers::NONE)),
+            Some(InputAction::Refresh)
+        );
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('c'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!((keymap.quit_hint(), keymap.refresh_hint()), ("q", "r"));
+    }
+
+    #[test]
+    fn custom_keymap_replaces_the_defaults_and_skips_unknown_keys() {
+        let warnings = WarningSink::new();
+        let keymap = Keymap::from_config(
+            &TuiConfig {
+                quit_keys: vec!["x".into(), "Ctrl+D".into(), "hyper+q".into()],
+                refresh_keys: vec!["nonsense".into()],
+            },
+            &warnings,
+        );
+        assert_eq!(warnings.take().len(), 2);
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(InputAction::Quit)
+        );
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            Some(InputAction::Quit)
+        );
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+            None
+        );
+        // No usable refresh key, so the default `r` still applies.
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('r'), KeyModifiers::NONE)),
+            Some(InputAction::Refresh)
+        );
+    }
+}
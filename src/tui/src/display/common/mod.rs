@@ -1,17 +1,25 @@
 //! Rendering helpers shared by the `analysis` and `usage` views.
 //!
 //! Groups the per-provider totals containers ([`averages`], [`provider`]), the
-//! comfy-table / ratatui cell and table builders ([`table`]), and the TUI
+//! cost traffic-light colors ([`mod@cost_color`]), the comfy-table / ratatui
+//! cell and table builders ([`table`]), the configurable quit / refresh keys
+//! ([`keymap`]), the summary bar's emphasis ([`summary_format`]), and the TUI
 //! scaffolding ([`tui`]: terminal setup, the input event loop, and refresh /
 //! row-highlight state). All items are re-exported at this module's root so
 //! callers reach them as `crate::display::common::<item>`.
 
 pub mod averages;
+pub mod cost_color;
+pub mod keymap;
 pub mod provider;
+pub mod summary_format;
 pub mod table;
 pub mod tui;
 
 pub use averages::*;
+pub use cost_color::*;
+pub use keymap::*;
 pub use provider::*;
+pub use summary_format::*;
 pub use table::*;
 pub use tui::*;
@@ -0,0 +1,125 @@
+//! Which totals the summary bar leads with (`--summary-format`).
+//!
+//! Each view tags its summary items with the [`SummaryMetric`] they measure.
+//! `cost` and `tokens` move the items of their metric to the front and draw
+//! only those in bold; `balanced` keeps the view's own order with every item
+//! bold. A view without the chosen metric (the analysis view has no spend)
+//! falls back to the balanced bar.
+
+use anyhow::{Result, bail};
+use ratatui::style::{Color, Style, Stylize};
+use std::str::FromStr;
+
+/// What a summary-bar item measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryMetric {
+    /// Spend in USD.
+    Cost,
+    /// Volume: tokens in the usage view, lines in the analysis view.
+    Volume,
+    /// Anything else (model and tool counts).
+    Count,
+}
+
+/// Which metric the summary bar emphasizes (`--summary-format`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SummaryFormat {
+    /// Lead with spend.
+    Cost,
+    /// Lead with volume.
+    Tokens,
+    /// The view's own order, every item bold (the default).
+    #[default]
+    Balanced,
+}
+
+impl FromStr for SummaryFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "cost" => Ok(Self::Cost),
+            "tokens" => Ok(Self::Tokens),
+            "balanced" => Ok(Self::Balanced),
+            other => {
+                bail!("unknown summary format `{other}` (expected cost, tokens, or balanced)")
+            }
+        }
+    }
+}
+
+impl SummaryFormat {
+    fn lead(self) -> Option<SummaryMetric> {
+        match self {
+            Self::Cost => Some(SummaryMetric::Cost),
+            Self::Tokens => Some(SummaryMetric::Volume),
+            Self::Balanced => None,
+        }
+    }
+
+    /// `items` in the order this format shows them, each with its style.
+    ///
+    /// The lead metric's items move to the front (keeping their relative
+    /// order) and stay bold; the rest follow in plain color. Without a lead,
+    /// or when no item has it, the order is unchanged and every item is bold.
+    pub fn arrange<'a>(
+        self,
+        items: &[(SummaryMetric, &'a str, &'a str, Color)],
+    ) -> Vec<(&'a str, &'a str, Style)> {
+        let lead = self
+            .lead()
+            .filter(|lead| items.iter().any(|(metric, ..)| metric == lead));
+        let (mut leading, rest): (Vec<_>, Vec<_>) = items
+            .iter()
+            .partition(|(metric, ..)| lead.is_none_or(|lead| *metric == lead));
+        leading.extend(rest);
+        leading
+            .into_iter()
+            .map(|&(metric, label, value, color)| {
+                let style = Style::default().fg(color);
+                let emphasized = lead.is_none_or(|lead| metric == lead);
+                (label, value, if emphasized { style.bold() } else { style })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Modifier;
+
+    #[test]
+    fn only_the_lead_metric_stays_bold_and_a_missing_lead_falls_back_to_balanced() {
+        let items = [
+            (SummaryMetric::Volume, "Total Lines:", "10", Color::Yellow),
+            (SummaryMetric::Count, "Models:", "2", Color::Blue),
+        ];
+        let bold = |arranged: Vec<(&str, &str, Style)>| -> Vec<(String, bool)> {
+            arranged
+                .into_iter()
+                .map(|(label, _, style)| {
+                    (
+                        label.to_string(),
+                        style.add_modifier.contains(Modifier::BOLD),
+                    )
+                })
+                .collect()
+        };
+
+        let tokens = bold(SummaryFormat::Tokens.arrange(&items));
+        assert_eq!(
+            tokens,
+            [("Total Lines:".into(), true), ("Models:".into(), false)]
+        );
+        // Nothing here measures spend, so `cost` draws the balanced bar.
+        assert_eq!(
+            bold(SummaryFormat::Cost.arrange(&items)),
+            bold(SummaryFormat::Balanced.arrange(&items))
+        );
+        assert_eq!(
+            bold(SummaryFormat::Balanced.arrange(&items)),
+            [("Total Lines:".into(), true), ("Models:".into(), true)]
+        );
+    }
+}
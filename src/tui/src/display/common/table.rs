@@ -7,6 +7,8 @@
 //! left-align the first two (index 0 and 1); the ratatui `styled_row` helper
 //! takes the number of left-aligned columns as its `left_cols` argument.
 
+use crate::display::common::keymap::Keymap;
+use crate::display::common::summary_format::{SummaryFormat, SummaryMetric};
 use crate::display::common::tui::ScrollState;
 use comfy_table::{Attribute, Cell, CellAlignment, Color, Table, presets::UTF8_FULL};
 use ratatui::{
@@ -19,7 +21,13 @@ use ratatui::{
         ScrollbarOrientation, ScrollbarState, Table as RatatuiTable,
     },
 };
+use std::borrow::Cow;
 use sysinfo::System;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Default for `--model-width`: the most columns a model name takes in a
+/// table.
+pub const DEFAULT_MODEL_WIDTH: usize = 40;
 
 /// Normalizes a process's raw (per-core-summed) CPU usage into a 0-100% share
 /// of the machine by dividing by the CPU count.
@@ -64,6 +72,40 @@ pub fn init_process_metrics(sys: &mut System, pid: sysinfo::Pid) {
     refresh_process_metrics(sys, pid);
 }
 
+/// `name` as a table shows it: cut to `width` columns (`--model-width`,
+/// [`DEFAULT_MODEL_WIDTH`] by default) with a trailing `…` when it is wider.
+///
+/// Proxy-mangled ids can run to hundreds of characters; without the cut one
+/// of them pushes every other column off a comfy-table or out of the ratatui
+/// model column. JSON and plain-text output are never cut.
+pub fn fit_model_name(name: &str, width: usize) -> Cow<'_, str> {
+    truncate_to_width(name, width)
+}
+
+/// `text` cut to at most `width` terminal columns, ending in `…` when it had
+/// to be cut. Widths are measured per character, so wide (CJK) characters
+/// count as two columns and are never split.
+pub fn truncate_to_width(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    let budget = width.saturating_sub(1);
+    let mut used = 0;
+    let mut cut = String::with_capacity(width + 2);
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > budget {
+            break;
+        }
+        used += ch_width;
+        cut.push(ch);
+    }
+    if width > 0 {
+        cut.push('…');
+    }
+    Cow::Owned(cut)
+}
+
 /// Builds the bordered, centered title paragraph for the top of a TUI view.
 pub fn create_title(title_text: &str, color: RatatuiColor) -> Paragraph<'_> {
     Paragraph::new(vec![Line::from(vec![Span::styled(
@@ -107,8 +149,9 @@ fn fit_diagnostics(
 
 /// Builds the TUI summary bar from caller-supplied items plus live memory and CPU readouts.
 ///
-/// Each `(icon, value, color)` tuple in `summary_items` becomes a colored,
-/// pipe-separated segment; these primary items are always rendered. A
+/// Each `(metric, icon, value, color)` tuple in `summary_items` becomes a
+/// colored, pipe-separated segment, ordered and emphasized by `format` (see
+/// [`SummaryFormat::arrange`]); these primary items are always rendered. A
 /// `Memory: <n> MB` and a `CPU: <n>%` segment for the current process `pid` are
 /// then appended **only while they fit** within `width` (the summary rect's
 /// width): on a terminal too narrow for the full line the diagnostic segments
@@ -117,7 +160,8 @@ fn fit_diagnostics(
 /// normalized to a 0-100% share of the machine. `sys` is expected to have been
 /// refreshed by the caller before this call.
 pub fn create_summary<'a>(
-    summary_items: Vec<(&'a str, &'a str, RatatuiColor)>, // (icon, value, color) tuples
+    summary_items: &[(SummaryMetric, &'a str, &'a str, RatatuiColor)],
+    format: SummaryFormat,
     sys: &'a System,
     pid: sysinfo::Pid,
     width: u16,
@@ -129,15 +173,15 @@ pub fn create_summary<'a>(
     let mut used = 0usize;
 
     // Primary items are always shown (they clip only on an extremely narrow bar).
-    for (i, (icon, value, color)) in summary_items.iter().enumerate() {
+    for (i, (icon, value, style)) in format.arrange(summary_items).into_iter().enumerate() {
         if i > 0 {
             spans.push(Span::raw(SUMMARY_SEP));
             used += seg_width(SUMMARY_SEP);
         }
         let label = format!("{} ", icon);
         used += seg_width(&label) + seg_width(value);
-        spans.push(Span::styled(label, Style::default().fg(*color).bold()));
-        spans.push(Span::styled(*value, Style::default().fg(*color).bold()));
+        spans.push(Span::styled(label, style));
+        spans.push(Span::styled(value, style));
     }
 
     // Diagnostic segments for this process. Both are measured up front; how
@@ -201,18 +245,20 @@ pub const REPO_URL: &str = "https://github.com/Mai0313/VibeCodingTracker";
 /// Everything is on one line to save vertical space; the repo link sits last so
 /// it is the first thing truncated on a narrow terminal, leaving the keys
 /// readable. `extra` inserts view-specific `(key, label)` hints just before
-/// `r refresh` — the usage view passes its `m merge` toggle; other views pass
-/// an empty slice. The label is drawn as plain (underlined) text here; a
-/// terminal hyperlink is layered on afterward by
+/// the refresh key, and the refresh and quit hints come from `keymap` — the
+/// usage view passes its `m merge` toggle; other views pass an empty slice.
+/// The label is drawn as plain (underlined) text here; a terminal hyperlink is
+/// layered on afterward by
 /// [`overlay_repo_hyperlink`](super::tui::overlay_repo_hyperlink).
-pub fn create_controls(extra: &[(&str, &str)]) -> Paragraph<'static> {
-    create_controls_with_status(extra, None)
+pub fn create_controls(extra: &[(&str, &str)], keymap: &Keymap) -> Paragraph<'static> {
+    create_controls_with_status(extra, None, keymap)
 }
 
 /// Builds the controls footer with an optional transient refresh status.
 pub fn create_controls_with_status(
     extra: &[(&str, &str)],
     status: Option<&str>,
+    keymap: &Keymap,
 ) -> Paragraph<'static> {
     let key = Style::default().fg(RatatuiColor::Cyan).bold();
     let dim = Style::default().fg(RatatuiColor::DarkGray);
@@ -221,10 +267,10 @@ pub fn create_controls_with_status(
         spans.push(Span::styled(k.to_string(), key));
         spans.push(Span::styled(label.to_string(), dim));
     }
-    spans.push(Span::styled("r", key));
+    spans.push(Span::styled(keymap.refresh_hint().to_string(), key));
     spans.push(Span::styled(" refresh  ", dim));
     spans.push(Span::styled(
-        "q",
+        keymap.quit_hint().to_string(),
         Style::default().fg(RatatuiColor::Red).bold(),
     ));
     spans.push(Span::styled(" quit", dim));
@@ -299,6 +345,71 @@ pub fn main_layout(area: Rect, panels_height: Option<u16>) -> FrameChunks {
     }
 }
 
+/// Hard minimum terminal height for the compact layout: the table's `Min(6)`
+/// plus the one-line status.
+pub const COMPACT_MIN_H: u16 = 7;
+
+/// Vertical chunk rects for the compact (`--compact-tui`) frame.
+pub struct CompactChunks {
+    /// Scrollable main table area.
+    pub table: Rect,
+    /// Single-line status: key totals, key hints, and refresh status.
+    pub status: Rect,
+}
+
+/// Splits `area` into the compact layout: the scrollable table over a
+/// one-line status, with no band, summary bar, or controls footer.
+pub fn compact_layout(area: Rect) -> CompactChunks {
+    let c = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(6), Constraint::Length(1)])
+        .split(area);
+    CompactChunks {
+        table: c[0],
+        status: c[1],
+    }
+}
+
+/// Builds the compact layout's one-line status: the totals the summary bar
+/// would show, in `format`'s order, the transient refresh status if any, then
+/// the `c` (full layout) key and `keymap`'s refresh and quit keys.
+///
+/// Process metrics and the repository link are left out; a narrow terminal
+/// clips the line from the right, so the key hints go first.
+pub fn create_compact_status(
+    items: &[(SummaryMetric, &str, &str, RatatuiColor)],
+    format: SummaryFormat,
+    status: Option<&str>,
+    keymap: &Keymap,
+) -> Paragraph<'static> {
+    let key = Style::default().fg(RatatuiColor::Cyan).bold();
+    let dim = Style::default().fg(RatatuiColor::DarkGray);
+    let mut spans = Vec::new();
+    for (i, (label, value, style)) in format.arrange(items).into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" · ", dim));
+        }
+        spans.push(Span::styled(format!("{label} {value}"), style));
+    }
+    if let Some(status) = status {
+        spans.push(Span::styled("  |  ", dim));
+        spans.push(Span::styled(
+            status.to_string(),
+            Style::default().fg(RatatuiColor::Yellow).bold(),
+        ));
+    }
+    spans.push(Span::styled("  |  ", dim));
+    for (k, label) in [
+        ("c", " full  "),
+        (keymap.refresh_hint(), " refresh  "),
+        (keymap.quit_hint(), " quit"),
+    ] {
+        spans.push(Span::styled(k.to_string(), key));
+        spans.push(Span::styled(label, dim));
+    }
+    Paragraph::new(Line::from(spans))
+}
+
 /// Builds a table row with the first `left_cols` cells left-aligned and the
 /// rest right-aligned, painted with `style`.
 ///
@@ -432,23 +543,36 @@ pub fn create_ratatui_table<'a>(
         )
 }
 
+/// Column alignment for a table whose first `columns` columns hold labels:
+/// those are left-aligned, every later (numeric) column right-aligned.
+pub fn leading_left(columns: usize) -> impl Fn(usize) -> CellAlignment {
+    move |i| {
+        if i < columns {
+            CellAlignment::Left
+        } else {
+            CellAlignment::Right
+        }
+    }
+}
+
 /// Builds an empty comfy [`Table`] with a colored, UTF-8-bordered header row.
 ///
-/// Header cells use the shared alignment convention: indices 0 and 1 are
-/// left-aligned, the rest right-aligned. The returned table has no body rows.
-pub fn create_comfy_table(headers: Vec<&str>, header_color: Color) -> Table {
+/// `alignment` maps a column index to its header alignment, usually
+/// [`leading_left`]. The returned table has no body rows.
+pub fn create_comfy_table(
+    headers: Vec<&str>,
+    header_color: Color,
+    alignment: impl Fn(usize) -> CellAlignment,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL).set_header(
         headers
             .iter()
             .enumerate()
             .map(|(i, &header)| {
-                let alignment = if i <= 1 {
-                    CellAlignment::Left
-                } else {
-                    CellAlignment::Right
-                };
-                Cell::new(header).fg(header_color).set_alignment(alignment)
+                Cell::new(header)
+                    .fg(header_color)
+                    .set_alignment(alignment(i))
             })
             .collect::<Vec<_>>(),
     );
@@ -457,20 +581,18 @@ pub fn create_comfy_table(headers: Vec<&str>, header_color: Color) -> Table {
 
 /// Appends a single colored totals row to `table`.
 ///
-/// Cells follow the shared alignment convention: indices 0 and 1 are
-/// left-aligned, the rest right-aligned. Every cell is painted `color`.
-pub fn add_totals_row(table: &mut Table, cells: Vec<String>, color: Color) {
+/// `alignment` maps a column index to its alignment, as for
+/// [`create_comfy_table`]. Every cell is painted `color`.
+pub fn add_totals_row(
+    table: &mut Table,
+    cells: Vec<String>,
+    color: Color,
+    alignment: impl Fn(usize) -> CellAlignment,
+) {
     let colored_cells: Vec<Cell> = cells
         .into_iter()
         .enumerate()
-        .map(|(i, text)| {
-            let alignment = if i <= 1 {
-                CellAlignment::Left
-            } else {
-                CellAlignment::Right
-            };
-            Cell::new(text).fg(color).set_alignment(alignment)
-        })
+        .map(|(i, text)| Cell::new(text).fg(color).set_alignment(alignment(i)))
         .collect();
 
     table.add_row(colored_cells);
@@ -513,7 +635,13 @@ pub fn create_provider_row<'a>(
 
 #[cfg(test)]
 mod tests {
-    use super::{fit_diagnostics, normalized_cpu};
+    use super::{
+        create_comfy_table, create_summary, fit_diagnostics, leading_left, normalized_cpu,
+        truncate_to_width,
+    };
+    use crate::display::common::summary_format::{SummaryFormat, SummaryMetric};
+    use ratatui::{Terminal, backend::TestBackend, style::Color};
+    use sysinfo::System;
 
     #[test]
     fn normalized_cpu_divides_by_cores_and_clamps() {
@@ -542,4 +670,56 @@ mod tests {
         );
         assert_eq!(fit_diagnostics(used, 60, mem, cpu), (false, false));
     }
+
+    #[test]
+    fn summary_format_puts_its_metric_first_in_the_rendered_summary() {
+        let items = [
+            (SummaryMetric::Count, "Models:", "3", Color::Blue),
+            (SummaryMetric::Cost, "Total Cost:", "$1.00", Color::Yellow),
+            (SummaryMetric::Volume, "Total Tokens:", "2K", Color::Cyan),
+        ];
+        let sys = System::new();
+        let pid = sysinfo::Pid::from_u32(std::process::id());
+        let rendered = |format| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 3)).unwrap();
+            terminal
+                .draw(|f| f.render_widget(create_summary(&items, format, &sys, pid, 100), f.area()))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            let line: String = (0..buffer.area.width)
+                .map(|x| buffer[(x, 1)].symbol())
+                .collect();
+            line.trim_matches(|c: char| c == '│' || c.is_whitespace())
+                .to_string()
+        };
+
+        assert!(rendered(SummaryFormat::Cost).starts_with("Total Cost: $1.00  |  Models: 3"));
+        assert!(rendered(SummaryFormat::Tokens).starts_with("Total Tokens: 2K  |  Models: 3"));
+        assert!(rendered(SummaryFormat::Balanced).starts_with("Models: 3  |  Total Cost: $1.00"));
+    }
+
+    #[test]
+    fn truncation_counts_terminal_columns_and_never_splits_a_wide_character() {
+        assert_eq!(truncate_to_width("gpt-4o", 6), "gpt-4o");
+        assert_eq!(truncate_to_width("claude-sonnet-4", 8), "claude-…");
+        // Each CJK character takes two columns: four fit in 9 with the `…`,
+        // and the fifth is dropped whole rather than cut in half.
+        assert_eq!(truncate_to_width("模型名稱很長", 10), "模型名稱…");
+        assert_eq!(truncate_to_width("模型名稱很長", 9), "模型名稱…");
+    }
+
+    #[test]
+    fn numeric_headers_are_right_aligned_past_the_label_columns() {
+        let mut table = create_comfy_table(
+            vec!["Model", "Input"],
+            comfy_table::Color::Yellow,
+            leading_left(1),
+        );
+        table.add_row(vec!["a-long-model-name", "1,234,567"]);
+        let rendered = table.to_string();
+        let header = rendered.lines().nth(1).expect("header line");
+        let cells: Vec<&str> = header.trim_matches('│').split('┆').collect();
+        assert!(cells[0].starts_with(" Model    "), "{header}");
+        assert!(cells[1].starts_with("     Input "), "{header}");
+    }
 }
@@ -4,7 +4,9 @@
 //! loop ([`handle_input`]), the worker that drives periodic refreshes
 //! ([`RefreshWorker`]), and recently-changed row highlighting ([`UpdateTracker`]).
 
-use crate::display::common::table::{REPO_LABEL, REPO_URL};
+use crate::display::common::keymap::Keymap;
+use crate::display::common::summary_format::SummaryFormat;
+use crate::display::common::table::{DEFAULT_MODEL_WIDTH, REPO_LABEL, REPO_URL};
 use crossterm::{
     cursor::{MoveTo, Show},
     event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
@@ -26,6 +28,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::{Duration, Instant};
+use vct_core::utils::ModelNames;
 
 /// Whether the alternate-screen TUI currently owns the terminal.
 ///
@@ -39,6 +42,34 @@ static TERMINAL_PANIC_HOOK: Once = Once::new();
 const LOADING_SPINNER: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 const MAX_DRAINED_EVENTS: usize = 64;
 
+/// Settings an interactive dashboard runs with, fixed for the whole session.
+#[derive(Debug, Clone)]
+pub struct TuiOptions {
+    /// Quit and refresh key bindings (`[tui]` in `config.toml`).
+    pub keymap: Keymap,
+    /// How model ids are named (`--pretty-models`).
+    pub model_names: ModelNames,
+    /// Start in the compact single-panel layout (`--compact-tui`); `c`
+    /// switches layouts at runtime without changing this starting value.
+    pub compact: bool,
+    /// Which totals the summary bar leads with (`--summary-format`).
+    pub summary_format: SummaryFormat,
+    /// The most columns a model name takes in the table (`--model-width`).
+    pub model_width: usize,
+}
+
+impl Default for TuiOptions {
+    fn default() -> Self {
+        Self {
+            keymap: Keymap::default(),
+            model_names: ModelNames::default(),
+            compact: false,
+            summary_format: SummaryFormat::default(),
+            model_width: DEFAULT_MODEL_WIDTH,
+        }
+    }
+}
+
 /// Puts the terminal into raw mode and the alternate screen, returning a ready [`Terminal`].
 ///
 /// Must be paired with [`restore_terminal`] before the process exits, otherwise
@@ -504,13 +535,14 @@ fn find_label_start(buffer: &Buffer) -> Option<(u16, u16)> {
 /// already-buffered events. Batching collapses resize bursts without allowing a
 /// continuous event stream to postpone redraw indefinitely.
 /// Returns [`InputAction::Continue`] when the poll times out with no event.
+/// Quit and refresh keys come from `keymap`.
 ///
 /// # Errors
 ///
 /// Returns an error if polling for or reading a terminal event fails (an
 /// underlying crossterm I/O error on the event source).
-pub fn handle_input() -> anyhow::Result<InputAction> {
-    handle_input_from(&mut CrosstermEventSource)
+pub fn handle_input(keymap: &Keymap) -> anyhow::Result<InputAction> {
+    handle_input_from(&mut CrosstermEventSource, keymap)
 }
 
 trait EventSource {
@@ -530,7 +562,10 @@ impl EventSource for CrosstermEventSource {
     }
 }
 
-fn handle_input_from(source: &mut impl EventSource) -> anyhow::Result<InputAction> {
+fn handle_input_from(
+    source: &mut impl EventSource,
+    keymap: &Keymap,
+) -> anyhow::Result<InputAction> {
     if !source.poll(Duration::from_millis(100))? {
         return Ok(InputAction::Continue);
     }
@@ -544,19 +579,22 @@ fn handle_input_from(source: &mut impl EventSource) -> anyhow::Result<InputActio
             // Unix only emits Press; drop Release so one keypress isn't counted
             // twice (which would double every nav step / page jump).
             Event::Key(key) if key.kind != KeyEventKind::Release => {
-                if key.code == KeyCode::Char('q')
-                    || key.code == KeyCode::Esc
-                    || (key.code == KeyCode::Char('c')
-                        && key.modifiers.contains(KeyModifiers::CONTROL))
-                {
-                    return Ok(InputAction::Quit);
+                if let Some(action) = keymap.action(&key) {
+                    return Ok(action);
                 }
-                if key.code == KeyCode::Char('r') || key.code == KeyCode::Char('R') {
-                    return Ok(InputAction::Refresh);
+                if key.code == KeyCode::Char('R') {
+                    return Ok(InputAction::RefreshPricing);
                 }
                 if key.code == KeyCode::Char('m') || key.code == KeyCode::Char('M') {
                     return Ok(InputAction::ToggleMerge);
                 }
+                // Plain `c` only, so Ctrl+C never flips the layout (even
+                // when `[tui]` rebinds quit without it).
+                if matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'))
+                    && !key.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    return Ok(InputAction::ToggleCompact);
+                }
                 // Navigation accumulates across the drained batch so a held key
                 // collapses into a single net move per tick.
                 match key.code {
@@ -605,11 +643,16 @@ impl NavDelta {
 pub enum InputAction {
     /// User asked to exit (`q`, `Esc`, or `Ctrl+C`).
     Quit,
-    /// User asked to re-fetch and redraw (`r` / `R`).
+    /// User asked to re-fetch and redraw (`r`).
     Refresh,
+    /// User asked to reload pricing as well as re-fetch (`R` / Shift+R);
+    /// views without pricing treat it as [`Refresh`](Self::Refresh).
+    RefreshPricing,
     /// User toggled provider-prefix merging (`m` / `M`); usage view only,
     /// ignored elsewhere.
     ToggleMerge,
+    /// User switched between the full and the compact layout (`c` / `C`).
+    ToggleCompact,
     /// User scrolled / moved the selection; redraw without re-fetching.
     Navigate(NavDelta),
     /// Terminal was resized — redraw the current frame at the new size
@@ -835,6 +878,7 @@ impl ScrollState {
 mod tests {
     use super::*;
     use crate::display::common::table::create_controls;
+    use crossterm::event::KeyModifiers;
     use ratatui::layout::Rect;
     use ratatui::widgets::Widget;
     use std::collections::VecDeque;
@@ -906,7 +950,7 @@ mod tests {
     fn find_label_start_locates_repo_label_on_bottom_row() {
         let area = Rect::new(0, 0, 120, 1);
         let mut buf = Buffer::empty(area);
-        create_controls(&[("m", " merge  ")]).render(area, &mut buf);
+        create_controls(&[("m", " merge  ")], &Keymap::default()).render(area, &mut buf);
 
         let (x, y) = find_label_start(&buf).expect("repo label should be present");
         assert_eq!(y, 0);
@@ -921,7 +965,7 @@ mod tests {
         // Too narrow to fit the whole label → nothing to hyperlink.
         let area = Rect::new(0, 0, 10, 1);
         let mut buf = Buffer::empty(area);
-        create_controls(&[]).render(area, &mut buf);
+        create_controls(&[], &Keymap::default()).render(area, &mut buf);
         assert!(find_label_start(&buf).is_none());
     }
 
@@ -984,7 +1028,10 @@ mod tests {
         });
         let mut source = FakeEventSource::new(events);
 
-        assert_eq!(handle_input_from(&mut source).unwrap(), InputAction::Resize);
+        assert_eq!(
+            handle_input_from(&mut source, &Keymap::default()).unwrap(),
+            InputAction::Resize
+        );
         assert_eq!(source.reads, MAX_DRAINED_EVENTS);
         assert_eq!(source.events.len(), 10);
     }
@@ -1002,19 +1049,91 @@ mod tests {
         started_rx.recv_timeout(Duration::from_secs(1)).unwrap();
 
         let mut resize = FakeEventSource::new([Event::Resize(100, 30)]);
-        assert_eq!(handle_input_from(&mut resize).unwrap(), InputAction::Resize);
+        assert_eq!(
+            handle_input_from(&mut resize, &Keymap::default()).unwrap(),
+            InputAction::Resize
+        );
 
         let mut quit = FakeEventSource::new([Event::Key(crossterm::event::KeyEvent::new(
             KeyCode::Char('q'),
             KeyModifiers::NONE,
         ))]);
-        assert_eq!(handle_input_from(&mut quit).unwrap(), InputAction::Quit);
+        assert_eq!(
+            handle_input_from(&mut quit, &Keymap::default()).unwrap(),
+            InputAction::Quit
+        );
         assert!(worker.is_active());
 
         release_tx.send(()).unwrap();
         assert_eq!(wait_for_result(&mut worker), Ok(1));
     }
 
+    #[test]
+    fn shift_r_requests_a_pricing_refresh() {
+        let key = |c| {
+            Event::Key(crossterm::event::KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            ))
+        };
+        let mut plain = FakeEventSource::new([key('r')]);
+        assert_eq!(
+            handle_input_from(&mut plain, &Keymap::default()).unwrap(),
+            InputAction::Refresh
+        );
+        let mut shifted = FakeEventSource::new([Event::Key(crossterm::event::KeyEvent::new(
+            KeyCode::Char('R'),
+            KeyModifiers::SHIFT,
+        ))]);
+        assert_eq!(
+            handle_input_from(&mut shifted, &Keymap::default()).unwrap(),
+            InputAction::RefreshPricing
+        );
+    }
+
+    #[test]
+    fn c_toggles_the_compact_layout_but_ctrl_c_still_quits() {
+        let key =
+            |c, modifiers| Event::Key(crossterm::event::KeyEvent::new(KeyCode::Char(c), modifiers));
+        let mut plain = FakeEventSource::new([key('c', KeyModifiers::NONE)]);
+        assert_eq!(
+            handle_input_from(&mut plain, &Keymap::default()).unwrap(),
+            InputAction::ToggleCompact
+        );
+        let mut ctrl = FakeEventSource::new([key('c', KeyModifiers::CONTROL)]);
+        assert_eq!(
+            handle_input_from(&mut ctrl, &Keymap::default()).unwrap(),
+            InputAction::Quit
+        );
+    }
+
+    #[test]
+    fn rebound_quit_key_replaces_q() {
+        let keymap = Keymap::from_config(
+            &vct_core::config::TuiConfig {
+                quit_keys: vec!["x".to_string()],
+                ..Default::default()
+            },
+            &vct_core::warnings::WarningSink::new(),
+        );
+        let key = |c| {
+            Event::Key(crossterm::event::KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            ))
+        };
+        let mut x = FakeEventSource::new([key('x')]);
+        assert_eq!(
+            handle_input_from(&mut x, &keymap).unwrap(),
+            InputAction::Quit
+        );
+        let mut q = FakeEventSource::new([key('q')]);
+        assert_eq!(
+            handle_input_from(&mut q, &keymap).unwrap(),
+            InputAction::Continue
+        );
+    }
+
     #[test]
     fn dropping_worker_cancels_a_queued_initial_load() {
         let (release_init_tx, release_init_rx) = mpsc::channel();
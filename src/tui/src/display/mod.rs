@@ -2,9 +2,13 @@
 //!
 //! Each view has its own submodule ([`analysis`], [`usage`]) holding the four
 //! output modes (TUI / table / text / JSON), while [`common`] gathers the
-//! rendering glue both views share.
+//! rendering glue both views share. [`quota`] renders the live quota panels
+//! and [`stats`] the `vct stats` overview that draws on both views;
+//! [`update`] prints release notes for `vct update --preview`.
 
 pub mod analysis;
 pub mod common;
 pub mod quota;
+pub mod stats;
+pub mod update;
 pub mod usage;
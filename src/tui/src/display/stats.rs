@@ -0,0 +1,82 @@
+//! Static renderer for `vct stats`: a headline table plus per-provider tokens.
+
+use crate::display::common::table::{
+    DEFAULT_MODEL_WIDTH, create_comfy_table, fit_model_name, leading_left,
+};
+use comfy_table::{Cell, CellAlignment, Color, Table, presets::UTF8_FULL};
+use owo_colors::OwoColorize;
+use vct_core::stats::StatsOverview;
+use vct_core::utils::format_number;
+
+/// Print the combined overview as two static tables.
+///
+/// The headline table holds one metric per row; the provider table is
+/// omitted when no provider recorded any usage.
+pub fn display_stats_table(overview: &StatsOverview) {
+    println!("{}", "Overview".bright_cyan().bold());
+    println!();
+
+    let top_model = overview.top_model.as_ref().map_or_else(
+        || "-".to_string(),
+        |top| {
+            format!(
+                "{} (${:.2})",
+                fit_model_name(&top.model, DEFAULT_MODEL_WIDTH),
+                top.cost_usd
+            )
+        },
+    );
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    for (label, value) in [
+        ("Today's cost", format!("${:.2}", overview.today_cost_usd)),
+        (
+            "This month's cost",
+            format!("${:.2}", overview.month_cost_usd),
+        ),
+        ("Total tokens", format_number(overview.total_tokens)),
+        (
+            "Lines written today",
+            format_number(overview.lines_written_today),
+        ),
+        ("Top model", top_model),
+        ("Active days", format_number(overview.active_days)),
+    ] {
+        table.add_row(vec![
+            Cell::new(label)
+                .fg(Color::Green)
+                .set_alignment(CellAlignment::Left),
+            Cell::new(value)
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{table}");
+
+    if overview.tokens_by_provider.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", "Tokens (by Provider)".bright_magenta().bold());
+    println!();
+
+    let mut providers = create_comfy_table(
+        vec!["Provider", "Tokens", "Cost"],
+        Color::Magenta,
+        leading_left(1),
+    );
+    for row in &overview.tokens_by_provider {
+        providers.add_row(vec![
+            Cell::new(&row.provider)
+                .fg(Color::Green)
+                .set_alignment(CellAlignment::Left),
+            Cell::new(format_number(row.tokens))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format!("${:.2}", row.cost_usd))
+                .fg(Color::Cyan)
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{providers}");
+}
@@ -0,0 +1,42 @@
+//! Renderer for `vct update --preview`: the release notes of a newer version.
+
+use std::io::IsTerminal;
+use termimad::MadSkin;
+
+/// Print a release's markdown notes, rendered for the terminal when stdout is
+/// a TTY and as the raw markdown otherwise (pipes, files, CI logs).
+pub fn display_release_notes(body: &str) {
+    let rendered = render_release_notes(body, std::io::stdout().is_terminal());
+    println!("{}", rendered.trim_end());
+}
+
+/// Format `body` for output: headings, lists, code blocks, and links are
+/// styled through termimad on a terminal, while `terminal = false` returns
+/// the markdown untouched so piped output carries no escape codes.
+fn render_release_notes(body: &str, terminal: bool) -> String {
+    if !terminal {
+        return body.to_string();
+    }
+    MadSkin::default().term_text(body).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str =
+        "## What's Changed\n\n- Faster scans in `usage`\n- [Docs](https://example.com)\n";
+
+    #[test]
+    fn non_terminal_output_keeps_the_raw_markdown() {
+        assert_eq!(render_release_notes(NOTES, false), NOTES);
+    }
+
+    #[test]
+    fn terminal_output_renders_the_markdown() {
+        let rendered = render_release_notes(NOTES, true);
+        assert!(rendered.contains("What's Changed"));
+        assert!(!rendered.contains("## "));
+        assert!(rendered.contains('\u{1b}'));
+    }
+}
@@ -0,0 +1,70 @@
+//! Renderers for `usage --model-budget`: each capped model's spend per day,
+//! with days over the cap drawn in red.
+
+use crate::display::common::table::{create_comfy_table, leading_left};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::usage::ModelBudgetDay;
+use vct_core::utils::format_cost;
+
+/// Prints one row per capped model per day: date, model, cost, and cap. The
+/// cost cell is red on days over the cap and green otherwise.
+pub fn display_model_budget_table(days: &[ModelBudgetDay]) {
+    if days.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+
+    println!("{}", "Model Budgets".bright_cyan().bold());
+    println!();
+
+    let mut table = create_comfy_table(
+        vec!["Date", "Model", "Cost", "Cap"],
+        Color::Yellow,
+        leading_left(2),
+    );
+    for day in days {
+        let cost_color = if day.over_model_budget {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        table.add_row([
+            Cell::new(&day.date).fg(Color::White),
+            Cell::new(&day.model).fg(Color::Green),
+            Cell::new(format_cost(day.cost_usd))
+                .fg(cost_color)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format_cost(day.cap_usd))
+                .fg(Color::Cyan)
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+
+    println!("{table}");
+    let over = days.iter().filter(|day| day.over_model_budget).count();
+    if over > 0 {
+        println!(
+            "{}",
+            format!("{over} day(s) over a model budget").red().bold()
+        );
+    }
+}
+
+/// Prints one `2025-10-11 opus: $12.40 of $10.00 (over)` line per row.
+pub fn display_model_budget_text(days: &[ModelBudgetDay]) {
+    if days.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+    for day in days {
+        println!(
+            "{} {}: {} of {}{}",
+            day.date,
+            day.model,
+            format_cost(day.cost_usd),
+            format_cost(day.cap_usd),
+            if day.over_model_budget { " (over)" } else { "" }
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Renderer for `usage --calendar`: a contribution-style heatmap of daily
+//! cost or tokens, one column per week and one row per weekday.
+//!
+//! Each day's value is bucketed against the busiest day of the range into
+//! one of [`INTENSITY_LEVELS`] shades; month labels sit above the week in
+//! which each month starts.
+
+use anyhow::{Result, bail};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use vct_core::pricing::ModelPricingMap;
+use vct_core::usage::{UsageData, cost_on_date, tokens_on_date};
+use vct_core::utils::{format_compact, format_cost};
+
+/// Number of shades a day can take, including the empty one.
+pub const INTENSITY_LEVELS: usize = 5;
+
+/// Block drawn for each intensity level (`0` is a day with no usage).
+const LEVEL_BLOCKS: [&str; INTENSITY_LEVELS] = ["░", "▒", "▓", "█", "█"];
+
+/// RGB gradient of the levels, from dim gray to bright green.
+const LEVEL_COLORS: [(u8, u8, u8); INTENSITY_LEVELS] = [
+    (88, 88, 88),
+    (14, 68, 41),
+    (0, 109, 50),
+    (38, 166, 65),
+    (57, 211, 83),
+];
+
+const WEEKDAY_LABELS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// What a calendar cell's intensity measures (`--by`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CalendarMetric {
+    /// Cost in USD (the default).
+    #[default]
+    Cost,
+    /// Total tokens.
+    Tokens,
+}
+
+impl FromStr for CalendarMetric {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "cost" => Ok(Self::Cost),
+            "tokens" => Ok(Self::Tokens),
+            other => bail!("unknown calendar metric `{other}` (expected cost or tokens)"),
+        }
+    }
+}
+
+impl CalendarMetric {
+    fn format(self, value: f64) -> String {
+        match self {
+            Self::Cost => format_cost(value),
+            Self::Tokens => format!("{} tokens", format_compact(value as i64)),
+        }
+    }
+}
+
+/// The intensity level (`0..INTENSITY_LEVELS`) of `value` against the
+/// range's busiest day `max`.
+///
+/// `0` is reserved for days with nothing recorded; any positive value gets at
+/// least level `1`, and the rest of the range is split into equal quarters of
+/// `max`.
+pub fn intensity_level(value: f64, max: f64) -> usize {
+    if value <= 0.0 || max <= 0.0 {
+        return 0;
+    }
+    let top = (INTENSITY_LEVELS - 1) as f64;
+    ((value / max * top).ceil() as usize).clamp(1, INTENSITY_LEVELS - 1)
+}
+
+/// Prints the heatmap of `data`'s days, priced like the `--group-by date`
+/// rows so the calendar total matches the table's.
+pub fn display_usage_calendar(data: &UsageData, pricing: &ModelPricingMap, metric: CalendarMetric) {
+    let days = data
+        .per_date
+        .keys()
+        .filter_map(|date| {
+            let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+            let value = match metric {
+                CalendarMetric::Cost => cost_on_date(data, date, pricing),
+                CalendarMetric::Tokens => tokens_on_date(data, date) as f64,
+            };
+            Some((day, value))
+        })
+        .collect();
+    print_calendar(&days, metric);
+}
+
+/// Prints the heatmap of `days` (value per local date) from the Monday of the
+/// first date's week through the last date, followed by a legend and the
+/// range's total and busiest day.
+fn print_calendar(days: &BTreeMap<NaiveDate, f64>, metric: CalendarMetric) {
+    let (Some((&first, _)), Some((&last, _))) = (days.first_key_value(), days.last_key_value())
+    else {
+        println!("No usage data found");
+        return;
+    };
+    let max = days.values().copied().fold(0.0, f64::max);
+    let start = first.week(Weekday::Mon).first_day();
+    let weeks = (last - start).num_days() / 7 + 1;
+
+    println!("{}", "Usage Calendar".bright_cyan().bold());
+    println!();
+    println!("    {}", month_labels(first, start, weeks));
+    for (offset, label) in WEEKDAY_LABELS.iter().enumerate() {
+        let mut line = format!("{label} ");
+        for week in 0..weeks {
+            let date = start + Duration::days(week * 7 + offset as i64);
+            if date < first || date > last {
+                line.push_str("  ");
+                continue;
+            }
+            let value = days.get(&date).copied().unwrap_or(0.0);
+            line.push_str(&shade(intensity_level(value, max)));
+            line.push(' ');
+        }
+        println!("{}", line.trim_end());
+    }
+    println!();
+
+    let legend: Vec<String> = (0..INTENSITY_LEVELS).map(shade).collect();
+    println!("    Less {} More", legend.join(" "));
+    let total: f64 = days.values().sum();
+    let active = days.values().filter(|value| **value > 0.0).count();
+    println!(
+        "    Total {} over {active} active day{}",
+        metric.format(total),
+        if active == 1 { "" } else { "s" }
+    );
+    if let Some((date, value)) = days.iter().find(|(_, value)| **value == max && max > 0.0) {
+        println!("    Busiest {date} ({})", metric.format(*value));
+    }
+}
+
+/// One colored block for `level`.
+fn shade(level: usize) -> String {
+    let (r, g, b) = LEVEL_COLORS[level];
+    LEVEL_BLOCKS[level].truecolor(r, g, b).to_string()
+}
+
+/// The month-label line: each month's abbreviation above the first week that
+/// holds its 1st (or the first week, for the month `first` falls in), pushed
+/// right a week at a time while it would run into the previous label.
+fn month_labels(first: NaiveDate, start: NaiveDate, weeks: i64) -> String {
+    let mut line = String::new();
+    let mut last_month = None;
+    for week in 0..weeks {
+        let month = if week == 0 {
+            first
+        } else {
+            start + Duration::days(week * 7 + 6)
+        };
+        let column = week as usize * 2;
+        if last_month != Some(month.month()) && line.chars().count() <= column {
+            line.push_str(&" ".repeat(column - line.chars().count()));
+            line.push_str(&month.format("%b").to_string());
+            last_month = Some(month.month());
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_values_bucket_into_quarters_of_the_busiest_day() {
+        let levels: Vec<usize> = [0.0, 0.01, 25.0, 25.5, 50.0, 74.0, 76.0, 100.0]
+            .into_iter()
+            .map(|value| intensity_level(value, 100.0))
+            .collect();
+        assert_eq!(levels, [0, 1, 1, 2, 2, 3, 4, 4]);
+        // A range with nothing recorded stays empty rather than dividing by 0.
+        assert_eq!(intensity_level(0.0, 0.0), 0);
+    }
+
+    #[test]
+    fn month_labels_sit_above_the_week_each_month_starts_in_without_overlapping() {
+        // Thursday 2025-09-25 through the week holding 2025-11-02: `Oct`
+        // starts in the second week but fits only from the third.
+        let first = NaiveDate::from_ymd_opt(2025, 9, 25).unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 9, 22).unwrap();
+        assert_eq!(month_labels(first, start, 7), "Sep Oct   Nov");
+    }
+}
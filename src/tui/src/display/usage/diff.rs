@@ -0,0 +1,168 @@
+//! Renderers for `usage --diff`: the per-model change between two saved
+//! `usage --json` exports.
+
+use crate::display::common::table::{
+    add_totals_row, create_comfy_table, fit_model_name, leading_left,
+};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::usage::{UsageDiff, UsageDiffRow};
+use vct_core::utils::{format_compact, format_cost, format_number};
+
+/// Prints the diff as a static table: before/after/delta for tokens and cost,
+/// biggest cost movers first, with a `TOTAL` row. Model names are cut to
+/// `model_width` columns.
+pub fn display_usage_diff_table(diff: &UsageDiff, model_width: usize) {
+    if diff.models.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+
+    println!("{}", "Token Usage Changes".bright_cyan().bold());
+    println!();
+
+    let mut table = create_comfy_table(
+        vec![
+            "Model",
+            "Tokens Before",
+            "Tokens After",
+            "Δ Tokens",
+            "Cost Before",
+            "Cost After",
+            "Δ Cost",
+        ],
+        Color::Yellow,
+        leading_left(1),
+    );
+    for row in &diff.models {
+        let delta_color = delta_color(row.cost_delta, row.tokens_delta);
+        table.add_row(vec![
+            Cell::new(fit_model_name(&row.model, model_width))
+                .fg(Color::Green)
+                .set_alignment(CellAlignment::Left),
+            Cell::new(format_number(row.tokens_before))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format_number(row.tokens_after))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(signed_number(row.tokens_delta))
+                .fg(delta_color)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format_cost(row.cost_before))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format_cost(row.cost_after))
+                .fg(Color::White)
+                .set_alignment(CellAlignment::Right),
+            Cell::new(signed_cost(row.cost_delta))
+                .fg(delta_color)
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    let total = &diff.total;
+    add_totals_row(
+        &mut table,
+        vec![
+            total.model.clone(),
+            format_number(total.tokens_before),
+            format_number(total.tokens_after),
+            signed_number(total.tokens_delta),
+            format_cost(total.cost_before),
+            format_cost(total.cost_after),
+            signed_cost(total.cost_delta),
+        ],
+        Color::Red,
+        leading_left(1),
+    );
+
+    println!("{table}");
+}
+
+/// Prints one `model: +$2.10, +45.0K tokens` line per model, then the total.
+pub fn display_usage_diff_text(diff: &UsageDiff) {
+    if diff.models.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+    for row in diff.models.iter().chain(std::iter::once(&diff.total)) {
+        println!("{}", diff_line(row));
+    }
+}
+
+fn diff_line(row: &UsageDiffRow) -> String {
+    let tokens = format_compact(row.tokens_delta);
+    let tokens = if row.tokens_delta > 0 {
+        format!("+{tokens}")
+    } else {
+        tokens
+    };
+    format!(
+        "{}: {}, {tokens} tokens",
+        row.model,
+        signed_cost(row.cost_delta)
+    )
+}
+
+/// Increases red (more spend), decreases green, no change dimmed.
+fn delta_color(cost_delta: f64, tokens_delta: i64) -> Color {
+    if cost_delta > 0.0 || (cost_delta == 0.0 && tokens_delta > 0) {
+        Color::Red
+    } else if cost_delta < 0.0 || tokens_delta < 0 {
+        Color::Green
+    } else {
+        Color::DarkGrey
+    }
+}
+
+fn signed_number(value: i64) -> String {
+    if value > 0 {
+        format!("+{}", format_number(value))
+    } else if value < 0 {
+        format!("-{}", format_number(value.unsigned_abs()))
+    } else {
+        "0".to_string()
+    }
+}
+
+fn signed_cost(value: f64) -> String {
+    let formatted = format_cost(value);
+    if formatted.starts_with('-') || formatted == "$0.00" {
+        formatted
+    } else {
+        format!("+{formatted}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(tokens_delta: i64, cost_delta: f64) -> UsageDiffRow {
+        UsageDiffRow {
+            model: "claude-3-5-sonnet".to_string(),
+            tokens_before: 0,
+            tokens_after: tokens_delta,
+            tokens_delta,
+            cost_before: 0.0,
+            cost_after: cost_delta,
+            cost_delta,
+        }
+    }
+
+    #[test]
+    fn diff_line_signs_both_deltas() {
+        assert_eq!(
+            diff_line(&row(45_000, 2.10)),
+            "claude-3-5-sonnet: +$2.10, +45.0K tokens"
+        );
+        assert_eq!(
+            diff_line(&row(-1_200, -0.5)),
+            "claude-3-5-sonnet: -$0.50, -1.20K tokens"
+        );
+        assert_eq!(
+            diff_line(&row(0, 0.0)),
+            "claude-3-5-sonnet: $0.00, 0 tokens"
+        );
+    }
+}
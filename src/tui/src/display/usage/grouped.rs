@@ -0,0 +1,128 @@
+//! Renderers for `usage --group-by`: usage summed per composite key (e.g.
+//! one row per date and provider).
+
+use crate::display::common::table::{
+    add_totals_row, create_comfy_table, fit_model_name, leading_left,
+};
+use comfy_table::{Cell, Color};
+use owo_colors::OwoColorize;
+use std::borrow::Cow;
+use vct_core::usage::{GroupBy, GroupKey, GroupedUsageRow};
+use vct_core::utils::{ModelNames, format_compact, format_cost, format_number};
+
+/// Prints the groups as a static table: one left-aligned column per key in
+/// `group_by` order, then tokens and cost, with a `TOTAL` row.
+///
+/// `show_sessions` adds a "Sessions" column; its `TOTAL` cell is left blank,
+/// since one session can span several groups. Models are named by `names`
+/// and cut to `model_width` columns.
+pub fn display_grouped_usage_table(
+    rows: &[GroupedUsageRow],
+    group_by: &GroupBy,
+    show_sessions: bool,
+    names: &ModelNames,
+    model_width: usize,
+) {
+    if rows.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+
+    println!("{}", "Token Usage (Grouped)".bright_cyan().bold());
+    println!();
+
+    let keys = group_by.keys();
+    let aligned = leading_left(keys.len());
+    let mut table = create_comfy_table(
+        keys.iter()
+            .map(|key| match key {
+                GroupKey::Date => "Date",
+                GroupKey::Provider => "Provider",
+                GroupKey::Model => "Model",
+            })
+            .chain(["Tokens", "Cost"])
+            .chain(show_sessions.then_some("Sessions"))
+            .collect(),
+        Color::Yellow,
+        &aligned,
+    );
+    for row in rows {
+        let cells = key_values(row, keys, names)
+            .into_iter()
+            .zip(keys)
+            .map(|(value, key)| match key {
+                GroupKey::Model => Cell::new(fit_model_name(&value, model_width)),
+                _ => Cell::new(value),
+            })
+            .map(|cell| cell.fg(Color::Green))
+            .chain([
+                Cell::new(format_number(row.tokens)).fg(Color::White),
+                Cell::new(format_cost(row.cost_usd)).fg(Color::Cyan),
+            ])
+            .chain(show_sessions.then(|| Cell::new(format_number(row.sessions)).fg(Color::Blue)))
+            .enumerate()
+            .map(|(i, cell)| cell.set_alignment(aligned(i)));
+        table.add_row(cells);
+    }
+
+    let tokens: i64 = rows.iter().map(|row| row.tokens).sum();
+    let cost: f64 = rows.iter().map(|row| row.cost_usd).sum();
+    let mut total = vec![String::new(); keys.len()];
+    total[0] = "TOTAL".to_string();
+    total.extend([format_number(tokens), format_cost(cost)]);
+    if show_sessions {
+        total.push(String::new());
+    }
+    add_totals_row(&mut table, total, Color::Red, aligned);
+
+    println!("{table}");
+}
+
+/// Prints one `2025-10-11 Claude: $1.20, 45.0K tokens` line per group;
+/// `show_sessions` appends `(N sessions)`, and models are named by `names`.
+pub fn display_grouped_usage_text(
+    rows: &[GroupedUsageRow],
+    group_by: &GroupBy,
+    show_sessions: bool,
+    names: &ModelNames,
+) {
+    if rows.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+    for row in rows {
+        let line = format!(
+            "{}: {}, {} tokens",
+            key_values(row, group_by.keys(), names).join(" "),
+            format_cost(row.cost_usd),
+            format_compact(row.tokens)
+        );
+        if show_sessions {
+            println!("{line} ({} sessions)", row.sessions);
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+/// The row's key values in `keys` order, models as `names` displays them.
+fn key_values<'a>(
+    row: &'a GroupedUsageRow,
+    keys: &[GroupKey],
+    names: &ModelNames,
+) -> Vec<Cow<'a, str>> {
+    keys.iter()
+        .map(|key| {
+            match key {
+                GroupKey::Date => &row.date,
+                GroupKey::Provider => &row.provider,
+                GroupKey::Model => {
+                    return names.display(row.model.as_deref().unwrap_or_default());
+                }
+            }
+            .as_deref()
+            .unwrap_or_default()
+            .into()
+        })
+        .collect()
+}
@@ -2,21 +2,23 @@
 //!
 //! Runs a render loop that incrementally re-aggregates the session directories
 //! every `refresh_secs` seconds (from `config.toml`), reusing one pricing map
-//! for the current UTC day and highlighting rows whose tokens changed since
-//! the last tick. The loop holds only the small per-model display state
-//! between frames so a resize repaints instantly without re-aggregating; memory
-//! is trimmed back to the OS after each refresh.
+//! for the current UTC day (or until `R` forces a reload) and highlighting rows
+//! whose tokens changed since the last tick. The loop holds only the small
+//! per-model display state between frames so a resize repaints instantly
+//! without re-aggregating; memory is trimmed back to the OS after each refresh.
 
-use crate::display::common::ProviderTotal;
 use crate::display::common::table::{
-    create_controls_with_status, create_provider_row, create_ratatui_table, create_summary,
+    COMPACT_MIN_H, compact_layout, create_compact_status, create_controls_with_status,
+    create_provider_row, create_ratatui_table, create_summary, fit_model_name,
     init_process_metrics, main_layout, refresh_process_metrics, render_scrollable_table,
     render_too_small, styled_row,
 };
 use crate::display::common::tui::{
-    InputAction, RefreshWorker, RefreshWorkerError, ScrollState, TerminalSession, UpdateTracker,
-    handle_input, overlay_repo_hyperlink, refresh_status, render_loading_frame,
+    InputAction, RefreshWorker, RefreshWorkerError, ScrollState, TerminalSession, TuiOptions,
+    UpdateTracker, handle_input, overlay_repo_hyperlink, refresh_status, render_loading_frame,
 };
+use crate::display::common::{ProviderTotal, SummaryMetric, cost_color};
+use crate::display::usage::UsageRenderOptions;
 use crate::display::usage::averages::{
     ProviderStats, UsageProviderTotals, UsageRow, UsageTotals, build_provider_total_rows,
     build_usage_summary, merge_rows_by_base_model,
@@ -40,7 +42,7 @@ use vct_core::models::{
     ClaudeQuotaSnapshot, CodexQuotaSnapshot, CopilotQuotaSnapshot, CursorQuotaSnapshot,
     QuotaSource, QuotaWindow,
 };
-use vct_core::pricing::{ModelPricingMap, fetch_model_pricing};
+use vct_core::pricing::{ModelPricingMap, ThresholdBasis, fetch_model_pricing};
 use vct_core::quota::{
     CLAUDE_LOGIN_HINT, CODEX_LOGIN_HINT, COPILOT_LOGIN_HINT, CURSOR_LOGIN_HINT, ClaudeState,
     CodexState, CopilotState, CursorState, load_claude_cache, load_codex_cache, load_copilot_cache,
@@ -49,9 +51,11 @@ use vct_core::quota::{
 };
 use vct_core::scan::build_scan_pool;
 use vct_core::summary_cache::SummaryScanCache;
+use vct_core::usage::cost_on_date;
 use vct_core::utils::{
     format_compact, format_cost, format_cost_compact, format_duration_until,
-    get_claude_credentials_path, get_copilot_config_path, get_cursor_auth_path, resolve_paths,
+    get_claude_credentials_path, get_copilot_config_path, get_current_date, get_cursor_auth_path,
+    resolve_paths,
 };
 
 /// Minimum height for the bottom quota panels. Sized for the common case
@@ -145,6 +149,7 @@ struct UsageRefreshPayload {
     merged_rows: Vec<UsageRow>,
     totals: UsageTotals,
     provider_totals: UsageProviderTotals,
+    today_cost: f64,
 }
 
 struct QuotaShutdownGuard {
@@ -298,9 +303,13 @@ struct UsageUiState {
     merged_rows: Vec<UsageRow>,
     totals: UsageTotals,
     provider_totals: UsageProviderTotals,
+    today_cost: f64,
+    options: UsageRenderOptions,
     update_tracker: UpdateTracker,
     scroll: ScrollState,
     merge_enabled: bool,
+    compact: bool,
+    tui: TuiOptions,
     claude: ClaudeQuotaSnapshot,
     codex: CodexQuotaSnapshot,
     copilot: CopilotQuotaSnapshot,
@@ -308,15 +317,19 @@ struct UsageUiState {
 }
 
 impl UsageUiState {
-    fn new(merge_enabled: bool) -> Self {
+    fn new(options: UsageRenderOptions, tui: TuiOptions) -> Self {
         Self {
             rows: Vec::new(),
             merged_rows: Vec::new(),
             totals: UsageTotals::default(),
             provider_totals: UsageProviderTotals::default(),
+            today_cost: 0.0,
+            options,
             update_tracker: UpdateTracker::new(MAX_TRACKED_ROWS, 1000),
             scroll: ScrollState::new(),
-            merge_enabled,
+            merge_enabled: options.merge,
+            compact: tui.compact,
+            tui,
             claude: ClaudeQuotaSnapshot::default(),
             codex: CodexQuotaSnapshot::default(),
             copilot: CopilotQuotaSnapshot::default(),
@@ -339,6 +352,7 @@ impl UsageUiState {
         self.merged_rows = payload.merged_rows;
         self.totals = payload.totals;
         self.provider_totals = payload.provider_totals;
+        self.today_cost = payload.today_cost;
 
         let fingerprints: Vec<_> = self
             .view()
@@ -427,25 +441,65 @@ impl UsageUiState {
             rows,
             &self.totals,
             &self.provider_totals,
+            self.today_cost,
+            &self.options,
             &self.update_tracker,
             sys,
             pid,
             &quota,
             &mut self.scroll,
             self.merge_enabled,
+            self.compact,
+            &self.tui,
             status,
             true,
         )
     }
 }
 
+/// Decides when the refresh worker reloads the pricing map.
+///
+/// Pricing is reused for the rest of the UTC day once loaded; `R` sets the
+/// shared `forced` flag so the next refresh reloads it regardless.
+struct PricingRefresh {
+    forced: Arc<AtomicBool>,
+    loaded_utc_date: Option<chrono::NaiveDate>,
+}
+
+impl PricingRefresh {
+    fn new(forced: Arc<AtomicBool>) -> Self {
+        Self {
+            forced,
+            loaded_utc_date: None,
+        }
+    }
+
+    /// Whether this refresh should reload pricing; consumes a pending force.
+    fn take_due(&mut self, today: chrono::NaiveDate) -> bool {
+        // Swap first so a force is consumed even when the day also rolled.
+        let forced = self.forced.swap(false, Ordering::Relaxed);
+        forced || self.loaded_utc_date != Some(today)
+    }
+
+    fn mark_loaded(&mut self, today: chrono::NaiveDate) {
+        self.loaded_utc_date = Some(today);
+    }
+}
+
 /// Displays usage with a dedicated scan pool supplied by the CLI.
+///
+/// `scan_options` carries the caller's parse knobs (e.g. `encoding`); its
+/// `tiers` is replaced on every pricing reload. `tui` carries the dashboard
+/// settings such as the configured keymap.
 #[allow(clippy::too_many_arguments)]
 pub fn display_usage_interactive_with_pool(
     time_range: vct_core::models::TimeRange,
-    merge_providers: bool,
+    options: UsageRenderOptions,
     quota_panels: Vec<String>,
     providers: ProvidersConfig,
+    threshold_basis: ThresholdBasis,
+    scan_options: vct_core::usage::UsageScanOptions,
+    tui: TuiOptions,
     refresh_secs: u64,
     quota_refresh_secs: u64,
     scan_pool: Arc<rayon::ThreadPool>,
@@ -459,22 +513,28 @@ pub fn display_usage_interactive_with_pool(
         let quota = QuotaRuntime::start(&quota_panels, providers, quota_refresh_secs);
         let worker_paths = paths.clone();
         let worker_pool = Arc::clone(&scan_pool);
+        let force_pricing = Arc::new(AtomicBool::new(false));
+        let worker_force_pricing = Arc::clone(&force_pricing);
         let mut worker = RefreshWorker::new_with_init(refresh_secs, move || {
             let mut cache = SummaryScanCache::new();
             let mut pricing = ModelPricingMap::new(HashMap::new());
-            let mut scan_options = vct_core::usage::UsageScanOptions::default();
-            let mut loaded_pricing_utc_date = None;
+            let mut scan_options = scan_options;
+            let mut pricing_refresh = PricingRefresh::new(worker_force_pricing);
             move || {
                 let today = chrono::Utc::now().date_naive();
-                if loaded_pricing_utc_date != Some(today) {
-                    match fetch_model_pricing() {
+                if pricing_refresh.take_due(today) {
+                    match fetch_model_pricing(&scan_options.warnings) {
                         Ok(map) => {
+                            let map =
+                                map.with_family_estimates(scan_options.estimate_missing_pricing);
                             // A new pricing map can move tier thresholds; the
                             // scan invalidates its cache when the snapshot's
                             // fingerprint changes.
-                            scan_options.tiers = Some(std::sync::Arc::new(map.tier_thresholds()));
+                            scan_options.tiers = Some(std::sync::Arc::new(
+                                map.tier_thresholds().with_basis(threshold_basis),
+                            ));
                             pricing = map;
-                            loaded_pricing_utc_date = Some(today);
+                            pricing_refresh.mark_loaded(today);
                         }
                         Err(error) => {
                             log::warn!("failed to refresh pricing: {error}");
@@ -510,20 +570,25 @@ pub fn display_usage_interactive_with_pool(
                     );
                 }
 
+                let today_cost = cost_on_date(&collection.data, &get_current_date(), &pricing);
                 let mut summary = build_usage_summary(
                     &collection.data.models,
                     &collection.data.per_provider,
                     &collection.data.provider_days,
                     &pricing,
                     &collection.data.stored_costs,
+                    &collection.data.model_renames,
+                    None,
                 );
                 summary.rows.retain(|row| row.total != 0 || row.cost != 0.0);
+                summary.attach_session_counts(&collection.data);
                 let merged_rows = merge_rows_by_base_model(&summary.rows);
                 Ok(UsageRefreshPayload {
                     rows: summary.rows,
                     merged_rows,
                     totals: summary.totals,
                     provider_totals: summary.provider_totals,
+                    today_cost,
                 })
             }
         });
@@ -537,7 +602,7 @@ pub fn display_usage_interactive_with_pool(
             Duration::from_millis(vct_core::constants::refresh::METRICS_REFRESH_MS);
         let mut last_metrics = Instant::now();
         let mut last_spinner = Instant::now();
-        let mut state = UsageUiState::new(merge_providers);
+        let mut state = UsageUiState::new(options, tui);
         let mut loaded = false;
         let mut failure_until = None;
 
@@ -594,9 +659,13 @@ pub fn display_usage_interactive_with_pool(
                 state.render(terminal.terminal_mut(), &sys, pid, &quota, status)?;
             }
 
-            match handle_input()? {
+            let action = handle_input(&state.tui.keymap)?;
+            match action {
                 InputAction::Quit => break,
-                InputAction::Refresh => {
+                InputAction::Refresh | InputAction::RefreshPricing => {
+                    if action == InputAction::RefreshPricing {
+                        force_pricing.store(true, Ordering::Relaxed);
+                    }
                     worker.request();
                     if loaded {
                         state.render(
@@ -620,6 +689,18 @@ pub fn display_usage_interactive_with_pool(
                         )?;
                     }
                 }
+                InputAction::ToggleCompact => {
+                    state.compact = !state.compact;
+                    if loaded {
+                        state.render(
+                            terminal.terminal_mut(),
+                            &sys,
+                            pid,
+                            &quota,
+                            refresh_status(worker.is_active(), failure_until),
+                        )?;
+                    }
+                }
                 InputAction::Navigate(delta) if loaded => {
                     state.scroll.apply(delta, state.view().len());
                     state.render(
@@ -660,10 +741,14 @@ pub fn display_usage_interactive_with_pool(
 /// - Real-time memory monitoring
 /// - Provider-grouped totals
 /// - Scrollable model table (arrow keys)
-/// - Keyboard controls: `q`, `Esc`, or `Ctrl+C` to exit, `r` to refresh, `m` to
+/// - Keyboard controls: `q`, `Esc`, or `Ctrl+C` to exit, `r` to refresh, `R` to
+///   also reload pricing (bypassing the once-per-UTC-day reuse), `m` to
 ///   toggle merging models that share a base name across provider prefixes
 ///   (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`). `merge_providers` seeds the
-///   initial state and the `m` toggle is persisted back to `config.toml`.
+///   initial state and the `m` toggle is persisted back to `config.toml`;
+///   `c` to switch to the compact layout (table and one status line), which
+///   `--compact-tui` starts in (see
+///   [`TuiOptions::compact`](crate::display::common::tui::TuiOptions::compact)).
 ///
 /// `quota_panels` selects which live quota panels to show (by provider name);
 /// an empty list drops the band entirely. `providers` (from the config) selects
@@ -690,11 +775,18 @@ pub fn display_usage_interactive(
 ) -> anyhow::Result<()> {
     let threads = vct_core::config::PerformanceConfig::default().resolved_scan_threads();
     let pool = Arc::new(build_scan_pool(threads)?);
+    let options = UsageRenderOptions {
+        merge: merge_providers,
+        ..UsageRenderOptions::default()
+    };
     display_usage_interactive_with_pool(
         time_range,
-        merge_providers,
+        options,
         quota_panels,
         providers,
+        ThresholdBasis::default(),
+        vct_core::usage::UsageScanOptions::default(),
+        TuiOptions::default(),
         refresh_secs,
         quota_refresh_secs,
         pool,
@@ -737,12 +829,16 @@ fn render_usage_frame_with_status<B: Backend>(
     rows_data: &[UsageRow],
     totals: &UsageTotals,
     provider_totals: &UsageProviderTotals,
+    today_cost: f64,
+    options: &UsageRenderOptions,
     update_tracker: &UpdateTracker,
     sys: &System,
     pid: Pid,
     quota: &QuotaView,
     scroll: &mut ScrollState,
     merge_enabled: bool,
+    compact: bool,
+    tui: &TuiOptions,
     status: Option<&str>,
     write_hyperlink: bool,
 ) -> anyhow::Result<()> {
@@ -750,8 +846,67 @@ fn render_usage_frame_with_status<B: Backend>(
 
     let completed = terminal.draw(|f| {
         let area = f.area();
-        if area.width < USAGE_MIN_W || area.height < USAGE_MIN_H {
-            render_too_small(f, USAGE_MIN_W, USAGE_MIN_H);
+        let min_h = if compact { COMPACT_MIN_H } else { USAGE_MIN_H };
+        if area.width < USAGE_MIN_W || area.height < min_h {
+            render_too_small(f, USAGE_MIN_W, min_h);
+            return;
+        }
+
+        let total_cost_str = format_cost(totals.cost);
+        let today_cost_str = format_cost(today_cost);
+        let today_color = cost_color(
+            today_cost,
+            options.cost_thresholds.low,
+            options.cost_thresholds.high,
+        )
+        .tui_color();
+        let total_tokens_str = format_compact(totals.total);
+        let entries_str = format!("{}", rows_data.len());
+
+        let summary_items = [
+            (
+                SummaryMetric::Cost,
+                "Total Cost:",
+                total_cost_str.as_str(),
+                RatatuiColor::Yellow,
+            ),
+            (
+                SummaryMetric::Cost,
+                "Today:",
+                today_cost_str.as_str(),
+                today_color,
+            ),
+            (
+                SummaryMetric::Volume,
+                "Total Tokens:",
+                total_tokens_str.as_str(),
+                RatatuiColor::Cyan,
+            ),
+            (
+                SummaryMetric::Count,
+                "Models:",
+                entries_str.as_str(),
+                RatatuiColor::Blue,
+            ),
+        ];
+        let summary_format = tui.summary_format;
+
+        // Compact: the table and one status line carrying the summary's totals.
+        if compact {
+            let chunks = compact_layout(area);
+            render_usage_table(
+                f,
+                chunks.table,
+                rows_data,
+                update_tracker,
+                scroll,
+                options,
+                tui,
+            );
+            f.render_widget(
+                create_compact_status(&summary_items, summary_format, status, &tui.keymap),
+                chunks.status,
+            );
             return;
         }
 
@@ -772,63 +927,14 @@ fn render_usage_frame_with_status<B: Backend>(
             provider_rows.len(),
         );
         let chunks = main_layout(area, panels_height);
-
-        let header = vec![
-            "Model",
-            "Input",
-            "Output",
-            "Cache Read",
-            "Cache Write",
-            "Total",
-            "Cost (USD)",
-        ];
-
-        // One selectable row per model. The grand total lives only in the
-        // summary bar below (it was redundant here and in the provider band).
-        let rows: Vec<RatatuiRow> = rows_data
-            .iter()
-            .map(|row| {
-                let style = if update_tracker.is_recently_updated(&row.model) {
-                    Style::default().bg(RatatuiColor::Rgb(60, 80, 60)).bold()
-                } else {
-                    Style::default()
-                };
-                styled_row(
-                    vec![
-                        row.display_model.clone(),
-                        format_compact(row.input_tokens),
-                        format_compact(row.output_with_reasoning()),
-                        format_compact(row.cache_read),
-                        format_compact(row.cache_creation),
-                        format_compact(row.total),
-                        format_cost(row.cost),
-                    ],
-                    style,
-                    1,
-                )
-            })
-            .collect();
-
-        let widths = [
-            Constraint::Min(16),
-            Constraint::Length(9),
-            Constraint::Length(9),
-            Constraint::Length(11),
-            Constraint::Length(11),
-            Constraint::Length(9),
-            Constraint::Length(12),
-        ];
-
-        let row_count = rows.len();
-        render_scrollable_table(
+        render_usage_table(
             f,
             chunks.table,
-            header,
-            rows,
-            &widths,
-            RatatuiColor::Green,
-            row_count,
+            rows_data,
+            update_tracker,
             scroll,
+            options,
+            tui,
         );
 
         if let Some(panel_area) = chunks.panels {
@@ -927,21 +1033,13 @@ fn render_usage_frame_with_status<B: Backend>(
             }
         }
 
-        let total_cost_str = format_cost(totals.cost);
-        let total_tokens_str = format_compact(totals.total);
-        let entries_str = format!("{}", rows_data.len());
-
-        let summary_items = vec![
-            ("Total Cost:", total_cost_str.as_str(), RatatuiColor::Yellow),
-            (
-                "Total Tokens:",
-                total_tokens_str.as_str(),
-                RatatuiColor::Cyan,
-            ),
-            ("Models:", entries_str.as_str(), RatatuiColor::Blue),
-        ];
-
-        let summary = create_summary(summary_items, sys, pid, chunks.summary.width);
+        let summary = create_summary(
+            &summary_items,
+            summary_format,
+            sys,
+            pid,
+            chunks.summary.width,
+        );
         f.render_widget(summary, chunks.summary);
 
         // When merged, the toggle un-merges, so label it "split" to match.
@@ -951,7 +1049,11 @@ fn render_usage_frame_with_status<B: Backend>(
             " merge  "
         };
         f.render_widget(
-            create_controls_with_status(&[("m", merge_hint)], status),
+            create_controls_with_status(
+                &[("m", merge_hint), ("R", " reprice  "), ("c", " compact  ")],
+                status,
+                &tui.keymap,
+            ),
             chunks.controls,
         );
     })?;
@@ -965,6 +1067,91 @@ fn render_usage_frame_with_status<B: Backend>(
     Ok(())
 }
 
+/// Draws the scrollable per-model table into `area`, highlighting rows whose
+/// tokens changed recently. Shared by the full and the compact layout;
+/// `options.show_sessions` adds a "Sessions" column (`usage --show-sessions`),
+/// and a fuzzy match below `options.fuzzy_hint_threshold` drops its
+/// `(matched)` hint as in the static table. Models are named and cut to width
+/// per `tui`.
+fn render_usage_table(
+    f: &mut Frame,
+    area: Rect,
+    rows_data: &[UsageRow],
+    update_tracker: &UpdateTracker,
+    scroll: &mut ScrollState,
+    options: &UsageRenderOptions,
+    tui: &TuiOptions,
+) {
+    let show_sessions = options.show_sessions;
+    let mut header = vec![
+        "Model",
+        "Input",
+        "Output",
+        "Cache Read",
+        "Cache Write",
+        "Total",
+        "Cost (USD)",
+    ];
+    if show_sessions {
+        header.push("Sessions");
+    }
+
+    // One selectable row per model. The grand total lives only in the
+    // summary bar below (it was redundant here and in the provider band).
+    let rows: Vec<RatatuiRow> = rows_data
+        .iter()
+        .map(|row| {
+            let style = if update_tracker.is_recently_updated(&row.model) {
+                Style::default().bg(RatatuiColor::Rgb(60, 80, 60)).bold()
+            } else {
+                Style::default()
+            };
+            let mut cells = vec![
+                fit_model_name(
+                    &row.model_label(options.fuzzy_hint_threshold, &tui.model_names),
+                    tui.model_width,
+                )
+                .into_owned(),
+                format_compact(row.input_tokens),
+                format_compact(row.output_with_reasoning()),
+                format_compact(row.cache_read),
+                format_compact(row.cache_creation),
+                format_compact(row.total),
+                format_cost(row.cost),
+            ];
+            if show_sessions {
+                cells.push(format_compact(row.sessions as i64));
+            }
+            styled_row(cells, style, 1)
+        })
+        .collect();
+
+    let mut widths = vec![
+        Constraint::Min(16),
+        Constraint::Length(9),
+        Constraint::Length(9),
+        Constraint::Length(11),
+        Constraint::Length(11),
+        Constraint::Length(9),
+        Constraint::Length(12),
+    ];
+    if show_sessions {
+        widths.push(Constraint::Length(9));
+    }
+
+    let row_count = rows.len();
+    render_scrollable_table(
+        f,
+        area,
+        header,
+        rows,
+        &widths,
+        RatatuiColor::Green,
+        row_count,
+        scroll,
+    );
+}
+
 /// Production-shaped usage frame fixture used by Criterion benchmarks.
 ///
 /// The fixture owns a [`TestBackend`] so benchmarks exercise the same table,
@@ -984,6 +1171,7 @@ pub struct UsageFrameBenchmark {
     copilot: CopilotQuotaSnapshot,
     cursor: CursorQuotaSnapshot,
     scroll: ScrollState,
+    compact: bool,
 }
 
 impl UsageFrameBenchmark {
@@ -1014,7 +1202,9 @@ impl UsageFrameBenchmark {
                 input_tokens + output_tokens + reasoning_tokens + cache_read + cache_creation;
             let model = format!("{}-{index}", MODELS[index % MODELS.len()]);
             let row = UsageRow {
-                display_model: model.clone(),
+                matched_model: None,
+                match_similarity: None,
+                estimated: false,
                 model,
                 input_tokens,
                 output_tokens,
@@ -1023,6 +1213,9 @@ impl UsageFrameBenchmark {
                 cache_creation,
                 total,
                 cost: scale as f64 * 0.0175,
+                sessions: 1,
+                session_ids: Default::default(),
+                grouped_models: Vec::new(),
             };
             totals.accumulate(&row);
             let stats = match index % MODELS.len() {
@@ -1070,9 +1263,15 @@ impl UsageFrameBenchmark {
             copilot: CopilotQuotaSnapshot::default(),
             cursor: CursorQuotaSnapshot::default(),
             scroll,
+            compact: false,
         })
     }
 
+    /// Switches later frames to the compact (`--compact-tui`) layout.
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
     /// Renders one frame with the supplied footer status.
     pub fn render(&mut self, status: Option<&str>) -> anyhow::Result<()> {
         let quota = QuotaView {
@@ -1093,12 +1292,16 @@ impl UsageFrameBenchmark {
             &self.rows,
             &self.totals,
             &self.provider_totals,
+            7.5,
+            &UsageRenderOptions::default(),
             &self.update_tracker,
             &self.sys,
             self.pid,
             &quota,
             &mut self.scroll,
             false,
+            self.compact,
+            &TuiOptions::default(),
             status,
             false,
         )
@@ -1685,6 +1888,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pricing_refresh_reuses_the_day_unless_forced() {
+        let forced = Arc::new(AtomicBool::new(false));
+        let mut refresh = PricingRefresh::new(Arc::clone(&forced));
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+
+        assert!(refresh.take_due(today));
+        refresh.mark_loaded(today);
+        assert!(!refresh.take_due(today));
+
+        forced.store(true, Ordering::Relaxed);
+        assert!(refresh.take_due(today));
+        refresh.mark_loaded(today);
+        // The force is consumed by the refresh that honored it.
+        assert!(!refresh.take_due(today));
+        assert!(refresh.take_due(today.succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn weak_fuzzy_match_drops_its_hint_below_the_threshold() {
+        let row = UsageRow {
+            matched_model: Some("matched".to_string()),
+            match_similarity: Some(0.5),
+            estimated: false,
+            model: "gpt-x".to_string(),
+            input_tokens: 1,
+            output_tokens: 1,
+            reasoning_tokens: 0,
+            cache_read: 0,
+            cache_creation: 0,
+            total: 2,
+            cost: 0.0,
+            sessions: 1,
+            session_ids: Default::default(),
+            grouped_models: Vec::new(),
+        };
+        let tracker = UpdateTracker::new(MAX_TRACKED_ROWS, 0);
+        let draw = |fuzzy_hint_threshold: f64| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 6)).unwrap();
+            let options = UsageRenderOptions {
+                fuzzy_hint_threshold,
+                ..UsageRenderOptions::default()
+            };
+            let mut scroll = ScrollState::new();
+            terminal
+                .draw(|f| {
+                    let area = f.area();
+                    render_usage_table(
+                        f,
+                        area,
+                        std::slice::from_ref(&row),
+                        &tracker,
+                        &mut scroll,
+                        &options,
+                        &TuiOptions::default(),
+                    );
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            buffer
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        assert!(draw(0.4).contains("gpt-x (matched)"));
+        let weak = draw(0.8);
+        assert!(weak.contains("gpt-x") && !weak.contains("(matched)"));
+    }
+
     #[test]
     fn quota_shutdown_guard_never_waits_and_sets_flag() {
         let shutdown = Arc::new(AtomicBool::new(false));
@@ -1830,6 +2104,32 @@ mod tests {
         assert!(rendered.contains("updated just now"));
     }
 
+    #[test]
+    fn compact_layout_is_the_table_over_a_one_line_status() {
+        // Shorter than the full layout's minimum height.
+        let (width, height) = (120, USAGE_MIN_H - 4);
+        let mut frame = UsageFrameBenchmark::new(width, height).unwrap();
+        frame.set_compact(true);
+        frame.render(Some("Refreshing...")).unwrap();
+
+        let buffer = frame.terminal.backend().buffer();
+        let lines: Vec<String> = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer.cell((x, y)).unwrap().symbol())
+                    .collect()
+            })
+            .collect();
+        let (status, table) = lines.split_last().unwrap();
+        let table = table.join("\n");
+        assert!(table.contains("Model") && table.contains("Cost (USD)"));
+        assert!(table.contains("claude-sonnet-4-6-0"));
+        assert!(!table.contains("Provider") && !table.contains("Memory:"));
+        assert!(status.starts_with("Total Cost: $"), "{status}");
+        assert!(status.contains("Models: 32") && status.contains("c full"));
+        assert!(status.contains("Refreshing..."));
+    }
+
     #[test]
     fn arrange_wide_keeps_table_in_one_row() {
         // Plenty of width for the table + 4 panels.
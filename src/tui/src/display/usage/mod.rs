@@ -4,15 +4,65 @@
 //! sorted [`UsageSummary`] shared by all output modes;
 //! `interactive`, `table`, and `text` render that summary as the
 //! auto-refreshing TUI, a static table, or one line per model respectively.
+//! `diff` renders the change between two saved `usage --json` exports,
+//! `grouped` the `--group-by` composite-key rows, `calendar` the
+//! `--calendar` daily heatmap, `budget` the `--model-budget` caps, and
+//! `split` the per-period `--split-by` tables.
 
 mod averages;
+mod budget;
+mod calendar;
+mod diff;
+mod grouped;
 mod interactive;
+mod split;
 mod table;
 mod text;
 
+use crate::display::common::CostThresholds;
 pub use averages::*;
+pub use budget::{display_model_budget_table, display_model_budget_text};
+pub use calendar::{CalendarMetric, display_usage_calendar, intensity_level};
+pub use diff::{display_usage_diff_table, display_usage_diff_text};
+pub use grouped::{display_grouped_usage_table, display_grouped_usage_text};
 pub use interactive::{
     UsageFrameBenchmark, display_usage_interactive, display_usage_interactive_with_pool,
 };
+pub use split::display_split_usage_table;
 pub use table::display_usage_table;
-pub use text::display_usage_text;
+pub use text::{display_usage_footer, display_usage_text};
+use vct_core::pricing::DEFAULT_FUZZY_HINT_THRESHOLD;
+
+/// Presentation knobs shared by the `usage` renderers; the interactive TUI
+/// reads `merge` (its initial state), `show_sessions`, and `cost_thresholds`.
+#[derive(Debug, Clone, Copy)]
+pub struct UsageRenderOptions {
+    /// Collapse rows sharing a base model name across provider prefixes
+    /// (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`).
+    pub merge: bool,
+    /// Show how many distinct sessions contributed each row.
+    pub show_sessions: bool,
+    /// Fold models costing less than this percentage of the total into one
+    /// `Other (N models)` row.
+    pub group_threshold: Option<f64>,
+    /// Title the table as an estimate (`usage --estimate-only`).
+    pub estimate: bool,
+    /// Breakpoints coloring today's cost in the footer.
+    pub cost_thresholds: CostThresholds,
+    /// Fuzzy pricing matches below this similarity drop their `(matched)`
+    /// hint and show the model in a warning color.
+    pub fuzzy_hint_threshold: f64,
+}
+
+impl Default for UsageRenderOptions {
+    fn default() -> Self {
+        Self {
+            merge: false,
+            show_sessions: false,
+            group_threshold: None,
+            estimate: false,
+            cost_thresholds: CostThresholds::default(),
+            fuzzy_hint_threshold: DEFAULT_FUZZY_HINT_THRESHOLD,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Renderer for `usage --split-by`: one table per week or month, each with
+//! its own subtotal, followed by the grand total.
+
+use crate::display::common::table::{
+    add_totals_row, create_comfy_table, fit_model_name, leading_left,
+};
+use comfy_table::{Cell, CellAlignment, Color};
+use owo_colors::OwoColorize;
+use vct_core::usage::{SplitPeriod, UsageSection};
+use vct_core::utils::{ModelNames, format_cost, format_number};
+
+/// Prints each section as a `Date | Model | Tokens | Cost` table headed by
+/// its period, with a `SUBTOTAL` row, then one grand-total line. Models are
+/// named by `names` and cut to `model_width` columns.
+pub fn display_split_usage_table(
+    sections: &[UsageSection],
+    period: SplitPeriod,
+    names: &ModelNames,
+    model_width: usize,
+) {
+    if sections.is_empty() {
+        println!("No usage data found");
+        return;
+    }
+
+    let noun = match period {
+        SplitPeriod::Week => "Week",
+        SplitPeriod::Month => "Month",
+    };
+    for section in sections {
+        println!(
+            "{}",
+            format!("{noun} {}", section.period).bright_cyan().bold()
+        );
+
+        let mut table = create_comfy_table(
+            vec!["Date", "Model", "Tokens", "Cost"],
+            Color::Yellow,
+            leading_left(2),
+        );
+        for row in &section.rows {
+            table.add_row([
+                Cell::new(row.date.as_deref().unwrap_or_default()).fg(Color::White),
+                Cell::new(fit_model_name(
+                    &names.display(row.model.as_deref().unwrap_or_default()),
+                    model_width,
+                ))
+                .fg(Color::Green),
+                Cell::new(format_number(row.tokens))
+                    .fg(Color::White)
+                    .set_alignment(CellAlignment::Right),
+                Cell::new(format_cost(row.cost_usd))
+                    .fg(Color::Cyan)
+                    .set_alignment(CellAlignment::Right),
+            ]);
+        }
+        add_totals_row(
+            &mut table,
+            vec![
+                "SUBTOTAL".to_string(),
+                String::new(),
+                format_number(section.tokens),
+                format_cost(section.cost_usd),
+            ],
+            Color::Red,
+            leading_left(2),
+        );
+        println!("{table}");
+        println!();
+    }
+
+    let tokens: i64 = sections.iter().map(|section| section.tokens).sum();
+    let cost: f64 = sections.iter().map(|section| section.cost_usd).sum();
+    println!(
+        "{}",
+        format!(
+            "TOTAL ({} {}s): {} tokens, {}",
+            sections.len(),
+            noun.to_lowercase(),
+            format_number(tokens),
+            format_cost(cost)
+        )
+        .red()
+        .bold()
+    );
+}
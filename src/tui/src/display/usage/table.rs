@@ -1,53 +1,66 @@
 //! Static-table renderer for the usage view (per-model table + per-provider footer).
 
+use crate::display::common::cost_color;
 use crate::display::common::table::{
-    add_totals_row, create_comfy_table, create_metric_cell, create_provider_cell,
+    add_totals_row, create_comfy_table, create_metric_cell, create_provider_cell, fit_model_name,
+    leading_left,
 };
+use crate::display::usage::UsageRenderOptions;
 use crate::display::usage::averages::{
     build_provider_total_rows, build_usage_summary, merge_rows_by_base_model,
 };
 use comfy_table::{Cell, CellAlignment, Color, Table, presets::UTF8_FULL};
 use owo_colors::OwoColorize;
-use std::collections::HashMap;
-use vct_core::pricing::{ModelPricingMap, fetch_model_pricing};
-use vct_core::usage::UsageData;
-use vct_core::utils::format_number;
+use vct_core::pricing::ModelPricingMap;
+use vct_core::usage::{UsageData, cost_on_date};
+use vct_core::utils::{ModelNames, format_number, get_current_date};
 
 /// Prints token usage to stdout as a colored per-model table plus a
 /// per-provider totals footer.
 ///
 /// The "Output" column folds `reasoning_tokens` back into the displayed number
 /// so each row reconciles with "Total Tokens", while cost is priced against the
-/// separated buckets. Prints a "no usage data" message when empty. If pricing
-/// cannot be fetched, a warning is written to stderr and costs are shown as
-/// `$0.00`. When `merge` is set, rows sharing a base model name across provider
-/// prefixes (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`) are collapsed into one.
-pub fn display_usage_table(usage_data: &UsageData, merge: bool) {
+/// separated buckets. Prints a "no usage data" message when empty. Costs come
+/// from `pricing_map`; an empty map (failed fetch) shows them as `$0.00`. When
+/// `options.merge` is set, rows sharing a base model name across provider
+/// prefixes (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`) are collapsed into one;
+/// `options.show_sessions` adds a "Sessions" column, and
+/// `options.group_threshold` folds the models under that share of the total
+/// cost into one `Other (N models)` row. A model priced by a fuzzy
+/// match below `options.fuzzy_hint_threshold` is shown bare in a warning color
+/// rather than with its `(matched)` hint. The footer ends with today's cost,
+/// colored against `options.cost_thresholds`. Models are named by `names` and
+/// cut to `model_width` columns.
+pub fn display_usage_table(
+    usage_data: &UsageData,
+    pricing_map: &ModelPricingMap,
+    options: UsageRenderOptions,
+    names: &ModelNames,
+    model_width: usize,
+) {
     if usage_data.models.is_empty() {
         println!("No usage data found in enabled provider sessions");
         return;
     }
 
-    println!("{}", "Token Usage Statistics".bright_cyan().bold());
+    if options.estimate {
+        println!(
+            "{}",
+            "Token Usage Statistics (estimate)".bright_cyan().bold()
+        );
+    } else {
+        println!("{}", "Token Usage Statistics".bright_cyan().bold());
+    }
     println!();
 
-    // Fetch pricing data
-    let pricing_map = match fetch_model_pricing() {
-        Ok(map) => map,
-        Err(e) => {
-            log::warn!("failed to fetch pricing data: {e}; costs shown as $0.00");
-            eprintln!("Warning: Failed to fetch pricing data: {}", e);
-            eprintln!("Costs will be shown as $0.00");
-            ModelPricingMap::new(HashMap::new())
-        }
-    };
-
     let mut summary = build_usage_summary(
         &usage_data.models,
         &usage_data.per_provider,
         &usage_data.provider_days,
-        &pricing_map,
+        pricing_map,
         &usage_data.stored_costs,
+        &usage_data.model_renames,
+        options.group_threshold,
     );
 
     if summary.rows.is_empty() {
@@ -55,8 +68,10 @@ pub fn display_usage_table(usage_data: &UsageData, merge: bool) {
         return;
     }
 
+    summary.attach_session_counts(usage_data);
+
     // Totals are a row-wise sum, so they are identical merged or not.
-    if merge {
+    if options.merge {
         summary.rows = merge_rows_by_base_model(&summary.rows);
     }
 
@@ -64,28 +79,41 @@ pub fn display_usage_table(usage_data: &UsageData, merge: bool) {
     let totals = &summary.totals;
 
     // Create table
-    let mut table = create_comfy_table(
-        vec![
-            "Model",
-            "Input",
-            "Output",
-            "Cache Read",
-            "Cache Write",
-            "Total Tokens",
-            "Cost (USD)",
-        ],
-        Color::Yellow,
-    );
+    let mut headers = vec![
+        "Model",
+        "Input",
+        "Output",
+        "Cache Read",
+        "Cache Write",
+        "Total Tokens",
+        "Cost (USD)",
+    ];
+    if options.show_sessions {
+        headers.push("Sessions");
+    }
+    let mut table = create_comfy_table(headers, Color::Yellow, leading_left(1));
 
     // Add data rows. The "Output" column folds `reasoning_tokens` back
     // into the displayed number so each row still adds up to `Total`
     // — costs are already calculated against the separated buckets via
     // `calculate_cost`.
     for row in rows {
-        table.add_row(vec![
-            Cell::new(&row.display_model)
-                .fg(Color::Green)
-                .set_alignment(CellAlignment::Left),
+        // A weak fuzzy match loses its hint and is drawn as a warning instead;
+        // a `--estimate-missing-pricing` family average gets its own color.
+        let model_color = if row.estimated {
+            Color::Blue
+        } else if row.is_low_confidence_match(options.fuzzy_hint_threshold) {
+            Color::DarkYellow
+        } else {
+            Color::Green
+        };
+        let mut cells = vec![
+            Cell::new(fit_model_name(
+                &row.model_label(options.fuzzy_hint_threshold, names),
+                model_width,
+            ))
+            .fg(model_color)
+            .set_alignment(CellAlignment::Left),
             Cell::new(format_number(row.input_tokens))
                 .fg(Color::White)
                 .set_alignment(CellAlignment::Right),
@@ -104,23 +132,31 @@ pub fn display_usage_table(usage_data: &UsageData, merge: bool) {
             Cell::new(format!("${:.2}", row.cost))
                 .fg(Color::Cyan)
                 .set_alignment(CellAlignment::Right),
-        ]);
+        ];
+        if options.show_sessions {
+            cells.push(
+                Cell::new(format_number(row.sessions))
+                    .fg(Color::White)
+                    .set_alignment(CellAlignment::Right),
+            );
+        }
+        table.add_row(cells);
     }
 
     // Add totals row
-    add_totals_row(
-        &mut table,
-        vec![
-            "TOTAL".to_string(),
-            format_number(totals.input_tokens),
-            format_number(totals.output_with_reasoning()),
-            format_number(totals.cache_read),
-            format_number(totals.cache_creation),
-            format_number(totals.total),
-            format!("${:.2}", totals.cost),
-        ],
-        Color::Red,
-    );
+    let mut total_cells = vec![
+        "TOTAL".to_string(),
+        format_number(totals.input_tokens),
+        format_number(totals.output_with_reasoning()),
+        format_number(totals.cache_read),
+        format_number(totals.cache_creation),
+        format_number(totals.total),
+        format!("${:.2}", totals.cost),
+    ];
+    if options.show_sessions {
+        total_cells.push(format_number(totals.sessions));
+    }
+    add_totals_row(&mut table, total_cells, Color::Red, leading_left(1));
 
     println!("{table}");
     println!();
@@ -160,6 +196,15 @@ pub fn display_usage_table(usage_data: &UsageData, merge: bool) {
         totals_table.add_row(vec![name_cell, tokens_cell, cost_cell]);
     }
 
+    let today_cost = cost_on_date(usage_data, &get_current_date(), pricing_map);
+    let thresholds = options.cost_thresholds;
+    let today_color = cost_color(today_cost, thresholds.low, thresholds.high).table_color();
+    totals_table.add_row(vec![
+        create_provider_cell("Today".to_string(), Color::White, false),
+        Cell::new(""),
+        create_metric_cell(format!("${today_cost:.2}"), today_color, true),
+    ]);
+
     println!("{totals_table}");
     println!();
 }
@@ -1,33 +1,42 @@
-//! Plain-text renderer for the usage view: one `model: $cost` line per model.
+//! Plain-text renderer for the usage view: one `model: $cost` line per model,
+//! or just the footer figures for `--summary-only`.
 
+use crate::display::usage::UsageRenderOptions;
 use crate::display::usage::averages::{build_usage_summary, merge_rows_by_base_model};
-use std::collections::HashMap;
-use vct_core::pricing::{ModelPricingMap, fetch_model_pricing};
+use vct_core::pricing::ModelPricingMap;
 use vct_core::usage::UsageData;
+use vct_core::usage::summary::UsageFooter;
+use vct_core::utils::{ModelNames, format_number};
 
 /// Prints token usage to stdout as one `model: $cost` line per model.
 ///
 /// Rows are ordered by ascending cost. Prints `No usage data found` when there
-/// is nothing to show. If pricing cannot be fetched, costs fall back to `$0.00`
-/// rather than failing. When `merge` is set, rows sharing a base model name
-/// across provider prefixes (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`) are
-/// collapsed into one.
-pub fn display_usage_text(usage_data: &UsageData, merge: bool) {
+/// is nothing to show. Costs come from `pricing_map`; an empty map (failed
+/// fetch) prices everything at `$0.00` rather than failing. When
+/// `options.merge` is set, rows sharing a base model name across provider
+/// prefixes (e.g. `openai/gpt-5.5` + `azure/gpt-5.5`) are collapsed into one;
+/// `options.show_sessions` appends `(N sessions)`, and `options.group_threshold`
+/// folds the models under that share of the total cost into one
+/// `Other (N models)` line. Models are named by `names`.
+pub fn display_usage_text(
+    usage_data: &UsageData,
+    pricing_map: &ModelPricingMap,
+    options: UsageRenderOptions,
+    names: &ModelNames,
+) {
     if usage_data.models.is_empty() {
         println!("No usage data found");
         return;
     }
 
-    // Fetch pricing data
-    let pricing_map =
-        fetch_model_pricing().unwrap_or_else(|_| ModelPricingMap::new(HashMap::new()));
-
     let mut summary = build_usage_summary(
         &usage_data.models,
         &usage_data.per_provider,
         &usage_data.provider_days,
-        &pricing_map,
+        pricing_map,
         &usage_data.stored_costs,
+        &usage_data.model_renames,
+        options.group_threshold,
     );
 
     if summary.rows.is_empty() {
@@ -35,11 +44,32 @@ pub fn display_usage_text(usage_data: &UsageData, merge: bool) {
         return;
     }
 
-    if merge {
+    summary.attach_session_counts(usage_data);
+    if options.merge {
         summary.rows = merge_rows_by_base_model(&summary.rows);
     }
 
     for row in &summary.rows {
-        println!("{}: ${:.6}", row.display_model, row.cost);
+        let label = row.model_label(options.fuzzy_hint_threshold, names);
+        if options.show_sessions {
+            println!("{}: ${:.6} ({} sessions)", label, row.cost, row.sessions);
+        } else {
+            println!("{}: ${:.6}", label, row.cost);
+        }
     }
 }
+
+/// Prints only the usage footer figures on one line:
+///
+/// ```text
+/// Total Cost: $12.34  Today: $0.56  Total Tokens: 1,234,567  Models: 5
+/// ```
+pub fn display_usage_footer(footer: &UsageFooter) {
+    println!(
+        "Total Cost: ${:.2}  Today: ${:.2}  Total Tokens: {}  Models: {}",
+        footer.total_cost,
+        footer.today_cost,
+        format_number(footer.total_tokens),
+        footer.models
+    );
+}